@@ -0,0 +1,70 @@
+// To run this, type:
+// cargo run --example ac-circuit-measures
+// Expected output:
+// Impedance: (10.0 - 0.4i) Ω = 10.0 Ω ∠ -2.4 deg
+// Current: (23.0 + 1.0i) A = 23.0 A ∠ 2.4 deg
+// Voltage across the inductor: (-29.9 + 721.3i) V = 721.9 V ∠ 92.4 deg
+
+measures::define_measure_types! {
+    with_directions exact with_complex,
+    scalar_properties [
+        ElectricPotential [
+            Volt {
+                suffix: " V",
+            }
+        ]
+        ElectricCurrent [
+            Ampere {
+                suffix: " A",
+            }
+        ]
+        ElectricalImpedance [
+            Ohm {
+                suffix: " \u{3a9}",
+            }
+        ]
+    ]
+    angle_measurement_units [
+        Degree {
+            suffix: " deg",
+            cycle_fraction: 360.,
+        }
+    ]
+    relationships [
+        Volt 1 == Ohm 1 * Ampere 1,
+    ]
+}
+
+// A resistor, an inductor and a capacitor in series, supplied by a sinusoidal voltage.
+fn main() {
+    let frequency = 50.; // Hz
+    let inductance = 0.1; // H
+    let capacitance = 1e-4; // F
+    let angular_frequency = 2. * std::f64::consts::PI * frequency;
+
+    let resistor = ComplexMeasure::<Ohm>::from(Measure::new(10.));
+    let inductor = ComplexMeasure::<Ohm>::new(0., angular_frequency * inductance);
+    let capacitor = ComplexMeasure::<Ohm>::new(0., -1. / (angular_frequency * capacitance));
+    let impedance = resistor + inductor + capacitor;
+
+    let supply_voltage =
+        ComplexMeasure::<Volt>::from_polar(Measure::new(230.), SignedDirection::<Degree>::new(0.));
+    let current: ComplexMeasure<Ampere> = supply_voltage / impedance;
+    let inductor_voltage: ComplexMeasure<Volt> = inductor * current;
+
+    println!(
+        "Impedance: {:.1} = {:.1}",
+        impedance,
+        impedance.polar_formatter::<Degree>()
+    );
+    println!(
+        "Current: {:.1} = {:.1}",
+        current,
+        current.polar_formatter::<Degree>()
+    );
+    println!(
+        "Voltage across the inductor: {:.1} = {:.1}",
+        inductor_voltage,
+        inductor_voltage.polar_formatter::<Degree>()
+    );
+}
@@ -106,6 +106,11 @@ measures::define_measure_types! {
 use std::f64::consts::PI;
 
 const SOLAR_MASS: Measure<Kilogram> = Measure::<Kilogram>::new(4.0 * PI * PI);
+// The benchmark uses astronomical units, years and solar masses divided by 4π²,
+// and so the gravitational constant is 1, instead of the SI value `GRAVITATIONAL_CONSTANT`
+// defined by the catalogue `measures::catalog::si`.
+const GRAVITATIONAL_CONSTANT: Measure<NewtonSquareMetrePerSquareKilogram> =
+    Measure::<NewtonSquareMetrePerSquareKilogram>::new(1.);
 const DPY: f64 = 365.24;
 
 pub struct Body {
@@ -193,14 +198,13 @@ pub fn offset_momentum(bodies: &mut [Body; N_BODIES]) {
 }
 
 pub fn energy(bodies: &[Body; N_BODIES]) -> Measure<Joule> {
-    let g = Measure::<NewtonSquareMetrePerSquareKilogram>::new(1.);
     let mut e = Measure::<Joule>::default();
     for i in 0..N_BODIES {
         let bi = &bodies[i];
         e += bi.mass * (bi.v * bi.v) * 0.5;
         for bj in &bodies[i + 1..] {
             let dx = bi.x - bj.x;
-            e -= g * (bi.mass * bj.mass / (dx * dx).sqrt());
+            e -= GRAVITATIONAL_CONSTANT * (bi.mass * bj.mass / (dx * dx).sqrt());
         }
     }
     e
@@ -225,13 +229,12 @@ pub fn advance(bodies: &mut [Body; N_BODIES], dt: Measure<Second>) {
         mag[i] = dt / (d1 * d1.sqrt());
     }
 
-    let g = Measure::<NewtonSquareMetrePerSquareKilogram>::new(1.);
     let mut i = 0;
     for j in 0..N_BODIES {
         for k in j + 1..N_BODIES {
             let f = r[i] * mag[i];
-            bodies[j].v -= f * bodies[k].mass * g;
-            bodies[k].v += f * bodies[j].mass * g;
+            bodies[j].v -= f * bodies[k].mass * GRAVITATIONAL_CONSTANT;
+            bodies[k].v += f * bodies[j].mass * GRAVITATIONAL_CONSTANT;
             i += 1
         }
     }
@@ -7,6 +7,10 @@ Expected output:
 S { position: at (178, 179) m }
 "{\"position\":[178.0,179.0,180.0]}"
 S { position: at (178, 179, 180) m }
+"{\"displacement\":[178.0,179.0,180.0,181.0,182.0]}"
+S { displacement: (178, 179, 180, 181, 182) m }
+"{\"position\":[178.0,179.0,180.0,181.0,182.0]}"
+S { position: at (178, 179, 180, 181, 182) m }
 "{\"direction\":-2.9203136}"
 S { direction: at -2.9203136 rad (in -180°..180°) }
 "{\"direction\":3.362863}"
@@ -33,11 +37,14 @@ S { position: at values=(278, 179, 180), covariances=
  ⎡ 0.1 0.2 0.3 ⎤ m
  ⎢ 0.4 0.5 0.6 ⎥
  ⎣ 0.7 0.8 0.9 ⎦ }
+"{\"name\":\"John Doe\",\"mass\":{\"value\":72.3,\"variance\":0.1,\"unit\":\"kg\"},\"height\":\"178 m\"}"
+Person { name: "John Doe", mass: [µ=72.3 σ²=0.1] kg, height: 178 m }
+unknown unit `lb`, expected one of the units `kg` at line 1 column 66
 */
 use serde::{Deserialize, Serialize};
 
 measures::define_measure_types! {
-    exact with_approx with_points with_directions with_2d with_3d with_serde,
+    exact with_approx with_points with_directions with_2d with_3d with_nd with_serde,
     scalar_properties [
         Mass [
             Kilogram {
@@ -152,6 +159,37 @@ fn use_measure_point_3d() {
     assert_eq!(s1, s2);
 }
 
+fn use_measure_n() {
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct S {
+        displacement: MeasureN<Centimetre, 5, f32>,
+    }
+    let s1 = S {
+        displacement: MeasureN::<Centimetre, 5, f32>::new([178., 179., 180., 181., 182.]),
+    };
+    let json = serde_json::to_string(&s1).unwrap();
+    println!("{json:#?}");
+    let s2 = serde_json::from_str::<S>(&json).unwrap();
+    println!("{s2:?}");
+    assert_eq!(s1, s2);
+    assert!(serde_json::from_str::<S>("{\"displacement\":[1.0,2.0]}").is_err());
+}
+
+fn use_measure_point_n() {
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct S {
+        position: MeasurePointN<Centimetre, 5, f32>,
+    }
+    let s1 = S {
+        position: MeasurePointN::<Centimetre, 5, f32>::new([178., 179., 180., 181., 182.]),
+    };
+    let json = serde_json::to_string(&s1).unwrap();
+    println!("{json:#?}");
+    let s2 = serde_json::from_str::<S>(&json).unwrap();
+    println!("{s2:?}");
+    assert_eq!(s1, s2);
+}
+
 fn use_signed_direction() {
     #[derive(Serialize, Deserialize, Debug, PartialEq)]
     struct S {
@@ -292,6 +330,33 @@ fn use_approx_measure_point_3d() {
     assert_eq!(s1, s2);
 }
 
+fn use_measures_with_unit() {
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Person {
+        name: String,
+        #[serde(with = "serde_tagged")]
+        mass: ApproxMeasure<Kilogram, f32>,
+        #[serde(with = "serde_text")]
+        height: Measure<Centimetre, f32>,
+    }
+    let p1 = Person {
+        name: "John Doe".to_string(),
+        mass: ApproxMeasure::<Kilogram, f32>::with_variance(72.3, 0.1),
+        height: Measure::<Centimetre, f32>::new(178.),
+    };
+
+    let json = serde_json::to_string(&p1).unwrap();
+    println!("{json:#?}");
+    let p2 = serde_json::from_str::<Person>(&json).unwrap();
+    println!("{p2:?}");
+    assert_eq!(p1, p2);
+    let error = serde_json::from_str::<Person>(
+        "{\"name\":\"John Doe\",\"mass\":{\"value\":72.3,\"variance\":0.1,\"unit\":\"lb\"},\"height\":\"178 m\"}",
+    )
+    .unwrap_err();
+    println!("{error}");
+}
+
 fn main() {
     use_measure();
     use_measure_2d();
@@ -299,6 +364,8 @@ fn main() {
     use_measure_point();
     use_measure_point_2d();
     use_measure_point_3d();
+    use_measure_n();
+    use_measure_point_n();
     use_signed_direction();
     use_unsigned_direction();
 
@@ -308,4 +375,6 @@ fn main() {
     use_approx_measure_point();
     use_approx_measure_point_2d();
     use_approx_measure_point_3d();
+
+    use_measures_with_unit();
 }
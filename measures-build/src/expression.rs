@@ -0,0 +1,145 @@
+//! Evaluation of the arithmetic expressions which can be written in place of numbers,
+//! like `"5 / 9"` or `"1 / tau"`.
+//! The operations are computed in `f64`, in the same order in which Rust computes them,
+//! so that the result is the same value which Rust would compute for the same expression.
+
+/// Evaluates an expression containing decimal numbers, the operators `+`, `-`, `*`, `/`,
+/// the parentheses, and the constants `pi`, `tau` and `e`.
+pub(crate) fn evaluate(text: &str) -> Result<f64, String> {
+    let mut parser = Parser {
+        text,
+        chars: text.char_indices().peekable(),
+    };
+    let value = parser.sum()?;
+    parser.skip_spaces();
+    match parser.chars.next() {
+        None => Ok(value),
+        Some((position, c)) => Err(parser.error(position, &format!("unexpected `{}`", c))),
+    }
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    chars: std::iter::Peekable<std::str::CharIndices<'a>>,
+}
+
+impl Parser<'_> {
+    fn error(&self, position: usize, message: &str) -> String {
+        format!(
+            "{} at position {} of the expression `{}`",
+            message,
+            position + 1,
+            self.text
+        )
+    }
+
+    fn skip_spaces(&mut self) {
+        while let Some(&(_, c)) = self.chars.peek() {
+            if !c.is_whitespace() {
+                break;
+            }
+            self.chars.next();
+        }
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_spaces();
+        self.chars.peek().map(|&(_, c)| c)
+    }
+
+    // sum := product (("+" | "-") product)*
+    fn sum(&mut self) -> Result<f64, String> {
+        let mut value = self.product()?;
+        loop {
+            match self.peek() {
+                Some('+') => {
+                    self.chars.next();
+                    value += self.product()?;
+                }
+                Some('-') => {
+                    self.chars.next();
+                    value -= self.product()?;
+                }
+                _ => return Ok(value),
+            }
+        }
+    }
+
+    // product := factor (("*" | "/") factor)*
+    fn product(&mut self) -> Result<f64, String> {
+        let mut value = self.factor()?;
+        loop {
+            match self.peek() {
+                Some('*') => {
+                    self.chars.next();
+                    value *= self.factor()?;
+                }
+                Some('/') => {
+                    self.chars.next();
+                    value /= self.factor()?;
+                }
+                _ => return Ok(value),
+            }
+        }
+    }
+
+    // factor := "-" factor | "(" sum ")" | number | constant
+    fn factor(&mut self) -> Result<f64, String> {
+        self.skip_spaces();
+        let (position, c) = match self.chars.peek() {
+            Some(&item) => item,
+            None => return Err(self.error(self.text.len(), "missing operand")),
+        };
+        if c == '-' {
+            self.chars.next();
+            Ok(-self.factor()?)
+        } else if c == '(' {
+            self.chars.next();
+            let value = self.sum()?;
+            match self.peek() {
+                Some(')') => {
+                    self.chars.next();
+                    Ok(value)
+                }
+                _ => Err(self.error(position, "unclosed parenthesis")),
+            }
+        } else if c.is_ascii_digit() || c == '.' {
+            let mut end = position;
+            let mut previous = ' ';
+            while let Some(&(i, c)) = self.chars.peek() {
+                let in_number = c.is_ascii_digit()
+                    || c == '.'
+                    || c == 'e'
+                    || c == 'E'
+                    || ((c == '+' || c == '-') && (previous == 'e' || previous == 'E'));
+                if !in_number {
+                    break;
+                }
+                previous = c;
+                end = i + c.len_utf8();
+                self.chars.next();
+            }
+            let literal = &self.text[position..end];
+            literal
+                .parse()
+                .map_err(|_| self.error(position, &format!("invalid number `{}`", literal)))
+        } else if c.is_ascii_alphabetic() {
+            let mut end = position;
+            while let Some(&(i, c)) = self.chars.peek() {
+                if !c.is_ascii_alphanumeric() && c != '_' {
+                    break;
+                }
+                end = i + c.len_utf8();
+                self.chars.next();
+            }
+            match &self.text[position..end] {
+                "pi" => Ok(core::f64::consts::PI),
+                "tau" => Ok(core::f64::consts::TAU),
+                "e" => Ok(core::f64::consts::E),
+                name => Err(self.error(position, &format!("unknown constant `{}`", name))),
+            }
+        } else {
+            Err(self.error(position, &format!("unexpected `{}`", c)))
+        }
+    }
+}
@@ -0,0 +1,154 @@
+//! A helper for the build scripts of the crates which use the macro `measures::define_measure_types!`.
+//!
+//! It reads a description of properties, units of measurement, angle units and relationships
+//! from a TOML or JSON file, validates it, and writes the equivalent invocation of the macro
+//! into a file of the directory `OUT_DIR`, to be included by the source code of the crate.
+//! So, the catalogue of units can be maintained by people who do not write Rust macros.
+//!
+//! A units file in TOML format looks like this:
+//!
+//! ```toml
+//! options = ["exact", "with_points"]
+//!
+//! [[scalar_properties]]
+//! name = "Temperature"
+//! units = [
+//!     { name = "Kelvin", suffix = " K" },
+//!     { name = "Celsius", suffix = " °C", offset = 273.15 },
+//!     { name = "Fahrenheit", suffix = " °F", ratio = "5 / 9", offset = "273.15 - 32 * 5 / 9" },
+//! ]
+//!
+//! [[vector_properties]]
+//! name = "Length"
+//! units = [
+//!     { name = "Metre", suffix = " m" },
+//!     { name = "Inch", suffix = " in", ratio = 0.0254 },
+//! ]
+//!
+//! [[angle_measurement_units]]
+//! name = "Degree"
+//! suffix = " deg"
+//! cycle_fraction = 360
+//!
+//! relationships = []
+//! ```
+//!
+//! Every number can also be written as a string containing an arithmetic expression,
+//! using the operators `+`, `-`, `*`, `/`, the parentheses and the constants `pi`, `tau` and `e`.
+//! The relationships are written with the syntax of the macro, like `"Joule 1 == Newton 1 * Metre 1"`.
+//! The JSON format has the same structure.
+//!
+//! The function `main` of the build script of the crate,
+//! having `measures-build` as a build dependency, contains:
+//!
+//! ```no_run
+//! if let Err(error) = measures_build::generate("units.toml", "units.rs") {
+//!     panic!("{}", error);
+//! }
+//! ```
+//!
+//! and the source code of the crate contains:
+//!
+//! ```ignore
+//! mod units {
+//!     include!(concat!(env!("OUT_DIR"), "/units.rs"));
+//! }
+//! ```
+
+mod expression;
+mod relationship;
+mod units_file;
+
+pub use relationship::{Operator, Relationship};
+pub use units_file::{AngleUnit, Number, Property, Unit, UnitsFile};
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// The options accepted by the macro `define_measure_types!`.
+pub const OPTIONS: &[&str] = &[
+    "exact",
+    "with_approx",
+    "with_correlation",
+    "with_points",
+    "with_directions",
+    "with_2d",
+    "with_3d",
+    "with_transformations",
+    "with_nd",
+    "with_complex",
+    "with_serde",
+    "with_nalgebra",
+];
+
+/// The error returned when a units file cannot be read, parsed, validated or written.
+#[derive(Debug)]
+pub enum Error {
+    /// The file could not be read or written.
+    Io(PathBuf, std::io::Error),
+    /// The file is not a valid TOML or JSON document, or it has not the expected structure.
+    Syntax(String),
+    /// The file is well formed, but its definitions are not valid, for the given reasons.
+    Invalid(Vec<String>),
+    /// The environment variable `OUT_DIR` is not defined, as the function is not called by a build script.
+    MissingOutDir,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(path, error) => {
+                write!(formatter, "cannot access `{}`: {}", path.display(), error)
+            }
+            Error::Syntax(message) => write!(formatter, "invalid units file: {}", message),
+            Error::Invalid(reasons) => {
+                formatter.write_str("invalid units file:")?;
+                for reason in reasons {
+                    write!(formatter, "\n- {}", reason)?;
+                }
+                Ok(())
+            }
+            Error::MissingOutDir => {
+                formatter.write_str("the environment variable `OUT_DIR` is not defined")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(_, error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Reads the units file `input`, in TOML format, or in JSON format if its extension is `json`,
+/// validates it, and writes the invocation of the macro `define_measure_types!`
+/// into the file named `output_file_name` in the directory `OUT_DIR`.
+/// It returns the path of the written file.
+/// It also tells Cargo to run again the build script when the units file changes.
+/// To be called by build scripts.
+pub fn generate(input: impl AsRef<Path>, output_file_name: &str) -> Result<PathBuf, Error> {
+    let input = input.as_ref();
+    println!("cargo:rerun-if-changed={}", input.display());
+    let out_dir = std::env::var_os("OUT_DIR").ok_or(Error::MissingOutDir)?;
+    let output = Path::new(&out_dir).join(output_file_name);
+    generate_to(input, &output)?;
+    Ok(output)
+}
+
+/// Reads the units file `input`, validates it,
+/// and writes the invocation of the macro `define_measure_types!` into the file `output`.
+/// It is like `generate`, but it does not use `OUT_DIR`, and so it can be used outside of build scripts.
+pub fn generate_to(input: &Path, output: &Path) -> Result<(), Error> {
+    let units_file = UnitsFile::read(input)?;
+    units_file.validate()?;
+    let code = format!(
+        "// Generated by `measures-build` from `{}`. Do not edit.\n\n{}",
+        input.display(),
+        units_file.to_macro_invocation()
+    );
+    std::fs::write(output, code).map_err(|error| Error::Io(output.to_path_buf(), error))
+}
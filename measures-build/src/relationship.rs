@@ -0,0 +1,138 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// The operator of a relationship.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operator {
+    /// `*`, the product of a scalar by a scalar or by a vector, or the dot product of two vectors.
+    Times,
+    /// `X`, the cross product of two vectors.
+    Cross,
+}
+
+/// A relationship among units, like `Joule 1 == Newton 1 * Metre 1`,
+/// meaning that the product of a measure in newtons by a measure in metres is a measure in joules,
+/// and the other derived operations.
+/// The numbers are the dimensions of the measures, which are 1, 2 or 3.
+/// If the second operand is `__`, it is the same unit of the first operand.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Relationship {
+    pub unit1: String,
+    pub dimensions1: u8,
+    pub unit2: String,
+    pub dimensions2: u8,
+    pub operator: Operator,
+    /// `None` for `__`.
+    pub unit3: Option<String>,
+    pub dimensions3: u8,
+}
+
+// The combinations of dimensions accepted by the macro,
+// as (dimensions1, dimensions2, operator, dimensions3, whether `__` is allowed).
+const SHAPES: &[(u8, u8, Operator, u8, bool)] = &[
+    (1, 1, Operator::Times, 1, true),
+    (2, 1, Operator::Times, 2, false),
+    (2, 2, Operator::Times, 1, false),
+    (3, 1, Operator::Times, 3, false),
+    (3, 3, Operator::Times, 1, false),
+    (1, 2, Operator::Times, 2, true),
+    (1, 3, Operator::Times, 3, true),
+    (1, 2, Operator::Cross, 2, true),
+    (3, 3, Operator::Cross, 3, true),
+];
+
+impl Relationship {
+    /// The unit of the second operand, which is the one of the first operand if it was `__`.
+    pub fn second_operand_unit(&self) -> &str {
+        self.unit3.as_deref().unwrap_or(&self.unit2)
+    }
+}
+
+impl FromStr for Relationship {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, String> {
+        let spaced = text.replace("==", " == ").replace('*', " * ");
+        let tokens: Vec<&str> = spaced.split_whitespace().collect();
+        let syntax_error = || {
+            format!(
+                "the relationship `{}` has not the form `Unit1 1 == Unit2 1 * Unit3 1`",
+                text
+            )
+        };
+        let (unit1, dimensions1, unit2, dimensions2, operator, unit3, dimensions3) = match tokens[..]
+        {
+            [unit1, dimensions1, "==", unit2, dimensions2, operator, unit3, dimensions3] => (
+                unit1,
+                dimensions1,
+                unit2,
+                dimensions2,
+                operator,
+                unit3,
+                dimensions3,
+            ),
+            _ => return Err(syntax_error()),
+        };
+        let dimensions = |token: &str| match token {
+            "1" => Ok(1),
+            "2" => Ok(2),
+            "3" => Ok(3),
+            _ => Err(syntax_error()),
+        };
+        let operator = match operator {
+            "*" => Operator::Times,
+            "X" => Operator::Cross,
+            _ => return Err(syntax_error()),
+        };
+        let relationship = Relationship {
+            unit1: unit1.to_string(),
+            dimensions1: dimensions(dimensions1)?,
+            unit2: unit2.to_string(),
+            dimensions2: dimensions(dimensions2)?,
+            operator,
+            unit3: if unit3 == "__" {
+                None
+            } else {
+                Some(unit3.to_string())
+            },
+            dimensions3: dimensions(dimensions3)?,
+        };
+        let shape_is_valid = SHAPES.iter().any(|&(d1, d2, op, d3, same_allowed)| {
+            (d1, d2, op, d3)
+                == (
+                    relationship.dimensions1,
+                    relationship.dimensions2,
+                    relationship.operator,
+                    relationship.dimensions3,
+                )
+                && (same_allowed || relationship.unit3.is_some())
+        });
+        if shape_is_valid {
+            Ok(relationship)
+        } else {
+            Err(format!(
+                "the relationship `{}` has a combination of dimensions and operator which is not supported",
+                text
+            ))
+        }
+    }
+}
+
+impl fmt::Display for Relationship {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "{} {} == {} {} {} {} {}",
+            self.unit1,
+            self.dimensions1,
+            self.unit2,
+            self.dimensions2,
+            match self.operator {
+                Operator::Times => "*",
+                Operator::Cross => "X",
+            },
+            self.unit3.as_deref().unwrap_or("__"),
+            self.dimensions3
+        )
+    }
+}
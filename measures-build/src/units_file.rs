@@ -0,0 +1,388 @@
+use crate::expression::evaluate;
+use crate::relationship::Relationship;
+use crate::{Error, OPTIONS};
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::ffi::OsStr;
+use std::fmt::Write;
+use std::path::Path;
+
+/// The contents of a units file.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UnitsFile {
+    /// The options of the macro, like `exact` or `with_points`.
+    #[serde(default)]
+    pub options: Vec<String>,
+    #[serde(default)]
+    pub scalar_properties: Vec<Property>,
+    #[serde(default)]
+    pub vector_properties: Vec<Property>,
+    #[serde(default)]
+    pub angle_measurement_units: Vec<AngleUnit>,
+    /// The relationships, with the syntax of the macro, like `Joule 1 == Newton 1 * Metre 1`.
+    #[serde(default)]
+    pub relationships: Vec<String>,
+}
+
+/// A property, with its units of measurement.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Property {
+    pub name: String,
+    pub units: Vec<Unit>,
+}
+
+/// A unit of measurement of a property.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Unit {
+    pub name: String,
+    /// The text printed after the value, like `" m"`.
+    pub suffix: String,
+    /// How many base units are in this unit. By default, 1.
+    #[serde(default)]
+    pub ratio: Option<Number>,
+    /// The base units which correspond to the zero of this unit. By default, 0.
+    /// Allowed only for scalar properties.
+    #[serde(default)]
+    pub offset: Option<Number>,
+}
+
+/// A unit of measurement of angles, in addition to the built-in `Radian`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AngleUnit {
+    pub name: String,
+    pub suffix: String,
+    /// How many of these units are in a full cycle, like 360 for degrees.
+    pub cycle_fraction: Number,
+}
+
+/// A number, written as a number or as a string containing an arithmetic expression, like `"5 / 9"`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum Number {
+    Value(f64),
+    Expression(String),
+}
+
+impl Number {
+    /// Number.evaluate() -> Result<f64, String>
+    /// It returns the value of the number or of the expression.
+    pub fn evaluate(&self) -> Result<f64, String> {
+        match self {
+            Number::Value(value) => Ok(*value),
+            Number::Expression(text) => evaluate(text),
+        }
+    }
+}
+
+// The built-in properties and units, defined by the crate `measures`.
+const BUILT_IN_PROPERTIES: &[&str] = &["Angle", "Dimensionless"];
+const BUILT_IN_SCALAR_UNITS: &[&str] = &["Radian"];
+const BUILT_IN_VECTOR_UNITS: &[&str] = &["One"];
+
+const KEYWORDS: &[&str] = &[
+    "Self", "abstract", "as", "async", "await", "become", "box", "break", "const", "continue",
+    "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl",
+    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    let first_is_valid = match chars.next() {
+        Some(c) => c.is_ascii_alphabetic() || c == '_',
+        None => false,
+    };
+    first_is_valid
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !name.chars().all(|c| c == '_')
+        && !KEYWORDS.contains(&name)
+}
+
+// The kind of the property of a unit.
+#[derive(Clone, Copy, PartialEq)]
+enum Kind {
+    Scalar,
+    Vector,
+}
+
+impl UnitsFile {
+    /// UnitsFile::from_toml(&str) -> Result<UnitsFile, Error>
+    pub fn from_toml(text: &str) -> Result<Self, Error> {
+        toml::from_str(text).map_err(|error| Error::Syntax(error.to_string()))
+    }
+
+    /// UnitsFile::from_json(&str) -> Result<UnitsFile, Error>
+    pub fn from_json(text: &str) -> Result<Self, Error> {
+        serde_json::from_str(text).map_err(|error| Error::Syntax(error.to_string()))
+    }
+
+    /// UnitsFile::read(&Path) -> Result<UnitsFile, Error>
+    /// It reads a file in JSON format if its extension is `json`, and in TOML format otherwise.
+    pub fn read(path: &Path) -> Result<Self, Error> {
+        let text =
+            std::fs::read_to_string(path).map_err(|error| Error::Io(path.to_path_buf(), error))?;
+        if path.extension() == Some(OsStr::new("json")) {
+            Self::from_json(&text)
+        } else {
+            Self::from_toml(&text)
+        }
+    }
+
+    /// UnitsFile.validate() -> Result<(), Error>
+    /// It checks that the options are known, that the names are valid Rust identifiers,
+    /// which are not repeated and do not clash with the built-in ones,
+    /// that the numbers are finite, that ratios and cycle fractions are positive,
+    /// that the offsets are used only for scalar properties,
+    /// and that the relationships have a supported form, use defined units,
+    /// use only units of vector properties as vectors, and are not repeated.
+    /// It reports all the problems found.
+    pub fn validate(&self) -> Result<(), Error> {
+        let mut reasons = Vec::new();
+
+        let mut options = HashSet::new();
+        for option in &self.options {
+            if !OPTIONS.contains(&option.as_str()) {
+                reasons.push(format!(
+                    "unknown option `{}`, expected one of `{}`",
+                    option,
+                    OPTIONS.join("`, `")
+                ));
+            } else if !options.insert(option.as_str()) {
+                reasons.push(format!("the option `{}` is repeated", option));
+            }
+        }
+
+        let mut property_names: HashSet<String> = BUILT_IN_PROPERTIES
+            .iter()
+            .map(|name| name.to_string())
+            .collect();
+        let mut unit_names: HashSet<String> = BUILT_IN_SCALAR_UNITS
+            .iter()
+            .chain(BUILT_IN_VECTOR_UNITS)
+            .map(|name| name.to_string())
+            .collect();
+        let mut unit_kinds: HashMap<&str, Kind> = BUILT_IN_SCALAR_UNITS
+            .iter()
+            .map(|&name| (name, Kind::Scalar))
+            .chain(
+                BUILT_IN_VECTOR_UNITS
+                    .iter()
+                    .map(|&name| (name, Kind::Vector)),
+            )
+            .collect();
+
+        let properties = self
+            .scalar_properties
+            .iter()
+            .map(|property| (property, Kind::Scalar))
+            .chain(
+                self.vector_properties
+                    .iter()
+                    .map(|property| (property, Kind::Vector)),
+            );
+        for (property, kind) in properties {
+            check_name(
+                &property.name,
+                "property",
+                &mut property_names,
+                &mut reasons,
+            );
+            if property.units.is_empty() {
+                reasons.push(format!("the property `{}` has no units", property.name));
+            }
+            for unit in &property.units {
+                check_name(&unit.name, "unit", &mut unit_names, &mut reasons);
+                unit_kinds.entry(&unit.name).or_insert(kind);
+                if let Some(ratio) = &unit.ratio {
+                    check_number(ratio, true, "ratio", &unit.name, &mut reasons);
+                }
+                if let Some(offset) = &unit.offset {
+                    if kind == Kind::Vector {
+                        reasons.push(format!(
+                            "the unit `{}` has an offset, but its property `{}` is a vector property",
+                            unit.name, property.name
+                        ));
+                    }
+                    check_number(offset, false, "offset", &unit.name, &mut reasons);
+                    if offset.evaluate() == Ok(0.) {
+                        reasons.push(format!(
+                            "the offset of the unit `{}` is zero, and so it must be omitted",
+                            unit.name
+                        ));
+                    }
+                }
+            }
+        }
+
+        for unit in &self.angle_measurement_units {
+            check_name(&unit.name, "unit", &mut unit_names, &mut reasons);
+            unit_kinds.entry(&unit.name).or_insert(Kind::Scalar);
+            check_number(
+                &unit.cycle_fraction,
+                true,
+                "cycle fraction",
+                &unit.name,
+                &mut reasons,
+            );
+        }
+
+        let mut relationships = HashSet::new();
+        for text in &self.relationships {
+            let relationship = match text.parse::<Relationship>() {
+                Ok(relationship) => relationship,
+                Err(reason) => {
+                    reasons.push(reason);
+                    continue;
+                }
+            };
+            let operands = [
+                (relationship.unit1.as_str(), relationship.dimensions1),
+                (relationship.unit2.as_str(), relationship.dimensions2),
+                (relationship.second_operand_unit(), relationship.dimensions3),
+            ];
+            for &(unit, dimensions) in &operands {
+                match unit_kinds.get(unit) {
+                    None => reasons.push(format!(
+                        "the relationship `{}` uses the undefined unit `{}`",
+                        text, unit
+                    )),
+                    Some(Kind::Scalar) if dimensions > 1 => reasons.push(format!(
+                        "the relationship `{}` uses the unit `{}` with {} dimensions, but its property is a scalar property",
+                        text, unit, dimensions
+                    )),
+                    _ => {}
+                }
+            }
+            for &(dimensions, option) in &[(2, "with_2d"), (3, "with_3d")] {
+                let uses_dimensions = operands.iter().any(|&(_, d)| d == dimensions);
+                if uses_dimensions && !options.contains(option) {
+                    reasons.push(format!(
+                        "the relationship `{}` uses measures with {} dimensions, but the option `{}` is missing",
+                        text, dimensions, option
+                    ));
+                }
+            }
+            if !relationships.insert(relationship) {
+                reasons.push(format!("the relationship `{}` is repeated", text));
+            }
+        }
+
+        if reasons.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Invalid(reasons))
+        }
+    }
+
+    /// UnitsFile.to_macro_invocation() -> String
+    /// It returns the equivalent invocation of the macro `define_measure_types!`,
+    /// in which the expressions are replaced by their values.
+    /// Precondition: the units file is valid.
+    pub fn to_macro_invocation(&self) -> String {
+        let mut code = String::new();
+        code += "measures::define_measure_types! {\n";
+        let _ = writeln!(code, "    {},", self.options.join(" "));
+        for (section, properties) in [
+            ("scalar_properties", &self.scalar_properties),
+            ("vector_properties", &self.vector_properties),
+        ]
+        .iter()
+        {
+            if properties.is_empty() {
+                continue;
+            }
+            let _ = writeln!(code, "    {} [", section);
+            for property in properties.iter() {
+                let _ = writeln!(code, "        {} [", property.name);
+                for unit in &property.units {
+                    let _ = writeln!(code, "            {} {{", unit.name);
+                    let _ = writeln!(code, "                suffix: {:?},", unit.suffix);
+                    if let Some(ratio) = &unit.ratio {
+                        let _ = writeln!(code, "                ratio: {},", literal(ratio));
+                    }
+                    if let Some(offset) = &unit.offset {
+                        let _ = writeln!(code, "                offset: {},", literal(offset));
+                    }
+                    code += "            }\n";
+                }
+                code += "        ]\n";
+            }
+            code += "    ]\n";
+        }
+        if !self.angle_measurement_units.is_empty() {
+            code += "    angle_measurement_units [\n";
+            for unit in &self.angle_measurement_units {
+                let _ = writeln!(code, "        {} {{", unit.name);
+                let _ = writeln!(code, "            suffix: {:?},", unit.suffix);
+                let _ = writeln!(
+                    code,
+                    "            cycle_fraction: {},",
+                    literal(&unit.cycle_fraction)
+                );
+                code += "        }\n";
+            }
+            code += "    ]\n";
+        }
+        if !self.relationships.is_empty() {
+            code += "    relationships [\n";
+            for text in &self.relationships {
+                if let Ok(relationship) = text.parse::<Relationship>() {
+                    let _ = writeln!(code, "        {},", relationship);
+                }
+            }
+            code += "    ]\n";
+        }
+        code += "}\n";
+        code
+    }
+}
+
+// It reports a name which is not a valid identifier, or which is already in `names`.
+fn check_name(name: &str, what: &str, names: &mut HashSet<String>, reasons: &mut Vec<String>) {
+    if !is_identifier(name) {
+        reasons.push(format!(
+            "the {} name `{}` is not a valid identifier",
+            what, name
+        ));
+    } else if !names.insert(name.to_string()) {
+        reasons.push(format!(
+            "the {} name `{}` is defined more than once",
+            what, name
+        ));
+    }
+}
+
+// It reports a number which cannot be evaluated, which is not finite,
+// or, if it must be positive, which is not positive.
+fn check_number(
+    number: &Number,
+    must_be_positive: bool,
+    what: &str,
+    unit: &str,
+    reasons: &mut Vec<String>,
+) {
+    match number.evaluate() {
+        Err(reason) => reasons.push(format!(
+            "the {} of the unit `{}` is not valid: {}",
+            what, unit, reason
+        )),
+        Ok(value) if !value.is_finite() => {
+            reasons.push(format!("the {} of the unit `{}` is not finite", what, unit))
+        }
+        Ok(value) if must_be_positive && value <= 0. => reasons.push(format!(
+            "the {} of the unit `{}` is not positive",
+            what, unit
+        )),
+        Ok(_) => {}
+    }
+}
+
+// A Rust literal of type `f64` having exactly the value of the number.
+fn literal(number: &Number) -> String {
+    format!("{:?}", number.evaluate().unwrap_or(f64::NAN))
+}
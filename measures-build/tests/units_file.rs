@@ -0,0 +1,365 @@
+use measures_build::{Error, Number, Operator, Relationship, UnitsFile};
+
+const TOML: &str = r#"
+options = ["exact", "with_points", "with_2d"]
+relationships = [
+    "Metre 1 == MetrePerSecond 1 * Second 1",
+    "Metre 2 == MetrePerSecond 2 * Second 1",
+]
+
+[[scalar_properties]]
+name = "Temperature"
+units = [
+    { name = "Kelvin", suffix = " K" },
+    { name = "Fahrenheit", suffix = " °F", ratio = "5 / 9", offset = "273.15 - 32 * 5 / 9" },
+]
+
+[[scalar_properties]]
+name = "Time"
+units = [{ name = "Second", suffix = " s" }]
+
+[[vector_properties]]
+name = "Length"
+units = [
+    { name = "Metre", suffix = " m" },
+    { name = "Inch", suffix = " in", ratio = 0.0254 },
+]
+
+[[vector_properties]]
+name = "Velocity"
+units = [{ name = "MetrePerSecond", suffix = " m/s" }]
+
+[[angle_measurement_units]]
+name = "Degree"
+suffix = " deg\""
+cycle_fraction = 360
+"#;
+
+const JSON: &str = r#"{
+    "options": ["exact", "with_points", "with_2d"],
+    "scalar_properties": [
+        {
+            "name": "Temperature",
+            "units": [
+                { "name": "Kelvin", "suffix": " K" },
+                { "name": "Fahrenheit", "suffix": " °F", "ratio": "5 / 9", "offset": "273.15 - 32 * 5 / 9" }
+            ]
+        },
+        { "name": "Time", "units": [{ "name": "Second", "suffix": " s" }] }
+    ],
+    "vector_properties": [
+        {
+            "name": "Length",
+            "units": [
+                { "name": "Metre", "suffix": " m" },
+                { "name": "Inch", "suffix": " in", "ratio": 0.0254 }
+            ]
+        },
+        { "name": "Velocity", "units": [{ "name": "MetrePerSecond", "suffix": " m/s" }] }
+    ],
+    "angle_measurement_units": [{ "name": "Degree", "suffix": " deg\"", "cycle_fraction": 360 }],
+    "relationships": [
+        "Metre 1 == MetrePerSecond 1 * Second 1",
+        "Metre 2 == MetrePerSecond 2 * Second 1"
+    ]
+}"#;
+
+fn reasons(units_file: &UnitsFile) -> Vec<String> {
+    match units_file.validate() {
+        Ok(()) => Vec::new(),
+        Err(Error::Invalid(reasons)) => reasons,
+        Err(error) => panic!("unexpected error: {}", error),
+    }
+}
+
+#[test]
+fn generate_macro_invocation() {
+    let units_file = UnitsFile::from_toml(TOML).unwrap();
+    units_file.validate().unwrap();
+    assert_eq!(
+        units_file.to_macro_invocation(),
+        r#"measures::define_measure_types! {
+    exact with_points with_2d,
+    scalar_properties [
+        Temperature [
+            Kelvin {
+                suffix: " K",
+            }
+            Fahrenheit {
+                suffix: " °F",
+                ratio: 0.5555555555555556,
+                offset: 255.3722222222222,
+            }
+        ]
+        Time [
+            Second {
+                suffix: " s",
+            }
+        ]
+    ]
+    vector_properties [
+        Length [
+            Metre {
+                suffix: " m",
+            }
+            Inch {
+                suffix: " in",
+                ratio: 0.0254,
+            }
+        ]
+        Velocity [
+            MetrePerSecond {
+                suffix: " m/s",
+            }
+        ]
+    ]
+    angle_measurement_units [
+        Degree {
+            suffix: " deg\"",
+            cycle_fraction: 360.0,
+        }
+    ]
+    relationships [
+        Metre 1 == MetrePerSecond 1 * Second 1,
+        Metre 2 == MetrePerSecond 2 * Second 1,
+    ]
+}
+"#
+    );
+}
+
+#[test]
+fn json_is_equivalent_to_toml() {
+    let from_json = UnitsFile::from_json(JSON).unwrap();
+    assert_eq!(
+        from_json.to_macro_invocation(),
+        UnitsFile::from_toml(TOML).unwrap().to_macro_invocation()
+    );
+}
+
+#[test]
+fn generate_to_file() {
+    let directory = std::env::temp_dir().join(format!("measures-build-{}", std::process::id()));
+    std::fs::create_dir_all(&directory).unwrap();
+    let input = directory.join("units.json");
+    let output = directory.join("units.rs");
+    std::fs::write(&input, JSON).unwrap();
+    measures_build::generate_to(&input, &output).unwrap();
+    let code = std::fs::read_to_string(&output).unwrap();
+    assert!(code.starts_with("// Generated by `measures-build` from `"));
+    assert!(code.ends_with(&UnitsFile::from_json(JSON).unwrap().to_macro_invocation()));
+
+    let error = measures_build::generate_to(&directory.join("missing.toml"), &output).unwrap_err();
+    assert!(matches!(error, Error::Io(..)));
+    std::fs::remove_dir_all(&directory).unwrap();
+}
+
+#[test]
+fn expressions() {
+    let evaluate = |text: &str| Number::Expression(text.to_string()).evaluate();
+    assert_eq!(evaluate("273.15 - 32 * 5 / 9"), Ok(273.15 - 32. * 5. / 9.));
+    assert_eq!(evaluate("1. / tau"), Ok(1. / core::f64::consts::TAU));
+    assert_eq!(evaluate(" -(2 + 3) * 1e-3 "), Ok(-(2. + 3.) * 1e-3));
+    assert_eq!(
+        evaluate("pi * e"),
+        Ok(core::f64::consts::PI * core::f64::consts::E)
+    );
+    assert_eq!(evaluate("6.0221413E+23"), Ok(6.0221413e23));
+    assert_eq!(
+        evaluate("2 * (3 + 4"),
+        Err("unclosed parenthesis at position 5 of the expression `2 * (3 + 4`".to_string())
+    );
+    assert_eq!(
+        evaluate("2 * inch"),
+        Err("unknown constant `inch` at position 5 of the expression `2 * inch`".to_string())
+    );
+    assert_eq!(
+        evaluate("2 3"),
+        Err("unexpected `3` at position 3 of the expression `2 3`".to_string())
+    );
+    assert!(evaluate("2 *").is_err());
+}
+
+#[test]
+fn relationships() {
+    let relationship: Relationship = "NewtonMetre 3 == Newton 3 X Metre 3".parse().unwrap();
+    assert_eq!(relationship.operator, Operator::Cross);
+    assert_eq!(relationship.second_operand_unit(), "Metre");
+    let relationship: Relationship = "SquareMetre 1==Metre 1*__ 1".parse().unwrap();
+    assert_eq!(relationship.unit3, None);
+    assert_eq!(relationship.second_operand_unit(), "Metre");
+    assert_eq!(relationship.to_string(), "SquareMetre 1 == Metre 1 * __ 1");
+
+    assert!("Joule 1 == Newton 1 / Metre 1"
+        .parse::<Relationship>()
+        .unwrap_err()
+        .contains("has not the form"));
+    assert!("Joule 4 == Newton 1 * Metre 1"
+        .parse::<Relationship>()
+        .unwrap_err()
+        .contains("has not the form"));
+    assert!("Joule 2 == Newton 2 * Metre 2"
+        .parse::<Relationship>()
+        .unwrap_err()
+        .contains("is not supported"));
+    assert!("Metre 2 == MetrePerSecond 2 * __ 1"
+        .parse::<Relationship>()
+        .unwrap_err()
+        .contains("is not supported"));
+}
+
+#[test]
+fn validation_errors() {
+    let units_file = UnitsFile::from_toml(
+        r#"
+options = ["exact", "with_foo", "exact"]
+relationships = [
+    "Metre 1 == MetrePerSecond 1 * Second 1",
+    "Metre 1 == MetrePerSecond 1 * Second 1",
+    "Second 2 == Hertz 2 * One 1",
+    "Furlong 1 == Metre 1 * __ 1",
+    "Metre 3 == MetrePerSecond 3 * Second 1",
+]
+
+[[scalar_properties]]
+name = "Time"
+units = [
+    { name = "Second", suffix = " s", ratio = 0 },
+    { name = "Minute", suffix = " min", ratio = "60 / 0" },
+    { name = "Hour", suffix = " h", ratio = 3600, offset = 0 },
+]
+
+[[scalar_properties]]
+name = "Angle"
+units = []
+
+[[vector_properties]]
+name = "Length"
+units = [
+    { name = "Metre", suffix = " m", offset = 1 },
+    { name = "Second", suffix = " s" },
+    { name = "2Metre", suffix = " 2m" },
+]
+
+[[vector_properties]]
+name = "Velocity"
+units = [{ name = "MetrePerSecond", suffix = " m/s", ratio = "one" }]
+
+[[angle_measurement_units]]
+name = "Degree"
+suffix = " deg"
+cycle_fraction = -360
+"#,
+    )
+    .unwrap();
+    assert_eq!(
+        reasons(&units_file),
+        [
+            "unknown option `with_foo`, expected one of `exact`, `with_approx`, `with_correlation`, `with_points`, `with_directions`, `with_2d`, `with_3d`, `with_transformations`, `with_nd`, `with_complex`, `with_serde`, `with_nalgebra`",
+            "the option `exact` is repeated",
+            "the ratio of the unit `Second` is not positive",
+            "the ratio of the unit `Minute` is not finite",
+            "the offset of the unit `Hour` is zero, and so it must be omitted",
+            "the property name `Angle` is defined more than once",
+            "the property `Angle` has no units",
+            "the unit `Metre` has an offset, but its property `Length` is a vector property",
+            "the unit name `Second` is defined more than once",
+            "the unit name `2Metre` is not a valid identifier",
+            "the ratio of the unit `MetrePerSecond` is not valid: unknown constant `one` at position 1 of the expression `one`",
+            "the cycle fraction of the unit `Degree` is not positive",
+            "the relationship `Metre 1 == MetrePerSecond 1 * Second 1` is repeated",
+            "the relationship `Second 2 == Hertz 2 * One 1` uses the unit `Second` with 2 dimensions, but its property is a scalar property",
+            "the relationship `Second 2 == Hertz 2 * One 1` uses the undefined unit `Hertz`",
+            "the relationship `Second 2 == Hertz 2 * One 1` uses measures with 2 dimensions, but the option `with_2d` is missing",
+            "the relationship `Furlong 1 == Metre 1 * __ 1` uses the undefined unit `Furlong`",
+            "the relationship `Metre 3 == MetrePerSecond 3 * Second 1` uses measures with 3 dimensions, but the option `with_3d` is missing",
+        ]
+    );
+}
+
+#[test]
+fn syntax_errors() {
+    let error = UnitsFile::from_toml("options = [\"exact\"]\nunits = []\n").unwrap_err();
+    assert!(error.to_string().contains("unknown field `units`"));
+    let error =
+        UnitsFile::from_json(r#"{"angle_measurement_units":[{"name":"Degree","suffix":" deg"}]}"#)
+            .unwrap_err();
+    assert!(error.to_string().contains("missing field `cycle_fraction`"));
+}
+
+// The units file of the examples must define the same units and relationships of `examples/units/mod.rs`.
+#[test]
+fn examples_units_file_matches_module() {
+    let directory = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../examples/units");
+    let units_file = UnitsFile::read(&directory.join("units.toml")).unwrap();
+    units_file.validate().unwrap();
+    let module = std::fs::read_to_string(directory.join("mod.rs")).unwrap();
+
+    // The names, suffixes and values of the units, and the relationships, in the order of the module.
+    let mut expected = Vec::new();
+    let mut in_relationships = false;
+    for line in module.lines().map(str::trim) {
+        let line = line.split(" //").next().unwrap();
+        if line == "relationships [" {
+            in_relationships = true;
+        } else if in_relationships {
+            if line == "]" {
+                break;
+            }
+            if !line.is_empty() && !line.starts_with("//") {
+                expected.push(line.trim_end_matches(',').to_string());
+            }
+        } else if line.contains('!') {
+            continue;
+        } else if let Some(name) = line.strip_suffix(" {") {
+            expected.push(name.to_string());
+        } else if let Some(suffix) = line.strip_prefix("suffix: ") {
+            let suffix = suffix.trim_end_matches(',');
+            let suffix = suffix[1..suffix.len() - 1].replace("\\\"", "\"");
+            let mut text = String::new();
+            let mut rest = suffix.as_str();
+            while let Some(start) = rest.find("\\u{") {
+                text += &rest[..start];
+                let end = start + rest[start..].find('}').unwrap();
+                let code = u32::from_str_radix(&rest[start + 3..end], 16).unwrap();
+                text.push(std::char::from_u32(code).unwrap());
+                rest = &rest[end + 1..];
+            }
+            text += rest;
+            expected.push(text);
+        } else {
+            for key in &["ratio: ", "offset: ", "cycle_fraction: "] {
+                if let Some(value) = line.strip_prefix(key) {
+                    let expression = value
+                        .trim_end_matches(',')
+                        .replace("core::f64::consts::TAU", "tau")
+                        .replace("core::f64::consts::PI", "pi");
+                    let value = Number::Expression(expression).evaluate().unwrap();
+                    expected.push(format!("{:?}", value));
+                }
+            }
+        }
+    }
+
+    let mut actual = Vec::new();
+    let value = |number: &Number| format!("{:?}", number.evaluate().unwrap());
+    for property in units_file
+        .scalar_properties
+        .iter()
+        .chain(&units_file.vector_properties)
+    {
+        for unit in &property.units {
+            actual.push(unit.name.clone());
+            actual.push(unit.suffix.clone());
+            actual.extend(unit.ratio.iter().map(value));
+            actual.extend(unit.offset.iter().map(value));
+        }
+    }
+    for unit in &units_file.angle_measurement_units {
+        actual.push(unit.name.clone());
+        actual.push(unit.suffix.clone());
+        actual.push(value(&unit.cycle_fraction));
+    }
+    actual.extend(units_file.relationships.iter().cloned());
+    assert_eq!(actual, expected);
+}
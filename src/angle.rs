@@ -1,5 +1,5 @@
 use crate::measurement_scalar_property;
-use crate::traits::{AngleMeasurementUnit, MeasurementUnit};
+use crate::traits::{AngleMeasurementUnit, LinearMeasurementUnit, MeasurementUnit};
 
 // A built-in scalar measure property.
 
@@ -18,3 +18,5 @@ impl MeasurementUnit for Radian {
 impl AngleMeasurementUnit for Radian {
     const CYCLE_FRACTION: f64 = core::f64::consts::TAU;
 }
+
+impl LinearMeasurementUnit for Radian {}
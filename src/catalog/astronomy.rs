@@ -0,0 +1,190 @@
+//! The catalogue of the astronomical units, added to the properties of the SI catalogue.
+//! The astronomical unit and the parsec have the exact values defined by the IAU in 2012 and 2015,
+//! and the solar and planetary units have the nominal values of the IAU 2015 resolution B3.
+//! The solar and planetary masses are their nominal mass parameters
+//! divided by the CODATA 2018 gravitational constant.
+//!
+//! The macro `define_measure_types!` of this module defines the types of the SI catalogue,
+//! the constants `NOMINAL_SOLAR_MASS_PARAMETER`, `NOMINAL_TERRESTRIAL_MASS_PARAMETER`
+//! and `NOMINAL_JOVIAN_MASS_PARAMETER`, and the following units:
+//!
+//! * `Angle`: `Milliarcsecond`.
+//! * `Length`: `AstronomicalUnit`, `LightYear`, `Parsec`, `SolarRadius`, `EarthRadius`.
+//! * `Mass`: `SolarMass`, `EarthMass`, `JupiterMass`.
+//! * `Power`: `SolarLuminosity`.
+//! * `Time`: `JulianYear`.
+//! * `Velocity`: `LightSpeed`.
+
+pub use crate::catalog_astronomy_define_measure_types as define_measure_types;
+
+/// Defines the measure types of the SI catalogue and of the astronomy catalogue, and the ones given as argument.
+#[macro_export]
+macro_rules! catalog_astronomy_define_measure_types {
+    ( $( $tokens:tt )* ) => {
+        $crate::catalog_define_measure_types! { catalogues [astronomy] $( $tokens )* }
+    };
+}
+
+#[macro_export]
+macro_rules! inner_catalog_astronomy {
+    {
+        $catalogues:tt $options:tt
+        scalar_slots {
+            Area [ $( $area:tt )* ]
+            Capacitance [ $( $capacitance:tt )* ]
+            DynamicViscosity [ $( $dynamic_viscosity:tt )* ]
+            ElectricCharge [ $( $electric_charge:tt )* ]
+            ElectricCurrent [ $( $electric_current:tt )* ]
+            ElectricPotential [ $( $electric_potential:tt )* ]
+            ElectricalConductance [ $( $electrical_conductance:tt )* ]
+            ElectricalResistance [ $( $electrical_resistance:tt )* ]
+            Energy [ $( $energy:tt )* ]
+            Illuminance [ $( $illuminance:tt )* ]
+            Inductance [ $( $inductance:tt )* ]
+            KinematicViscosity [ $( $kinematic_viscosity:tt )* ]
+            Luminance [ $( $luminance:tt )* ]
+            MagneticFlux [ $( $magnetic_flux:tt )* ]
+            Mass [ $( $mass:tt )* ]
+            Power [ $( $power:tt )* ]
+            Pressure [ $( $pressure:tt )* ]
+            Temperature [ $( $temperature:tt )* ]
+            Time [ $( $time:tt )* ]
+            Volume [ $( $volume:tt )* ]
+        }
+        vector_slots {
+            Acceleration [ $( $acceleration:tt )* ]
+            Force [ $( $force:tt )* ]
+            Length [ $( $length:tt )* ]
+            MagneticFluxDensity [ $( $magnetic_flux_density:tt )* ]
+            Momentum [ $( $momentum:tt )* ]
+            Torque [ $( $torque:tt )* ]
+            Velocity [ $( $velocity:tt )* ]
+        }
+        scalar_properties [ $( $scalar_property:tt )* ]
+        vector_properties [ $( $vector_property:tt )* ]
+        angle_measurement_units [ $( $angle_unit:tt )* ]
+        relationships [ $( $relationship:tt )* ]
+        relationships_2d [ $( $relationship_2d:tt )* ]
+        relationships_3d [ $( $relationship_3d:tt )* ]
+    } => {
+        /// The nominal solar mass parameter, GM☉, exact by the IAU 2015 resolution B3.
+        pub const NOMINAL_SOLAR_MASS_PARAMETER: Measure<CubicMetrePerSquareSecond, f64> =
+            Measure::new(1.327_124_4e20);
+        /// The nominal terrestrial mass parameter, GM⊕, exact by the IAU 2015 resolution B3.
+        pub const NOMINAL_TERRESTRIAL_MASS_PARAMETER: Measure<CubicMetrePerSquareSecond, f64> =
+            Measure::new(3.986_004e14);
+        /// The nominal Jovian mass parameter, GMJ, exact by the IAU 2015 resolution B3.
+        pub const NOMINAL_JOVIAN_MASS_PARAMETER: Measure<CubicMetrePerSquareSecond, f64> =
+            Measure::new(1.266_865_3e17);
+
+        $crate::inner_catalog_next! {
+            $catalogues $options
+            scalar_slots {
+                Area [ $( $area )* ]
+                Capacitance [ $( $capacitance )* ]
+                DynamicViscosity [ $( $dynamic_viscosity )* ]
+                ElectricCharge [ $( $electric_charge )* ]
+                ElectricCurrent [ $( $electric_current )* ]
+                ElectricPotential [ $( $electric_potential )* ]
+                ElectricalConductance [ $( $electrical_conductance )* ]
+                ElectricalResistance [ $( $electrical_resistance )* ]
+                Energy [ $( $energy )* ]
+                Illuminance [ $( $illuminance )* ]
+                Inductance [ $( $inductance )* ]
+                KinematicViscosity [ $( $kinematic_viscosity )* ]
+                Luminance [ $( $luminance )* ]
+                MagneticFlux [ $( $magnetic_flux )* ]
+                Mass [
+                    $( $mass )*
+                    SolarMass {
+                        suffix: " M\u{2609}", // M☉
+                        ratio: 1.988409870698051e30,
+                    }
+                    EarthMass {
+                        suffix: " M\u{2295}", // M⊕
+                        ratio: 5.972167867791379e24,
+                    }
+                    JupiterMass {
+                        suffix: " MJ",
+                        ratio: 1.8981245973360505e27,
+                    }
+                ]
+                Power [
+                    $( $power )*
+                    SolarLuminosity {
+                        suffix: " L\u{2609}", // L☉
+                        ratio: 3.828e26,
+                    }
+                ]
+                Pressure [ $( $pressure )* ]
+                Temperature [ $( $temperature )* ]
+                Time [
+                    $( $time )*
+                    JulianYear {
+                        suffix: " a",
+                        ratio: 365.25 * 86400.,
+                    }
+                ]
+                Volume [ $( $volume )* ]
+            }
+            vector_slots {
+                Acceleration [ $( $acceleration )* ]
+                Force [ $( $force )* ]
+                Length [
+                    $( $length )*
+                    AstronomicalUnit {
+                        suffix: " au",
+                        ratio: 149597870700.,
+                    }
+                    LightYear {
+                        suffix: " ly",
+                        ratio: 31557600. * 2.99792458e8,
+                    }
+                    Parsec {
+                        suffix: " pc",
+                        ratio: 149597870700. * 648000. / core::f64::consts::PI,
+                    }
+                    SolarRadius {
+                        suffix: " R\u{2609}", // R☉
+                        ratio: 6.957e8,
+                    }
+                    EarthRadius {
+                        suffix: " R\u{2295}", // R⊕
+                        ratio: 6.3781e6,
+                    }
+                ]
+                MagneticFluxDensity [ $( $magnetic_flux_density )* ]
+                Momentum [ $( $momentum )* ]
+                Torque [ $( $torque )* ]
+                Velocity [
+                    $( $velocity )*
+                    LightSpeed {
+                        suffix: " c",
+                        ratio: 299792458.,
+                    }
+                ]
+            }
+            scalar_properties [ $( $scalar_property )* ]
+            vector_properties [ $( $vector_property )* ]
+            angle_measurement_units [
+                $( $angle_unit )*
+                Milliarcsecond {
+                    suffix: " mas",
+                    cycle_fraction: 360. * 3600. * 1000.,
+                }
+            ]
+            relationships [
+                $( $relationship )*
+                LightYear 1 == LightSpeed 1 * JulianYear 1,
+            ]
+            relationships_2d [
+                $( $relationship_2d )*
+                LightYear 2 == LightSpeed 2 * JulianYear 1,
+            ]
+            relationships_3d [
+                $( $relationship_3d )*
+                LightYear 3 == LightSpeed 3 * JulianYear 1,
+            ]
+        }
+    };
+}
@@ -0,0 +1,244 @@
+//! The catalogue of the mechanical units of the centimetre-gram-second system,
+//! added to the properties of the SI catalogue.
+//! The electromagnetic units of the CGS system are not included, except the gauss and the maxwell,
+//! as they have different dimensions in the Gaussian system.
+//!
+//! The macro `define_measure_types!` of this module defines the types of the SI catalogue,
+//! and the following units:
+//!
+//! * `Acceleration`: `CentimetrePerSquareSecond`.
+//! * `DynamicViscosity`: `Poise`, `Centipoise`.
+//! * `Energy`: `Erg`.
+//! * `Force`: `Dyne`.
+//! * `Illuminance`: `Phot`.
+//! * `KinematicViscosity`: `Stoke`, `Centistoke`.
+//! * `Luminance`: `Stilb`.
+//! * `MagneticFlux`: `Maxwell`.
+//! * `MagneticFluxDensity`: `Gauss`.
+//! * `Momentum`: `DyneSecond`, `GramCentimetrePerSecond`.
+//! * `Power`: `ErgPerSecond`.
+//! * `Pressure`: `Barye`.
+//! * `Velocity`: `CentimetrePerSecond`.
+
+pub use crate::catalog_cgs_define_measure_types as define_measure_types;
+
+/// Defines the measure types of the SI catalogue and of the cgs catalogue, and the ones given as argument.
+#[macro_export]
+macro_rules! catalog_cgs_define_measure_types {
+    ( $( $tokens:tt )* ) => {
+        $crate::catalog_define_measure_types! { catalogues [cgs] $( $tokens )* }
+    };
+}
+
+#[macro_export]
+macro_rules! inner_catalog_cgs {
+    {
+        $catalogues:tt $options:tt
+        scalar_slots {
+            Area [ $( $area:tt )* ]
+            Capacitance [ $( $capacitance:tt )* ]
+            DynamicViscosity [ $( $dynamic_viscosity:tt )* ]
+            ElectricCharge [ $( $electric_charge:tt )* ]
+            ElectricCurrent [ $( $electric_current:tt )* ]
+            ElectricPotential [ $( $electric_potential:tt )* ]
+            ElectricalConductance [ $( $electrical_conductance:tt )* ]
+            ElectricalResistance [ $( $electrical_resistance:tt )* ]
+            Energy [ $( $energy:tt )* ]
+            Illuminance [ $( $illuminance:tt )* ]
+            Inductance [ $( $inductance:tt )* ]
+            KinematicViscosity [ $( $kinematic_viscosity:tt )* ]
+            Luminance [ $( $luminance:tt )* ]
+            MagneticFlux [ $( $magnetic_flux:tt )* ]
+            Mass [ $( $mass:tt )* ]
+            Power [ $( $power:tt )* ]
+            Pressure [ $( $pressure:tt )* ]
+            Temperature [ $( $temperature:tt )* ]
+            Time [ $( $time:tt )* ]
+            Volume [ $( $volume:tt )* ]
+        }
+        vector_slots {
+            Acceleration [ $( $acceleration:tt )* ]
+            Force [ $( $force:tt )* ]
+            Length [ $( $length:tt )* ]
+            MagneticFluxDensity [ $( $magnetic_flux_density:tt )* ]
+            Momentum [ $( $momentum:tt )* ]
+            Torque [ $( $torque:tt )* ]
+            Velocity [ $( $velocity:tt )* ]
+        }
+        scalar_properties [ $( $scalar_property:tt )* ]
+        vector_properties [ $( $vector_property:tt )* ]
+        angle_measurement_units [ $( $angle_unit:tt )* ]
+        relationships [ $( $relationship:tt )* ]
+        relationships_2d [ $( $relationship_2d:tt )* ]
+        relationships_3d [ $( $relationship_3d:tt )* ]
+    } => {
+        $crate::inner_catalog_next! {
+            $catalogues $options
+            scalar_slots {
+                Area [ $( $area )* ]
+                Capacitance [ $( $capacitance )* ]
+                DynamicViscosity [
+                    $( $dynamic_viscosity )*
+                    Poise {
+                        suffix: " P",
+                        ratio: 0.1,
+                    }
+                    Centipoise {
+                        suffix: " cP",
+                        ratio: 1e-3,
+                    }
+                ]
+                ElectricCharge [ $( $electric_charge )* ]
+                ElectricCurrent [ $( $electric_current )* ]
+                ElectricPotential [ $( $electric_potential )* ]
+                ElectricalConductance [ $( $electrical_conductance )* ]
+                ElectricalResistance [ $( $electrical_resistance )* ]
+                Energy [
+                    $( $energy )*
+                    Erg {
+                        suffix: " erg",
+                        ratio: 1e-7,
+                    }
+                ]
+                Illuminance [
+                    $( $illuminance )*
+                    Phot {
+                        suffix: " phot",
+                        ratio: 1e4,
+                    }
+                ]
+                Inductance [ $( $inductance )* ]
+                KinematicViscosity [
+                    $( $kinematic_viscosity )*
+                    Stoke {
+                        suffix: " St",
+                        ratio: 1e-4,
+                    }
+                    Centistoke {
+                        suffix: " cSt",
+                        ratio: 1e-6,
+                    }
+                ]
+                Luminance [
+                    $( $luminance )*
+                    Stilb {
+                        suffix: " sb",
+                        ratio: 1e4,
+                    }
+                ]
+                MagneticFlux [
+                    $( $magnetic_flux )*
+                    Maxwell {
+                        suffix: " Mx",
+                        ratio: 1e-8,
+                    }
+                ]
+                Mass [ $( $mass )* ]
+                Power [
+                    $( $power )*
+                    ErgPerSecond {
+                        suffix: " erg/s",
+                        ratio: 1e-7,
+                    }
+                ]
+                Pressure [
+                    $( $pressure )*
+                    Barye {
+                        suffix: " Ba",
+                        ratio: 0.1,
+                    }
+                ]
+                Temperature [ $( $temperature )* ]
+                Time [ $( $time )* ]
+                Volume [ $( $volume )* ]
+            }
+            vector_slots {
+                Acceleration [
+                    $( $acceleration )*
+                    CentimetrePerSquareSecond {
+                        suffix: " cm/s\u{b2}", // cm/s²
+                        ratio: 1e-2,
+                    }
+                ]
+                Force [
+                    $( $force )*
+                    Dyne {
+                        suffix: " dyn",
+                        ratio: 1e-5,
+                    }
+                ]
+                Length [ $( $length )* ]
+                MagneticFluxDensity [
+                    $( $magnetic_flux_density )*
+                    Gauss {
+                        suffix: " G",
+                        ratio: 1e-4,
+                    }
+                ]
+                Momentum [
+                    $( $momentum )*
+                    DyneSecond {
+                        suffix: " dyn\u{b7}s", // dyn·s
+                        ratio: 1e-5,
+                    }
+                    GramCentimetrePerSecond {
+                        suffix: " g\u{b7}cm/s", // g·cm/s
+                        ratio: 1e-5,
+                    }
+                ]
+                Torque [ $( $torque )* ]
+                Velocity [
+                    $( $velocity )*
+                    CentimetrePerSecond {
+                        suffix: " cm/s",
+                        ratio: 0.01,
+                    }
+                ]
+            }
+            scalar_properties [ $( $scalar_property )* ]
+            vector_properties [ $( $vector_property )* ]
+            angle_measurement_units [ $( $angle_unit )* ]
+            relationships [
+                $( $relationship )*
+                CentimetrePerSecond 1 == CentimetrePerSquareSecond 1 * Second 1,
+                Centimetre 1 == CentimetrePerSecond 1 * Second 1,
+                SquareCentimetre 1 == Stoke 1 * Second 1,
+                SquareMillimetre 1 == Centistoke 1 * Second 1,
+                SquareMicrometre 1 == Centistoke 1 * Microsecond 1,
+                Stoke 1 == Centimetre 1 * CentimetrePerSecond 1,
+                Erg 1 == Dyne 1 * Centimetre 1,
+                Erg 1 == DyneSecond 1 * CentimetrePerSecond 1,
+                Dyne 1 == Gram 1 * CentimetrePerSquareSecond 1,
+                DyneSecond 1 == Dyne 1 * Second 1,
+                DyneSecond 1 == Gram 1 * CentimetrePerSecond 1,
+                Erg 1 == ErgPerSecond 1 * Second 1,
+                Lumen 1 == Phot 1 * SquareCentimetre 1,
+                Phot 1 == Stilb 1 * Steradian 1,
+                Candela 1 == Stilb 1 * SquareCentimetre 1,
+                Dyne 1 == Barye 1 * SquareCentimetre 1,
+                Poise 1 == Barye 1 * Second 1,
+                Maxwell 1 == Gauss 1 * SquareCentimetre 1,
+            ]
+            relationships_2d [
+                $( $relationship_2d )*
+                CentimetrePerSecond 2 == CentimetrePerSquareSecond 2 * Second 1,
+                Centimetre 2 == CentimetrePerSecond 2 * Second 1,
+                Erg 1 == Dyne 2 * Centimetre 2,
+                Erg 1 == DyneSecond 2 * CentimetrePerSecond 2,
+                Dyne 2 == Gram 1 * CentimetrePerSquareSecond 2,
+                DyneSecond 2 == Dyne 2 * Second 1,
+                DyneSecond 2 == Gram 1 * CentimetrePerSecond 2,
+            ]
+            relationships_3d [
+                $( $relationship_3d )*
+                CentimetrePerSecond 3 == CentimetrePerSquareSecond 3 * Second 1,
+                Centimetre 3 == CentimetrePerSecond 3 * Second 1,
+                Erg 1 == Dyne 3 * Centimetre 3,
+                Erg 1 == DyneSecond 3 * CentimetrePerSecond 3,
+                Dyne 3 == Gram 1 * CentimetrePerSquareSecond 3,
+                DyneSecond 3 == Dyne 3 * Second 1,
+                DyneSecond 3 == Gram 1 * CentimetrePerSecond 3,
+            ]
+        }
+    };
+}
@@ -0,0 +1,316 @@
+//! The catalogue of the electrical units, having more multiples and submultiples
+//! of the electrical units of the SI catalogue, and more electromagnetic properties.
+//!
+//! The macro `define_measure_types!` of this module defines the types of the SI catalogue,
+//! the constants `MAGNETIC_FLUX_QUANTUM`, `CONDUCTANCE_QUANTUM` and `VON_KLITZING_CONSTANT`,
+//! with the option `with_approx` also the constants `VACUUM_MAGNETIC_PERMEABILITY`
+//! and `VACUUM_ELECTRIC_PERMITTIVITY`, and the following units and properties:
+//!
+//! * `Capacitance`: `Millifarad`, `Nanofarad`, `Picofarad`.
+//! * `CurrentDensity`: `AmperePerSquareMetre`.
+//! * `ElectricCharge`: `Nanocoulomb`, `Picocoulomb`, `AmpereHour`, `MilliampereHour`.
+//! * `ElectricChargeDensity`: `CoulombPerCubicMetre`.
+//! * `ElectricCurrent`: `Kiloampere`, `Nanoampere`.
+//! * `ElectricDisplacement`: `CoulombPerSquareMetre`.
+//! * `ElectricPotential`: `Microvolt`, `Megavolt`.
+//! * `ElectricalConductance`: `Millisiemens`.
+//! * `ElectricalConductivity`: `SiemensPerMetre`.
+//! * `ElectricalResistance`: `Milliohm`, `Megaohm`.
+//! * `ElectricalResistivity`: `OhmMetre`.
+//! * `Energy`: `WattHour`, `KilowattHour`, `MegawattHour`.
+//! * `Inductance`: `Millihenry`, `Microhenry`.
+//! * `LinearElectricChargeDensity`: `CoulombPerMetre`.
+//! * `MagneticPermeability`: `HenryPerMetre`.
+//! * `MagneticReluctance`: `InverseHenry`.
+//! * `Permittivity`: `FaradPerMetre`.
+
+pub use crate::catalog_electrical_define_measure_types as define_measure_types;
+
+/// Defines the measure types of the SI catalogue and of the electrical catalogue, and the ones given as argument.
+#[macro_export]
+macro_rules! catalog_electrical_define_measure_types {
+    ( $( $tokens:tt )* ) => {
+        $crate::catalog_define_measure_types! { catalogues [electrical] $( $tokens )* }
+    };
+}
+
+#[macro_export]
+macro_rules! inner_catalog_electrical {
+    {
+        $catalogues:tt $options:tt
+        scalar_slots {
+            Area [ $( $area:tt )* ]
+            Capacitance [ $( $capacitance:tt )* ]
+            DynamicViscosity [ $( $dynamic_viscosity:tt )* ]
+            ElectricCharge [ $( $electric_charge:tt )* ]
+            ElectricCurrent [ $( $electric_current:tt )* ]
+            ElectricPotential [ $( $electric_potential:tt )* ]
+            ElectricalConductance [ $( $electrical_conductance:tt )* ]
+            ElectricalResistance [ $( $electrical_resistance:tt )* ]
+            Energy [ $( $energy:tt )* ]
+            Illuminance [ $( $illuminance:tt )* ]
+            Inductance [ $( $inductance:tt )* ]
+            KinematicViscosity [ $( $kinematic_viscosity:tt )* ]
+            Luminance [ $( $luminance:tt )* ]
+            MagneticFlux [ $( $magnetic_flux:tt )* ]
+            Mass [ $( $mass:tt )* ]
+            Power [ $( $power:tt )* ]
+            Pressure [ $( $pressure:tt )* ]
+            Temperature [ $( $temperature:tt )* ]
+            Time [ $( $time:tt )* ]
+            Volume [ $( $volume:tt )* ]
+        }
+        vector_slots {
+            Acceleration [ $( $acceleration:tt )* ]
+            Force [ $( $force:tt )* ]
+            Length [ $( $length:tt )* ]
+            MagneticFluxDensity [ $( $magnetic_flux_density:tt )* ]
+            Momentum [ $( $momentum:tt )* ]
+            Torque [ $( $torque:tt )* ]
+            Velocity [ $( $velocity:tt )* ]
+        }
+        scalar_properties [ $( $scalar_property:tt )* ]
+        vector_properties [ $( $vector_property:tt )* ]
+        angle_measurement_units [ $( $angle_unit:tt )* ]
+        relationships [ $( $relationship:tt )* ]
+        relationships_2d [ $( $relationship_2d:tt )* ]
+        relationships_3d [ $( $relationship_3d:tt )* ]
+    } => {
+        /// The magnetic flux quantum, h / 2e, exact.
+        pub const MAGNETIC_FLUX_QUANTUM: Measure<Weber, f64> =
+            Measure::new(6.626_070_15e-34 / (2. * 1.602_176_634e-19));
+        /// The conductance quantum, 2e² / h, exact.
+        pub const CONDUCTANCE_QUANTUM: Measure<Siemens, f64> =
+            Measure::new(2. * 1.602_176_634e-19 * 1.602_176_634e-19 / 6.626_070_15e-34);
+        /// The von Klitzing constant, h / e², exact.
+        pub const VON_KLITZING_CONSTANT: Measure<Ohm, f64> =
+            Measure::new(6.626_070_15e-34 / (1.602_176_634e-19 * 1.602_176_634e-19));
+
+        // The measured constants, with the CODATA 2018 standard uncertainty, squared as variance.
+        $crate::inner_catalog_if_approx! {
+            $options
+
+            /// The vacuum magnetic permeability, 1.256 637 062 12(19) × 10⁻⁶ H/m.
+            pub const VACUUM_MAGNETIC_PERMEABILITY: ApproxMeasure<HenryPerMetre, f64> =
+                ApproxMeasure::with_variance(1.256_637_062_12e-6, 0.000_000_000_19e-6 * 0.000_000_000_19e-6);
+            /// The vacuum electric permittivity, 8.854 187 8128(13) × 10⁻¹² F/m.
+            pub const VACUUM_ELECTRIC_PERMITTIVITY: ApproxMeasure<FaradPerMetre, f64> =
+                ApproxMeasure::with_variance(8.854_187_812_8e-12, 0.000_000_001_3e-12 * 0.000_000_001_3e-12);
+        }
+
+        $crate::inner_catalog_next! {
+            $catalogues $options
+            scalar_slots {
+                Area [ $( $area )* ]
+                Capacitance [
+                    $( $capacitance )*
+                    Millifarad {
+                        suffix: " mF",
+                        ratio: 1e-3,
+                    }
+                    Nanofarad {
+                        suffix: " nF",
+                        ratio: 1e-9,
+                    }
+                    Picofarad {
+                        suffix: " pF",
+                        ratio: 1e-12,
+                    }
+                ]
+                DynamicViscosity [ $( $dynamic_viscosity )* ]
+                ElectricCharge [
+                    $( $electric_charge )*
+                    Nanocoulomb {
+                        suffix: " ",
+                        ratio: 1e-9,
+                    }
+                    Picocoulomb {
+                        suffix: " pC",
+                        ratio: 1e-12,
+                    }
+                    AmpereHour {
+                        suffix: " A\u{b7}h", // A·h
+                        ratio: 3600.,
+                    }
+                    MilliampereHour {
+                        suffix: " mA\u{b7}h", // mA·h
+                        ratio: 3.6,
+                    }
+                ]
+                ElectricCurrent [
+                    $( $electric_current )*
+                    Kiloampere {
+                        suffix: " kA",
+                        ratio: 1e3,
+                    }
+                    Nanoampere {
+                        suffix: " nA",
+                        ratio: 1e-9,
+                    }
+                ]
+                ElectricPotential [
+                    $( $electric_potential )*
+                    Microvolt {
+                        suffix: " \u{b5}V", // µV
+                        ratio: 1e-6,
+                    }
+                    Megavolt {
+                        suffix: " MV",
+                        ratio: 1e6,
+                    }
+                ]
+                ElectricalConductance [
+                    $( $electrical_conductance )*
+                    Millisiemens {
+                        suffix: " mS",
+                        ratio: 1e-3,
+                    }
+                ]
+                ElectricalResistance [
+                    $( $electrical_resistance )*
+                    Milliohm {
+                        suffix: " m\u{3a9}", // mΩ
+                        ratio: 1e-3,
+                    }
+                    Megaohm {
+                        suffix: " M\u{3a9}", // MΩ
+                        ratio: 1e6,
+                    }
+                ]
+                Energy [
+                    $( $energy )*
+                    WattHour {
+                        suffix: " W\u{b7}h", // W·h
+                        ratio: 3600.,
+                    }
+                    KilowattHour {
+                        suffix: " kW\u{b7}h", // kW·h
+                        ratio: 3.6e6,
+                    }
+                    MegawattHour {
+                        suffix: " MW\u{b7}h", // MW·h
+                        ratio: 3.6e9,
+                    }
+                ]
+                Illuminance [ $( $illuminance )* ]
+                Inductance [
+                    $( $inductance )*
+                    Millihenry {
+                        suffix: " mH",
+                        ratio: 1e-3,
+                    }
+                    Microhenry {
+                        suffix: " \u{b5}H", // µH
+                        ratio: 1e-6,
+                    }
+                ]
+                KinematicViscosity [ $( $kinematic_viscosity )* ]
+                Luminance [ $( $luminance )* ]
+                MagneticFlux [ $( $magnetic_flux )* ]
+                Mass [ $( $mass )* ]
+                Power [ $( $power )* ]
+                Pressure [ $( $pressure )* ]
+                Temperature [ $( $temperature )* ]
+                Time [ $( $time )* ]
+                Volume [ $( $volume )* ]
+            }
+            vector_slots {
+                Acceleration [ $( $acceleration )* ]
+                Force [ $( $force )* ]
+                Length [ $( $length )* ]
+                MagneticFluxDensity [ $( $magnetic_flux_density )* ]
+                Momentum [ $( $momentum )* ]
+                Torque [ $( $torque )* ]
+                Velocity [ $( $velocity )* ]
+            }
+            scalar_properties [
+                $( $scalar_property )*
+                ElectricChargeDensity [
+                    CoulombPerCubicMetre {
+                        suffix: " C/m\u{b3}", // C/m³
+                    }
+                ]
+                ElectricDisplacement [
+                    CoulombPerSquareMetre {
+                        suffix: " C/m\u{b2}", // C/m²
+                    }
+                ]
+                ElectricalConductivity [
+                    SiemensPerMetre {
+                        suffix: " S/m",
+                    }
+                ]
+                ElectricalResistivity [
+                    OhmMetre {
+                        suffix: " \u{3a9}\u{b7}m", // Ω·m
+                    }
+                ]
+                LinearElectricChargeDensity [
+                    CoulombPerMetre {
+                        suffix: " C/m",
+                    }
+                ]
+                MagneticPermeability [
+                    HenryPerMetre {
+                        suffix: " H/m",
+                    }
+                ]
+                MagneticReluctance [
+                    InverseHenry {
+                        suffix: " 1/H",
+                    }
+                ]
+                Permittivity [
+                    FaradPerMetre {
+                        suffix: " F/m",
+                    }
+                ]
+            ]
+            vector_properties [
+                $( $vector_property )*
+                CurrentDensity [
+                    AmperePerSquareMetre {
+                        suffix: " A/m\u{b2}", // A/m²
+                    }
+                ]
+            ]
+            angle_measurement_units [ $( $angle_unit )* ]
+            relationships [
+                $( $relationship )*
+                WattHour 1 == Watt 1 * Hour 1,
+                KilowattHour 1 == Kilowatt 1 * Hour 1,
+                Millicoulomb 1 == Millifarad 1 * Volt 1,
+                Coulomb 1 == Millifarad 1 * Kilovolt 1,
+                Nanocoulomb 1 == Nanofarad 1 * Volt 1,
+                Microcoulomb 1 == Nanofarad 1 * Kilovolt 1,
+                Picocoulomb 1 == Picofarad 1 * Volt 1,
+                Nanocoulomb 1 == Picofarad 1 * Kilovolt 1,
+                Ampere 1 == AmperePerSquareMetre 1 * SquareMetre 1,
+                Siemens 1 == SiemensPerMetre 1 * Metre 1,
+                Coulomb 1 == CoulombPerCubicMetre 1 * CubicMetre 1,
+                Millicoulomb 1 == CoulombPerCubicMetre 1 * Litre 1,
+                Microcoulomb 1 == CoulombPerCubicMetre 1 * Millilitre 1,
+                Coulomb 1 == CoulombPerSquareMetre 1 * SquareMetre 1,
+                Microcoulomb 1 == CoulombPerSquareMetre 1 * SquareMillimetre 1,
+                Coulomb 1 == CoulombPerMetre 1 * Metre 1,
+                Millicoulomb 1 == CoulombPerMetre 1 * Millimetre 1,
+                Microcoulomb 1 == CoulombPerMetre 1 * Micrometre 1,
+                Farad 1 == FaradPerMetre 1 * Metre 1,
+                Millifarad 1 == FaradPerMetre 1 * Millimetre 1,
+                Microfarad 1 == FaradPerMetre 1 * Micrometre 1,
+                Nanofarad 1 == FaradPerMetre 1 * Nanometre 1,
+                One 1 == InverseHenry 1 * Henry 1,
+                AmpereHour 1 == Ampere 1 * Hour 1,
+                MilliampereHour 1 == Milliampere 1 * Hour 1,
+                MegawattHour 1 == Megawatt 1 * Hour 1,
+                Megavolt 1 == Megaohm 1 * Ampere 1,
+            ]
+            relationships_2d [
+                $( $relationship_2d )*
+            ]
+            relationships_3d [
+                $( $relationship_3d )*
+            ]
+        }
+    };
+}
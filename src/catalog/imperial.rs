@@ -0,0 +1,349 @@
+//! The catalogue of the imperial and United States customary units,
+//! added to the properties of the SI catalogue.
+//! The pint and the gallon are the imperial ones, while the United States ones are named `UsPint` and `UsGallon`.
+//! The unit `AmericanWireGauge` is the gauge of the cross-section of a round wire,
+//! which isn't linear, as the gauge 36 has diameter 0.005 inches,
+//! and the diameter grows 92 times every 39 gauges less.
+//!
+//! The macro `define_measure_types!` of this module defines the types of the SI catalogue,
+//! and the following units:
+//!
+//! * `Acceleration`: `FootPerSquareSecond`.
+//! * `Area`: `SquareInch`, `SquareFoot`, `SquareYard`, `SquareMile`, `Acre`, `AmericanWireGauge`.
+//! * `Energy`: `FootPound`, `BritishThermalUnit`.
+//! * `Force`: `PoundForce`, `Poundal`.
+//! * `Illuminance`: `FootCandle`.
+//! * `Length`: `Inch`, `Foot`, `Yard`, `Mile`, `NauticalMile`.
+//! * `Luminance`: `CandelaPerSquareFoot`.
+//! * `Mass`: `Pound`, `Ounce`, `Stone`, `ImperialTon`, `USTon`.
+//! * `Power`: `HorsePower`.
+//! * `Pressure`: `PoundForcePerSquareInch`.
+//! * `Temperature`: `Fahrenheit`, `Rankine`.
+//! * `Torque`: `PoundFoot`, `PoundInch`.
+//! * `Velocity`: `FootPerSecond`, `MilePerHour`, `Knot`.
+//! * `Volume`: `CubicInch`, `CubicFoot`, `CubicYard`, `Pint`, `Gallon`, `UsPint`, `UsGallon`.
+
+pub use crate::catalog_imperial_define_measure_types as define_measure_types;
+
+/// Defines the measure types of the SI catalogue and of the imperial catalogue, and the ones given as argument.
+#[macro_export]
+macro_rules! catalog_imperial_define_measure_types {
+    ( $( $tokens:tt )* ) => {
+        $crate::catalog_define_measure_types! { catalogues [imperial] $( $tokens )* }
+    };
+}
+
+#[macro_export]
+macro_rules! inner_catalog_imperial {
+    {
+        $catalogues:tt $options:tt
+        scalar_slots {
+            Area [ $( $area:tt )* ]
+            Capacitance [ $( $capacitance:tt )* ]
+            DynamicViscosity [ $( $dynamic_viscosity:tt )* ]
+            ElectricCharge [ $( $electric_charge:tt )* ]
+            ElectricCurrent [ $( $electric_current:tt )* ]
+            ElectricPotential [ $( $electric_potential:tt )* ]
+            ElectricalConductance [ $( $electrical_conductance:tt )* ]
+            ElectricalResistance [ $( $electrical_resistance:tt )* ]
+            Energy [ $( $energy:tt )* ]
+            Illuminance [ $( $illuminance:tt )* ]
+            Inductance [ $( $inductance:tt )* ]
+            KinematicViscosity [ $( $kinematic_viscosity:tt )* ]
+            Luminance [ $( $luminance:tt )* ]
+            MagneticFlux [ $( $magnetic_flux:tt )* ]
+            Mass [ $( $mass:tt )* ]
+            Power [ $( $power:tt )* ]
+            Pressure [ $( $pressure:tt )* ]
+            Temperature [ $( $temperature:tt )* ]
+            Time [ $( $time:tt )* ]
+            Volume [ $( $volume:tt )* ]
+        }
+        vector_slots {
+            Acceleration [ $( $acceleration:tt )* ]
+            Force [ $( $force:tt )* ]
+            Length [ $( $length:tt )* ]
+            MagneticFluxDensity [ $( $magnetic_flux_density:tt )* ]
+            Momentum [ $( $momentum:tt )* ]
+            Torque [ $( $torque:tt )* ]
+            Velocity [ $( $velocity:tt )* ]
+        }
+        scalar_properties [ $( $scalar_property:tt )* ]
+        vector_properties [ $( $vector_property:tt )* ]
+        angle_measurement_units [ $( $angle_unit:tt )* ]
+        relationships [ $( $relationship:tt )* ]
+        relationships_2d [ $( $relationship_2d:tt )* ]
+        relationships_3d [ $( $relationship_3d:tt )* ]
+    } => {
+        $crate::inner_catalog_next! {
+            $catalogues $options
+            scalar_slots {
+                Area [
+                    $( $area )*
+                    SquareInch {
+                        suffix: " in\u{b2}", // in²
+                        ratio: 0.0254 * 0.0254,
+                    }
+                    SquareFoot {
+                        suffix: " ft\u{b2}", // ft²
+                        ratio: 0.3048 * 0.3048,
+                    }
+                    SquareYard {
+                        suffix: " yd\u{b2}", // yd²
+                        ratio: 0.9144 * 0.9144,
+                    }
+                    SquareMile {
+                        suffix: " mi\u{b2}", // mi²
+                        ratio: 1609.344 * 1609.344,
+                    }
+                    Acre {
+                        suffix: " ac",
+                        ratio: 4046.8564224,
+                    }
+                    AmericanWireGauge {
+                        suffix: " AWG",
+                        to_base: |value| {
+                            let diameter = 0.000_127
+                                * measures::float_math::FloatFunctions::powf(92_f64, (36. - value) / 39.);
+                            core::f64::consts::FRAC_PI_4 * diameter * diameter
+                        },
+                        from_base: |value| {
+                            let diameter = measures::float_math::FloatFunctions::sqrt(value / core::f64::consts::FRAC_PI_4);
+                            36. - 39. * measures::float_math::FloatFunctions::log10(diameter / 0.000_127)
+                                / measures::float_math::FloatFunctions::log10(92_f64)
+                        },
+                    }
+                ]
+                Capacitance [ $( $capacitance )* ]
+                DynamicViscosity [ $( $dynamic_viscosity )* ]
+                ElectricCharge [ $( $electric_charge )* ]
+                ElectricCurrent [ $( $electric_current )* ]
+                ElectricPotential [ $( $electric_potential )* ]
+                ElectricalConductance [ $( $electrical_conductance )* ]
+                ElectricalResistance [ $( $electrical_resistance )* ]
+                Energy [
+                    $( $energy )*
+                    FootPound {
+                        suffix: " ft\u{b7}lbf", // ft·lbf
+                        ratio: 0.3048 * 0.45359237 * 9.80665,
+                    }
+                    BritishThermalUnit {
+                        suffix: " BTU",
+                        ratio: 1055.05585262,
+                    }
+                ]
+                Illuminance [
+                    $( $illuminance )*
+                    FootCandle {
+                        suffix: " fc",
+                        ratio: 1. / (0.3048 * 0.3048),
+                    }
+                ]
+                Inductance [ $( $inductance )* ]
+                KinematicViscosity [ $( $kinematic_viscosity )* ]
+                Luminance [
+                    $( $luminance )*
+                    CandelaPerSquareFoot {
+                        suffix: " cd/ft\u{b2}", // cd/ft²
+                        ratio: 1. / (0.3048 * 0.3048),
+                    }
+                ]
+                MagneticFlux [ $( $magnetic_flux )* ]
+                Mass [
+                    $( $mass )*
+                    Pound {
+                        suffix: " lb",
+                        ratio: 0.45359237,
+                    }
+                    Ounce {
+                        suffix: " oz",
+                        ratio: 0.45359237 / 16.,
+                    }
+                    Stone {
+                        suffix: " st.",
+                        ratio: 0.45359237 * 14.,
+                    }
+                    ImperialTon {
+                        suffix: " long tn",
+                        ratio: 0.45359237 * 2240.,
+                    }
+                    USTon {
+                        suffix: " sh tn",
+                        ratio: 0.45359237 * 2000.,
+                    }
+                ]
+                Power [
+                    $( $power )*
+                    HorsePower {
+                        suffix: " hp",
+                        ratio: 745.699872,
+                    }
+                ]
+                Pressure [
+                    $( $pressure )*
+                    PoundForcePerSquareInch {
+                        suffix: " psi",
+                        ratio: 0.45359237 * 9.80665 / (0.0254 * 0.0254),
+                    }
+                ]
+                Temperature [
+                    $( $temperature )*
+                    Fahrenheit {
+                        suffix: " \u{b0}F", // °F
+                        ratio: 5. / 9.,
+                        offset: 273.15 - 32. * 5. / 9.,
+                    }
+                    Rankine {
+                        suffix: " \u{b0}R", // °R
+                        ratio: 5. / 9.,
+                    }
+                ]
+                Time [ $( $time )* ]
+                Volume [
+                    $( $volume )*
+                    CubicInch {
+                        suffix: " in\u{b3}", // in³
+                        ratio: 0.0254 * 0.0254 * 0.0254,
+                    }
+                    CubicFoot {
+                        suffix: " ft\u{b3}", // ft³
+                        ratio: 0.3048 * 0.3048 * 0.3048,
+                    }
+                    CubicYard {
+                        suffix: " yd\u{b3}", // yd³
+                        ratio: 0.9144 * 0.9144 * 0.9144,
+                    }
+                    Pint {
+                        suffix: " pt",
+                        ratio: 568.26125e-6,
+                    }
+                    Gallon {
+                        suffix: " gal",
+                        ratio: 4.54609e-3,
+                    }
+                    UsPint {
+                        suffix: " US pt",
+                        ratio: 473.176473e-6,
+                    }
+                    UsGallon {
+                        suffix: " US gal",
+                        ratio: 3.785411784e-3,
+                    }
+                ]
+            }
+            vector_slots {
+                Acceleration [
+                    $( $acceleration )*
+                    FootPerSquareSecond {
+                        suffix: " ft/s\u{b2}", // ft/s²
+                        ratio: 0.3048,
+                    }
+                ]
+                Force [
+                    $( $force )*
+                    PoundForce {
+                        suffix: " lbf",
+                        ratio: 0.45359237 * 9.80665,
+                    }
+                    Poundal {
+                        suffix: " pdl",
+                        ratio: 0.45359237 * 0.3048,
+                    }
+                ]
+                Length [
+                    $( $length )*
+                    Inch {
+                        suffix: " in",
+                        ratio: 0.0254,
+                    }
+                    Foot {
+                        suffix: " ft",
+                        ratio: 0.3048,
+                    }
+                    Yard {
+                        suffix: " yd",
+                        ratio: 0.9144,
+                    }
+                    Mile {
+                        suffix: " mi",
+                        ratio: 1609.344,
+                    }
+                    NauticalMile {
+                        suffix: " nmi",
+                        ratio: 1852.,
+                    }
+                ]
+                MagneticFluxDensity [ $( $magnetic_flux_density )* ]
+                Momentum [ $( $momentum )* ]
+                Torque [
+                    $( $torque )*
+                    PoundFoot {
+                        suffix: " lbf\u{b7}ft", // lbf·ft
+                        ratio: 0.45359237 * 9.80665 * 0.3048,
+                    }
+                    PoundInch {
+                        suffix: " lbf\u{b7}in", // lbf·in
+                        ratio: 0.45359237 * 9.80665 * 0.0254,
+                    }
+                ]
+                Velocity [
+                    $( $velocity )*
+                    FootPerSecond {
+                        suffix: " ft/s",
+                        ratio: 0.3048,
+                    }
+                    MilePerHour {
+                        suffix: " mi/h",
+                        ratio: 1609.344 / 3600.,
+                    }
+                    Knot {
+                        suffix: " kt",
+                        ratio: 1852. / 3600.,
+                    }
+                ]
+            }
+            scalar_properties [ $( $scalar_property )* ]
+            vector_properties [ $( $vector_property )* ]
+            angle_measurement_units [ $( $angle_unit )* ]
+            relationships [
+                $( $relationship )*
+                SquareInch 1 == Inch 1 * __ 1,
+                SquareFoot 1 == Foot 1 * __ 1,
+                SquareYard 1 == Yard 1 * __ 1,
+                SquareMile 1 == Mile 1 * __ 1,
+                CubicInch 1 == SquareInch 1 * Inch 1,
+                CubicFoot 1 == SquareFoot 1 * Foot 1,
+                CubicYard 1 == SquareYard 1 * Yard 1,
+                NauticalMile 1 == Knot 1 * Hour 1,
+                Mile 1 == MilePerHour 1 * Hour 1,
+                PoundForce 1 == PoundForcePerSquareInch 1 * SquareInch 1,
+                Lumen 1 == FootCandle 1 * SquareFoot 1,
+                FootCandle 1 == CandelaPerSquareFoot 1 * Steradian 1,
+                Candela 1 == CandelaPerSquareFoot 1 * SquareFoot 1,
+                Foot 1 == FootPerSecond 1 * Second 1,
+                FootPerSecond 1 == FootPerSquareSecond 1 * Second 1,
+                FootPound 1 == PoundForce 1 * Foot 1,
+            ]
+            relationships_2d [
+                $( $relationship_2d )*
+                NauticalMile 2 == Knot 2 * Hour 1,
+                Mile 2 == MilePerHour 2 * Hour 1,
+                PoundFoot 1 == PoundForce 2 X Foot 2,
+                PoundInch 1 == PoundForce 2 X Inch 2,
+                Foot 2 == FootPerSecond 2 * Second 1,
+                FootPerSecond 2 == FootPerSquareSecond 2 * Second 1,
+                FootPound 1 == PoundForce 2 * Foot 2,
+            ]
+            relationships_3d [
+                $( $relationship_3d )*
+                NauticalMile 3 == Knot 3 * Hour 1,
+                Mile 3 == MilePerHour 3 * Hour 1,
+                PoundFoot 3 == PoundForce 3 X Foot 3,
+                PoundInch 3 == PoundForce 3 X Inch 3,
+                Foot 3 == FootPerSecond 3 * Second 1,
+                FootPerSecond 3 == FootPerSquareSecond 3 * Second 1,
+                FootPound 1 == PoundForce 3 * Foot 3,
+            ]
+        }
+    };
+}
@@ -0,0 +1,249 @@
+//! Ready-made catalogues of properties, units of measurement and relationships,
+//! to be used instead of writing the whole invocation of the macro `define_measure_types!`.
+//!
+//! Each catalogue is enabled by a feature of this crate:
+//! * `catalog_si`: the module `si`, having the units of the International System of Units,
+//!   and some accepted non-SI units. All the other catalogues extend it.
+//! * `catalog_imperial`: the module `imperial`, having the imperial and United States customary units.
+//! * `catalog_cgs`: the module `cgs`, having the units of the centimetre-gram-second system.
+//! * `catalog_astronomy`: the module `astronomy`, having the astronomical units.
+//! * `catalog_electrical`: the module `electrical`, having more electrical units and properties.
+//!
+//! Each module has a macro `define_measure_types!`, having the same syntax of the main macro,
+//! which defines the types of the SI catalogue and of its catalogue,
+//! and also the properties, the units, the angle units and the relationships given as argument.
+//! So, a project can extend the catalogue with its own properties and relationships.
+//! To add units to the properties of the catalogues, the macro `measurement_unit!` can be used.
+//!
+//! ```
+//! mod units {
+//!     measures::catalog::si::define_measure_types! {
+//!         exact with_approx with_2d,
+//!         scalar_properties [
+//!             Information [
+//!                 Bit {
+//!                     suffix: " b",
+//!                 }
+//!             ]
+//!             InformationRate [
+//!                 BitPerSecond {
+//!                     suffix: " b/s",
+//!                 }
+//!             ]
+//!         ]
+//!         relationships [
+//!             Bit 1 == BitPerSecond 1 * Second 1,
+//!         ]
+//!     }
+//! }
+//! use units::*;
+//!
+//! let force = Measure2d::<Newton>::new([3., 4.]);
+//! let displacement = Measure2d::<Metre>::new([2., 0.]);
+//! assert_eq!((force * displacement).value, 6.);
+//! assert_eq!(Measure::<Kilometre>::new(1.5).convert::<Metre>().value, 1500.);
+//! assert_eq!((Measure::<BitPerSecond>::new(8.) * Measure::<Second>::new(2.)).value, 16.);
+//! ```
+//!
+//! The macro `define_measure_types!` of this module combines several catalogues,
+//! listed after the keyword `catalogues`, besides the SI catalogue, which is always included:
+//!
+//! ```ignore
+//! mod units {
+//!     measures::catalog::define_measure_types! {
+//!         catalogues [imperial electrical]
+//!         exact with_approx,
+//!     }
+//! }
+//! ```
+//!
+//! The properties extended by the catalogues are collected in slots,
+//! which are passed from the macro of a catalogue to the macro of the next one,
+//! until all the collected definitions are passed to the main macro.
+
+#[cfg(feature = "catalog_astronomy")]
+pub mod astronomy;
+#[cfg(feature = "catalog_cgs")]
+pub mod cgs;
+#[cfg(feature = "catalog_electrical")]
+pub mod electrical;
+#[cfg(feature = "catalog_imperial")]
+pub mod imperial;
+pub mod si;
+
+pub use crate::catalog_define_measure_types as define_measure_types;
+
+/// Defines the measure types of the SI catalogue, of the listed catalogues,
+/// and the ones given as argument.
+#[macro_export]
+macro_rules! catalog_define_measure_types {
+    (
+        catalogues [ $( $catalogue:ident )* ]
+        $( $option:ident )* ,
+        $( scalar_properties [ $( $scalar_property:tt )* ] )?
+        $( vector_properties [ $( $vector_property:tt )* ] )?
+        $( angle_measurement_units [ $( $angle_unit:tt )* ] )?
+        $( relationships [ $( $relationship:tt )* ] )?
+    ) => {
+        $crate::inner_catalog_si! {
+            [ $( $catalogue )* ] [ $( $option )* ]
+            scalar_properties [ $( $( $scalar_property )* )? ]
+            vector_properties [ $( $( $vector_property )* )? ]
+            angle_measurement_units [ $( $( $angle_unit )* )? ]
+            relationships [ $( $( $relationship )* )? ]
+        }
+    };
+}
+
+// It passes the collected definitions to the macro of the first catalogue of the list,
+// or, if the list is empty, to the main macro.
+#[macro_export]
+macro_rules! inner_catalog_next {
+    { [ ] $( $definitions:tt )* } => {
+        $crate::inner_catalog_emit! { $( $definitions )* }
+    };
+    { [ imperial $( $catalogue:ident )* ] $( $definitions:tt )* } => {
+        $crate::inner_catalog_imperial! { [ $( $catalogue )* ] $( $definitions )* }
+    };
+    { [ cgs $( $catalogue:ident )* ] $( $definitions:tt )* } => {
+        $crate::inner_catalog_cgs! { [ $( $catalogue )* ] $( $definitions )* }
+    };
+    { [ astronomy $( $catalogue:ident )* ] $( $definitions:tt )* } => {
+        $crate::inner_catalog_astronomy! { [ $( $catalogue )* ] $( $definitions )* }
+    };
+    { [ electrical $( $catalogue:ident )* ] $( $definitions:tt )* } => {
+        $crate::inner_catalog_electrical! { [ $( $catalogue )* ] $( $definitions )* }
+    };
+    { [ $catalogue:ident $( $rest:tt )* ] $( $definitions:tt )* } => {
+        compile_error!(concat!(
+            "unknown unit catalogue `",
+            stringify!($catalogue),
+            "`, expected one of `imperial`, `cgs`, `astronomy`, `electrical`"
+        ));
+    };
+}
+
+// The relationships of the catalogues among 2-dimensional or 3-dimensional measures
+// are collected in separate lists, which are passed to the main macro
+// only if the given options contain `with_2d` or `with_3d`.
+// The relationships given as argument are always passed,
+// and so they are checked against the options by the main macro.
+#[macro_export]
+macro_rules! inner_catalog_emit {
+    {
+        $options:tt
+        scalar_slots { $( $scalar_slot:ident [ $( $scalar_slot_unit:tt )* ] )* }
+        vector_slots { $( $vector_slot:ident [ $( $vector_slot_unit:tt )* ] )* }
+        scalar_properties [ $( $scalar_property:tt )* ]
+        vector_properties [ $( $vector_property:tt )* ]
+        angle_measurement_units [ $( $angle_unit:tt )* ]
+        relationships $relationships:tt
+        relationships_2d $relationships_2d:tt
+        relationships_3d $relationships_3d:tt
+    } => {
+        $crate::inner_catalog_select_relationships! {
+            $options $options
+            {
+                scalar_properties [
+                    $( $scalar_slot [ $( $scalar_slot_unit )* ] )*
+                    $( $scalar_property )*
+                ]
+                vector_properties [
+                    $( $vector_slot [ $( $vector_slot_unit )* ] )*
+                    $( $vector_property )*
+                ]
+                angle_measurement_units [ $( $angle_unit )* ]
+            }
+            $relationships $relationships_2d $relationships_3d
+        }
+    };
+}
+
+// It scans the options, moving the relationships among 2-dimensional measures
+// to the emitted ones when it finds `with_2d`, and the ones among 3-dimensional measures
+// when it finds `with_3d`.
+#[macro_export]
+macro_rules! inner_catalog_select_relationships {
+    {
+        [ ] [ $( $option:ident )* ] { $( $definitions:tt )* }
+        [ $( $relationship:tt )* ] $relationships_2d:tt $relationships_3d:tt
+    } => {
+        measures::define_measure_types! {
+            $( $option )* ,
+            $( $definitions )*
+            relationships [ $( $relationship )* ]
+        }
+    };
+    {
+        [ with_2d $( $rest:ident )* ] $options:tt $definitions:tt
+        [ $( $relationship:tt )* ] [ $( $relationship_2d:tt )* ] $relationships_3d:tt
+    } => {
+        $crate::inner_catalog_select_relationships! {
+            [ $( $rest )* ] $options $definitions
+            [ $( $relationship_2d )* $( $relationship )* ] [ ] $relationships_3d
+        }
+    };
+    {
+        [ with_3d $( $rest:ident )* ] $options:tt $definitions:tt
+        [ $( $relationship:tt )* ] $relationships_2d:tt [ $( $relationship_3d:tt )* ]
+    } => {
+        $crate::inner_catalog_select_relationships! {
+            [ $( $rest )* ] $options $definitions
+            [ $( $relationship_3d )* $( $relationship )* ] $relationships_2d [ ]
+        }
+    };
+    {
+        [ $first:ident $( $rest:ident )* ] $options:tt $definitions:tt
+        $relationships:tt $relationships_2d:tt $relationships_3d:tt
+    } => {
+        $crate::inner_catalog_select_relationships! {
+            [ $( $rest )* ] $options $definitions
+            $relationships $relationships_2d $relationships_3d
+        }
+    };
+}
+
+// It expands the given items only if the given options contain `with_approx`,
+// to define the constants having an uncertainty.
+#[macro_export]
+macro_rules! inner_catalog_if_approx {
+    { [ ] $( $item:item )* } => {};
+    { [ with_approx $( $option:ident )* ] $( $item:item )* } => { $( $item )* };
+    { [ $first:ident $( $option:ident )* ] $( $item:item )* } => {
+        $crate::inner_catalog_if_approx! { [ $( $option )* ] $( $item )* }
+    };
+}
+
+// The catalogues whose feature is not enabled.
+
+#[cfg(not(feature = "catalog_imperial"))]
+#[macro_export]
+macro_rules! inner_catalog_imperial {
+    { $( $definitions:tt )* } => {
+        compile_error!("the unit catalogue `imperial` requires the feature `catalog_imperial`");
+    };
+}
+
+#[cfg(not(feature = "catalog_cgs"))]
+#[macro_export]
+macro_rules! inner_catalog_cgs {
+    { $( $definitions:tt )* } => {
+        compile_error!("the unit catalogue `cgs` requires the feature `catalog_cgs`");
+    };
+}
+
+#[cfg(not(feature = "catalog_astronomy"))]
+#[macro_export]
+macro_rules! inner_catalog_astronomy {
+    { $( $definitions:tt )* } => {
+        compile_error!("the unit catalogue `astronomy` requires the feature `catalog_astronomy`");
+    };
+}
+
+#[cfg(not(feature = "catalog_electrical"))]
+#[macro_export]
+macro_rules! inner_catalog_electrical {
+    { $( $definitions:tt )* } => {
+        compile_error!("the unit catalogue `electrical` requires the feature `catalog_electrical`");
+    };
+}
@@ -0,0 +1,801 @@
+//! The catalogue of the units of the International System of Units (SI),
+//! with some accepted non-SI units, like the hour, the litre, the tonne and the electronvolt.
+//! It is the base of all the other catalogues.
+//!
+//! The macro `define_measure_types!` of this module has the same syntax of the main macro,
+//! and it defines the following properties and units,
+//! besides the ones given as argument:
+//!
+//! * `Acceleration`: `MetrePerSquareSecond`.
+//! * `Action`: `JouleSecond`.
+//! * `Amount`: `Mole`, `Millimole`.
+//! * `Angle`: `Cycle`, `Gradian`, `Degree`, `ArcMinute`, `ArcSecond`.
+//! * `AngularMomentum`: `KilogramSquareMetrePerSecond`.
+//! * `Area`: `SquareMetre`, `SquareKilometre`, `Hectare`, `SquareDecimetre`, `SquareCentimetre`, `SquareMillimetre`, `SquareMicrometre`.
+//! * `Capacitance`: `Farad`, `Microfarad`.
+//! * `CatalyticActivity`: `Katal`.
+//! * `DoseEquivalent`: `Sievert`.
+//! * `DynamicViscosity`: `PascalSecond`.
+//! * `ElectricCharge`: `Coulomb`, `Millicoulomb`, `Microcoulomb`.
+//! * `ElectricCurrent`: `Ampere`, `Milliampere`, `Microampere`.
+//! * `ElectricFieldStrength`: `VoltPerMetre`.
+//! * `ElectricPotential`: `Volt`, `Kilovolt`, `Millivolt`.
+//! * `ElectricalConductance`: `Siemens`.
+//! * `ElectricalResistance`: `Ohm`, `Kiloohm`.
+//! * `Energy`: `Joule`, `Kilojoule`, `Megajoule`, `Electronvolt`.
+//! * `Entropy`: `JoulePerKelvin`.
+//! * `Force`: `Newton`.
+//! * `Frequency`: `Hertz`, `Kilohertz`, `Megahertz`, `Gigahertz`.
+//! * `GravitationalConstant`: `NewtonSquareMetrePerSquareKilogram`.
+//! * `GravitationalParameter`: `CubicMetrePerSquareSecond`.
+//! * `Illuminance`: `Lux`.
+//! * `Inductance`: `Henry`.
+//! * `InverseAmount`: `PerMole`.
+//! * `Irradiance`: `WattPerSquareMetre`.
+//! * `KinematicViscosity`: `SquareMetrePerSecond`.
+//! * `Length`: `Metre`, `Kilometre`, `Decimetre`, `Centimetre`, `Millimetre`, `Micrometre`, `Nanometre`.
+//! * `Luminance`: `CandelaPerSquareMetre`.
+//! * `LuminousFlux`: `Lumen`.
+//! * `LuminousIntensity`: `Candela`.
+//! * `MagneticFieldStrength`: `AmperePerMetre`.
+//! * `MagneticFlux`: `Weber`.
+//! * `MagneticFluxDensity`: `Tesla`.
+//! * `Mass`: `Kilogram`, `Tonne`, `Gram`, `Milligram`, `Microgram`.
+//! * `MassDensity`: `KilogramPerCubicMetre`, `GramPerMillilitre`.
+//! * `MassFlowRate`: `KilogramPerSecond`.
+//! * `MolarConcentration`: `MolePerCubicMetre`.
+//! * `MolarHeatCapacity`: `JoulePerKelvinPerMole`.
+//! * `MomentOfInertia`: `KilogramSquareMetre`.
+//! * `Momentum`: `NewtonSecond`, `KilogramMetrePerSecond`.
+//! * `Power`: `Watt`, `Milliwatt`, `Kilowatt`, `Megawatt`, `Gigawatt`, `DecibelWatt`, `DecibelMilliwatt`.
+//! * `Pressure`: `Pascal`, `Hectopascal`, `Kilopascal`, `Megapascal`, `Bar`, `Millibar`.
+//! * `RadioactiveActivity`: `Becquerel`.
+//! * `RadioactiveDose`: `Gray`.
+//! * `SolidAngle`: `Steradian`.
+//! * `SpecificEnergy`: `JoulePerKilogram`.
+//! * `SpecificHeatCapacity`: `JoulePerKilogramPerKelvin`.
+//! * `SquareTime`: `SquareSecond`.
+//! * `Temperature`: `Kelvin`, `Celsius`.
+//! * `ThermalConductivity`: `WattPerMetrePerKelvin`.
+//! * `Time`: `Second`, `Minute`, `Hour`, `Day`, `Millisecond`, `Microsecond`, `Nanosecond`.
+//! * `Torque`: `NewtonMetre`.
+//! * `Velocity`: `MetrePerSecond`, `KilometrePerHour`, `KilometrePerSecond`.
+//! * `Volume`: `CubicMetre`, `CubicDecimetre`, `Litre`, `Millilitre`, `CubicCentimetre`, `CubicMillimetre`.
+//! * `VolumetricFlowRate`: `CubicMetrePerSecond`.
+//!
+//! The relationships among these units are defined too, like `Joule 1 == Newton 1 * Metre 1`,
+//! and the properties `Power`, `ElectricPotential` and `ElectricCurrent` implement
+//! the traits `PowerQuantity` and `RootPowerQuantity`, to be expressed in decibels.
+//! The units `DecibelWatt` and `DecibelMilliwatt` are not linear,
+//! so their measure points are converted by logarithms, like `MeasurePoint::<Watt>::new(1.).convert::<DecibelMilliwatt>()`,
+//! which has value 30.
+//!
+//! The physical constants are defined as constant measures, with the CODATA 2018 values.
+//! The exact ones, like `SPEED_OF_LIGHT`, `PLANCK_CONSTANT`, `ELEMENTARY_CHARGE`,
+//! `BOLTZMANN_CONSTANT` and `AVOGADRO_CONSTANT`, have type `Measure`.
+//! With the option `with_approx`, the measured ones, like `GRAVITATIONAL_CONSTANT`
+//! and `ELECTRON_MASS`, are defined too, having type `ApproxMeasure`,
+//! whose variance is the square of the standard uncertainty.
+
+pub use crate::catalog_si_define_measure_types as define_measure_types;
+
+/// Defines the measure types of the SI catalogue, and the ones given as argument.
+#[macro_export]
+macro_rules! catalog_si_define_measure_types {
+    ( $( $tokens:tt )* ) => {
+        $crate::catalog_define_measure_types! { catalogues [] $( $tokens )* }
+    };
+}
+
+#[macro_export]
+macro_rules! inner_catalog_si {
+    {
+        $catalogues:tt $options:tt
+        scalar_properties [ $( $scalar_property:tt )* ]
+        vector_properties [ $( $vector_property:tt )* ]
+        angle_measurement_units [ $( $angle_unit:tt )* ]
+        relationships [ $( $relationship:tt )* ]
+    } => {
+        impl measures::traits::PowerQuantity for Power {}
+        impl measures::traits::RootPowerQuantity for ElectricPotential {}
+        impl measures::traits::RootPowerQuantity for ElectricCurrent {}
+
+        /// The speed of light in vacuum, exact.
+        pub const SPEED_OF_LIGHT: Measure<MetrePerSecond, f64> = Measure::new(299_792_458.);
+        /// The Planck constant, exact.
+        pub const PLANCK_CONSTANT: Measure<JouleSecond, f64> = Measure::new(6.626_070_15e-34);
+        /// The reduced Planck constant, h / 2π, exact.
+        pub const REDUCED_PLANCK_CONSTANT: Measure<JouleSecond, f64> =
+            Measure::new(6.626_070_15e-34 / core::f64::consts::TAU);
+        /// The elementary charge, exact.
+        pub const ELEMENTARY_CHARGE: Measure<Coulomb, f64> = Measure::new(1.602_176_634e-19);
+        /// The Boltzmann constant, exact.
+        pub const BOLTZMANN_CONSTANT: Measure<JoulePerKelvin, f64> = Measure::new(1.380_649e-23);
+        /// The Avogadro constant, exact.
+        pub const AVOGADRO_CONSTANT: Measure<PerMole, f64> = Measure::new(6.022_140_76e23);
+        /// The molar gas constant, the product of the Avogadro constant by the Boltzmann constant, exact.
+        pub const MOLAR_GAS_CONSTANT: Measure<JoulePerKelvinPerMole, f64> =
+            Measure::new(6.022_140_76e23 * 1.380_649e-23);
+        /// The standard acceleration of gravity, exact by convention.
+        pub const STANDARD_GRAVITY: Measure<MetrePerSquareSecond, f64> = Measure::new(9.806_65);
+        /// The standard atmosphere, exact by convention.
+        pub const STANDARD_ATMOSPHERE: Measure<Pascal, f64> = Measure::new(101_325.);
+
+        // The measured constants, with the CODATA 2018 standard uncertainty, squared as variance.
+        $crate::inner_catalog_if_approx! {
+            $options
+
+            /// The Newtonian constant of gravitation, 6.674 30(15) × 10⁻¹¹ N·m²/kg².
+            pub const GRAVITATIONAL_CONSTANT: ApproxMeasure<NewtonSquareMetrePerSquareKilogram, f64> =
+                ApproxMeasure::with_variance(6.674_30e-11, 0.000_15e-11 * 0.000_15e-11);
+            /// The fine-structure constant, 7.297 352 5693(11) × 10⁻³.
+            pub const FINE_STRUCTURE_CONSTANT: ApproxMeasure<One, f64> =
+                ApproxMeasure::with_variance(7.297_352_569_3e-3, 0.000_000_001_1e-3 * 0.000_000_001_1e-3);
+            /// The electron mass, 9.109 383 7015(28) × 10⁻³¹ kg.
+            pub const ELECTRON_MASS: ApproxMeasure<Kilogram, f64> =
+                ApproxMeasure::with_variance(9.109_383_701_5e-31, 0.000_000_002_8e-31 * 0.000_000_002_8e-31);
+            /// The proton mass, 1.672 621 923 69(51) × 10⁻²⁷ kg.
+            pub const PROTON_MASS: ApproxMeasure<Kilogram, f64> =
+                ApproxMeasure::with_variance(1.672_621_923_69e-27, 0.000_000_000_51e-27 * 0.000_000_000_51e-27);
+            /// The neutron mass, 1.674 927 498 04(95) × 10⁻²⁷ kg.
+            pub const NEUTRON_MASS: ApproxMeasure<Kilogram, f64> =
+                ApproxMeasure::with_variance(1.674_927_498_04e-27, 0.000_000_000_95e-27 * 0.000_000_000_95e-27);
+        }
+
+        $crate::inner_catalog_next! {
+            $catalogues $options
+            scalar_slots {
+                Area [
+                    SquareMetre {
+                        suffix: " m\u{b2}", // m²
+                    }
+                    SquareKilometre {
+                        suffix: " km\u{b2}", // km²
+                        ratio: 1e6,
+                    }
+                    Hectare {
+                        suffix: " ha",
+                        ratio: 1e4,
+                    }
+                    SquareDecimetre {
+                        suffix: " dm\u{b2}", // dm²
+                        ratio: 0.01,
+                    }
+                    SquareCentimetre {
+                        suffix: " cm\u{b2}", // cm²
+                        ratio: 1e-4,
+                    }
+                    SquareMillimetre {
+                        suffix: " mm\u{b2}", // mm²
+                        ratio: 1e-6,
+                    }
+                    SquareMicrometre {
+                        suffix: " \u{b5}m\u{b2}", // µm²
+                        ratio: 1e-12,
+                    }
+                ]
+                Capacitance [
+                    Farad {
+                        suffix: " F",
+                    }
+                    Microfarad {
+                        suffix: " \u{b5}F", // µF
+                        ratio: 1e-6,
+                    }
+                ]
+                DynamicViscosity [
+                    PascalSecond {
+                        suffix: " Pa\u{b7}s", // Pa·s
+                    }
+                ]
+                ElectricCharge [
+                    Coulomb {
+                        suffix: " C",
+                    }
+                    Millicoulomb {
+                        suffix: " mC",
+                        ratio: 1e-3,
+                    }
+                    Microcoulomb {
+                        suffix: " \u{b5}C", // µC
+                        ratio: 1e-6,
+                    }
+                ]
+                ElectricCurrent [
+                    Ampere {
+                        suffix: " A",
+                    }
+                    Milliampere {
+                        suffix: " mA",
+                        ratio: 1e-3,
+                    }
+                    Microampere {
+                        suffix: " \u{b5}A", // µA
+                        ratio: 1e-6,
+                    }
+                ]
+                ElectricPotential [
+                    Volt {
+                        suffix: " V",
+                    }
+                    Kilovolt {
+                        suffix: " kV",
+                        ratio: 1e3,
+                    }
+                    Millivolt {
+                        suffix: " mV",
+                        ratio: 1e-3,
+                    }
+                ]
+                ElectricalConductance [
+                    Siemens {
+                        suffix: " S",
+                    }
+                ]
+                ElectricalResistance [
+                    Ohm {
+                        suffix: " \u{3a9}", // Ω
+                    }
+                    Kiloohm {
+                        suffix: " k\u{3a9}", // kΩ
+                        ratio: 1e3,
+                    }
+                ]
+                Energy [
+                    Joule {
+                        suffix: " J",
+                    }
+                    Kilojoule {
+                        suffix: " kJ",
+                        ratio: 1e3,
+                    }
+                    Megajoule {
+                        suffix: " MJ",
+                        ratio: 1e6,
+                    }
+                    Electronvolt {
+                        suffix: " eV",
+                        ratio: 1.602176634e-19,
+                    }
+                ]
+                Illuminance [
+                    Lux {
+                        suffix: " lx",
+                    }
+                ]
+                Inductance [
+                    Henry {
+                        suffix: " H",
+                    }
+                ]
+                KinematicViscosity [
+                    SquareMetrePerSecond {
+                        suffix: " m\u{b2}/s", // m²/s
+                    }
+                ]
+                Luminance [
+                    CandelaPerSquareMetre {
+                        suffix: " cd/m\u{b2}", // cd/m²
+                    }
+                ]
+                MagneticFlux [
+                    Weber {
+                        suffix: " Wb",
+                    }
+                ]
+                Mass [
+                    Kilogram {
+                        suffix: " kg",
+                    }
+                    Tonne {
+                        suffix: " t",
+                        ratio: 1e3,
+                    }
+                    Gram {
+                        suffix: " g",
+                        ratio: 1e-3,
+                    }
+                    Milligram {
+                        suffix: " mg",
+                        ratio: 1e-6,
+                    }
+                    Microgram {
+                        suffix: " \u{b5}g", // µg
+                        ratio: 1e-9,
+                    }
+                ]
+                Power [
+                    Watt {
+                        suffix: " W",
+                    }
+                    Milliwatt {
+                        suffix: " mW",
+                        ratio: 1e-3,
+                    }
+                    Kilowatt {
+                        suffix: " kW",
+                        ratio: 1e3,
+                    }
+                    Megawatt {
+                        suffix: " MW",
+                        ratio: 1e6,
+                    }
+                    Gigawatt {
+                        suffix: " GW",
+                        ratio: 1e9,
+                    }
+                    DecibelWatt {
+                        suffix: " dBW",
+                        to_base: |value| measures::traits::PowerDecibel::power_decibels_to_value(value),
+                        from_base: |value| measures::traits::PowerDecibel::to_power_decibels(value),
+                    }
+                    DecibelMilliwatt {
+                        suffix: " dBm",
+                        to_base: |value| measures::traits::PowerDecibel::power_decibels_to_value(value) * 1e-3,
+                        from_base: |value| measures::traits::PowerDecibel::to_power_decibels(value * 1e3),
+                    }
+                ]
+                Pressure [
+                    Pascal {
+                        suffix: " Pa",
+                    }
+                    Hectopascal {
+                        suffix: " hPa",
+                        ratio: 100.,
+                    }
+                    Kilopascal {
+                        suffix: " kPa",
+                        ratio: 1e3,
+                    }
+                    Megapascal {
+                        suffix: " MPa",
+                        ratio: 1e6,
+                    }
+                    Bar {
+                        suffix: " bar",
+                        ratio: 1e5,
+                    }
+                    Millibar {
+                        suffix: " mbar",
+                        ratio: 100.,
+                    }
+                ]
+                Temperature [
+                    Kelvin {
+                        suffix: " K",
+                    }
+                    Celsius {
+                        suffix: " \u{b0}C", // °C
+                        ratio: 1.,
+                        offset: 273.15,
+                    }
+                ]
+                Time [
+                    Second {
+                        suffix: " s",
+                    }
+                    Minute {
+                        suffix: " min",
+                        ratio: 60.,
+                    }
+                    Hour {
+                        suffix: " h",
+                        ratio: 3600.,
+                    }
+                    Day {
+                        suffix: " d",
+                        ratio: 86400.,
+                    }
+                    Millisecond {
+                        suffix: " ms",
+                        ratio: 1e-3,
+                    }
+                    Microsecond {
+                        suffix: " \u{b5}s", // µs
+                        ratio: 1e-6,
+                    }
+                    Nanosecond {
+                        suffix: " ns",
+                        ratio: 1e-9,
+                    }
+                ]
+                Volume [
+                    CubicMetre {
+                        suffix: " m\u{b3}", // m³
+                    }
+                    CubicDecimetre {
+                        suffix: " dm\u{b3}", // dm³
+                        ratio: 1e-3,
+                    }
+                    Litre {
+                        suffix: " l",
+                        ratio: 1e-3,
+                    }
+                    Millilitre {
+                        suffix: " ml",
+                        ratio: 1e-6,
+                    }
+                    CubicCentimetre {
+                        suffix: " cm\u{b3}", // cm³
+                        ratio: 1e-6,
+                    }
+                    CubicMillimetre {
+                        suffix: " mm\u{b3}", // mm³
+                        ratio: 1e-9,
+                    }
+                ]
+            }
+            vector_slots {
+                Acceleration [
+                    MetrePerSquareSecond {
+                        suffix: " m/s\u{b2}", // m/s²
+                    }
+                ]
+                Force [
+                    Newton {
+                        suffix: " N",
+                    }
+                ]
+                Length [
+                    Metre {
+                        suffix: " m",
+                    }
+                    Kilometre {
+                        suffix: " km",
+                        ratio: 1e3,
+                    }
+                    Decimetre {
+                        suffix: " dm",
+                        ratio: 0.1,
+                    }
+                    Centimetre {
+                        suffix: " cm",
+                        ratio: 0.01,
+                    }
+                    Millimetre {
+                        suffix: " mm",
+                        ratio: 1e-3,
+                    }
+                    Micrometre {
+                        suffix: " \u{b5}m", // µm
+                        ratio: 1e-6,
+                    }
+                    Nanometre {
+                        suffix: " nm",
+                        ratio: 1e-9,
+                    }
+                ]
+                MagneticFluxDensity [
+                    Tesla {
+                        suffix: " T",
+                    }
+                ]
+                Momentum [
+                    NewtonSecond {
+                        suffix: " N\u{b7}s", // N·s
+                    }
+                    KilogramMetrePerSecond {
+                        suffix: " kg\u{b7}m/s", // kg·m/s
+                    }
+                ]
+                Torque [
+                    NewtonMetre {
+                        suffix: " N\u{b7}m", // N·m
+                    }
+                ]
+                Velocity [
+                    MetrePerSecond {
+                        suffix: " m/s",
+                    }
+                    KilometrePerHour {
+                        suffix: " km/h",
+                        ratio: 1. / 3.6,
+                    }
+                    KilometrePerSecond {
+                        suffix: " km/s",
+                        ratio: 1e3,
+                    }
+                ]
+            }
+            scalar_properties [
+                Action [
+                    JouleSecond {
+                        suffix: " J\u{b7}s", // J·s
+                    }
+                ]
+                Amount [
+                    Mole {
+                        suffix: " mol",
+                    }
+                    Millimole {
+                        suffix: " mmol",
+                        ratio: 1e-3,
+                    }
+                ]
+                CatalyticActivity [
+                    Katal {
+                        suffix: " kat",
+                    }
+                ]
+                DoseEquivalent [
+                    Sievert {
+                        suffix: " Sv",
+                    }
+                ]
+                Entropy [
+                    JoulePerKelvin {
+                        suffix: " J/\u{b0}K", // J/°K
+                    }
+                ]
+                Frequency [
+                    Hertz {
+                        suffix: " Hz",
+                    }
+                    Kilohertz {
+                        suffix: " kHz",
+                        ratio: 1e3,
+                    }
+                    Megahertz {
+                        suffix: " MHz",
+                        ratio: 1e6,
+                    }
+                    Gigahertz {
+                        suffix: " GHz",
+                        ratio: 1e9,
+                    }
+                ]
+                GravitationalConstant [
+                    NewtonSquareMetrePerSquareKilogram {
+                        suffix: " N\u{b7}m\u{b2}/kg\u{b2}", // N·m²/kg²
+                    }
+                ]
+                GravitationalParameter [
+                    CubicMetrePerSquareSecond {
+                        suffix: " m\u{b3}/s\u{b2}", // m³/s²
+                    }
+                ]
+                InverseAmount [
+                    PerMole {
+                        suffix: " mol\u{207b}\u{b9}", // mol⁻¹
+                    }
+                ]
+                Irradiance [
+                    WattPerSquareMetre {
+                        suffix: " W/m\u{b2}", // W/m²
+                    }
+                ]
+                LuminousIntensity [
+                    Candela {
+                        suffix: " cd",
+                    }
+                ]
+                MassDensity [
+                    KilogramPerCubicMetre {
+                        suffix: " kg/m\u{b3}", // kg/m³
+                    }
+                    GramPerMillilitre {
+                        suffix: " g/ml",
+                        ratio: 1e3,
+                    }
+                ]
+                MassFlowRate [
+                    KilogramPerSecond {
+                        suffix: " kg/s",
+                    }
+                ]
+                MolarConcentration [
+                    MolePerCubicMetre {
+                        suffix: " mol/m\u{b3}", // mol/m³
+                    }
+                ]
+                MolarHeatCapacity [
+                    JoulePerKelvinPerMole {
+                        suffix: " J/K/mol",
+                    }
+                ]
+                MomentOfInertia [
+                    KilogramSquareMetre {
+                        suffix: " kg\u{b7}m\u{b2}", // kg·m²
+                    }
+                ]
+                RadioactiveActivity [
+                    Becquerel {
+                        suffix: " Bq",
+                    }
+                ]
+                RadioactiveDose [
+                    Gray {
+                        suffix: " Gy",
+                    }
+                ]
+                SolidAngle [
+                    Steradian {
+                        suffix: " sr",
+                    }
+                ]
+                SpecificEnergy [
+                    JoulePerKilogram {
+                        suffix: " J/kg",
+                    }
+                ]
+                SpecificHeatCapacity [
+                    JoulePerKilogramPerKelvin {
+                        suffix: " J/kg/\u{b0}K", // J/kg/°K
+                    }
+                ]
+                SquareTime [
+                    SquareSecond {
+                        suffix: " s\u{b2}", // s²
+                    }
+                ]
+                ThermalConductivity [
+                    WattPerMetrePerKelvin {
+                        suffix: " W/m/\u{b0}K", // W/m/°K
+                    }
+                ]
+                VolumetricFlowRate [
+                    CubicMetrePerSecond {
+                        suffix: " m\u{b3}/s", // m³/s
+                    }
+                ]
+                $( $scalar_property )*
+            ]
+            vector_properties [
+                AngularMomentum [
+                    KilogramSquareMetrePerSecond {
+                        suffix: " kg\u{b7}m\u{b2}/s", // kg·m²/s
+                    }
+                ]
+                ElectricFieldStrength [
+                    VoltPerMetre {
+                        suffix: " V/m",
+                    }
+                ]
+                LuminousFlux [
+                    Lumen {
+                        suffix: " lm",
+                    }
+                ]
+                MagneticFieldStrength [
+                    AmperePerMetre {
+                        suffix: " A/m",
+                    }
+                ]
+                $( $vector_property )*
+            ]
+            angle_measurement_units [
+                Cycle {
+                    suffix: " rev",
+                    cycle_fraction: 1.,
+                }
+                Gradian {
+                    suffix: " grad",
+                    cycle_fraction: 400.,
+                }
+                Degree {
+                    suffix: " deg",
+                    cycle_fraction: 360.,
+                }
+                ArcMinute {
+                    suffix: " deg'",
+                    cycle_fraction: 360. * 60.,
+                }
+                ArcSecond {
+                    suffix: " deg\"",
+                    cycle_fraction: 360. * 60. * 60.,
+                }
+                $( $angle_unit )*
+            ]
+            relationships [
+                SquareMetre 1 == Metre 1 * __ 1,
+                SquareKilometre 1 == Kilometre 1 * __ 1,
+                SquareDecimetre 1 == Decimetre 1 * __ 1,
+                SquareCentimetre 1 == Centimetre 1 * __ 1,
+                SquareMillimetre 1 == Millimetre 1 * __ 1,
+                SquareMicrometre 1 == Micrometre 1 * __ 1,
+                CubicMetre 1 == SquareMetre 1 * Metre 1,
+                Litre 1 == SquareDecimetre 1 * Decimetre 1,
+                Millilitre 1 == SquareCentimetre 1 * Centimetre 1,
+                MetrePerSecond 1 == MetrePerSquareSecond 1 * Second 1,
+                Metre 1 == MetrePerSecond 1 * Second 1,
+                Kilometre 1 == KilometrePerHour 1 * Hour 1,
+                Cycle 1 == Hertz 1 * Second 1,
+                SquareMetre 1 == SquareMetrePerSecond 1 * Second 1,
+                SquareMillimetre 1 == SquareMetrePerSecond 1 * Microsecond 1,
+                SquareMetrePerSecond 1 == Metre 1 * MetrePerSecond 1,
+                SquareSecond 1 == Second 1 * __ 1,
+                CubicMetre 1 == CubicMetrePerSecond 1 * Second 1,
+                CubicMetrePerSecond 1 == SquareMetre 1 * MetrePerSecond 1,
+                JouleSecond 1 == Joule 1 * Second 1,
+                JouleSecond 1 == Watt 1 * SquareSecond 1,
+                KilogramSquareMetre 1 == KilogramSquareMetrePerSecond 1 * Second 1,
+                PascalSecond 1 == Pascal 1 * Second 1,
+                Joule 1 == Newton 1 * Metre 1,
+                Joule 1 == NewtonSecond 1 * MetrePerSecond 1,
+                KilogramSquareMetre 1 == Joule 1 * SquareSecond 1,
+                Newton 1 == Kilogram 1 * MetrePerSquareSecond 1,
+                Kilogram 1 == KilogramPerCubicMetre 1 * CubicMetre 1,
+                Gram 1 == GramPerMillilitre 1 * Millilitre 1,
+                Kilogram 1 == KilogramPerSecond 1 * Second 1,
+                KilogramSquareMetre 1 == Kilogram 1 * SquareMetre 1,
+                NewtonSecond 1 == Newton 1 * Second 1,
+                NewtonSecond 1 == Kilogram 1 * MetrePerSecond 1,
+                Joule 1 == Watt 1 * Second 1,
+                Newton 1 == Pascal 1 * SquareMetre 1,
+                Newton 1 == Hectopascal 1 * SquareDecimetre 1,
+                Joule 1 == JoulePerKilogram 1 * Kilogram 1,
+                Joule 1 == JoulePerKelvin 1 * Kelvin 1,
+                JoulePerKelvin 1 == JoulePerKilogramPerKelvin 1 * Kilogram 1,
+                Mole 1 == Katal 1 * Second 1,
+                Mole 1 == MolePerCubicMetre 1 * CubicMetre 1,
+                Lumen 1 == Lux 1 * SquareMetre 1,
+                Lux 1 == CandelaPerSquareMetre 1 * Steradian 1,
+                Watt 1 == WattPerSquareMetre 1 * SquareMetre 1,
+                Candela 1 == CandelaPerSquareMetre 1 * SquareMetre 1,
+                Lumen 1 == Candela 1 * Steradian 1,
+                Coulomb 1 == Farad 1 * Volt 1,
+                Microcoulomb 1 == Microfarad 1 * Volt 1,
+                Millicoulomb 1 == Microfarad 1 * Kilovolt 1,
+                Ampere 1 == Siemens 1 * Volt 1,
+                One 1 == Siemens 1 * Ohm 1,
+                Volt 1 == Ohm 1 * Ampere 1,
+                Coulomb 1 == Ampere 1 * Second 1,
+                Millicoulomb 1 == Ampere 1 * Millisecond 1,
+                Millicoulomb 1 == Milliampere 1 * Second 1,
+                Microcoulomb 1 == Milliampere 1 * Millisecond 1,
+                Microcoulomb 1 == Microampere 1 * Second 1,
+                Volt 1 == VoltPerMetre 1 * Metre 1,
+                Watt 1 == Volt 1 * Ampere 1,
+                Milliwatt 1 == Volt 1 * Milliampere 1,
+                Watt 1 == Kilovolt 1 * Milliampere 1,
+                Milliwatt 1 == Kilovolt 1 * Microampere 1,
+                Kilowatt 1 == Kilovolt 1 * Ampere 1,
+                Milliwatt 1 == Millivolt 1 * Ampere 1,
+                Weber 1 == Henry 1 * Ampere 1,
+                Ampere 1 == AmperePerMetre 1 * Metre 1,
+                Milliampere 1 == AmperePerMetre 1 * Millimetre 1,
+                Microampere 1 == AmperePerMetre 1 * Micrometre 1,
+                VoltPerMetre 1 == MetrePerSecond 1 * Tesla 1,
+                Weber 1 == Tesla 1 * SquareMetre 1,
+                Kilojoule 1 == Kilowatt 1 * Second 1,
+                Megajoule 1 == Megawatt 1 * Second 1,
+                Volt 1 == Kiloohm 1 * Milliampere 1,
+                One 1 == PerMole 1 * Mole 1,
+                JoulePerKelvin 1 == JoulePerKelvinPerMole 1 * Mole 1,
+                CubicMetrePerSquareSecond 1 == NewtonSquareMetrePerSquareKilogram 1 * Kilogram 1,
+                CubicMetrePerSquareSecond 1 == MetrePerSquareSecond 1 * SquareMetre 1,
+                $( $relationship )*
+            ]
+            relationships_2d [
+                MetrePerSecond 2 == MetrePerSquareSecond 2 * Second 1,
+                Metre 2 == MetrePerSecond 2 * Second 1,
+                Kilometre 2 == KilometrePerHour 2 * Hour 1,
+                KilogramSquareMetrePerSecond 1 == KilogramMetrePerSecond 2 X Metre 2,
+                Joule 1 == Newton 2 * Metre 2,
+                Joule 1 == NewtonSecond 2 * MetrePerSecond 2,
+                Newton 2 == Kilogram 1 * MetrePerSquareSecond 2,
+                NewtonSecond 2 == Newton 2 * Second 1,
+                NewtonSecond 2 == Kilogram 1 * MetrePerSecond 2,
+                NewtonMetre 1 == Newton 2 X Metre 2,
+                VoltPerMetre 1 == MetrePerSecond 2 X Tesla 2,
+                One 1 == One 2 * __ 2,
+                One 1 == One 2 X __ 2,
+            ]
+            relationships_3d [
+                MetrePerSecond 3 == MetrePerSquareSecond 3 * Second 1,
+                Metre 3 == MetrePerSecond 3 * Second 1,
+                Kilometre 3 == KilometrePerHour 3 * Hour 1,
+                KilogramSquareMetrePerSecond 3 == KilogramMetrePerSecond 3 X Metre 3,
+                Joule 1 == Newton 3 * Metre 3,
+                Joule 1 == NewtonSecond 3 * MetrePerSecond 3,
+                Newton 3 == Kilogram 1 * MetrePerSquareSecond 3,
+                NewtonSecond 3 == Newton 3 * Second 1,
+                NewtonSecond 3 == Kilogram 1 * MetrePerSecond 3,
+                NewtonMetre 3 == Newton 3 X Metre 3,
+                VoltPerMetre 3 == MetrePerSecond 3 X Tesla 3,
+                One 1 == One 3 * __ 3,
+                One 3 == One 3 X __ 3,
+            ]
+        }
+    };
+}
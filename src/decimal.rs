@@ -0,0 +1,183 @@
+//! Implementation of the numeric traits for the type `rust_decimal::Decimal`,
+//! available with the feature `decimal`.
+//!
+//! A `Decimal` has 28 significant decimal digits, and so it is useful
+//! when the values must be represented exactly in base ten, like for money or for cadastral data.
+//! Its range is about ±7.9e28, and so the conversions of units having a larger ratio, and the
+//! operations whose result is out of range, panic.
+//! The inverse trigonometric functions and the cubic root are computed starting from
+//! the `f64` approximation, and refining it using `Decimal` arithmetic.
+
+use crate::float_math::FloatFunctions;
+use crate::traits::{
+    CubicRoot, FromF64, HasEpsilon, HasHalf, HasOne, HasSign, HasZero, InverseTrigonometry,
+    Logarithm, LossyFrom, Pow, PowerDecibel, RootPowerDecibel, ScaleByRatio, Sqrt, Trigonometry,
+};
+use core::convert::TryFrom;
+pub use rust_decimal::Decimal;
+use rust_decimal::MathematicalOps;
+
+fn to_f64(n: Decimal) -> f64 {
+    f64::try_from(n).unwrap_or(f64::NAN)
+}
+
+impl Pow for Decimal {
+    fn powf(self, exponent: Self) -> Self {
+        self.powd(exponent)
+    }
+}
+
+impl FromF64 for Decimal {
+    fn from_f64(n: f64) -> Self {
+        Decimal::try_from(n).expect("f64 value not representable as Decimal")
+    }
+}
+
+impl ScaleByRatio for Decimal {
+    fn scale_by_ratio(self, ratio: f64) -> Self {
+        self * Self::from_f64(ratio)
+    }
+    fn apply_conversion<F: Fn(f64) -> f64>(self, function: F) -> Self {
+        Self::from_f64(function(to_f64(self)))
+    }
+}
+
+impl Sqrt for Decimal {
+    type Output = Decimal;
+    fn sqrt(self) -> Self {
+        MathematicalOps::sqrt(&self).expect("square root of a negative Decimal")
+    }
+}
+
+impl CubicRoot for Decimal {
+    type Output = Decimal;
+    fn cubic_root(self) -> Self {
+        if self.is_zero() {
+            return self;
+        }
+        // Newton iterations for the solution of y^3 = self.
+        let mut y = Decimal::from_f64(FloatFunctions::cbrt(to_f64(self)));
+        for _ in 0..2 {
+            y -= (y * y * y - self) / (Decimal::from(3) * y * y);
+        }
+        y
+    }
+}
+
+impl Trigonometry for Decimal {
+    type Output = Decimal;
+    fn cos(self) -> Self::Output {
+        MathematicalOps::cos(&self)
+    }
+    fn sin(self) -> Self::Output {
+        MathematicalOps::sin(&self)
+    }
+    // The tangent computed by `rust_decimal` is less accurate than the ratio of sine and cosine.
+    fn tan(self) -> Self::Output {
+        MathematicalOps::sin(&self) / MathematicalOps::cos(&self)
+    }
+    fn sin_cos(self) -> (Self::Output, Self::Output) {
+        (MathematicalOps::sin(&self), MathematicalOps::cos(&self))
+    }
+}
+
+impl InverseTrigonometry for Decimal {
+    type Output = Decimal;
+    fn acos(self) -> Self::Output {
+        ((Decimal::ONE - self) * (Decimal::ONE + self))
+            .sqrt()
+            .atan2(self)
+    }
+    fn asin(self) -> Self::Output {
+        self.atan2(((Decimal::ONE - self) * (Decimal::ONE + self)).sqrt())
+    }
+    fn atan2(self, other: Self) -> Self::Output {
+        let angle = Decimal::from_f64(FloatFunctions::atan2(to_f64(self), to_f64(other)));
+        if self.is_zero() && other.is_zero() {
+            return angle;
+        }
+        // A Newton iteration for the solution of tan(angle) = self / other.
+        let (sin, cos) = angle.sin_cos();
+        angle + (self * cos - other * sin) / (other * cos + self * sin)
+    }
+}
+
+impl Logarithm for Decimal {
+    fn ln(self) -> Self {
+        MathematicalOps::ln(&self)
+    }
+}
+
+impl PowerDecibel for Decimal {
+    fn to_power_decibels(self) -> Self {
+        self.log10() * Decimal::TEN
+    }
+    fn power_decibels_to_value(self) -> Self {
+        Decimal::TEN.powd(self / Decimal::TEN)
+    }
+}
+
+impl RootPowerDecibel for Decimal {
+    fn to_root_power_decibels(self) -> Self {
+        self.log10() * Decimal::TWO * Decimal::TEN
+    }
+    fn root_power_decibels_to_value(self) -> Self {
+        Decimal::TEN.powd(self / (Decimal::TWO * Decimal::TEN))
+    }
+}
+
+impl HasZero for Decimal {
+    const ZERO: Self = Decimal::ZERO;
+}
+
+impl HasOne for Decimal {
+    const ONE: Self = Decimal::ONE;
+}
+
+impl HasHalf for Decimal {
+    const HALF: Self = Decimal::from_parts(5, 0, 0, false, 1);
+}
+
+// The numbers near 1 have 28 decimal digits after the point.
+impl HasEpsilon for Decimal {
+    const EPSILON: Self = Decimal::from_parts(1, 0, 0, false, 28);
+}
+
+impl HasSign for Decimal {
+    fn signum(self) -> Self {
+        if self.is_sign_negative() {
+            -Decimal::ONE
+        } else {
+            Decimal::ONE
+        }
+    }
+    fn abs(self) -> Self {
+        Decimal::abs(&self)
+    }
+}
+
+impl LossyFrom<f32> for Decimal {
+    fn lossy_from(n: f32) -> Self {
+        Decimal::from_f64(n as f64)
+    }
+}
+impl LossyFrom<f64> for Decimal {
+    fn lossy_from(n: f64) -> Self {
+        Decimal::from_f64(n)
+    }
+}
+impl LossyFrom<Decimal> for Decimal {
+    fn lossy_from(n: Decimal) -> Self {
+        n
+    }
+}
+impl LossyFrom<Decimal> for f32 {
+    fn lossy_from(n: Decimal) -> Self {
+        to_f64(n) as f32
+    }
+}
+impl LossyFrom<Decimal> for f64 {
+    fn lossy_from(n: Decimal) -> Self {
+        to_f64(n)
+    }
+}
@@ -5,6 +5,57 @@ macro_rules! if_all_true {
     ( { true $($rest:tt)* } $( $fragment:item )* ) => { measures::if_all_true! { { $($rest)* } $( $fragment )* } };
 }
 
+// It expands the given items only if the feature `alloc` is enabled,
+// as the feature is checked in this crate, and not in the crate calling the macro.
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! if_alloc {
+    ( $( $fragment:item )* ) => { $( $fragment )* };
+}
+
+#[cfg(not(feature = "alloc"))]
+#[macro_export]
+macro_rules! if_alloc {
+    ( $( $fragment:item )* ) => {};
+}
+
+// It expands the given items only if the feature `dual` is enabled.
+#[cfg(feature = "dual")]
+#[macro_export]
+macro_rules! if_dual {
+    ( $( $fragment:item )* ) => { $( $fragment )* };
+}
+
+#[cfg(not(feature = "dual"))]
+#[macro_export]
+macro_rules! if_dual {
+    ( $( $fragment:item )* ) => {};
+}
+
+// It expands the given items only if the feature `bytemuck` is enabled.
+#[cfg(feature = "bytemuck")]
+#[macro_export]
+macro_rules! if_bytemuck {
+    ( $( $fragment:item )* ) => { $( $fragment )* };
+}
+
+#[cfg(not(feature = "bytemuck"))]
+#[macro_export]
+macro_rules! if_bytemuck {
+    ( $( $fragment:item )* ) => {};
+}
+
+// Without the feature `nalgebra`, the option `with_nalgebra` cannot be used.
+#[cfg(not(feature = "nalgebra"))]
+#[macro_export]
+macro_rules! inner_define_nalgebra_conversions {
+    { $( $flag:ident )* } => {
+        compile_error!(
+            "The option `with_nalgebra` requires the feature `nalgebra` of the crate `measures`."
+        );
+    };
+}
+
 #[macro_export]
 macro_rules! define_scalar_property {
     ( $scalar_property:tt ) => {};
@@ -30,7 +81,10 @@ macro_rules! define_measure_types_aux {
         exact: $exact:tt,
         with_approx: $with_approx:tt,
         with_correlation: $with_correlation:tt,
-        with_serde: $with_serde:tt;
+        with_serde: $with_serde:tt,
+        with_nd: $with_nd:tt,
+        with_complex: $with_complex:tt,
+        with_nalgebra: $with_nalgebra:tt;
         $(
             scalar_properties [
                 $(
@@ -82,7 +136,8 @@ macro_rules! define_measure_types_aux {
             dimensionless::{Dimensionless, One},
             angle::{Angle, Radian},
             traits::{
-                AngleMeasurementUnit, ArithmeticOps, LossyFrom, MeasurementUnit, Sqrt, VectorProperty, Trigonometry, PowerQuantity, RootPowerQuantity,
+                AngleMeasurementUnit, ArithmeticOps, BasicArithmeticOps, LinearMeasurementUnit, LossyFrom, MeasurementUnit, Sqrt, VectorMeasurementUnit, Trigonometry, PowerQuantity, RootPowerQuantity,
+                ScaleByRatio,
             },
         };
         use core::fmt;
@@ -117,16 +172,16 @@ macro_rules! define_measure_types_aux {
             measures::inner_define_measure_point_3d! { $with_approx $with_serde }
         }
         measures::if_all_true! { { $with_2d $with_transformations }
-            measures::inner_define_linear_map_2d! {}
+            measures::inner_define_linear_map_2d! { $with_serde }
         }
         measures::if_all_true! { { $with_3d $with_transformations }
-            measures::inner_define_linear_map_3d! {}
+            measures::inner_define_linear_map_3d! { $with_serde }
         }
         measures::if_all_true! { { $with_2d $with_transformations $with_points }
-            measures::inner_define_affine_map_2d! {}
+            measures::inner_define_affine_map_2d! { $with_serde }
         }
         measures::if_all_true! { { $with_3d $with_transformations $with_points }
-            measures::inner_define_affine_map_3d! {}
+            measures::inner_define_affine_map_3d! { $with_serde }
         }
 
         measures::if_all_true! { { $with_approx $with_2d }
@@ -141,11 +196,66 @@ macro_rules! define_measure_types_aux {
         measures::if_all_true! { { $with_approx $with_points $with_3d }
             measures::inner_define_approx_measure_point_3d! { $exact $with_serde }
         }
+        measures::if_all_true! { { $with_nd }
+            measures::inner_define_measure_n! { $with_2d $with_3d $with_serde }
+        }
+        measures::if_all_true! { { $with_nd $with_points }
+            measures::inner_define_measure_point_n! { $with_2d $with_3d $exact $with_serde }
+        }
+        measures::if_all_true! { { $with_nd $with_transformations }
+            measures::inner_define_linear_map_n! { $with_2d $with_3d }
+        }
+        measures::if_all_true! { { $exact $with_nalgebra }
+            measures::inner_define_nalgebra_conversions! { $with_points $with_2d $with_3d $with_transformations $with_approx }
+        }
+        measures::if_all_true! { { $exact $with_complex }
+            measures::inner_define_complex_measure! { $with_directions $with_serde }
+        }
+        measures::if_all_true! { { $with_serde }
+            measures::inner_define_serde_formats! { $with_points $with_approx $with_2d $with_3d $with_transformations }
+        }
+        measures::if_all_true! { { $with_approx }
+            measures::inner_define_kalman_filters! { $with_points $with_3d $with_transformations }
+        }
+        measures::inner_define_solvers! {}
+        measures::if_dual! {
+            measures::inner_define_differentiation! {}
+        }
+        measures::if_all_true! { { $exact }
+            measures::if_alloc! {
+                measures::inner_define_measure_vec! {}
+            }
+        }
+        measures::if_all_true! { { $exact $with_2d }
+            measures::if_alloc! {
+                measures::inner_define_measure_2d_vec! {}
+            }
+        }
+        measures::if_all_true! { { $exact $with_3d }
+            measures::if_alloc! {
+                measures::inner_define_measure_3d_vec! {}
+            }
+        }
+        measures::if_all_true! { { $with_points }
+            measures::if_alloc! {
+                measures::inner_define_interpolation! {}
+            }
+        }
+        measures::if_all_true! { { $with_points }
+            measures::inner_define_integration! { $with_2d $with_3d $with_nd $exact }
+        }
 
         // `scalar_properties` section
         $(
             $(
                 measures::measurement_scalar_property! { $scalar_prop }
+                measures::if_all_true! { { $with_serde }
+                    impl PropertyUnits for $scalar_prop {
+                        const UNITS: &'static [measures::traits::UnitDescription] = &[
+                            $( measures::traits::UnitDescription::of::<$scalar_unit>(), )*
+                        ];
+                    }
+                }
                 $(
                     measures::measurement_unit! {
                         name: $scalar_unit,
@@ -165,6 +275,13 @@ macro_rules! define_measure_types_aux {
         $(
             $(
                 measures::measurement_vector_property! { $vector_prop }
+                measures::if_all_true! { { $with_serde }
+                    impl PropertyUnits for $vector_prop {
+                        const UNITS: &'static [measures::traits::UnitDescription] = &[
+                            $( measures::traits::UnitDescription::of::<$vector_unit>(), )*
+                        ];
+                    }
+                }
                 $(
                     measures::measurement_unit! {
                         name: $vector_unit,
@@ -192,11 +309,27 @@ macro_rules! define_measure_types_aux {
             )*
         )?
 
+        // Built-in properties, having also the units of the `angle_measurement_units` section
+        measures::if_all_true! { { $with_serde }
+            impl PropertyUnits for Angle {
+                const UNITS: &'static [measures::traits::UnitDescription] = &[
+                    measures::traits::UnitDescription::of::<Radian>(),
+                    $( $( measures::traits::UnitDescription::of::<$angle_unit>(), )* )?
+                ];
+            }
+
+            impl PropertyUnits for Dimensionless {
+                const UNITS: &'static [measures::traits::UnitDescription] = &[
+                    measures::traits::UnitDescription::of::<One>(),
+                ];
+            }
+        }
+
         // `relationships` section
         $(
             $(
                 measures::define_units_relationship! {
-                    $exact $with_approx $with_correlation,
+                    $exact $with_approx $with_correlation $with_complex,
                     $unit1 $dim1 == $unit2 $dim2 $op $unit3 $dim3
                 }
             )*
@@ -215,7 +348,10 @@ macro_rules! define_measure_types_aux {
         exact: $exact:tt,
         with_approx: $with_approx:tt,
         with_correlation: $with_correlation:tt,
-        with_serde: $with_serde:tt;
+        with_serde: $with_serde:tt,
+        with_nd: $with_nd:tt,
+        with_complex: $with_complex:tt,
+        with_nalgebra: $with_nalgebra:tt;
 
         $(
             scalar_properties [
@@ -274,7 +410,10 @@ macro_rules! define_measure_types_aux {
             exact: $exact,
             with_approx: $with_approx,
             with_correlation: $with_correlation,
-            with_serde: $with_serde;
+            with_serde: $with_serde,
+            with_nd: $with_nd,
+            with_complex: $with_complex,
+            with_nalgebra: $with_nalgebra;
 
             $(
                 scalar_properties [
@@ -336,7 +475,10 @@ macro_rules! define_measure_types_aux {
         exact: $exact:tt,
         with_approx: $with_approx:tt,
         with_correlation: $with_correlation:tt,
-        with_serde: $with_serde:tt;
+        with_serde: $with_serde:tt,
+        with_nd: $with_nd:tt,
+        with_complex: $with_complex:tt,
+        with_nalgebra: $with_nalgebra:tt;
 
         $(
             scalar_properties [
@@ -395,7 +537,10 @@ macro_rules! define_measure_types_aux {
             exact: $exact,
             with_approx: $with_approx,
             with_correlation: $with_correlation,
-            with_serde: $with_serde;
+            with_serde: $with_serde,
+            with_nd: $with_nd,
+            with_complex: $with_complex,
+            with_nalgebra: $with_nalgebra;
 
             $(
                 scalar_properties [
@@ -457,7 +602,10 @@ macro_rules! define_measure_types_aux {
         exact: $exact:tt,
         with_approx: $with_approx:tt,
         with_correlation: $with_correlation:tt,
-        with_serde: $with_serde:tt;
+        with_serde: $with_serde:tt,
+        with_nd: $with_nd:tt,
+        with_complex: $with_complex:tt,
+        with_nalgebra: $with_nalgebra:tt;
 
         $(
             scalar_properties [
@@ -516,7 +664,10 @@ macro_rules! define_measure_types_aux {
             exact: $exact,
             with_approx: $with_approx,
             with_correlation: $with_correlation,
-            with_serde: $with_serde;
+            with_serde: $with_serde,
+            with_nd: $with_nd,
+            with_complex: $with_complex,
+            with_nalgebra: $with_nalgebra;
 
             $(
                 scalar_properties [
@@ -578,7 +729,10 @@ macro_rules! define_measure_types_aux {
         exact: $exact:tt,
         with_approx: $with_approx:tt,
         with_correlation: $with_correlation:tt,
-        with_serde: $with_serde:tt;
+        with_serde: $with_serde:tt,
+        with_nd: $with_nd:tt,
+        with_complex: $with_complex:tt,
+        with_nalgebra: $with_nalgebra:tt;
 
         $(
             scalar_properties [
@@ -637,7 +791,10 @@ macro_rules! define_measure_types_aux {
             exact: $exact,
             with_approx: $with_approx,
             with_correlation: $with_correlation,
-            with_serde: $with_serde;
+            with_serde: $with_serde,
+            with_nd: $with_nd,
+            with_complex: $with_complex,
+            with_nalgebra: $with_nalgebra;
 
             $(
                 scalar_properties [
@@ -699,7 +856,10 @@ macro_rules! define_measure_types_aux {
         exact: $exact:tt,
         with_approx: $with_approx:tt,
         with_correlation: $with_correlation:tt,
-        with_serde: $with_serde:tt;
+        with_serde: $with_serde:tt,
+        with_nd: $with_nd:tt,
+        with_complex: $with_complex:tt,
+        with_nalgebra: $with_nalgebra:tt;
 
         $(
             scalar_properties [
@@ -758,7 +918,10 @@ macro_rules! define_measure_types_aux {
             exact: $exact,
             with_approx: $with_approx,
             with_correlation: $with_correlation,
-            with_serde: $with_serde;
+            with_serde: $with_serde,
+            with_nd: $with_nd,
+            with_complex: $with_complex,
+            with_nalgebra: $with_nalgebra;
 
             $(
                 scalar_properties [
@@ -820,7 +983,10 @@ macro_rules! define_measure_types_aux {
         exact: false,
         with_approx: $with_approx:tt,
         with_correlation: $with_correlation:tt,
-        with_serde: $with_serde:tt;
+        with_serde: $with_serde:tt,
+        with_nd: $with_nd:tt,
+        with_complex: $with_complex:tt,
+        with_nalgebra: $with_nalgebra:tt;
 
         $(
             scalar_properties [
@@ -879,7 +1045,10 @@ macro_rules! define_measure_types_aux {
             exact: true,
             with_approx: $with_approx,
             with_correlation: $with_correlation,
-            with_serde: $with_serde;
+            with_serde: $with_serde,
+            with_nd: $with_nd,
+            with_complex: $with_complex,
+            with_nalgebra: $with_nalgebra;
 
             $(
                 scalar_properties [
@@ -941,7 +1110,10 @@ macro_rules! define_measure_types_aux {
         exact: $exact:tt,
         with_approx: false,
         with_correlation: $with_correlation:tt,
-        with_serde: $with_serde:tt;
+        with_serde: $with_serde:tt,
+        with_nd: $with_nd:tt,
+        with_complex: $with_complex:tt,
+        with_nalgebra: $with_nalgebra:tt;
 
         $(
             scalar_properties [
@@ -1000,7 +1172,10 @@ macro_rules! define_measure_types_aux {
             exact: $exact,
             with_approx: true,
             with_correlation: $with_correlation,
-            with_serde: $with_serde;
+            with_serde: $with_serde,
+            with_nd: $with_nd,
+            with_complex: $with_complex,
+            with_nalgebra: $with_nalgebra;
 
             $(
                 scalar_properties [
@@ -1062,7 +1237,10 @@ macro_rules! define_measure_types_aux {
         exact: $exact:tt,
         with_approx: $with_approx:tt,
         with_correlation: false,
-        with_serde: $with_serde:tt;
+        with_serde: $with_serde:tt,
+        with_nd: $with_nd:tt,
+        with_complex: $with_complex:tt,
+        with_nalgebra: $with_nalgebra:tt;
 
         $(
             scalar_properties [
@@ -1120,7 +1298,10 @@ macro_rules! define_measure_types_aux {
             exact: $exact,
             with_approx: $with_approx,
             with_correlation: true,
-            with_serde: $with_serde;
+            with_serde: $with_serde,
+            with_nd: $with_nd,
+            with_complex: $with_complex,
+            with_nalgebra: $with_nalgebra;
 
             $(
                 scalar_properties [
@@ -1182,7 +1363,136 @@ macro_rules! define_measure_types_aux {
         exact: $exact:tt,
         with_approx: $with_approx:tt,
         with_correlation: $with_correlation:tt,
-        with_serde: false;
+        with_serde: false,
+        with_nd: $with_nd:tt,
+        with_complex: $with_complex:tt,
+        with_nalgebra: $with_nalgebra:tt;
+
+        $(
+            scalar_properties [
+                $(
+                    $scalar_prop:ident [
+                        $(
+                            $scalar_unit:ident {
+                                $($scalar_key:ident : $scalar_val:expr),* $(,)?
+                            }
+                        )*
+                    ]
+                )*
+            ]
+        )?
+
+        $(
+            vector_properties [
+                $(
+                    $vector_prop:ident [
+                        $(
+                            $vector_unit:ident {
+                                $($vector_key:ident : $vector_val:expr),* $(,)?
+                            }
+                        )*
+                    ]
+                )*
+            ]
+        )?
+
+        $(
+            angle_measurement_units [
+                $(
+                    $angle_unit:ident {
+                        $($angle_key:ident : $angle_val:expr),* $(,)?
+                    }
+                )*
+            ]
+        )?
+
+        $(
+            relationships [
+                $(
+                    $unit1:ident $dim1:tt == $unit2:ident $dim2:tt $op:tt $unit3:ident $dim3:tt $(,)?
+                )*
+            ]
+        )?
+    } => {
+        measures::define_measure_types_aux!{
+            $( $flag ) *,
+            with_points: $with_points,
+            with_directions: $with_directions,
+            with_2d: $with_2d,
+            with_3d: $with_3d,
+            with_transformations: $with_transformations,
+            exact: $exact,
+            with_approx: $with_approx,
+            with_correlation: $with_correlation,
+            with_serde: true,
+            with_nd: $with_nd,
+            with_complex: $with_complex,
+            with_nalgebra: $with_nalgebra;
+
+            $(
+                scalar_properties [
+                    $(
+                        $scalar_prop [
+                            $(
+                                $scalar_unit {
+                                    $($scalar_key : $scalar_val),*
+                                }
+                            )*
+                        ]
+                    )*
+                ]
+            )?
+
+            $(
+                vector_properties [
+                    $(
+                        $vector_prop [
+                            $(
+                                $vector_unit {
+                                    $($vector_key : $vector_val),*
+                                }
+                            )*
+                        ]
+                    )*
+                ]
+            )?
+
+            $(
+                angle_measurement_units [
+                    $(
+                        $angle_unit {
+                            $($angle_key : $angle_val),*
+                        }
+                    )*
+                ]
+            )?
+
+            $(
+                relationships [
+                    $(
+                        $unit1 $dim1 == $unit2 $dim2 $op $unit3 $dim3
+                    )*
+                ]
+            )?
+        }
+    };
+
+    // First option is "with_nd"
+    {
+        with_nd $( $flag:ident )*,
+
+        with_points: $with_points:tt,
+        with_directions: $with_directions:tt,
+        with_2d: $with_2d:tt,
+        with_3d: $with_3d:tt,
+        with_transformations: $with_transformations:tt,
+        exact: $exact:tt,
+        with_approx: $with_approx:tt,
+        with_correlation: $with_correlation:tt,
+        with_serde: $with_serde:tt,
+        with_nd: false,
+        with_complex: $with_complex:tt,
+        with_nalgebra: $with_nalgebra:tt;
 
         $(
             scalar_properties [
@@ -1240,7 +1550,262 @@ macro_rules! define_measure_types_aux {
             exact: $exact,
             with_approx: $with_approx,
             with_correlation: $with_correlation,
-            with_serde: true;
+            with_serde: $with_serde,
+            with_nd: true,
+            with_complex: $with_complex,
+            with_nalgebra: $with_nalgebra;
+
+            $(
+                scalar_properties [
+                    $(
+                        $scalar_prop [
+                            $(
+                                $scalar_unit {
+                                    $($scalar_key : $scalar_val),*
+                                }
+                            )*
+                        ]
+                    )*
+                ]
+            )?
+
+            $(
+                vector_properties [
+                    $(
+                        $vector_prop [
+                            $(
+                                $vector_unit {
+                                    $($vector_key : $vector_val),*
+                                }
+                            )*
+                        ]
+                    )*
+                ]
+            )?
+
+            $(
+                angle_measurement_units [
+                    $(
+                        $angle_unit {
+                            $($angle_key : $angle_val),*
+                        }
+                    )*
+                ]
+            )?
+
+            $(
+                relationships [
+                    $(
+                        $unit1 $dim1 == $unit2 $dim2 $op $unit3 $dim3
+                    )*
+                ]
+            )?
+        }
+    };
+
+    // First option is "with_complex"
+    {
+        with_complex $( $flag:ident )*,
+
+        with_points: $with_points:tt,
+        with_directions: $with_directions:tt,
+        with_2d: $with_2d:tt,
+        with_3d: $with_3d:tt,
+        with_transformations: $with_transformations:tt,
+        exact: $exact:tt,
+        with_approx: $with_approx:tt,
+        with_correlation: $with_correlation:tt,
+        with_serde: $with_serde:tt,
+        with_nd: $with_nd:tt,
+        with_complex: false,
+        with_nalgebra: $with_nalgebra:tt;
+
+        $(
+            scalar_properties [
+                $(
+                    $scalar_prop:ident [
+                        $(
+                            $scalar_unit:ident {
+                                $($scalar_key:ident : $scalar_val:expr),* $(,)?
+                            }
+                        )*
+                    ]
+                )*
+            ]
+        )?
+
+        $(
+            vector_properties [
+                $(
+                    $vector_prop:ident [
+                        $(
+                            $vector_unit:ident {
+                                $($vector_key:ident : $vector_val:expr),* $(,)?
+                            }
+                        )*
+                    ]
+                )*
+            ]
+        )?
+
+        $(
+            angle_measurement_units [
+                $(
+                    $angle_unit:ident {
+                        $($angle_key:ident : $angle_val:expr),* $(,)?
+                    }
+                )*
+            ]
+        )?
+
+        $(
+            relationships [
+                $(
+                    $unit1:ident $dim1:tt == $unit2:ident $dim2:tt $op:tt $unit3:ident $dim3:tt $(,)?
+                )*
+            ]
+        )?
+    } => {
+        measures::define_measure_types_aux!{
+            $( $flag ) *,
+            with_points: $with_points,
+            with_directions: $with_directions,
+            with_2d: $with_2d,
+            with_3d: $with_3d,
+            with_transformations: $with_transformations,
+            exact: $exact,
+            with_approx: $with_approx,
+            with_correlation: $with_correlation,
+            with_serde: $with_serde,
+            with_nd: $with_nd,
+            with_complex: true,
+            with_nalgebra: $with_nalgebra;
+
+            $(
+                scalar_properties [
+                    $(
+                        $scalar_prop [
+                            $(
+                                $scalar_unit {
+                                    $($scalar_key : $scalar_val),*
+                                }
+                            )*
+                        ]
+                    )*
+                ]
+            )?
+
+            $(
+                vector_properties [
+                    $(
+                        $vector_prop [
+                            $(
+                                $vector_unit {
+                                    $($vector_key : $vector_val),*
+                                }
+                            )*
+                        ]
+                    )*
+                ]
+            )?
+
+            $(
+                angle_measurement_units [
+                    $(
+                        $angle_unit {
+                            $($angle_key : $angle_val),*
+                        }
+                    )*
+                ]
+            )?
+
+            $(
+                relationships [
+                    $(
+                        $unit1 $dim1 == $unit2 $dim2 $op $unit3 $dim3
+                    )*
+                ]
+            )?
+        }
+    };
+
+    // First option is "with_nalgebra"
+    {
+        with_nalgebra $( $flag:ident )*,
+
+        with_points: $with_points:tt,
+        with_directions: $with_directions:tt,
+        with_2d: $with_2d:tt,
+        with_3d: $with_3d:tt,
+        with_transformations: $with_transformations:tt,
+        exact: $exact:tt,
+        with_approx: $with_approx:tt,
+        with_correlation: $with_correlation:tt,
+        with_serde: $with_serde:tt,
+        with_nd: $with_nd:tt,
+        with_complex: $with_complex:tt,
+        with_nalgebra: false;
+
+        $(
+            scalar_properties [
+                $(
+                    $scalar_prop:ident [
+                        $(
+                            $scalar_unit:ident {
+                                $($scalar_key:ident : $scalar_val:expr),* $(,)?
+                            }
+                        )*
+                    ]
+                )*
+            ]
+        )?
+
+        $(
+            vector_properties [
+                $(
+                    $vector_prop:ident [
+                        $(
+                            $vector_unit:ident {
+                                $($vector_key:ident : $vector_val:expr),* $(,)?
+                            }
+                        )*
+                    ]
+                )*
+            ]
+        )?
+
+        $(
+            angle_measurement_units [
+                $(
+                    $angle_unit:ident {
+                        $($angle_key:ident : $angle_val:expr),* $(,)?
+                    }
+                )*
+            ]
+        )?
+
+        $(
+            relationships [
+                $(
+                    $unit1:ident $dim1:tt == $unit2:ident $dim2:tt $op:tt $unit3:ident $dim3:tt $(,)?
+                )*
+            ]
+        )?
+    } => {
+        measures::define_measure_types_aux!{
+            $( $flag ) *,
+            with_points: $with_points,
+            with_directions: $with_directions,
+            with_2d: $with_2d,
+            with_3d: $with_3d,
+            with_transformations: $with_transformations,
+            exact: $exact,
+            with_approx: $with_approx,
+            with_correlation: $with_correlation,
+            with_serde: $with_serde,
+            with_nd: $with_nd,
+            with_complex: $with_complex,
+            with_nalgebra: true;
 
             $(
                 scalar_properties [
@@ -1353,7 +1918,10 @@ macro_rules! define_measure_types {
             exact: false,
             with_approx: false,
             with_correlation: false,
-            with_serde: false;
+            with_serde: false,
+            with_nd: false,
+            with_complex: false,
+            with_nalgebra: false;
 
             $(
                 scalar_properties [
@@ -1436,13 +2004,34 @@ macro_rules! angle_measurement_unit {
             suffix: $suffix,
             offset: 0.,
             cycle_fraction: $cycle_fraction,
+            affine: false,
+        }
+    };
+    {
+        name: $name:ident,
+        suffix: $suffix:expr,
+        offset: $offset:expr,
+        cycle_fraction: $cycle_fraction:expr,
+    } => {
+        $crate::angle_measurement_unit! {
+            name: $name,
+            suffix: $suffix,
+            offset: $offset,
+            cycle_fraction: $cycle_fraction,
+            affine: true,
         }
+
+        const _: () = assert!(
+            ($offset) != 0.,
+            concat!("the unit `", stringify!($name), "` has a zero offset, and so the field `offset` must be omitted"),
+        );
     };
     {
         name: $name:ident,
         suffix: $suffix:expr,
         offset: $offset:expr,
         cycle_fraction: $cycle_fraction:expr,
+        affine: $affine:tt,
     } => {
         /// An `Angle` unit of measurement.
         pub struct $name;
@@ -1457,6 +2046,12 @@ macro_rules! angle_measurement_unit {
             const CYCLE_FRACTION: f64 = $cycle_fraction;
         }
 
+        impl measures::traits::LinearMeasurementUnit for $name {}
+
+        measures::if_all_true! { { $affine }
+            impl measures::traits::AffineUnit for $name {}
+        }
+
         impl<Number> core::ops::Mul<Measure<$name, Number>> for Measure<measures::dimensionless::One, Number>
         where
             Number: measures::traits::ArithmeticOps,
@@ -1491,6 +2086,11 @@ macro_rules! measurement_unit_aux {
         suffix: $suffix:expr,
         ratio: $ratio:expr,
         offset: $offset:expr,
+        $(
+            to_base: $to_base:expr,
+            from_base: $from_base:expr,
+        )?
+        linear: $linear:tt,
         with_2d: $with_2d:tt,
         with_3d: $with_3d:tt,
         vector: $vector:tt,
@@ -1502,6 +2102,25 @@ macro_rules! measurement_unit_aux {
             const RATIO: f64 = $ratio;
             const OFFSET: f64 = $offset;
             const SUFFIX: &'static str = $suffix;
+            $(
+                const LINEAR: bool = false;
+                fn to_base(value: f64) -> f64 {
+                    let function: fn(f64) -> f64 = $to_base;
+                    function(value)
+                }
+                fn from_base(value: f64) -> f64 {
+                    let function: fn(f64) -> f64 = $from_base;
+                    function(value)
+                }
+            )?
+        }
+
+        measures::if_all_true! { { $linear }
+            impl measures::traits::LinearMeasurementUnit for $name {}
+        }
+
+        measures::if_all_true! { { $vector }
+            impl measures::traits::VectorMeasurementUnit for $name {}
         }
 
         impl<Number> core::ops::Mul<Measure<$name, Number>> for Measure<measures::dimensionless::One, Number>
@@ -1662,6 +2281,7 @@ macro_rules! measurement_unit {
             suffix: $suffix,
             ratio: $ratio,
             offset: 0.,
+            linear: true,
             with_2d: $with_2d,
             with_3d: $with_3d,
             vector: true,
@@ -1682,6 +2302,7 @@ macro_rules! measurement_unit {
             suffix: $suffix,
             ratio: $ratio,
             offset: 0.,
+            linear: true,
             with_2d: $with_2d,
             with_3d: $with_3d,
             vector: false,
@@ -1703,6 +2324,38 @@ macro_rules! measurement_unit {
             suffix: $suffix,
             ratio: $ratio,
             offset: $offset,
+            linear: true,
+            with_2d: $with_2d,
+            with_3d: $with_3d,
+            vector: false,
+        }
+
+        impl measures::traits::AffineUnit for $name {}
+
+        const _: () = assert!(
+            ($offset) != 0.,
+            concat!("the unit `", stringify!($name), "` has a zero offset, and so the field `offset` must be omitted"),
+        );
+    };
+    {
+        name: $name:ident,
+        property: $property:ident,
+        suffix: $suffix:expr,
+        to_base: $to_base:expr,
+        from_base: $from_base:expr,
+        with_2d: $with_2d:tt,
+        with_3d: $with_3d:tt,
+        vector: false,
+    } => {
+        $crate::measurement_unit_aux! {
+            name: $name,
+            property: $property,
+            suffix: $suffix,
+            ratio: 1.,
+            offset: 0.,
+            to_base: $to_base,
+            from_base: $from_base,
+            linear: false,
             with_2d: $with_2d,
             with_3d: $with_3d,
             vector: false,
@@ -0,0 +1,647 @@
+// Generates a typed vector, i.e. a struct of 1-dimensional measures having possibly different units,
+// together with a covariance matrix and a Jacobian matrix for it.
+// It must be invoked in a module in which the types generated by `define_measure_types` are visible.
+#[macro_export]
+macro_rules! define_typed_vector {
+    {
+        vector: $vector:ident,
+        covariance: $covariance:ident,
+        jacobian: $jacobian:ident,
+        components: $components:ident,
+        fields [
+            $( $field:ident : $unit:ident ),+ $(,)?
+        ] $(,)?
+    } => {
+        /// Marker types identifying the components of the typed vector,
+        /// to be used as type arguments of its generic methods.
+        #[allow(non_camel_case_types)]
+        pub mod $components {
+            $(
+                pub struct $field;
+            )+
+
+            pub(super) enum Index {
+                $( $field, )+
+            }
+
+            /// Number of components of the typed vector.
+            pub const DIMENSIONS: usize = [$( stringify!($field) ),+].len();
+        }
+
+        $(
+            impl<Number> measures::traits::TypedVectorComponent<$vector<Number>> for $components::$field
+            where
+                Number: measures::traits::ArithmeticOps,
+            {
+                type Unit = $unit;
+                const INDEX: usize = $components::Index::$field as usize;
+            }
+        )+
+
+        /// Vector of 1-dimensional measures, each one having its own unit of measurement.
+        pub struct $vector<Number = f64>
+        where
+            Number: measures::traits::ArithmeticOps,
+        {
+            $( pub $field: Measure<$unit, Number>, )+
+        }
+
+        impl<Number> $vector<Number>
+        where
+            Number: measures::traits::ArithmeticOps,
+        {
+            /// TypedVector::new(Measure, ...) -> TypedVector
+            #[allow(clippy::too_many_arguments)]
+            pub const fn new($( $field: Measure<$unit, Number> ),+) -> Self {
+                Self { $( $field ),+ }
+            }
+
+            /// TypedVector::from_values([Number; DIMENSIONS]) -> TypedVector
+            /// Every value is expressed in the unit of its component.
+            pub fn from_values(values: [Number; $components::DIMENSIONS]) -> Self {
+                let [$( $field ),+] = values;
+                Self { $( $field: Measure::<$unit, Number>::new($field) ),+ }
+            }
+
+            /// TypedVector.values() -> [Number; DIMENSIONS]
+            pub fn values(self) -> [Number; $components::DIMENSIONS] {
+                [$( self.$field.value ),+]
+            }
+
+            /// TypedVector.get::<Component>() -> Measure<Component::Unit>
+            pub fn get<Component>(self) -> Measure<Component::Unit, Number>
+            where
+                Component: measures::traits::TypedVectorComponent<Self>,
+            {
+                Measure::<Component::Unit, Number>::new(self.values()[Component::INDEX])
+            }
+
+            /// TypedVector.set::<Component>(Measure<Component::Unit>)
+            pub fn set<Component>(&mut self, measure: Measure<Component::Unit, Number>)
+            where
+                Component: measures::traits::TypedVectorComponent<Self>,
+            {
+                let mut values = self.values();
+                values[Component::INDEX] = measure.value;
+                *self = Self::from_values(values);
+            }
+        }
+
+        impl<Number> Default for $vector<Number>
+        where
+            Number: measures::traits::ArithmeticOps,
+        {
+            /// TypedVector::default() -> TypedVector
+            /// It returns the zero vector.
+            fn default() -> Self {
+                Self { $( $field: Measure::<$unit, Number>::default() ),+ }
+            }
+        }
+
+        impl<Number> core::ops::Neg for $vector<Number>
+        where
+            Number: measures::traits::ArithmeticOps,
+        {
+            type Output = Self;
+
+            /// -TypedVector -> TypedVector
+            fn neg(self) -> Self::Output {
+                Self { $( $field: -self.$field ),+ }
+            }
+        }
+
+        impl<Number> core::ops::Add<$vector<Number>> for $vector<Number>
+        where
+            Number: measures::traits::ArithmeticOps,
+        {
+            type Output = Self;
+
+            /// TypedVector + TypedVector -> TypedVector
+            fn add(self, other: $vector<Number>) -> Self::Output {
+                Self { $( $field: self.$field + other.$field ),+ }
+            }
+        }
+
+        impl<Number> core::ops::AddAssign<$vector<Number>> for $vector<Number>
+        where
+            Number: measures::traits::ArithmeticOps,
+        {
+            /// TypedVector += TypedVector
+            fn add_assign(&mut self, other: $vector<Number>) {
+                $( self.$field += other.$field; )+
+            }
+        }
+
+        impl<Number> core::ops::Sub<$vector<Number>> for $vector<Number>
+        where
+            Number: measures::traits::ArithmeticOps,
+        {
+            type Output = Self;
+
+            /// TypedVector - TypedVector -> TypedVector
+            fn sub(self, other: $vector<Number>) -> Self::Output {
+                Self { $( $field: self.$field - other.$field ),+ }
+            }
+        }
+
+        impl<Number> core::ops::SubAssign<$vector<Number>> for $vector<Number>
+        where
+            Number: measures::traits::ArithmeticOps,
+        {
+            /// TypedVector -= TypedVector
+            fn sub_assign(&mut self, other: $vector<Number>) {
+                $( self.$field -= other.$field; )+
+            }
+        }
+
+        impl<Number> core::ops::Mul<Number> for $vector<Number>
+        where
+            Number: measures::traits::ArithmeticOps,
+        {
+            type Output = Self;
+
+            /// TypedVector * Number -> TypedVector
+            fn mul(self, n: Number) -> Self::Output {
+                Self { $( $field: self.$field * n ),+ }
+            }
+        }
+
+        impl<Number> core::ops::Div<Number> for $vector<Number>
+        where
+            Number: measures::traits::ArithmeticOps,
+        {
+            type Output = Self;
+
+            /// TypedVector / Number -> TypedVector
+            fn div(self, n: Number) -> Self::Output {
+                Self { $( $field: self.$field / n ),+ }
+            }
+        }
+
+        impl<Number> PartialEq<$vector<Number>> for $vector<Number>
+        where
+            Number: measures::traits::ArithmeticOps,
+        {
+            /// TypedVector == TypedVector -> bool
+            fn eq(&self, other: &$vector<Number>) -> bool {
+                $( self.$field == other.$field )&&+
+            }
+        }
+
+        impl<Number> Clone for $vector<Number>
+        where
+            Number: measures::traits::ArithmeticOps,
+        {
+            /// TypedVector.clone() -> TypedVector
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        /// TypedVector = TypedVector
+        impl<Number> Copy for $vector<Number>
+        where
+            Number: measures::traits::ArithmeticOps,
+        {
+        }
+
+        impl<Number> core::fmt::Display for $vector<Number>
+        where
+            Number: measures::traits::ArithmeticOps,
+        {
+            /// format!("{}", TypedVector) -> String
+            /// TypedVector.to_string() -> String
+            fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                formatter.write_str("{")?;
+                let mut separator = " ";
+                $(
+                    formatter.write_str(separator)?;
+                    formatter.write_str(stringify!($field))?;
+                    formatter.write_str(": ")?;
+                    core::fmt::Display::fmt(&self.$field, formatter)?;
+                    separator = ", ";
+                )+
+                let _ = separator;
+                formatter.write_str(" }")
+            }
+        }
+
+        impl<Number> core::fmt::Debug for $vector<Number>
+        where
+            Number: measures::traits::ArithmeticOps,
+        {
+            /// format!("{:?}", TypedVector) -> String
+            fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Display::fmt(self, formatter)
+            }
+        }
+
+        /// Covariance matrix of a typed vector.
+        /// The item in row `i` and column `j` is expressed in the product
+        /// of the units of the components `i` and `j`.
+        pub struct $covariance<Number = f64>
+        where
+            Number: measures::traits::ArithmeticOps,
+        {
+            pub c: [[Number; $components::DIMENSIONS]; $components::DIMENSIONS],
+        }
+
+        impl<Number> $covariance<Number>
+        where
+            Number: measures::traits::ArithmeticOps,
+        {
+            /// Create a covariance matrix from its coefficients.
+            pub const fn new(
+                coefficients: [[Number; $components::DIMENSIONS]; $components::DIMENSIONS],
+            ) -> Self {
+                Self { c: coefficients }
+            }
+
+            /// Create the covariance matrix of uncorrelated components,
+            /// having the specified standard deviations.
+            pub fn from_standard_deviations(standard_deviations: $vector<Number>) -> Self {
+                let values = standard_deviations.values();
+                Self {
+                    c: core::array::from_fn(|row| {
+                        core::array::from_fn(|column| {
+                            if row == column {
+                                values[row] * values[row]
+                            } else {
+                                Number::ZERO
+                            }
+                        })
+                    }),
+                }
+            }
+
+            /// Covariance.variance::<Component>() -> Number
+            /// The result is expressed in the square of the unit of the component.
+            pub fn variance<Component>(&self) -> Number
+            where
+                Component: measures::traits::TypedVectorComponent<$vector<Number>>,
+            {
+                self.c[Component::INDEX][Component::INDEX]
+            }
+
+            /// Covariance.standard_deviation::<Component>() -> Measure<Component::Unit>
+            pub fn standard_deviation<Component>(&self) -> Measure<Component::Unit, Number>
+            where
+                Component: measures::traits::TypedVectorComponent<$vector<Number>>,
+            {
+                Measure::<Component::Unit, Number>::new(measures::traits::Sqrt::sqrt(
+                    self.variance::<Component>(),
+                ))
+            }
+
+            /// Covariance.covariance::<Row, Column>() -> Number
+            /// The result is expressed in the product of the units of the two components.
+            pub fn covariance<Row, Column>(&self) -> Number
+            where
+                Row: measures::traits::TypedVectorComponent<$vector<Number>>,
+                Column: measures::traits::TypedVectorComponent<$vector<Number>>,
+            {
+                self.c[Row::INDEX][Column::INDEX]
+            }
+
+            /// Covariance.set_covariance::<Row, Column>(Number)
+            /// It sets both the symmetric items.
+            pub fn set_covariance<Row, Column>(&mut self, covariance: Number)
+            where
+                Row: measures::traits::TypedVectorComponent<$vector<Number>>,
+                Column: measures::traits::TypedVectorComponent<$vector<Number>>,
+            {
+                self.c[Row::INDEX][Column::INDEX] = covariance;
+                self.c[Column::INDEX][Row::INDEX] = covariance;
+            }
+
+            /// Covariance.correlation::<Row, Column>() -> Number
+            /// It returns the Pearson correlation coefficient, which is dimensionless.
+            pub fn correlation<Row, Column>(&self) -> Number
+            where
+                Row: measures::traits::TypedVectorComponent<$vector<Number>>,
+                Column: measures::traits::TypedVectorComponent<$vector<Number>>,
+            {
+                self.c[Row::INDEX][Column::INDEX]
+                    / measures::traits::Sqrt::sqrt(
+                        self.c[Row::INDEX][Row::INDEX] * self.c[Column::INDEX][Column::INDEX],
+                    )
+            }
+
+            /// Covariance.transformed(&Jacobian) -> Covariance
+            /// It returns `J * C * Jᵀ`, i.e. the covariance of the vectors
+            /// obtained by applying the Jacobian to vectors having this covariance.
+            pub fn transformed(&self, jacobian: &$jacobian<Number>) -> Self {
+                jacobian.transform_covariance(self)
+            }
+        }
+
+        impl<Number> Default for $covariance<Number>
+        where
+            Number: measures::traits::ArithmeticOps,
+        {
+            /// Covariance::default() -> Covariance
+            /// It returns the zero matrix, i.e. no uncertainty.
+            fn default() -> Self {
+                Self::new([[Number::ZERO; $components::DIMENSIONS]; $components::DIMENSIONS])
+            }
+        }
+
+        impl<Number> core::ops::Add<$covariance<Number>> for $covariance<Number>
+        where
+            Number: measures::traits::ArithmeticOps,
+        {
+            type Output = Self;
+
+            /// Covariance + Covariance -> Covariance
+            /// It is the covariance of the sum of two independent vectors.
+            fn add(self, other: $covariance<Number>) -> Self::Output {
+                Self {
+                    c: core::array::from_fn(|row| {
+                        core::array::from_fn(|column| self.c[row][column] + other.c[row][column])
+                    }),
+                }
+            }
+        }
+
+        // Covariance == Covariance -> bool
+        impl<Number> PartialEq<$covariance<Number>> for $covariance<Number>
+        where
+            Number: measures::traits::ArithmeticOps,
+        {
+            fn eq(&self, other: &$covariance<Number>) -> bool {
+                self.c == other.c
+            }
+        }
+
+        // Covariance.clone() -> Covariance
+        impl<Number> Clone for $covariance<Number>
+        where
+            Number: measures::traits::ArithmeticOps,
+        {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        // Covariance = Covariance
+        impl<Number> Copy for $covariance<Number>
+        where
+            Number: measures::traits::ArithmeticOps,
+        {
+        }
+
+        /// format!("{}", Covariance) -> String
+        /// Covariance.to_string() -> String
+        impl<Number> core::fmt::Display for $covariance<Number>
+        where
+            Number: measures::traits::ArithmeticOps,
+        {
+            fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                measures::matrix_utils::format_matrix::<
+                    { $components::DIMENSIONS },
+                    { $components::DIMENSIONS },
+                    Number,
+                >(formatter, &self.c, "", 1)
+            }
+        }
+
+        // format!("{:?}", Covariance)
+        impl<Number> core::fmt::Debug for $covariance<Number>
+        where
+            Number: measures::traits::ArithmeticOps,
+        {
+            fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Display::fmt(self, formatter)
+            }
+        }
+
+        measures::inner_define_typed_jacobian! {
+            $jacobian,
+            $vector, $covariance, $components,
+            $vector, $covariance, $components
+        }
+
+        impl<Number> $jacobian<Number>
+        where
+            Number: measures::traits::ArithmeticOps,
+        {
+            // Composition of transformations.
+            // Applying the resulting transformation is equivalent to apply first
+            // `other` and then `self`.
+            pub fn combined_with(&self, other: &$jacobian<Number>) -> Self {
+                Self {
+                    c: measures::matrix_utils::matrix_product(&self.c, &other.c),
+                }
+            }
+        }
+
+        impl<Number> Default for $jacobian<Number>
+        where
+            Number: measures::traits::ArithmeticOps,
+        {
+            /// Jacobian::default() -> Jacobian
+            /// It returns the identity transformation.
+            fn default() -> Self {
+                Self {
+                    c: measures::matrix_utils::identity_matrix(),
+                }
+            }
+        }
+    };
+}
+
+// Generates the Jacobian matrix of a function from a typed vector to another typed vector,
+// possibly having a different number of components, like an observation matrix
+// mapping the state of a Kalman filter to the observed quantities.
+// The two typed vectors must have been generated by `define_typed_vector` in the same module.
+#[macro_export]
+macro_rules! define_typed_jacobian {
+    {
+        jacobian: $jacobian:ident,
+        from [
+            vector: $from_vector:ident,
+            covariance: $from_covariance:ident,
+            components: $from_components:ident $(,)?
+        ],
+        to [
+            vector: $to_vector:ident,
+            covariance: $to_covariance:ident,
+            components: $to_components:ident $(,)?
+        ] $(,)?
+    } => {
+        measures::inner_define_typed_jacobian! {
+            $jacobian,
+            $from_vector, $from_covariance, $from_components,
+            $to_vector, $to_covariance, $to_components
+        }
+
+        impl<Number> Default for $jacobian<Number>
+        where
+            Number: measures::traits::ArithmeticOps,
+        {
+            /// Jacobian::default() -> Jacobian
+            /// It returns the zero matrix, whose items are then set by component.
+            fn default() -> Self {
+                Self {
+                    c: [[Number::ZERO; $from_components::DIMENSIONS]; $to_components::DIMENSIONS],
+                }
+            }
+        }
+    };
+}
+
+// Generates the part of a Jacobian matrix type which is shared
+// by `define_typed_vector` and `define_typed_jacobian`.
+#[macro_export]
+macro_rules! inner_define_typed_jacobian {
+    {
+        $jacobian:ident,
+        $from_vector:ident, $from_covariance:ident, $from_components:ident,
+        $to_vector:ident, $to_covariance:ident, $to_components:ident
+    } => {
+        /// Jacobian matrix of a function from a typed vector to a typed vector,
+        /// or matrix of a linear transformation of such vectors.
+        /// The item in row `i` and column `j` is the derivative of the component `i`
+        /// of the result with respect to the component `j` of the argument,
+        /// and so it is expressed in the unit of the component `i`
+        /// divided by the unit of the component `j`.
+        /// The items can be set only by measures having such unit.
+        pub struct $jacobian<Number = f64>
+        where
+            Number: measures::traits::ArithmeticOps,
+        {
+            c: [[Number; $from_components::DIMENSIONS]; $to_components::DIMENSIONS],
+        }
+
+        impl<Number> $jacobian<Number>
+        where
+            Number: measures::traits::ArithmeticOps,
+        {
+            /// Jacobian.get::<Row, Column, DerivativeUnit>() -> Measure<DerivativeUnit>
+            /// The unit of the result must be declared as the quotient
+            /// between the units of the two components.
+            pub fn get<Row, Column, DerivativeUnit>(&self) -> Measure<DerivativeUnit, Number>
+            where
+                Row: measures::traits::TypedVectorComponent<$to_vector<Number>>,
+                Column: measures::traits::TypedVectorComponent<$from_vector<Number>>,
+                DerivativeUnit: measures::traits::MeasurementUnit,
+                Measure<DerivativeUnit, Number>:
+                    core::ops::Mul<Measure<Column::Unit, Number>, Output = Measure<Row::Unit, Number>>,
+            {
+                Measure::<DerivativeUnit, Number>::new(self.c[Row::INDEX][Column::INDEX])
+            }
+
+            /// Jacobian.set::<Row, Column>(Measure<DerivativeUnit>)
+            /// The unit of the derivative multiplied by the unit of the column component
+            /// must be declared to be the unit of the row component,
+            /// and so an item having a wrong unit is a compile-time error.
+            pub fn set<Row, Column, DerivativeUnit>(&mut self, derivative: Measure<DerivativeUnit, Number>)
+            where
+                Row: measures::traits::TypedVectorComponent<$to_vector<Number>>,
+                Column: measures::traits::TypedVectorComponent<$from_vector<Number>>,
+                DerivativeUnit: measures::traits::MeasurementUnit,
+                Measure<DerivativeUnit, Number>:
+                    core::ops::Mul<Measure<Column::Unit, Number>, Output = Measure<Row::Unit, Number>>,
+            {
+                self.c[Row::INDEX][Column::INDEX] = derivative.value;
+            }
+
+            /// Jacobian.apply_to(TypedVector) -> TypedVector
+            pub fn apply_to(&self, v: $from_vector<Number>) -> $to_vector<Number> {
+                let values = v.values();
+                $to_vector::<Number>::from_values(core::array::from_fn(|row| {
+                    self.c[row]
+                        .iter()
+                        .zip(values)
+                        .fold(Number::ZERO, |sum, (&coefficient, value)| sum + coefficient * value)
+                }))
+            }
+
+            /// Jacobian.transform_covariance(&Covariance) -> Covariance
+            /// It returns `J * C * Jᵀ`, i.e. the covariance of the vectors
+            /// obtained by applying the Jacobian to vectors having the specified covariance.
+            pub fn transform_covariance(&self, covariance: &$from_covariance<Number>) -> $to_covariance<Number> {
+                $to_covariance::<Number>::new(measures::matrix_utils::transformed_covariances(
+                    &self.c,
+                    &covariance.c,
+                ))
+            }
+
+            /// Jacobian.kalman_update(&mut TypedVector, &mut Covariance, TypedVector, &Covariance) -> bool
+            /// It fuses an observation into the state of a linear Kalman filter,
+            /// using this matrix to predict the observation from the state.
+            /// It returns `false`, leaving the state unchanged,
+            /// if the covariance matrix of the innovation is singular.
+            pub fn kalman_update(
+                &self,
+                state: &mut $from_vector<Number>,
+                covariance: &mut $from_covariance<Number>,
+                observation: $to_vector<Number>,
+                observation_covariance: &$to_covariance<Number>,
+            ) -> bool {
+                let mut values = state.values();
+                let is_fused = measures::matrix_utils::kalman_update(
+                    &mut values,
+                    &mut covariance.c,
+                    &self.c,
+                    &observation.values(),
+                    &observation_covariance.c,
+                );
+                *state = $from_vector::<Number>::from_values(values);
+                is_fused
+            }
+        }
+
+        // Jacobian == Jacobian -> bool
+        impl<Number> PartialEq<$jacobian<Number>> for $jacobian<Number>
+        where
+            Number: measures::traits::ArithmeticOps,
+        {
+            fn eq(&self, other: &$jacobian<Number>) -> bool {
+                self.c == other.c
+            }
+        }
+
+        // Jacobian.clone() -> Jacobian
+        impl<Number> Clone for $jacobian<Number>
+        where
+            Number: measures::traits::ArithmeticOps,
+        {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        // Jacobian = Jacobian
+        impl<Number> Copy for $jacobian<Number>
+        where
+            Number: measures::traits::ArithmeticOps,
+        {
+        }
+
+        /// format!("{}", Jacobian) -> String
+        /// Jacobian.to_string() -> String
+        impl<Number> core::fmt::Display for $jacobian<Number>
+        where
+            Number: measures::traits::ArithmeticOps,
+        {
+            fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                measures::matrix_utils::format_matrix::<
+                    { $to_components::DIMENSIONS },
+                    { $from_components::DIMENSIONS },
+                    Number,
+                >(formatter, &self.c, "", 1)
+            }
+        }
+
+        // format!("{:?}", Jacobian)
+        impl<Number> core::fmt::Debug for $jacobian<Number>
+        where
+            Number: measures::traits::ArithmeticOps,
+        {
+            fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Display::fmt(self, formatter)
+            }
+        }
+    };
+}
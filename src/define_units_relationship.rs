@@ -1,45 +1,45 @@
 #[macro_export]
 macro_rules! define_units_relationship {
-    { $exact:tt $with_approx:tt $with_correlation:tt, $unit1:ident 1 == $unit2:ident 1 * __ 1 } => {
-        measures::expand_1_1_same! { $exact $with_approx $with_correlation, $unit2 $unit1 }
+    { $exact:tt $with_approx:tt $with_correlation:tt $with_complex:tt, $unit1:ident 1 == $unit2:ident 1 * __ 1 } => {
+        measures::expand_1_1_same! { $exact $with_approx $with_correlation $with_complex, $unit2 $unit1 }
     };
-    { $exact:tt $with_approx:tt $with_correlation:tt, $unit1:ident 1 == $unit2:ident 1 * $unit3:ident 1 } => {
-        measures::expand_1_1! { $exact $with_approx $with_correlation, $unit2 $unit3 $unit1 }
+    { $exact:tt $with_approx:tt $with_correlation:tt $with_complex:tt, $unit1:ident 1 == $unit2:ident 1 * $unit3:ident 1 } => {
+        measures::expand_1_1! { $exact $with_approx $with_correlation $with_complex, $unit2 $unit3 $unit1 }
     };
-    { $exact:tt $with_approx:tt $with_correlation:tt, $unit1:ident 2 == $unit2:ident 1 * $unit3:ident 2 } => {
+    { $exact:tt $with_approx:tt $with_correlation:tt $with_complex:tt, $unit1:ident 2 == $unit2:ident 1 * $unit3:ident 2 } => {
         measures::expand_1_2! { $exact $with_approx $with_correlation, $unit2 $unit3 $unit1 }
     };
-    { $exact:tt $with_approx:tt $with_correlation:tt, $unit1:ident 2 == $unit2:ident 2 * $unit3:ident 1 } => {
+    { $exact:tt $with_approx:tt $with_correlation:tt $with_complex:tt, $unit1:ident 2 == $unit2:ident 2 * $unit3:ident 1 } => {
         measures::expand_1_2! { $exact $with_approx $with_correlation, $unit3 $unit2 $unit1 }
     };
-    { $exact:tt $with_approx:tt $with_correlation:tt, $unit1:ident 3 == $unit2:ident 1 * $unit3:ident 3 } => {
+    { $exact:tt $with_approx:tt $with_correlation:tt $with_complex:tt, $unit1:ident 3 == $unit2:ident 1 * $unit3:ident 3 } => {
         measures::expand_1_3! { $exact $with_approx $with_correlation, $unit2 $unit3 $unit1 }
     };
-    { $exact:tt $with_approx:tt $with_correlation:tt, $unit1:ident 3 == $unit2:ident 3 * $unit3:ident 1 } => {
+    { $exact:tt $with_approx:tt $with_correlation:tt $with_complex:tt, $unit1:ident 3 == $unit2:ident 3 * $unit3:ident 1 } => {
         measures::expand_1_3! { $exact $with_approx $with_correlation, $unit3 $unit2 $unit1 }
     };
-    { $exact:tt $with_approx:tt $with_correlation:tt, $unit1:ident 1 == $unit2:ident 2 * __ 2 } => {
+    { $exact:tt $with_approx:tt $with_correlation:tt $with_complex:tt, $unit1:ident 1 == $unit2:ident 2 * __ 2 } => {
         measures::expand_2_2_same! { $exact $with_approx $with_correlation, $unit2 $unit1 }
     };
-    { $exact:tt $with_approx:tt $with_correlation:tt, $unit1:ident 1 == $unit2:ident 2 * $unit3:ident 2 } => {
+    { $exact:tt $with_approx:tt $with_correlation:tt $with_complex:tt, $unit1:ident 1 == $unit2:ident 2 * $unit3:ident 2 } => {
         measures::expand_2_2! { $exact $with_approx $with_correlation, $unit2 $unit3 $unit1 }
     };
-    { $exact:tt $with_approx:tt $with_correlation:tt, $unit1:ident 1 == $unit2:ident 3 * __ 3 } => {
+    { $exact:tt $with_approx:tt $with_correlation:tt $with_complex:tt, $unit1:ident 1 == $unit2:ident 3 * __ 3 } => {
         measures::expand_3_3_same! { $exact $with_approx $with_correlation, $unit2 $unit1 }
     };
-    { $exact:tt $with_approx:tt $with_correlation:tt, $unit1:ident 1 == $unit2:ident 3 * $unit3:ident 3 } => {
+    { $exact:tt $with_approx:tt $with_correlation:tt $with_complex:tt, $unit1:ident 1 == $unit2:ident 3 * $unit3:ident 3 } => {
         measures::expand_3_3! { $exact $with_approx $with_correlation, $unit2 $unit3 $unit1 }
     };
-    { $exact:tt $with_approx:tt $with_correlation:tt, $unit1:ident 1 == $unit2:ident 2 X __ 2 } => {
+    { $exact:tt $with_approx:tt $with_correlation:tt $with_complex:tt, $unit1:ident 1 == $unit2:ident 2 X __ 2 } => {
         measures::expand_cross_2_same! { $exact $with_approx $with_correlation, $unit2 $unit1 }
     };
-    { $exact:tt $with_approx:tt $with_correlation:tt, $unit1:ident 1 == $unit2:ident 2 X $unit3:ident 2 } => {
+    { $exact:tt $with_approx:tt $with_correlation:tt $with_complex:tt, $unit1:ident 1 == $unit2:ident 2 X $unit3:ident 2 } => {
         measures::expand_cross_2! { $exact $with_approx $with_correlation, $unit2 $unit3 $unit1 }
     };
-    { $exact:tt $with_approx:tt $with_correlation:tt, $unit1:ident 3 == $unit2:ident 3 X __ 3 } => {
+    { $exact:tt $with_approx:tt $with_correlation:tt $with_complex:tt, $unit1:ident 3 == $unit2:ident 3 X __ 3 } => {
         measures::expand_cross_3_same! { $exact $with_approx $with_correlation, $unit2 $unit1 }
     };
-    { $exact:tt $with_approx:tt $with_correlation:tt, $unit1:ident 3 == $unit2:ident 3 X $unit3:ident 3 } => {
+    { $exact:tt $with_approx:tt $with_correlation:tt $with_complex:tt, $unit1:ident 3 == $unit2:ident 3 X $unit3:ident 3 } => {
         measures::expand_cross_3! { $exact $with_approx $with_correlation, $unit2 $unit3 $unit1 }
     };
 }
@@ -48,7 +48,7 @@ macro_rules! define_units_relationship {
 #[macro_export]
 macro_rules! expand_1_1 {
     {
-        $exact:ident $with_approx:ident $with_correlation:ident,
+        $exact:ident $with_approx:ident $with_correlation:ident $with_complex:ident,
         $unit1:ident $unit2:ident $unit3:ident
     } => {
         // Operations for exact measures.
@@ -142,6 +142,55 @@ macro_rules! expand_1_1 {
             }
         }
 
+        // Operations for complex measures.
+        measures::if_all_true! { { $with_complex }
+            // ComplexMeasure<U1> * ComplexMeasure<U2> -> ComplexMeasure<U3>
+            impl<Number: ArithmeticOps> Mul<ComplexMeasure<$unit2, Number>> for ComplexMeasure<$unit1, Number> {
+                type Output = ComplexMeasure<$unit3, Number>;
+                fn mul(self, other: ComplexMeasure<$unit2, Number>) -> Self::Output {
+                    Self::Output::new(
+                        self.re * other.re - self.im * other.im,
+                        self.re * other.im + self.im * other.re,
+                    )
+                }
+            }
+
+            // ComplexMeasure<U2> * ComplexMeasure<U1> -> ComplexMeasure<U3>
+            impl<Number: ArithmeticOps> Mul<ComplexMeasure<$unit1, Number>> for ComplexMeasure<$unit2, Number> {
+                type Output = ComplexMeasure<$unit3, Number>;
+                fn mul(self, other: ComplexMeasure<$unit1, Number>) -> Self::Output {
+                    Self::Output::new(
+                        self.re * other.re - self.im * other.im,
+                        self.re * other.im + self.im * other.re,
+                    )
+                }
+            }
+
+            // ComplexMeasure<U3> / ComplexMeasure<U1> -> ComplexMeasure<U2>
+            impl<Number: ArithmeticOps> Div<ComplexMeasure<$unit1, Number>> for ComplexMeasure<$unit3, Number> {
+                type Output = ComplexMeasure<$unit2, Number>;
+                fn div(self, other: ComplexMeasure<$unit1, Number>) -> Self::Output {
+                    let squared_magnitude = other.re * other.re + other.im * other.im;
+                    Self::Output::new(
+                        (self.re * other.re + self.im * other.im) / squared_magnitude,
+                        (self.im * other.re - self.re * other.im) / squared_magnitude,
+                    )
+                }
+            }
+
+            // ComplexMeasure<U3> / ComplexMeasure<U2> -> ComplexMeasure<U1>
+            impl<Number: ArithmeticOps> Div<ComplexMeasure<$unit2, Number>> for ComplexMeasure<$unit3, Number> {
+                type Output = ComplexMeasure<$unit1, Number>;
+                fn div(self, other: ComplexMeasure<$unit2, Number>) -> Self::Output {
+                    let squared_magnitude = other.re * other.re + other.im * other.im;
+                    Self::Output::new(
+                        (self.re * other.re + self.im * other.im) / squared_magnitude,
+                        (self.im * other.re - self.re * other.im) / squared_magnitude,
+                    )
+                }
+            }
+        }
+
         // Operations with correlation, for measures with a variance.
         measures::if_all_true! { { $with_correlation }
             // ApproxMeasure<U1>.multiply_with_correlation(ApproxMeasure<U2>, Number) -> ApproxMeasure<U3>
@@ -192,7 +241,7 @@ macro_rules! expand_1_1 {
 #[macro_export]
 macro_rules! expand_1_1_same {
     {
-        $exact:ident $with_approx:ident $with_correlation:ident,
+        $exact:ident $with_approx:ident $with_correlation:ident $with_complex:ident,
         $unit1:ident $unit3:ident
     } => {
         measures::if_all_true! { { $exact }
@@ -279,6 +328,38 @@ macro_rules! expand_1_1_same {
                 }
             }
         }
+
+        measures::if_all_true! { { $with_complex }
+            /// ComplexMeasure<U1> * ComplexMeasure<U1> -> ComplexMeasure<U3>
+            impl<Number: ArithmeticOps> Mul<ComplexMeasure<$unit1, Number>> for ComplexMeasure<$unit1, Number> {
+                type Output = ComplexMeasure<$unit3, Number>;
+                fn mul(self, other: ComplexMeasure<$unit1, Number>) -> Self::Output {
+                    Self::Output::new(
+                        self.re * other.re - self.im * other.im,
+                        self.re * other.im + self.im * other.re,
+                    )
+                }
+            }
+
+            /// ComplexMeasure<U3> / ComplexMeasure<U1> -> ComplexMeasure<U1>
+            impl<Number: ArithmeticOps> Div<ComplexMeasure<$unit1, Number>> for ComplexMeasure<$unit3, Number> {
+                type Output = ComplexMeasure<$unit1, Number>;
+                fn div(self, other: ComplexMeasure<$unit1, Number>) -> Self::Output {
+                    let squared_magnitude = other.re * other.re + other.im * other.im;
+                    Self::Output::new(
+                        (self.re * other.re + self.im * other.im) / squared_magnitude,
+                        (self.im * other.re - self.re * other.im) / squared_magnitude,
+                    )
+                }
+            }
+
+            /// ComplexMeasure<U1>.squared() -> ComplexMeasure<U3>
+            impl<Number: ArithmeticOps> ComplexMeasure<$unit1, Number> {
+                pub fn squared(self) -> ComplexMeasure<$unit3, Number> {
+                    self * self
+                }
+            }
+        }
     };
 }
 
@@ -9,3 +9,7 @@ impl crate::traits::MeasurementUnit for One {
     const OFFSET: f64 = 0.;
     const SUFFIX: &'static str = "";
 }
+
+impl crate::traits::LinearMeasurementUnit for One {}
+
+impl crate::traits::VectorMeasurementUnit for One {}
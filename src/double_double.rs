@@ -0,0 +1,790 @@
+//! Double-double floating-point number type, available with the feature `double_double`.
+//!
+//! A `DoubleDouble` represents a number as the unevaluated sum of two `f64` numbers,
+//! the second one being smaller than half unit in the last place of the first one.
+//! So, it has about 106 bits of mantissa (about 32 significant decimal digits),
+//! but the same exponent range of `f64`.
+//! It can be used as `Number` type of measures, like in `Measure<Metre, DoubleDouble>`,
+//! when the rounding errors of `f64` accumulate too much, like in long simulations.
+//! Its operations are implemented in software, and so they are much slower than the ones of `f64`.
+
+use crate::float_math::FloatFunctions;
+use crate::traits::{
+    CubicRoot, FromF64, HasEpsilon, HasHalf, HasOne, HasSign, HasZero, InverseTrigonometry,
+    Logarithm, LossyFrom, Pow, PowerDecibel, RootPowerDecibel, ScaleByRatio, Sqrt, Trigonometry,
+};
+use core::cmp::Ordering;
+use core::fmt::{self, Write};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+use core::str::FromStr;
+
+/// Number having about twice the precision of `f64`,
+/// represented as the sum of two non-overlapping `f64` numbers.
+#[derive(Clone, Copy, Default, PartialEq)]
+pub struct DoubleDouble {
+    hi: f64,
+    lo: f64,
+}
+
+// The sum of two numbers, and its rounding error.
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    let bb = s - a;
+    (s, (a - (s - bb)) + (b - bb))
+}
+
+// The sum of two numbers, and its rounding error, assuming that `|a| >= |b|`.
+fn quick_two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    (s, b - (s - a))
+}
+
+// The product of two numbers, and its rounding error.
+fn two_prod(a: f64, b: f64) -> (f64, f64) {
+    let p = a * b;
+    (p, FloatFunctions::mul_add(a, b, -p))
+}
+
+impl DoubleDouble {
+    pub const PI: Self = Self {
+        hi: core::f64::consts::PI,
+        lo: 1.2246467991473532e-16,
+    };
+    pub const FRAC_PI_2: Self = Self {
+        hi: core::f64::consts::FRAC_PI_2,
+        lo: 6.123233995736766e-17,
+    };
+    pub const LN_2: Self = Self {
+        hi: core::f64::consts::LN_2,
+        lo: 2.3190468138462996e-17,
+    };
+    pub const LN_10: Self = Self {
+        hi: core::f64::consts::LN_10,
+        lo: -2.1707562233822494e-16,
+    };
+
+    // Threshold under which the terms of the Taylor series are negligible.
+    const SERIES_EPSILON: f64 = 1e-34;
+
+    /// DoubleDouble::new(f64, f64) -> DoubleDouble
+    /// It returns the sum of the two numbers, rounded to the precision of `DoubleDouble`.
+    pub fn new(hi: f64, lo: f64) -> Self {
+        let (hi, lo) = two_sum(hi, lo);
+        Self::from_parts(hi, lo)
+    }
+
+    /// DoubleDouble.hi() -> f64
+    /// It returns the most significant part, that is the nearest `f64`.
+    pub const fn hi(self) -> f64 {
+        self.hi
+    }
+
+    /// DoubleDouble.lo() -> f64
+    /// It returns the least significant part.
+    pub const fn lo(self) -> f64 {
+        self.lo
+    }
+
+    pub fn is_nan(self) -> bool {
+        self.hi.is_nan()
+    }
+
+    pub fn is_finite(self) -> bool {
+        self.hi.is_finite()
+    }
+
+    /// DoubleDouble.floor() -> DoubleDouble
+    /// It returns the largest integer less than or equal to `self`.
+    pub fn floor(self) -> Self {
+        let hi = FloatFunctions::floor(self.hi);
+        if hi == self.hi {
+            let (hi, lo) = quick_two_sum(hi, FloatFunctions::floor(self.lo));
+            Self::from_parts(hi, lo)
+        } else {
+            Self { hi, lo: 0. }
+        }
+    }
+
+    /// DoubleDouble.trunc() -> DoubleDouble
+    /// It returns the integer part of `self`.
+    pub fn trunc(self) -> Self {
+        if self.hi < 0. {
+            -(-self).floor()
+        } else {
+            self.floor()
+        }
+    }
+
+    /// DoubleDouble.round() -> DoubleDouble
+    /// It returns the nearest integer to `self`, rounding half-way cases away from zero.
+    pub fn round(self) -> Self {
+        if self.hi < 0. {
+            -(-self + Self::HALF).floor()
+        } else {
+            (self + Self::HALF).floor()
+        }
+    }
+
+    /// DoubleDouble.exp() -> DoubleDouble
+    /// It returns e raised to `self`.
+    pub fn exp(self) -> Self {
+        if self.hi > 709.8 {
+            return Self::from(f64::INFINITY);
+        }
+        if self.hi < -745.2 {
+            return Self::ZERO;
+        }
+        if self.is_nan() {
+            return self;
+        }
+        // self = k * ln(2) + r, with |r| <= ln(2) / 2,
+        // and exp(r) is computed as exp(r / 1024) ^ 1024.
+        let k = FloatFunctions::round(self.hi / core::f64::consts::LN_2);
+        let r = (self - Self::LN_2 * Self::from(k)) * Self::from(1. / 1024.);
+        let mut term = r;
+        let mut exp_minus_1 = r;
+        let mut n = 2.;
+        while term.hi.abs() > Self::SERIES_EPSILON {
+            term = term * r / Self::from(n);
+            exp_minus_1 += term;
+            n += 1.;
+        }
+        for _ in 0..10 {
+            exp_minus_1 = exp_minus_1 * Self::from(2.) + exp_minus_1 * exp_minus_1;
+        }
+        let half_power = FloatFunctions::powf(2_f64, (k as i32 / 2) as f64);
+        (exp_minus_1 + Self::ONE)
+            * Self::from(half_power)
+            * Self::from(FloatFunctions::powf(
+                2_f64,
+                (k as i32 - k as i32 / 2) as f64,
+            ))
+    }
+
+    /// DoubleDouble.ln() -> DoubleDouble
+    /// It returns the natural logarithm of `self`.
+    pub fn ln(self) -> Self {
+        if self.hi <= 0. || !self.hi.is_finite() {
+            return Self::from(FloatFunctions::ln(self.hi));
+        }
+        // A Newton iteration for the solution of exp(y) = self.
+        let y = Self::from(FloatFunctions::ln(self.hi));
+        y + self * (-y).exp() - Self::ONE
+    }
+
+    /// DoubleDouble.log10() -> DoubleDouble
+    /// It returns the base-10 logarithm of `self`.
+    pub fn log10(self) -> Self {
+        self.ln() / Self::LN_10
+    }
+
+    fn from_parts(hi: f64, lo: f64) -> Self {
+        if hi.is_finite() {
+            Self { hi, lo }
+        } else {
+            Self { hi, lo: 0. }
+        }
+    }
+
+    fn mul_f64(self, other: f64) -> Self {
+        let (p1, p2) = two_prod(self.hi, other);
+        let (hi, lo) = quick_two_sum(p1, p2 + self.lo * other);
+        Self::from_parts(hi, lo)
+    }
+
+    // Power of ten having the specified exponent.
+    fn power_of_ten(exponent: i32) -> Self {
+        let mut result = Self::ONE;
+        let mut base = Self::from(10.);
+        let mut n = exponent.unsigned_abs();
+        while n > 0 {
+            if n & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            n >>= 1;
+        }
+        if exponent < 0 {
+            Self::ONE / result
+        } else {
+            result
+        }
+    }
+
+    // Sine and cosine of an angle, by Taylor series, assuming that `|self| <= π/4`.
+    fn reduced_sin_cos(self) -> (Self, Self) {
+        let minus_squared = -(self * self);
+        let mut sin_term = self;
+        let mut sin = self;
+        let mut cos_term = Self::ONE;
+        let mut cos = Self::ONE;
+        let mut n = 1.;
+        while sin_term.hi.abs() > Self::SERIES_EPSILON || cos_term.hi.abs() > Self::SERIES_EPSILON {
+            cos_term = cos_term * minus_squared / Self::from(n * (n + 1.));
+            cos += cos_term;
+            sin_term = sin_term * minus_squared / Self::from((n + 1.) * (n + 2.));
+            sin += sin_term;
+            n += 2.;
+        }
+        (sin, cos)
+    }
+
+    // Decimal digits of `|self|`, which must be finite and non-zero,
+    // rounded to `digit_count` significant digits,
+    // and the decimal exponent of the first digit.
+    // The digits beyond `MAX_DIGITS` are not significant, and they are considered zeros.
+    fn decimal_digits(self, digit_count: usize) -> (Digits, i32) {
+        let mut value = if self.hi < 0. { -self } else { self };
+        let mut exponent = FloatFunctions::floor(FloatFunctions::log10(value.hi)) as i32;
+        value *= Self::power_of_ten(-exponent);
+        if value.hi < 1. {
+            value = value.mul_f64(10.);
+            exponent -= 1;
+        } else if value.hi >= 10. {
+            value /= Self::from(10.);
+            exponent += 1;
+        }
+        let mut digits = Digits {
+            digits: [0; MAX_DIGITS],
+            len: digit_count.min(MAX_DIGITS),
+        };
+        for i in 0..digits.len {
+            let digit = value.floor();
+            digits.digits[i] = digit.hi.clamp(0., 9.) as u8;
+            value = (value - digit).mul_f64(10.);
+        }
+        if value.hi >= 5. {
+            let mut i = digits.len;
+            loop {
+                if i == 0 {
+                    // All the digits were nines, and now they are zeros.
+                    digits.digits[0] = 1;
+                    exponent += 1;
+                    break;
+                }
+                i -= 1;
+                if digits.digits[i] == 9 {
+                    digits.digits[i] = 0;
+                } else {
+                    digits.digits[i] += 1;
+                    break;
+                }
+            }
+        }
+        (digits, exponent)
+    }
+
+    // It writes `|self|` in positional notation, with `fraction_digits` digits
+    // after the decimal point, or, if it is `None`, with all the significant digits.
+    fn write_positional(
+        self,
+        fraction_digits: Option<usize>,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        let (digits, exponent) = match fraction_digits {
+            Some(fraction_digits) => {
+                let first_exponent =
+                    FloatFunctions::floor(FloatFunctions::log10(self.hi.abs())) as i32;
+                let digit_count = first_exponent + 1 + fraction_digits as i32;
+                if digit_count <= 0 {
+                    // The value is smaller than the last displayed digit.
+                    let half_last = Self::power_of_ten(-(fraction_digits as i32)).mul_f64(0.5);
+                    if self.hi.abs() >= half_last.hi {
+                        (Digits::one(), -(fraction_digits as i32))
+                    } else {
+                        (Digits::zero(), 0)
+                    }
+                } else {
+                    self.decimal_digits(digit_count as usize)
+                }
+            }
+            None => {
+                let (mut digits, exponent) = self.decimal_digits(32);
+                digits.trim_trailing_zeros();
+                (digits, exponent)
+            }
+        };
+        let integer_digit_count = exponent + 1;
+        let fraction_digit_count = match fraction_digits {
+            Some(fraction_digits) => fraction_digits,
+            None => (digits.len as i32 - integer_digit_count).max(0) as usize,
+        };
+        if integer_digit_count <= 0 {
+            formatter.write_char('0')?;
+        } else {
+            for position in 0..integer_digit_count as usize {
+                formatter.write_char(digits.char_at(position))?;
+            }
+        }
+        if fraction_digit_count > 0 {
+            formatter.write_char('.')?;
+            for position in integer_digit_count..integer_digit_count + fraction_digit_count as i32 {
+                formatter.write_char(if position < 0 {
+                    '0'
+                } else {
+                    digits.char_at(position as usize)
+                })?;
+            }
+        }
+        Ok(())
+    }
+
+    // It writes `|self|` in scientific notation, with `fraction_digits` digits
+    // after the decimal point, or, if it is `None`, with all the significant digits.
+    fn write_scientific(
+        self,
+        fraction_digits: Option<usize>,
+        exponent_char: char,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        let digit_count = fraction_digits.unwrap_or(31) + 1;
+        let (mut digits, exponent) = self.decimal_digits(digit_count);
+        if fraction_digits.is_none() {
+            digits.trim_trailing_zeros();
+        }
+        let digit_count = fraction_digits.map_or(digits.len, |_| digit_count);
+        formatter.write_char(digits.char_at(0))?;
+        if digit_count > 1 {
+            formatter.write_char('.')?;
+            for position in 1..digit_count {
+                formatter.write_char(digits.char_at(position))?;
+            }
+        }
+        formatter.write_char(exponent_char)?;
+        write!(formatter, "{}", exponent)
+    }
+
+    // It formats `self`, using the formatting of `f64` if it is exactly representable as `f64`.
+    fn format(
+        self,
+        formatter: &mut fmt::Formatter<'_>,
+        scientific: Option<char>,
+        format_f64: fn(&f64, &mut fmt::Formatter<'_>) -> fmt::Result,
+    ) -> fmt::Result {
+        if self.lo == 0. || !self.hi.is_finite() {
+            return format_f64(&self.hi, formatter);
+        }
+        if self.hi < 0. {
+            formatter.write_char('-')?;
+        } else if formatter.sign_plus() {
+            formatter.write_char('+')?;
+        }
+        match scientific {
+            Some(exponent_char) => {
+                self.write_scientific(formatter.precision(), exponent_char, formatter)
+            }
+            None => self.write_positional(formatter.precision(), formatter),
+        }
+    }
+}
+
+// Maximum number of stored decimal digits, beyond the precision of `DoubleDouble`.
+const MAX_DIGITS: usize = 34;
+
+// Decimal digits of a number, stored without allocating memory.
+// The digits beyond `len` are zeros.
+struct Digits {
+    digits: [u8; MAX_DIGITS],
+    len: usize,
+}
+
+impl Digits {
+    fn zero() -> Self {
+        Self {
+            digits: [0; MAX_DIGITS],
+            len: 1,
+        }
+    }
+
+    fn one() -> Self {
+        let mut digits = Self::zero();
+        digits.digits[0] = 1;
+        digits
+    }
+
+    fn char_at(&self, position: usize) -> char {
+        if position < self.len {
+            (b'0' + self.digits[position]) as char
+        } else {
+            '0'
+        }
+    }
+
+    fn trim_trailing_zeros(&mut self) {
+        while self.len > 1 && self.digits[self.len - 1] == 0 {
+            self.len -= 1;
+        }
+    }
+}
+
+impl From<f64> for DoubleDouble {
+    fn from(n: f64) -> Self {
+        Self { hi: n, lo: 0. }
+    }
+}
+
+impl From<f32> for DoubleDouble {
+    fn from(n: f32) -> Self {
+        Self::from(n as f64)
+    }
+}
+
+impl FromStr for DoubleDouble {
+    type Err = core::num::ParseFloatError;
+
+    /// It parses a decimal number, like "-1.234e-5", with the precision of `DoubleDouble`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // The validation and the special values are delegated to `f64`.
+        let approximation = s.parse::<f64>()?;
+        if !approximation.is_finite() || approximation == 0. {
+            return Ok(Self::from(approximation));
+        }
+        let trimmed = s.trim_start_matches(['+', '-']);
+        let (mantissa, exponent) = match trimmed.find(['e', 'E']) {
+            Some(position) => (
+                &trimmed[..position],
+                trimmed[position + 1..].parse::<i32>().unwrap_or(0),
+            ),
+            None => (trimmed, 0),
+        };
+        let mut value = Self::ZERO;
+        let mut fraction_digit_count = 0;
+        let mut after_point = false;
+        for c in mantissa.chars() {
+            if c == '.' {
+                after_point = true;
+            } else if let Some(digit) = c.to_digit(10) {
+                value = value.mul_f64(10.) + Self::from(digit as f64);
+                if after_point {
+                    fraction_digit_count += 1;
+                }
+            }
+        }
+        let scale = exponent - fraction_digit_count;
+        value = if scale < 0 {
+            value / Self::power_of_ten(-scale)
+        } else {
+            value * Self::power_of_ten(scale)
+        };
+        Ok(if approximation < 0. { -value } else { value })
+    }
+}
+
+impl PartialOrd for DoubleDouble {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.hi.partial_cmp(&other.hi) {
+            Some(Ordering::Equal) => self.lo.partial_cmp(&other.lo),
+            ordering => ordering,
+        }
+    }
+}
+
+impl Neg for DoubleDouble {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self {
+            hi: -self.hi,
+            lo: -self.lo,
+        }
+    }
+}
+
+impl Add for DoubleDouble {
+    type Output = Self;
+    fn add(self, other: Self) -> Self::Output {
+        let (s1, s2) = two_sum(self.hi, other.hi);
+        let (t1, t2) = two_sum(self.lo, other.lo);
+        let (s1, s2) = quick_two_sum(s1, s2 + t1);
+        let (hi, lo) = quick_two_sum(s1, s2 + t2);
+        Self::from_parts(hi, lo)
+    }
+}
+
+impl AddAssign for DoubleDouble {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for DoubleDouble {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self::Output {
+        self + -other
+    }
+}
+
+impl SubAssign for DoubleDouble {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Mul for DoubleDouble {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self::Output {
+        let (p1, p2) = two_prod(self.hi, other.hi);
+        let (hi, lo) = quick_two_sum(p1, p2 + (self.hi * other.lo + self.lo * other.hi));
+        Self::from_parts(hi, lo)
+    }
+}
+
+impl MulAssign for DoubleDouble {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl Div for DoubleDouble {
+    type Output = Self;
+    fn div(self, other: Self) -> Self::Output {
+        let q1 = self.hi / other.hi;
+        if !q1.is_finite() {
+            return Self::from(q1);
+        }
+        let r = self - other.mul_f64(q1);
+        let q2 = r.hi / other.hi;
+        let r = r - other.mul_f64(q2);
+        let q3 = r.hi / other.hi;
+        let (hi, lo) = quick_two_sum(q1, q2);
+        Self::from_parts(hi, lo) + Self::from(q3)
+    }
+}
+
+impl DivAssign for DoubleDouble {
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl Rem for DoubleDouble {
+    type Output = Self;
+    /// The remainder has the same sign of the dividend, like for `f64`.
+    fn rem(self, other: Self) -> Self::Output {
+        self - (self / other).trunc() * other
+    }
+}
+
+impl core::iter::Sum for DoubleDouble {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |sum, n| sum + n)
+    }
+}
+
+impl Pow for DoubleDouble {
+    fn powf(self, exponent: Self) -> Self {
+        if exponent == Self::ZERO {
+            return Self::ONE;
+        }
+        if self.hi == 0. || !self.is_finite() || !exponent.is_finite() {
+            return Self::from(FloatFunctions::powf(self.hi, exponent.hi));
+        }
+        if self.hi < 0. {
+            // Negative bases have a real power only for integer exponents.
+            if exponent.floor() != exponent {
+                return Self::from(f64::NAN);
+            }
+            let power = (exponent * (-self).ln()).exp();
+            return if (exponent * Self::HALF).floor() == exponent * Self::HALF {
+                power
+            } else {
+                -power
+            };
+        }
+        (exponent * self.ln()).exp()
+    }
+}
+
+impl FromF64 for DoubleDouble {
+    fn from_f64(n: f64) -> Self {
+        Self::from(n)
+    }
+}
+
+impl ScaleByRatio for DoubleDouble {
+    fn scale_by_ratio(self, ratio: f64) -> Self {
+        self * Self::from_f64(ratio)
+    }
+    fn apply_conversion<F: Fn(f64) -> f64>(self, function: F) -> Self {
+        Self::from_f64(function(self.hi + self.lo))
+    }
+}
+
+impl Sqrt for DoubleDouble {
+    type Output = Self;
+    fn sqrt(self) -> Self {
+        if self.hi <= 0. || !self.hi.is_finite() {
+            return Self::from(FloatFunctions::sqrt(self.hi));
+        }
+        // A Newton iteration for the solution of y^2 = self.
+        let y = Self::from(FloatFunctions::sqrt(self.hi));
+        y + (self - y * y) / y.mul_f64(2.)
+    }
+}
+
+impl CubicRoot for DoubleDouble {
+    type Output = Self;
+    fn cubic_root(self) -> Self {
+        if self.hi == 0. || !self.is_finite() {
+            return Self::from(FloatFunctions::cbrt(self.hi));
+        }
+        // A Newton iteration for the solution of y^3 = self.
+        let y = Self::from(FloatFunctions::cbrt(self.hi));
+        y - (y * y * y - self) / (y * y).mul_f64(3.)
+    }
+}
+
+impl Trigonometry for DoubleDouble {
+    type Output = Self;
+    fn cos(self) -> Self::Output {
+        self.sin_cos().1
+    }
+    fn sin(self) -> Self::Output {
+        self.sin_cos().0
+    }
+    fn tan(self) -> Self::Output {
+        let (sin, cos) = self.sin_cos();
+        sin / cos
+    }
+    fn sin_cos(self) -> (Self::Output, Self::Output) {
+        if !self.is_finite() {
+            return (Self::from(f64::NAN), Self::from(f64::NAN));
+        }
+        // self = quadrant * π/2 + reduced, with |reduced| <= π/4.
+        let quadrant = (self / Self::FRAC_PI_2).round();
+        let (sin, cos) = (self - quadrant * Self::FRAC_PI_2).reduced_sin_cos();
+        match (((quadrant.hi + quadrant.lo) % 4. + 4.) % 4.) as u8 {
+            0 => (sin, cos),
+            1 => (cos, -sin),
+            2 => (-sin, -cos),
+            _ => (-cos, sin),
+        }
+    }
+}
+
+impl InverseTrigonometry for DoubleDouble {
+    type Output = Self;
+    fn acos(self) -> Self::Output {
+        ((Self::ONE - self) * (Self::ONE + self)).sqrt().atan2(self)
+    }
+    fn asin(self) -> Self::Output {
+        self.atan2(((Self::ONE - self) * (Self::ONE + self)).sqrt())
+    }
+    fn atan2(self, other: Self) -> Self::Output {
+        let angle = Self::from(FloatFunctions::atan2(self.hi, other.hi));
+        if (self.hi == 0. && other.hi == 0.) || !self.is_finite() || !other.is_finite() {
+            return angle;
+        }
+        // A Newton iteration for the solution of tan(angle) = self / other.
+        let (sin, cos) = angle.sin_cos();
+        angle + (self * cos - other * sin) / (other * cos + self * sin)
+    }
+}
+
+impl Logarithm for DoubleDouble {
+    fn ln(self) -> Self {
+        DoubleDouble::ln(self)
+    }
+}
+
+impl PowerDecibel for DoubleDouble {
+    fn to_power_decibels(self) -> Self {
+        self.log10().mul_f64(10.)
+    }
+    fn power_decibels_to_value(self) -> Self {
+        (self * Self::LN_10 / Self::from(10.)).exp()
+    }
+}
+
+impl RootPowerDecibel for DoubleDouble {
+    fn to_root_power_decibels(self) -> Self {
+        self.log10().mul_f64(20.)
+    }
+    fn root_power_decibels_to_value(self) -> Self {
+        (self * Self::LN_10 / Self::from(20.)).exp()
+    }
+}
+
+impl HasZero for DoubleDouble {
+    const ZERO: Self = Self { hi: 0., lo: 0. };
+}
+
+impl HasOne for DoubleDouble {
+    const ONE: Self = Self { hi: 1., lo: 0. };
+}
+
+impl HasHalf for DoubleDouble {
+    const HALF: Self = Self { hi: 0.5, lo: 0. };
+}
+
+// The 106-bit significand has a precision of 2⁻¹⁰⁴ near 1.
+impl HasEpsilon for DoubleDouble {
+    const EPSILON: Self = Self {
+        hi: f64::EPSILON * f64::EPSILON,
+        lo: 0.,
+    };
+}
+
+impl HasSign for DoubleDouble {
+    fn signum(self) -> Self {
+        Self::from(self.hi.signum())
+    }
+    fn abs(self) -> Self {
+        if self.hi < 0. {
+            -self
+        } else {
+            self
+        }
+    }
+}
+
+impl LossyFrom<f32> for DoubleDouble {
+    fn lossy_from(n: f32) -> Self {
+        Self::from(n)
+    }
+}
+impl LossyFrom<f64> for DoubleDouble {
+    fn lossy_from(n: f64) -> Self {
+        Self::from(n)
+    }
+}
+impl LossyFrom<DoubleDouble> for DoubleDouble {
+    fn lossy_from(n: DoubleDouble) -> Self {
+        n
+    }
+}
+impl LossyFrom<DoubleDouble> for f32 {
+    fn lossy_from(n: DoubleDouble) -> Self {
+        n.hi as f32
+    }
+}
+impl LossyFrom<DoubleDouble> for f64 {
+    fn lossy_from(n: DoubleDouble) -> Self {
+        n.hi
+    }
+}
+
+impl fmt::Display for DoubleDouble {
+    /// The numbers representable as `f64` are formatted like `f64`.
+    /// The other ones are formatted with up to 32 significant digits.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.format(formatter, None, fmt::Display::fmt)
+    }
+}
+
+impl fmt::Debug for DoubleDouble {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.format(formatter, None, fmt::Debug::fmt)
+    }
+}
+
+impl fmt::LowerExp for DoubleDouble {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.format(formatter, Some('e'), fmt::LowerExp::fmt)
+    }
+}
+
+impl fmt::UpperExp for DoubleDouble {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.format(formatter, Some('E'), fmt::UpperExp::fmt)
+    }
+}
@@ -0,0 +1,416 @@
+//! Dual numbers for forward-mode automatic differentiation, available with the feature `dual`.
+//!
+//! A `DualN<N, Real>` represents a value together with its gradient with respect to
+//! `N` independent variables, and every operation propagates the gradient by the chain rule.
+//! It can be used as `Number` type of measures, like in `Measure<Metre, Dual>`,
+//! so that the derivatives of a unit-checked computation are computed exactly,
+//! instead of being approximated by finite differences.
+//! `Dual<Real>` is the type having a single derivative.
+//!
+//! The comparison operators compare only the values, ignoring the gradients,
+//! and also the `Display` format prints only the value, while the `Debug` format
+//! prints also the gradient.
+//! The functions generated by `define_measure_types`, `dual_derivative` and `partial_derivative`,
+//! return the derivatives as measures whose unit is the quotient of the units
+//! of the output and of the input.
+
+use crate::traits::{
+    ArithmeticOps, CubicRoot, FromF64, HasEpsilon, HasHalf, HasOne, HasSign, HasZero,
+    InverseTrigonometry, Logarithm, LossyFrom, Pow, PowerDecibel, RootPowerDecibel, ScaleByRatio,
+    Sqrt, Trigonometry,
+};
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+/// Number having a value and the derivatives of such value
+/// with respect to `N` independent variables.
+#[derive(Clone, Copy)]
+pub struct DualN<const N: usize, Real = f64> {
+    pub value: Real,
+    pub gradient: [Real; N],
+}
+
+/// Number having a value and its derivative with respect to one independent variable.
+pub type Dual<Real = f64> = DualN<1, Real>;
+
+impl<const N: usize, Real: ArithmeticOps> DualN<N, Real> {
+    /// DualN::new(Real, [Real; N]) -> DualN
+    pub const fn new(value: Real, gradient: [Real; N]) -> Self {
+        Self { value, gradient }
+    }
+
+    /// DualN::constant(Real) -> DualN
+    /// It returns a number not depending on any variable, and so having a zero gradient.
+    pub const fn constant(value: Real) -> Self {
+        Self::new(value, [Real::ZERO; N])
+    }
+
+    /// DualN::variable(Real, usize) -> DualN
+    /// It returns the independent variable having the specified index,
+    /// and so having a derivative equal to one with respect to itself,
+    /// and equal to zero with respect to the other variables.
+    pub fn variable(value: Real, index: usize) -> Self {
+        let mut gradient = [Real::ZERO; N];
+        gradient[index] = Real::ONE;
+        Self::new(value, gradient)
+    }
+
+    // The number having the given value, and the gradient of `self` multiplied by `factor`,
+    // as required by the chain rule for a function having derivative `factor`.
+    fn chain(self, value: Real, factor: Real) -> Self {
+        Self::new(value, self.gradient.map(|d| d * factor))
+    }
+}
+
+impl<Real: ArithmeticOps> DualN<1, Real> {
+    /// Dual.derivative() -> Real
+    /// It returns the derivative with respect to the only variable.
+    pub const fn derivative(self) -> Real {
+        self.gradient[0]
+    }
+}
+
+impl<const N: usize, Real: ArithmeticOps> Default for DualN<N, Real> {
+    fn default() -> Self {
+        Self::constant(Real::ZERO)
+    }
+}
+
+impl<const N: usize, Real: ArithmeticOps> From<Real> for DualN<N, Real> {
+    fn from(value: Real) -> Self {
+        Self::constant(value)
+    }
+}
+
+impl<const N: usize, Real: ArithmeticOps> PartialEq for DualN<N, Real> {
+    /// Only the values are compared.
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<const N: usize, Real: ArithmeticOps> PartialOrd for DualN<N, Real> {
+    /// Only the values are compared.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<const N: usize, Real: ArithmeticOps> Neg for DualN<N, Real> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.value, self.gradient.map(|d| -d))
+    }
+}
+
+impl<const N: usize, Real: ArithmeticOps> Add for DualN<N, Real> {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        let mut gradient = self.gradient;
+        for (d, other_d) in gradient.iter_mut().zip(other.gradient) {
+            *d += other_d;
+        }
+        Self::new(self.value + other.value, gradient)
+    }
+}
+
+impl<const N: usize, Real: ArithmeticOps> AddAssign for DualN<N, Real> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<const N: usize, Real: ArithmeticOps> Sub for DualN<N, Real> {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl<const N: usize, Real: ArithmeticOps> SubAssign for DualN<N, Real> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<const N: usize, Real: ArithmeticOps> Mul for DualN<N, Real> {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        let mut gradient = self.gradient;
+        for (d, other_d) in gradient.iter_mut().zip(other.gradient) {
+            *d = *d * other.value + self.value * other_d;
+        }
+        Self::new(self.value * other.value, gradient)
+    }
+}
+
+impl<const N: usize, Real: ArithmeticOps> MulAssign for DualN<N, Real> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl<const N: usize, Real: ArithmeticOps> Div for DualN<N, Real> {
+    type Output = Self;
+    fn div(self, other: Self) -> Self {
+        let quotient = self.value / other.value;
+        let mut gradient = self.gradient;
+        for (d, other_d) in gradient.iter_mut().zip(other.gradient) {
+            *d = (*d - quotient * other_d) / other.value;
+        }
+        Self::new(quotient, gradient)
+    }
+}
+
+impl<const N: usize, Real: ArithmeticOps> DivAssign for DualN<N, Real> {
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl<const N: usize, Real: ArithmeticOps> Rem for DualN<N, Real> {
+    type Output = Self;
+    fn rem(self, other: Self) -> Self {
+        // self % other == self - other * truncated_quotient.
+        let remainder = self.value % other.value;
+        let truncated_quotient = (self.value - remainder) / other.value;
+        let mut gradient = self.gradient;
+        for (d, other_d) in gradient.iter_mut().zip(other.gradient) {
+            *d -= truncated_quotient * other_d;
+        }
+        Self::new(remainder, gradient)
+    }
+}
+
+impl<const N: usize, Real: ArithmeticOps> core::iter::Sum for DualN<N, Real> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |a, b| a + b)
+    }
+}
+
+impl<const N: usize, Real: ArithmeticOps> Pow for DualN<N, Real> {
+    fn powf(self, exponent: Self) -> Self {
+        let power = self.value.powf(exponent.value);
+        if exponent.gradient.iter().all(|&d| d == Real::ZERO) {
+            // A constant exponent, which allows also a negative base.
+            return self.chain(
+                power,
+                exponent.value * self.value.powf(exponent.value - Real::ONE),
+            );
+        }
+        let ln_base = self.value.ln();
+        let mut gradient = self.gradient;
+        for (d, exponent_d) in gradient.iter_mut().zip(exponent.gradient) {
+            *d = power * (exponent_d * ln_base + exponent.value * *d / self.value);
+        }
+        Self::new(power, gradient)
+    }
+}
+
+impl<const N: usize, Real: ArithmeticOps> FromF64 for DualN<N, Real> {
+    fn from_f64(n: f64) -> Self {
+        Self::constant(Real::from_f64(n))
+    }
+}
+
+impl<const N: usize, Real: ArithmeticOps> ScaleByRatio for DualN<N, Real> {
+    fn scale_by_ratio(self, ratio: f64) -> Self {
+        Self::new(
+            self.value.scale_by_ratio(ratio),
+            self.gradient.map(|d| d.scale_by_ratio(ratio)),
+        )
+    }
+    fn apply_conversion<F: Fn(f64) -> f64>(self, function: F) -> Self {
+        let derivative = self
+            .value
+            .apply_conversion(|value| crate::traits::conversion_derivative(&function, value));
+        Self::new(
+            self.value.apply_conversion(&function),
+            self.gradient.map(|d| d * derivative),
+        )
+    }
+}
+
+impl<const N: usize, Real: ArithmeticOps> Sqrt for DualN<N, Real> {
+    type Output = Self;
+    fn sqrt(self) -> Self {
+        let root = self.value.sqrt();
+        self.chain(root, Real::HALF / root)
+    }
+}
+
+impl<const N: usize, Real: ArithmeticOps> CubicRoot for DualN<N, Real> {
+    type Output = Self;
+    fn cubic_root(self) -> Self {
+        let root = self.value.cubic_root();
+        self.chain(root, Real::ONE / (Real::from_f64(3.) * root * root))
+    }
+}
+
+impl<const N: usize, Real: ArithmeticOps> Trigonometry for DualN<N, Real> {
+    type Output = Self;
+    fn cos(self) -> Self::Output {
+        self.sin_cos().1
+    }
+    fn sin(self) -> Self::Output {
+        self.sin_cos().0
+    }
+    fn tan(self) -> Self::Output {
+        let (sin, cos) = self.value.sin_cos();
+        self.chain(sin / cos, Real::ONE / (cos * cos))
+    }
+    fn sin_cos(self) -> (Self::Output, Self::Output) {
+        let (sin, cos) = self.value.sin_cos();
+        (self.chain(sin, cos), self.chain(cos, -sin))
+    }
+}
+
+impl<const N: usize, Real: ArithmeticOps> InverseTrigonometry for DualN<N, Real> {
+    type Output = Self;
+    fn acos(self) -> Self::Output {
+        let factor = -Real::ONE / (Real::ONE - self.value * self.value).sqrt();
+        self.chain(self.value.acos(), factor)
+    }
+    fn asin(self) -> Self::Output {
+        let factor = Real::ONE / (Real::ONE - self.value * self.value).sqrt();
+        self.chain(self.value.asin(), factor)
+    }
+    fn atan2(self, other: Self) -> Self::Output {
+        let squared_norm = self.value * self.value + other.value * other.value;
+        let mut gradient = self.gradient;
+        for (d, other_d) in gradient.iter_mut().zip(other.gradient) {
+            *d = (other.value * *d - self.value * other_d) / squared_norm;
+        }
+        Self::new(self.value.atan2(other.value), gradient)
+    }
+}
+
+impl<const N: usize, Real: ArithmeticOps> Logarithm for DualN<N, Real> {
+    fn ln(self) -> Self {
+        self.chain(self.value.ln(), Real::ONE / self.value)
+    }
+}
+
+impl<const N: usize, Real: ArithmeticOps> PowerDecibel for DualN<N, Real> {
+    fn to_power_decibels(self) -> Self {
+        let factor = Real::from_f64(10. / core::f64::consts::LN_10) / self.value;
+        self.chain(self.value.to_power_decibels(), factor)
+    }
+    fn power_decibels_to_value(self) -> Self {
+        let power = self.value.power_decibels_to_value();
+        self.chain(
+            power,
+            power * Real::from_f64(core::f64::consts::LN_10 / 10.),
+        )
+    }
+}
+
+impl<const N: usize, Real: ArithmeticOps> RootPowerDecibel for DualN<N, Real> {
+    fn to_root_power_decibels(self) -> Self {
+        let factor = Real::from_f64(20. / core::f64::consts::LN_10) / self.value;
+        self.chain(self.value.to_root_power_decibels(), factor)
+    }
+    fn root_power_decibels_to_value(self) -> Self {
+        let amplitude = self.value.root_power_decibels_to_value();
+        self.chain(
+            amplitude,
+            amplitude * Real::from_f64(core::f64::consts::LN_10 / 20.),
+        )
+    }
+}
+
+impl<const N: usize, Real: ArithmeticOps> HasZero for DualN<N, Real> {
+    const ZERO: Self = Self::constant(Real::ZERO);
+}
+
+impl<const N: usize, Real: ArithmeticOps> HasOne for DualN<N, Real> {
+    const ONE: Self = Self::constant(Real::ONE);
+}
+
+impl<const N: usize, Real: ArithmeticOps> HasHalf for DualN<N, Real> {
+    const HALF: Self = Self::constant(Real::HALF);
+}
+
+impl<const N: usize, Real: ArithmeticOps> HasEpsilon for DualN<N, Real> {
+    const EPSILON: Self = Self::constant(Real::EPSILON);
+}
+
+impl<const N: usize, Real: ArithmeticOps> HasSign for DualN<N, Real> {
+    fn signum(self) -> Self {
+        Self::constant(self.value.signum())
+    }
+    fn abs(self) -> Self {
+        if self.value < Real::ZERO {
+            -self
+        } else {
+            self
+        }
+    }
+}
+
+impl<const N: usize, Real: ArithmeticOps> LossyFrom<DualN<N, Real>> for DualN<N, Real> {
+    fn lossy_from(n: DualN<N, Real>) -> Self {
+        n
+    }
+}
+impl<const N: usize> LossyFrom<f32> for DualN<N, f64> {
+    fn lossy_from(n: f32) -> Self {
+        Self::constant(n as f64)
+    }
+}
+impl<const N: usize> LossyFrom<f64> for DualN<N, f64> {
+    fn lossy_from(n: f64) -> Self {
+        Self::constant(n)
+    }
+}
+impl<const N: usize> LossyFrom<DualN<N, f64>> for f32 {
+    fn lossy_from(n: DualN<N, f64>) -> Self {
+        n.value as f32
+    }
+}
+impl<const N: usize> LossyFrom<DualN<N, f64>> for f64 {
+    fn lossy_from(n: DualN<N, f64>) -> Self {
+        n.value
+    }
+}
+
+impl<const N: usize, Real: ArithmeticOps> fmt::Display for DualN<N, Real> {
+    /// Only the value is printed, so that measures are printed like the ones having a real value.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value, formatter)
+    }
+}
+
+impl<const N: usize, Real: ArithmeticOps> fmt::Debug for DualN<N, Real> {
+    /// The value is followed by the gradient, like in "3 + 2ε" or in "3 + [2, 0]ε".
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.value, formatter)?;
+        formatter.write_str(" + ")?;
+        if N != 1 {
+            formatter.write_str("[")?;
+        }
+        for (i, d) in self.gradient.iter().enumerate() {
+            if i > 0 {
+                formatter.write_str(", ")?;
+            }
+            fmt::Debug::fmt(d, formatter)?;
+        }
+        if N != 1 {
+            formatter.write_str("]")?;
+        }
+        formatter.write_str("\u{3b5}") // ε
+    }
+}
+
+impl<const N: usize, Real: ArithmeticOps> fmt::LowerExp for DualN<N, Real> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerExp::fmt(&self.value, formatter)
+    }
+}
+
+impl<const N: usize, Real: ArithmeticOps> fmt::UpperExp for DualN<N, Real> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperExp::fmt(&self.value, formatter)
+    }
+}
@@ -0,0 +1,139 @@
+//! Interoperability with the matrices of the crate `faer`, available with the feature `faer`.
+//!
+//! The functions `peel_mat_ref`, `wrap_mat_ref`, `peel_mat_mut`, `wrap_mat_mut`,
+//! `peel_col_ref` and `wrap_col_ref` view a matrix or a column of measures
+//! as a matrix or a column of numbers, and vice versa, without copying it.
+//! They work for the measure types which implement the trait `Transparent`,
+//! like `Measure<Metre, f64>`, which can be viewed as `f64`.
+//!
+//! The functions `matrix_vector_product`, `matrix_product` and `inner_product`
+//! multiply matrices and columns of measures using the optimized products of `faer`,
+//! and type their results through the relationships declared between the units.
+//! For example, if the relationship `Joule 1 == Newton 1 * Metre 1` is declared,
+//! the product of a `MatRef<Measure<Newton>>` by a `ColRef<Measure<Metre>>`
+//! is a `Col<Measure<Joule>>`.
+
+use crate::traits::Transparent;
+use core::ops::Mul;
+use faer::traits::ComplexField;
+use faer::{Col, ColRef, Mat, MatMut, MatRef};
+
+/// Views a matrix of measures as a matrix of their numbers, without copying it.
+pub fn peel_mat_ref<Outer: Transparent<Inner>, Inner>(mat: MatRef<'_, Outer>) -> MatRef<'_, Inner> {
+    // SAFETY: `Outer` has the same layout and validity of `Inner`, as required by `Transparent`,
+    // and the returned matrix has the same lifetime, shape, and strides of `mat`.
+    unsafe {
+        MatRef::from_raw_parts(
+            mat.as_ptr() as *const Inner,
+            mat.nrows(),
+            mat.ncols(),
+            mat.row_stride(),
+            mat.col_stride(),
+        )
+    }
+}
+
+/// Views a matrix of numbers as a matrix of measures, without copying it.
+pub fn wrap_mat_ref<Outer: Transparent<Inner>, Inner>(mat: MatRef<'_, Inner>) -> MatRef<'_, Outer> {
+    // SAFETY: `Outer` has the same layout and validity of `Inner`, as required by `Transparent`,
+    // and the returned matrix has the same lifetime, shape, and strides of `mat`.
+    unsafe {
+        MatRef::from_raw_parts(
+            mat.as_ptr() as *const Outer,
+            mat.nrows(),
+            mat.ncols(),
+            mat.row_stride(),
+            mat.col_stride(),
+        )
+    }
+}
+
+/// Views a mutable matrix of measures as a mutable matrix of their numbers, without copying it.
+pub fn peel_mat_mut<Outer: Transparent<Inner>, Inner>(mat: MatMut<'_, Outer>) -> MatMut<'_, Inner> {
+    // SAFETY: `Outer` has the same layout and validity of `Inner`, as required by `Transparent`,
+    // and the returned matrix replaces `mat`, having its lifetime, shape, and strides.
+    unsafe {
+        MatMut::from_raw_parts_mut(
+            mat.as_ptr_mut() as *mut Inner,
+            mat.nrows(),
+            mat.ncols(),
+            mat.row_stride(),
+            mat.col_stride(),
+        )
+    }
+}
+
+/// Views a mutable matrix of numbers as a mutable matrix of measures, without copying it.
+pub fn wrap_mat_mut<Outer: Transparent<Inner>, Inner>(mat: MatMut<'_, Inner>) -> MatMut<'_, Outer> {
+    // SAFETY: `Outer` has the same layout and validity of `Inner`, as required by `Transparent`,
+    // and the returned matrix replaces `mat`, having its lifetime, shape, and strides.
+    unsafe {
+        MatMut::from_raw_parts_mut(
+            mat.as_ptr_mut() as *mut Outer,
+            mat.nrows(),
+            mat.ncols(),
+            mat.row_stride(),
+            mat.col_stride(),
+        )
+    }
+}
+
+/// Views a column of measures as a column of their numbers, without copying it.
+pub fn peel_col_ref<Outer: Transparent<Inner>, Inner>(col: ColRef<'_, Outer>) -> ColRef<'_, Inner> {
+    // SAFETY: `Outer` has the same layout and validity of `Inner`, as required by `Transparent`,
+    // and the returned column has the same lifetime, length, and stride of `col`.
+    unsafe { ColRef::from_raw_parts(col.as_ptr() as *const Inner, col.nrows(), col.row_stride()) }
+}
+
+/// Views a column of numbers as a column of measures, without copying it.
+pub fn wrap_col_ref<Outer: Transparent<Inner>, Inner>(col: ColRef<'_, Inner>) -> ColRef<'_, Outer> {
+    // SAFETY: `Outer` has the same layout and validity of `Inner`, as required by `Transparent`,
+    // and the returned column has the same lifetime, length, and stride of `col`.
+    unsafe { ColRef::from_raw_parts(col.as_ptr() as *const Outer, col.nrows(), col.row_stride()) }
+}
+
+/// Matrix of measures * column of measures -> column of measures.
+/// It panics if the number of columns of `lhs` is different from the number of rows of `rhs`.
+pub fn matrix_vector_product<Lhs, Rhs, Output, Number>(
+    lhs: MatRef<'_, Lhs>,
+    rhs: ColRef<'_, Rhs>,
+) -> Col<Output>
+where
+    Lhs: Transparent<Number> + Mul<Rhs, Output = Output>,
+    Rhs: Transparent<Number>,
+    Output: Transparent<Number>,
+    Number: ComplexField,
+{
+    let product = peel_mat_ref(lhs) * peel_col_ref(rhs);
+    Col::from_fn(product.nrows(), |i| Output::wrap(product[i].clone()))
+}
+
+/// Matrix of measures * matrix of measures -> matrix of measures.
+/// It panics if the number of columns of `lhs` is different from the number of rows of `rhs`.
+pub fn matrix_product<Lhs, Rhs, Output, Number>(
+    lhs: MatRef<'_, Lhs>,
+    rhs: MatRef<'_, Rhs>,
+) -> Mat<Output>
+where
+    Lhs: Transparent<Number> + Mul<Rhs, Output = Output>,
+    Rhs: Transparent<Number>,
+    Output: Transparent<Number>,
+    Number: ComplexField,
+{
+    let product = peel_mat_ref(lhs) * peel_mat_ref(rhs);
+    Mat::from_fn(product.nrows(), product.ncols(), |i, j| {
+        Output::wrap(product[(i, j)].clone())
+    })
+}
+
+/// Column of measures * column of measures -> measure.
+/// It panics if the numbers of rows of `lhs` and `rhs` are different.
+pub fn inner_product<Lhs, Rhs, Output, Number>(lhs: ColRef<'_, Lhs>, rhs: ColRef<'_, Rhs>) -> Output
+where
+    Lhs: Transparent<Number> + Mul<Rhs, Output = Output>,
+    Rhs: Transparent<Number>,
+    Output: Transparent<Number>,
+    Number: ComplexField,
+{
+    Output::wrap(peel_col_ref(lhs).transpose() * peel_col_ref(rhs))
+}
@@ -0,0 +1,91 @@
+//! Implementation of the numeric traits required by `BasicArithmeticOps`
+//! for the signed fixed-point types of the crate `fixed`, available with the feature `fixed`.
+//!
+//! So, types like `Measure<Millimetre, I16F16>` can be used on targets lacking a floating-point unit.
+//! Such types support only the operations which don't need real-number functions.
+//! The types must have at least one integer bit, because the number one must be representable.
+//! The conversions between units round the result to the nearest representable number,
+//! and panic if the result is out of range.
+
+use crate::traits::{
+    rational_approximation, scale_integer_by_fraction, HasOne, HasSign, HasZero, LossyFrom,
+    ScaleByRatio,
+};
+use core::convert::TryFrom;
+pub use fixed::types;
+use fixed::types::extra::{LeEqU16, LeEqU32, LeEqU64, LeEqU8};
+use fixed::{FixedI16, FixedI32, FixedI64, FixedI8};
+
+macro_rules! impl_fixed_number_traits {
+    ($($fixed:ident, $bits:ty, $frac_bound:ident;)*) => {
+        $(
+            impl<Frac: $frac_bound> ScaleByRatio for $fixed<Frac> {
+                fn scale_by_ratio(self, ratio: f64) -> Self {
+                    let mut values = [self];
+                    Self::scale_all_by_ratio(&mut values, ratio);
+                    values[0]
+                }
+                fn scale_all_by_ratio(values: &mut [Self], ratio: f64) {
+                    let fraction = rational_approximation(ratio);
+                    for value in values {
+                        *value = Self::from_bits(
+                            scale_integer_by_fraction(value.to_bits() as i128, fraction)
+                                .and_then(|bits| <$bits>::try_from(bits).ok())
+                                .expect("fixed-point overflow in the conversion of a measure"),
+                        );
+                    }
+                }
+                fn apply_conversion<F: Fn(f64) -> f64>(self, function: F) -> Self {
+                    Self::checked_from_num(function(self.to_num::<f64>()))
+                        .expect("fixed-point overflow in the conversion of a measure")
+                }
+            }
+            impl<Frac: $frac_bound> HasZero for $fixed<Frac> {
+                const ZERO: Self = Self::ZERO;
+            }
+            impl<Frac: $frac_bound> HasOne for $fixed<Frac> {
+                const ONE: Self = Self::from_bits(1 << Frac::U32);
+            }
+            impl<Frac: $frac_bound> HasSign for $fixed<Frac> {
+                fn signum(self) -> Self {
+                    self.signum()
+                }
+                fn abs(self) -> Self {
+                    self.abs()
+                }
+            }
+            impl<Frac: $frac_bound> LossyFrom<$fixed<Frac>> for $fixed<Frac> {
+                fn lossy_from(n: $fixed<Frac>) -> Self {
+                    n
+                }
+            }
+            impl<Frac: $frac_bound> LossyFrom<$fixed<Frac>> for f32 {
+                fn lossy_from(n: $fixed<Frac>) -> Self {
+                    n.to_num()
+                }
+            }
+            impl<Frac: $frac_bound> LossyFrom<$fixed<Frac>> for f64 {
+                fn lossy_from(n: $fixed<Frac>) -> Self {
+                    n.to_num()
+                }
+            }
+            impl<Frac: $frac_bound> LossyFrom<f32> for $fixed<Frac> {
+                fn lossy_from(n: f32) -> Self {
+                    Self::from_num(n)
+                }
+            }
+            impl<Frac: $frac_bound> LossyFrom<f64> for $fixed<Frac> {
+                fn lossy_from(n: f64) -> Self {
+                    Self::from_num(n)
+                }
+            }
+        )*
+    };
+}
+
+impl_fixed_number_traits! {
+    FixedI8, i8, LeEqU8;
+    FixedI16, i16, LeEqU16;
+    FixedI32, i32, LeEqU32;
+    FixedI64, i64, LeEqU64;
+}
@@ -0,0 +1,248 @@
+//! Quadruple-precision number type, available with the feature `f128`,
+//! which requires a nightly compiler and the standard library.
+//!
+//! The primitive type `f128` cannot be used directly as `Number` type of measures,
+//! because the standard library does not format it in decimal notation yet.
+//! So, it is wrapped by the type `Float128`, which implements all the numeric traits
+//! by delegating to `f128`, and which is formatted by converting it to `DoubleDouble`.
+//! Therefore, at most 32 significant decimal digits are printed,
+//! even if `f128` has about 34 significant decimal digits.
+
+use crate::double_double::DoubleDouble;
+use crate::traits::{
+    CubicRoot, FromF64, HasEpsilon, HasHalf, HasOne, HasSign, HasZero, InverseTrigonometry,
+    Logarithm, LossyFrom, Pow, PowerDecibel, RootPowerDecibel, ScaleByRatio, Sqrt, Trigonometry,
+};
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+/// Number having the IEEE 754 quadruple precision.
+#[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct Float128(pub f128);
+
+impl Float128 {
+    // The nearest double-double number, used for formatting.
+    fn to_double_double(self) -> DoubleDouble {
+        let hi = self.0 as f64;
+        if !hi.is_finite() {
+            return DoubleDouble::from(hi);
+        }
+        DoubleDouble::new(hi, (self.0 - hi as f128) as f64)
+    }
+}
+
+impl From<f64> for Float128 {
+    fn from(n: f64) -> Self {
+        Self(n as f128)
+    }
+}
+
+impl From<f32> for Float128 {
+    fn from(n: f32) -> Self {
+        Self(n as f128)
+    }
+}
+
+impl Neg for Float128 {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+macro_rules! float128_binary_operator {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:tt) => {
+        impl $trait for Float128 {
+            type Output = Self;
+            fn $method(self, other: Self) -> Self {
+                Self(self.0 $op other.0)
+            }
+        }
+        impl $assign_trait for Float128 {
+            fn $assign_method(&mut self, other: Self) {
+                *self = Self(self.0 $op other.0);
+            }
+        }
+    };
+}
+
+float128_binary_operator!(Add, add, AddAssign, add_assign, +);
+float128_binary_operator!(Sub, sub, SubAssign, sub_assign, -);
+float128_binary_operator!(Mul, mul, MulAssign, mul_assign, *);
+float128_binary_operator!(Div, div, DivAssign, div_assign, /);
+
+impl Rem for Float128 {
+    type Output = Self;
+    fn rem(self, other: Self) -> Self {
+        Self(self.0 % other.0)
+    }
+}
+
+impl core::iter::Sum for Float128 {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |a, b| a + b)
+    }
+}
+
+impl Pow for Float128 {
+    fn powf(self, exponent: Self) -> Self {
+        Self(self.0.powf(exponent.0))
+    }
+}
+
+impl FromF64 for Float128 {
+    fn from_f64(n: f64) -> Self {
+        Self(n as f128)
+    }
+}
+
+impl ScaleByRatio for Float128 {
+    fn scale_by_ratio(self, ratio: f64) -> Self {
+        self * Self::from_f64(ratio)
+    }
+    fn apply_conversion<F: Fn(f64) -> f64>(self, function: F) -> Self {
+        Self::from_f64(function(self.0 as f64))
+    }
+}
+
+impl Sqrt for Float128 {
+    type Output = Float128;
+    fn sqrt(self) -> Self {
+        Self(self.0.sqrt())
+    }
+}
+
+impl CubicRoot for Float128 {
+    type Output = Float128;
+    fn cubic_root(self) -> Self {
+        Self(self.0.cbrt())
+    }
+}
+
+impl Trigonometry for Float128 {
+    type Output = Float128;
+    fn cos(self) -> Self::Output {
+        Self(self.0.cos())
+    }
+    fn sin(self) -> Self::Output {
+        Self(self.0.sin())
+    }
+    fn tan(self) -> Self::Output {
+        Self(self.0.tan())
+    }
+    fn sin_cos(self) -> (Self::Output, Self::Output) {
+        (Self(self.0.sin()), Self(self.0.cos()))
+    }
+}
+
+impl InverseTrigonometry for Float128 {
+    type Output = Float128;
+    fn acos(self) -> Self::Output {
+        Self(self.0.acos())
+    }
+    fn asin(self) -> Self::Output {
+        Self(self.0.asin())
+    }
+    fn atan2(self, other: Self) -> Self::Output {
+        Self(self.0.atan2(other.0))
+    }
+}
+
+impl Logarithm for Float128 {
+    fn ln(self) -> Self {
+        Self(self.0.ln())
+    }
+}
+
+impl PowerDecibel for Float128 {
+    fn to_power_decibels(self) -> Self {
+        Self(self.0.log10() * 10.)
+    }
+    fn power_decibels_to_value(self) -> Self {
+        Self(10_f128.powf(self.0 / 10.))
+    }
+}
+
+impl RootPowerDecibel for Float128 {
+    fn to_root_power_decibels(self) -> Self {
+        Self(self.0.log10() * 20.)
+    }
+    fn root_power_decibels_to_value(self) -> Self {
+        Self(10_f128.powf(self.0 / 20.))
+    }
+}
+
+impl HasZero for Float128 {
+    const ZERO: Self = Self(0.);
+}
+
+impl HasOne for Float128 {
+    const ONE: Self = Self(1.);
+}
+
+impl HasHalf for Float128 {
+    const HALF: Self = Self(0.5);
+}
+
+impl HasEpsilon for Float128 {
+    const EPSILON: Self = Self(f128::EPSILON);
+}
+
+impl HasSign for Float128 {
+    fn signum(self) -> Self {
+        Self(self.0.signum())
+    }
+    fn abs(self) -> Self {
+        Self(self.0.abs())
+    }
+}
+
+impl LossyFrom<f32> for Float128 {
+    fn lossy_from(n: f32) -> Self {
+        Self(n as f128)
+    }
+}
+impl LossyFrom<f64> for Float128 {
+    fn lossy_from(n: f64) -> Self {
+        Self(n as f128)
+    }
+}
+impl LossyFrom<Float128> for Float128 {
+    fn lossy_from(n: Float128) -> Self {
+        n
+    }
+}
+impl LossyFrom<Float128> for f32 {
+    fn lossy_from(n: Float128) -> Self {
+        n.0 as f32
+    }
+}
+impl LossyFrom<Float128> for f64 {
+    fn lossy_from(n: Float128) -> Self {
+        n.0 as f64
+    }
+}
+
+impl fmt::Display for Float128 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_double_double(), f)
+    }
+}
+
+impl fmt::Debug for Float128 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_double_double(), f)
+    }
+}
+
+impl fmt::LowerExp for Float128 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerExp::fmt(&self.to_double_double(), f)
+    }
+}
+
+impl fmt::UpperExp for Float128 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperExp::fmt(&self.to_double_double(), f)
+    }
+}
@@ -0,0 +1,105 @@
+//! Elementary functions of `f32` and `f64`, which are not provided by the core library.
+//!
+//! With the feature `std`, they are computed by the standard library.
+//! Otherwise, they are computed by the crate `libm`, which must be enabled by the feature `libm`,
+//! like for targets lacking an operating system.
+//! The methods must be called using the qualified syntax, like in `FloatFunctions::sqrt(x)`,
+//! because the standard library defines inherent methods having the same names.
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!(
+    "Either the feature `std` or the feature `libm` of the crate `measures` must be enabled."
+);
+
+/// Elementary functions of floating-point numbers.
+pub trait FloatFunctions: Sized {
+    fn mul_add(self, a: Self, b: Self) -> Self;
+    fn powf(self, exponent: Self) -> Self;
+    fn sqrt(self) -> Self;
+    fn cbrt(self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn log10(self) -> Self;
+    fn floor(self) -> Self;
+    fn round(self) -> Self;
+    fn trunc(self) -> Self;
+    fn cos(self) -> Self;
+    fn sin(self) -> Self;
+    fn tan(self) -> Self;
+    fn sin_cos(self) -> (Self, Self);
+    fn acos(self) -> Self;
+    fn asin(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+}
+
+macro_rules! impl_float_functions {
+    ($float:ident, $libm_sin_cos:ident, $($function:ident($($arg:ident),*) => $libm_function:ident,)*) => {
+        impl FloatFunctions for $float {
+            $(
+                #[inline]
+                fn $function(self $(, $arg: Self)*) -> Self {
+                    #[cfg(feature = "std")]
+                    {
+                        <$float>::$function(self $(, $arg)*)
+                    }
+                    #[cfg(not(feature = "std"))]
+                    {
+                        libm::$libm_function(self $(, $arg)*)
+                    }
+                }
+            )*
+
+            #[inline]
+            fn sin_cos(self) -> (Self, Self) {
+                #[cfg(feature = "std")]
+                {
+                    <$float>::sin_cos(self)
+                }
+                #[cfg(not(feature = "std"))]
+                {
+                    libm::$libm_sin_cos(self)
+                }
+            }
+        }
+    };
+}
+
+impl_float_functions! {
+    f32, sincosf,
+    mul_add(a, b) => fmaf,
+    powf(exponent) => powf,
+    sqrt() => sqrtf,
+    cbrt() => cbrtf,
+    exp() => expf,
+    ln() => logf,
+    log10() => log10f,
+    floor() => floorf,
+    round() => roundf,
+    trunc() => truncf,
+    cos() => cosf,
+    sin() => sinf,
+    tan() => tanf,
+    acos() => acosf,
+    asin() => asinf,
+    atan2(other) => atan2f,
+}
+
+impl_float_functions! {
+    f64, sincos,
+    mul_add(a, b) => fma,
+    powf(exponent) => pow,
+    sqrt() => sqrt,
+    cbrt() => cbrt,
+    exp() => exp,
+    ln() => log,
+    log10() => log10,
+    floor() => floor,
+    round() => round,
+    trunc() => trunc,
+    cos() => cos,
+    sin() => sin,
+    tan() => tan,
+    acos() => acos,
+    asin() => asin,
+    atan2(other) => atan2,
+}
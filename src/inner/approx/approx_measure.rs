@@ -53,9 +53,11 @@ macro_rules! inner_define_approx_measure {
             }
 
             /// ApproxMeasure.convert() -> ApproxMeasure
+            /// Like for `Measure`, the units must be linear.
             pub fn convert<DestUnit>(self) -> ApproxMeasure<DestUnit, Number>
             where
-                DestUnit: MeasurementUnit<Property = Unit::Property>,
+                Unit: LinearMeasurementUnit,
+                DestUnit: LinearMeasurementUnit<Property = Unit::Property>,
             {
                 let ratio = Number::from_f64(Unit::RATIO / DestUnit::RATIO);
                 ApproxMeasure::<DestUnit, Number>::with_variance(
@@ -656,5 +658,37 @@ macro_rules! inner_define_approx_measure {
                 formatter.write_str(Unit::SUFFIX)
             }
         }
+
+        measures::if_all_true! { { $with_serde }
+            /// It serializes the measure as the text it prints, like "30 dB W",
+            /// to be read by people. It cannot be deserialized.
+            impl<Unit, Number> serde::Serialize for ApproxPowerDecibelsMeasureFormatter<Unit, Number>
+            where
+                Unit: MeasurementUnit<Property: PowerQuantity>,
+                Number: ArithmeticOps,
+            {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: serde::Serializer
+                {
+                    serializer.collect_str(self)
+                }
+            }
+
+            /// It serializes the measure as the text it prints, like "30 dB W",
+            /// to be read by people. It cannot be deserialized.
+            impl<Unit, Number> serde::Serialize for ApproxRootPowerDecibelsMeasureFormatter<Unit, Number>
+            where
+                Unit: MeasurementUnit<Property: RootPowerQuantity>,
+                Number: ArithmeticOps,
+            {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: serde::Serializer
+                {
+                    serializer.collect_str(self)
+                }
+            }
+        }
     };
 }
@@ -15,7 +15,7 @@ macro_rules! inner_define_approx_measure_2d {
 
         impl<Unit, Number> ApproxMeasure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// Measure2d::with_covariances([number; 2], [[number; 2]; 2]) -> Measure2d
@@ -51,10 +51,8 @@ macro_rules! inner_define_approx_measure_2d {
             /// Measure2d.convert() -> Measure2d
             pub fn convert<DestUnit>(self) -> ApproxMeasure2d<DestUnit, Number>
             where
-                DestUnit: MeasurementUnit<Property = Unit::Property>,
+                DestUnit: VectorMeasurementUnit<Property = Unit::Property>,
             {
-                debug_assert!(Unit::OFFSET == 0.);
-                debug_assert!(DestUnit::OFFSET == 0.);
                 let ratio = Number::from_f64(Unit::RATIO / DestUnit::RATIO);
                 let r2 = ratio * ratio;
                 ApproxMeasure2d::<DestUnit, Number>::with_covariances(
@@ -127,7 +125,7 @@ macro_rules! inner_define_approx_measure_2d {
 
         impl<Unit, Number> Default for ApproxMeasure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// ApproxMeasure2d::default() -> ApproxMeasure2d
@@ -142,7 +140,7 @@ macro_rules! inner_define_approx_measure_2d {
 
         impl<Unit> From<ApproxMeasure2d<Unit, f32>> for ApproxMeasure2d<Unit, f64>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
         {
             fn from(m: ApproxMeasure2d<Unit, f32>) -> Self {
                 Self::with_covariances(
@@ -158,7 +156,7 @@ macro_rules! inner_define_approx_measure_2d {
         measures::if_all_true! { { $with_serde }
             impl<Unit, Number> serde::Serialize for ApproxMeasure2d<Unit, Number>
             where
-                Unit: MeasurementUnit<Property: VectorProperty>,
+                Unit: VectorMeasurementUnit,
                 Number: ArithmeticOps + serde::Serialize,
             {
                 fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -171,7 +169,7 @@ macro_rules! inner_define_approx_measure_2d {
 
             impl<'de, Unit, Number> serde::Deserialize<'de> for ApproxMeasure2d<Unit, Number>
             where
-                Unit: MeasurementUnit<Property: VectorProperty>,
+                Unit: VectorMeasurementUnit,
                 Number: ArithmeticOps + serde::Deserialize<'de>,
             {
                 fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
@@ -187,7 +185,7 @@ macro_rules! inner_define_approx_measure_2d {
         /// -ApproxMeasure2d -> ApproxMeasure2d
         impl<Unit, Number> Neg for ApproxMeasure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = Self;
@@ -199,7 +197,7 @@ macro_rules! inner_define_approx_measure_2d {
         /// ApproxMeasure2d + ApproxMeasure2d -> ApproxMeasure2d
         impl<Unit, Number> Add<ApproxMeasure2d<Unit, Number>> for ApproxMeasure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = Self;
@@ -226,7 +224,7 @@ macro_rules! inner_define_approx_measure_2d {
         /// ApproxMeasure2d += ApproxMeasure2d
         impl<Unit, Number> AddAssign<ApproxMeasure2d<Unit, Number>> for ApproxMeasure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn add_assign(&mut self, other: ApproxMeasure2d<Unit, Number>) {
@@ -243,7 +241,7 @@ macro_rules! inner_define_approx_measure_2d {
         /// ApproxMeasure2d - ApproxMeasure2d -> ApproxMeasure2d
         impl<Unit, Number> Sub<ApproxMeasure2d<Unit, Number>> for ApproxMeasure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = Self;
@@ -270,7 +268,7 @@ macro_rules! inner_define_approx_measure_2d {
         /// ApproxMeasure2d -= ApproxMeasure2d
         impl<Unit, Number> SubAssign<ApproxMeasure2d<Unit, Number>> for ApproxMeasure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn sub_assign(&mut self, other: ApproxMeasure2d<Unit, Number>) {
@@ -287,7 +285,7 @@ macro_rules! inner_define_approx_measure_2d {
         /// ApproxMeasure2d * Number -> ApproxMeasure2d
         impl<Unit, Number> Mul<Number> for ApproxMeasure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = Self;
@@ -306,7 +304,7 @@ macro_rules! inner_define_approx_measure_2d {
         /// ApproxMeasure2d * ApproxMeasure<One> -> ApproxMeasure2d
         impl<Unit, Number> Mul<ApproxMeasure<One, Number>> for ApproxMeasure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = Self;
@@ -321,7 +319,7 @@ macro_rules! inner_define_approx_measure_2d {
         /// ApproxMeasure<One> * ApproxMeasure2d -> ApproxMeasure2d
         impl<Unit, Number> Mul<ApproxMeasure2d<Unit, Number>> for ApproxMeasure<One, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = ApproxMeasure2d<Unit, Number>;
@@ -336,7 +334,7 @@ macro_rules! inner_define_approx_measure_2d {
         /// ApproxMeasure2d *= Number
         impl<Unit, Number> MulAssign<Number> for ApproxMeasure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn mul_assign(&mut self, n: Number) {
@@ -348,7 +346,7 @@ macro_rules! inner_define_approx_measure_2d {
         /// ApproxMeasure2d *= ApproxMeasure<One>
         impl<Unit, Number> MulAssign<ApproxMeasure<One, Number>> for ApproxMeasure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn mul_assign(&mut self, other: ApproxMeasure<One, Number>) {
@@ -360,7 +358,7 @@ macro_rules! inner_define_approx_measure_2d {
         /// f64 * ApproxMeasure2d -> ApproxMeasure2d
         impl<Unit> Mul<ApproxMeasure2d<Unit, f64>> for f64
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
         {
             type Output = ApproxMeasure2d<Unit, f64>;
             fn mul(self, other: ApproxMeasure2d<Unit, f64>) -> Self::Output {
@@ -374,7 +372,7 @@ macro_rules! inner_define_approx_measure_2d {
         /// f32 * ApproxMeasure2d -> ApproxMeasure2d
         impl<Unit> Mul<ApproxMeasure2d<Unit, f32>> for f32
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
         {
             type Output = ApproxMeasure2d<Unit, f32>;
             fn mul(self, other: ApproxMeasure2d<Unit, f32>) -> Self::Output {
@@ -388,7 +386,7 @@ macro_rules! inner_define_approx_measure_2d {
         /// ApproxMeasure2d / Number -> ApproxMeasure2d
         impl<Unit, Number> Div<Number> for ApproxMeasure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = Self;
@@ -404,7 +402,7 @@ macro_rules! inner_define_approx_measure_2d {
         /// ApproxMeasure2d /= Number
         impl<Unit, Number> DivAssign<Number> for ApproxMeasure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn div_assign(&mut self, n: Number) {
@@ -417,7 +415,7 @@ macro_rules! inner_define_approx_measure_2d {
         /// ApproxMeasure2d / ApproxMeasure -> ApproxMeasure2d<One>
         impl<Unit, Number> Div<ApproxMeasure<Unit, Number>> for ApproxMeasure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = ApproxMeasure2d<One, Number>;
@@ -444,7 +442,7 @@ macro_rules! inner_define_approx_measure_2d {
         /// ApproxMeasure2d == ApproxMeasure2d -> bool
         impl<Unit, Number> PartialEq<ApproxMeasure2d<Unit, Number>> for ApproxMeasure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn eq(&self, other: &ApproxMeasure2d<Unit, Number>) -> bool {
@@ -455,7 +453,7 @@ macro_rules! inner_define_approx_measure_2d {
         /// ApproxMeasure2d.clone() -> ApproxMeasure2d
         impl<Unit, Number> Clone for ApproxMeasure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn clone(&self) -> Self {
@@ -466,7 +464,7 @@ macro_rules! inner_define_approx_measure_2d {
         /// ApproxMeasure2d = ApproxMeasure2d
         impl<Unit, Number> Copy for ApproxMeasure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
         }
@@ -475,7 +473,7 @@ macro_rules! inner_define_approx_measure_2d {
         /// ApproxMeasure2d.to_string() -> String
         impl<Unit, Number> fmt::Display for ApproxMeasure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -499,7 +497,7 @@ macro_rules! inner_define_approx_measure_2d {
         /// format!("{:?}", ApproxMeasure2d)
         impl<Unit, Number> fmt::Debug for ApproxMeasure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -508,14 +506,11 @@ macro_rules! inner_define_approx_measure_2d {
                 formatter.write_str(", ")?;
                 fmt::Display::fmt(&self.values[1], formatter)?;
                 formatter.write_str("), covariances=\n")?;
-                write!(
+                measures::matrix_utils::format_matrix::<2, 2, Number>(
                     formatter,
-                    "{}",
-                    measures::matrix_utils::format_matrix::<2, 2, Number>(
-                        &self.covariances,
-                        Unit::SUFFIX,
-                        1
-                    )
+                    &self.covariances,
+                    Unit::SUFFIX,
+                    1,
                 )
             }
         }
@@ -15,7 +15,7 @@ macro_rules! inner_define_approx_measure_3d {
 
         impl<Unit, Number> ApproxMeasure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// Measure3d::with_covariances([number; 3], [[number; 3]; 3]) -> Measure3d
@@ -58,10 +58,8 @@ macro_rules! inner_define_approx_measure_3d {
             /// Measure3d.convert() -> Measure3d
             pub fn convert<DestUnit>(self) -> ApproxMeasure3d<DestUnit, Number>
             where
-                DestUnit: MeasurementUnit<Property = Unit::Property>,
+                DestUnit: VectorMeasurementUnit<Property = Unit::Property>,
             {
-                debug_assert!(Unit::OFFSET == 0.);
-                debug_assert!(DestUnit::OFFSET == 0.);
                 let ratio = Number::from_f64(Unit::RATIO / DestUnit::RATIO);
                 let r2 = ratio * ratio;
                 ApproxMeasure3d::<DestUnit, Number>::with_covariances(
@@ -186,7 +184,7 @@ macro_rules! inner_define_approx_measure_3d {
 
         impl<Unit, Number> Default for ApproxMeasure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// ApproxMeasure3d::default() -> ApproxMeasure3d
@@ -205,7 +203,7 @@ macro_rules! inner_define_approx_measure_3d {
 
         impl<Unit> From<ApproxMeasure3d<Unit, f32>> for ApproxMeasure3d<Unit, f64>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
         {
             fn from(m: ApproxMeasure3d<Unit, f32>) -> Self {
                 Self::with_covariances(
@@ -234,7 +232,7 @@ macro_rules! inner_define_approx_measure_3d {
         measures::if_all_true! { { $with_serde }
             impl<Unit, Number> serde::Serialize for ApproxMeasure3d<Unit, Number>
             where
-                Unit: MeasurementUnit<Property: VectorProperty>,
+                Unit: VectorMeasurementUnit,
                 Number: ArithmeticOps + serde::Serialize,
             {
                 fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -247,7 +245,7 @@ macro_rules! inner_define_approx_measure_3d {
 
             impl<'de, Unit, Number> serde::Deserialize<'de> for ApproxMeasure3d<Unit, Number>
             where
-                Unit: MeasurementUnit<Property: VectorProperty>,
+                Unit: VectorMeasurementUnit,
                 Number: ArithmeticOps + serde::Deserialize<'de>,
             {
                 fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
@@ -263,7 +261,7 @@ macro_rules! inner_define_approx_measure_3d {
         /// -ApproxMeasure3d -> ApproxMeasure3d
         impl<Unit, Number> Neg for ApproxMeasure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = Self;
@@ -278,7 +276,7 @@ macro_rules! inner_define_approx_measure_3d {
         /// ApproxMeasure3d + ApproxMeasure3d -> ApproxMeasure3d
         impl<Unit, Number> Add<ApproxMeasure3d<Unit, Number>> for ApproxMeasure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = Self;
@@ -313,7 +311,7 @@ macro_rules! inner_define_approx_measure_3d {
         /// ApproxMeasure3d += ApproxMeasure3d
         impl<Unit, Number> AddAssign<ApproxMeasure3d<Unit, Number>> for ApproxMeasure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn add_assign(&mut self, other: ApproxMeasure3d<Unit, Number>) {
@@ -335,7 +333,7 @@ macro_rules! inner_define_approx_measure_3d {
         /// ApproxMeasure3d - ApproxMeasure3d -> ApproxMeasure3d
         impl<Unit, Number> Sub<ApproxMeasure3d<Unit, Number>> for ApproxMeasure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = Self;
@@ -370,7 +368,7 @@ macro_rules! inner_define_approx_measure_3d {
         /// ApproxMeasure3d -= ApproxMeasure3d
         impl<Unit, Number> SubAssign<ApproxMeasure3d<Unit, Number>> for ApproxMeasure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn sub_assign(&mut self, other: ApproxMeasure3d<Unit, Number>) {
@@ -392,7 +390,7 @@ macro_rules! inner_define_approx_measure_3d {
         /// ApproxMeasure3d * Number -> ApproxMeasure3d
         impl<Unit, Number> Mul<Number> for ApproxMeasure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = Self;
@@ -424,7 +422,7 @@ macro_rules! inner_define_approx_measure_3d {
         /// ApproxMeasure3d * ApproxMeasure<One> -> ApproxMeasure3d
         impl<Unit, Number> Mul<ApproxMeasure<One, Number>> for ApproxMeasure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = Self;
@@ -443,7 +441,7 @@ macro_rules! inner_define_approx_measure_3d {
         /// ApproxMeasure<One> * ApproxMeasure3d -> ApproxMeasure3d
         impl<Unit, Number> Mul<ApproxMeasure3d<Unit, Number>> for ApproxMeasure<One, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = ApproxMeasure3d<Unit, Number>;
@@ -462,7 +460,7 @@ macro_rules! inner_define_approx_measure_3d {
         /// ApproxMeasure3d *= Number
         impl<Unit, Number> MulAssign<Number> for ApproxMeasure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn mul_assign(&mut self, n: Number) {
@@ -475,7 +473,7 @@ macro_rules! inner_define_approx_measure_3d {
         /// ApproxMeasure3d *= ApproxMeasure<One>
         impl<Unit, Number> MulAssign<ApproxMeasure<One, Number>> for ApproxMeasure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn mul_assign(&mut self, other: ApproxMeasure<One, Number>) {
@@ -488,7 +486,7 @@ macro_rules! inner_define_approx_measure_3d {
         /// f64 * ApproxMeasure3d -> ApproxMeasure3d
         impl<Unit> Mul<ApproxMeasure3d<Unit, f64>> for f64
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
         {
             type Output = ApproxMeasure3d<Unit, f64>;
             fn mul(self, other: ApproxMeasure3d<Unit, f64>) -> Self::Output {
@@ -506,7 +504,7 @@ macro_rules! inner_define_approx_measure_3d {
         /// f32 * ApproxMeasure3d -> ApproxMeasure3d
         impl<Unit> Mul<ApproxMeasure3d<Unit, f32>> for f32
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
         {
             type Output = ApproxMeasure3d<Unit, f32>;
             fn mul(self, other: ApproxMeasure3d<Unit, f32>) -> Self::Output {
@@ -524,7 +522,7 @@ macro_rules! inner_define_approx_measure_3d {
         /// ApproxMeasure3d / Number -> ApproxMeasure3d
         impl<Unit, Number> Div<Number> for ApproxMeasure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = Self;
@@ -544,7 +542,7 @@ macro_rules! inner_define_approx_measure_3d {
         /// ApproxMeasure3d /= Number
         impl<Unit, Number> DivAssign<Number> for ApproxMeasure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn div_assign(&mut self, n: Number) {
@@ -558,7 +556,7 @@ macro_rules! inner_define_approx_measure_3d {
         /// ApproxMeasure3d / ApproxMeasure -> ApproxMeasure3d<One>
         impl<Unit, Number> Div<ApproxMeasure<Unit, Number>> for ApproxMeasure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = ApproxMeasure3d<One, Number>;
@@ -590,7 +588,7 @@ macro_rules! inner_define_approx_measure_3d {
         /// ApproxMeasure3d == ApproxMeasure3d -> bool
         impl<Unit, Number> PartialEq<ApproxMeasure3d<Unit, Number>> for ApproxMeasure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn eq(&self, other: &ApproxMeasure3d<Unit, Number>) -> bool {
@@ -601,7 +599,7 @@ macro_rules! inner_define_approx_measure_3d {
         /// ApproxMeasure3d.clone() -> ApproxMeasure3d
         impl<Unit, Number> Clone for ApproxMeasure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn clone(&self) -> Self {
@@ -612,7 +610,7 @@ macro_rules! inner_define_approx_measure_3d {
         /// ApproxMeasure3d = ApproxMeasure3d
         impl<Unit, Number> Copy for ApproxMeasure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
         }
@@ -621,7 +619,7 @@ macro_rules! inner_define_approx_measure_3d {
         /// ApproxMeasure3d.to_string() -> String
         impl<Unit, Number> fmt::Display for ApproxMeasure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -657,7 +655,7 @@ macro_rules! inner_define_approx_measure_3d {
         /// format!("{:?}", ApproxMeasure3d)
         impl<Unit, Number> fmt::Debug for ApproxMeasure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -668,14 +666,11 @@ macro_rules! inner_define_approx_measure_3d {
                 formatter.write_str(", ")?;
                 fmt::Display::fmt(&self.values[2], formatter)?;
                 formatter.write_str("), covariances=\n")?;
-                write!(
+                measures::matrix_utils::format_matrix::<3, 3, Number>(
                     formatter,
-                    "{}",
-                    measures::matrix_utils::format_matrix::<3, 3, Number>(
-                        &self.covariances,
-                        Unit::SUFFIX,
-                        1
-                    )
+                    &self.covariances,
+                    Unit::SUFFIX,
+                    1,
                 )
             }
         }
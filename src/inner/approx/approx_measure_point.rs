@@ -53,16 +53,29 @@ macro_rules! inner_define_approx_measure_point {
             }
 
             /// ApproxMeasurePoint.convert() -> ApproxMeasurePoint
+            /// If a unit isn't linear, the value is converted by the functions of the units,
+            /// and the variance is scaled by the square of the derivative of the conversion.
             pub fn convert<DestUnit>(self) -> ApproxMeasurePoint<DestUnit, Number>
             where
                 DestUnit: MeasurementUnit<Property = Unit::Property>,
             {
-                let ratio = Number::from_f64(Unit::RATIO / DestUnit::RATIO);
-                ApproxMeasurePoint::<DestUnit, Number>::with_variance(
-                    self.value * ratio
-                        + Number::from_f64((Unit::OFFSET - DestUnit::OFFSET) / DestUnit::RATIO),
-                    self.variance * ratio * ratio,
-                )
+                if Unit::LINEAR && DestUnit::LINEAR {
+                    let ratio = Number::from_f64(Unit::RATIO / DestUnit::RATIO);
+                    ApproxMeasurePoint::<DestUnit, Number>::with_variance(
+                        self.value * ratio
+                            + Number::from_f64((Unit::OFFSET - DestUnit::OFFSET) / DestUnit::RATIO),
+                        self.variance * ratio * ratio,
+                    )
+                } else {
+                    let conversion = |value| DestUnit::from_base(Unit::to_base(value));
+                    let derivative = self.value.apply_conversion(|value| {
+                        measures::traits::conversion_derivative(conversion, value)
+                    });
+                    ApproxMeasurePoint::<DestUnit, Number>::with_variance(
+                        self.value.apply_conversion(conversion),
+                        self.variance * derivative * derivative,
+                    )
+                }
             }
 
             pub fn lossless_into<DestNumber>(self) -> ApproxMeasurePoint<Unit, DestNumber>
@@ -351,6 +364,82 @@ macro_rules! inner_define_approx_measure_point {
             )
         }
 
+        impl<Unit, Number> ApproxMeasurePoint<Unit, Number>
+        where
+            Unit: AngleMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// ApproxMeasurePoint::circular_mean(impl IntoIterator<Item = ApproxMeasurePoint>) -> Option<ApproxMeasurePoint>
+            /// It returns the direction of the sum of the unit vectors of the given approximate directions,
+            /// between minus half cycle and plus half cycle,
+            /// with the variance propagated through the derivatives of the mean by each direction.
+            /// It returns `None` if there are no directions,
+            /// or if they cancel out, as their mean resultant length is less than one millionth.
+            pub fn circular_mean<I>(directions: I) -> Option<Self>
+            where
+                I: IntoIterator<Item = Self>,
+            {
+                let mut sum_sin = Number::ZERO;
+                let mut sum_cos = Number::ZERO;
+                let mut count = Number::ZERO;
+                // The sums of the variances weighted by the products of the sines and cosines.
+                let mut cos_cos = Number::ZERO;
+                let mut sin_cos = Number::ZERO;
+                let mut sin_sin = Number::ZERO;
+                for direction in directions {
+                    let (sin, cos) = Measure::<Unit, Number>::new(direction.value)
+                        .convert::<Radian>()
+                        .value
+                        .sin_cos();
+                    sum_sin += sin;
+                    sum_cos += cos;
+                    count += Number::ONE;
+                    cos_cos += direction.variance * cos * cos;
+                    sin_cos += direction.variance * sin * cos;
+                    sin_sin += direction.variance * sin * sin;
+                }
+                let squared_length = sum_sin * sum_sin + sum_cos * sum_cos;
+                if count == Number::ZERO || squared_length.sqrt() < count * Number::from_f64(1e-6) {
+                    return None;
+                }
+                let radians = measures::traits::InverseTrigonometry::atan2(sum_sin, sum_cos);
+                // The derivative of the mean by a direction is the cosine of their difference,
+                // divided by the resultant length, and so it doesn't depend on the unit.
+                let variance = (cos_cos * sum_cos * sum_cos
+                    + (sin_cos + sin_cos) * sum_sin * sum_cos
+                    + sin_sin * sum_sin * sum_sin)
+                    / (squared_length * squared_length);
+                Some(Self::with_variance(
+                    Measure::<Radian, Number>::new(radians)
+                        .convert::<Unit>()
+                        .value,
+                    variance,
+                ))
+            }
+
+            /// ApproxMeasurePoint.lerp_shortest(ApproxMeasurePoint, Number) -> ApproxMeasurePoint
+            /// It interpolates between the two approximate directions along the shortest arc,
+            /// so that `t` equal to zero gives `self`, and `t` equal to one gives `other`,
+            /// considering their variances as independent.
+            pub fn lerp_shortest(self, other: Self, t: Number) -> Self {
+                let cycle = Number::from_f64(Unit::CYCLE_FRACTION);
+                let half_cycle = cycle * Number::HALF;
+                let difference = (other.value - self.value) % cycle;
+                let difference = if difference > half_cycle {
+                    difference - cycle
+                } else if difference < -half_cycle {
+                    difference + cycle
+                } else {
+                    difference
+                };
+                let s = Number::ONE - t;
+                Self::with_variance(
+                    self.value + difference * t,
+                    self.variance * s * s + other.variance * t * t,
+                )
+            }
+        }
+
         //...
         impl<Unit, Number> PartialEq<ApproxMeasurePoint<Unit, Number>> for ApproxMeasurePoint<Unit, Number>
         where
@@ -15,7 +15,7 @@ macro_rules! inner_define_approx_measure_point_2d {
 
         impl<Unit, Number> ApproxMeasurePoint2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             pub const fn with_covariances(values: [Number; 2], covariances: [[Number; 2]; 2]) -> Self {
@@ -46,10 +46,8 @@ macro_rules! inner_define_approx_measure_point_2d {
 
             pub fn convert<DestUnit>(self) -> ApproxMeasurePoint2d<DestUnit, Number>
             where
-                DestUnit: MeasurementUnit<Property = Unit::Property>,
+                DestUnit: VectorMeasurementUnit<Property = Unit::Property>,
             {
-                debug_assert!(Unit::OFFSET == 0.);
-                debug_assert!(DestUnit::OFFSET == 0.);
                 let ratio = Number::from_f64(Unit::RATIO / DestUnit::RATIO);
                 let r2 = ratio * ratio;
                 ApproxMeasurePoint2d::<DestUnit, Number>::with_covariances(
@@ -101,7 +99,7 @@ macro_rules! inner_define_approx_measure_point_2d {
 
         impl<Unit, Number> Default for ApproxMeasurePoint2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// ApproxMeasurePoint2d::default() -> ApproxMeasurePoint2d
@@ -116,7 +114,7 @@ macro_rules! inner_define_approx_measure_point_2d {
 
         impl<Unit> From<ApproxMeasurePoint2d<Unit, f32>> for ApproxMeasurePoint2d<Unit, f64>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
         {
             fn from(m: ApproxMeasurePoint2d<Unit, f32>) -> Self {
                 Self::with_covariances(
@@ -132,7 +130,7 @@ macro_rules! inner_define_approx_measure_point_2d {
         measures::if_all_true! { { $with_serde }
             impl<Unit, Number> serde::Serialize for ApproxMeasurePoint2d<Unit, Number>
             where
-                Unit: MeasurementUnit<Property: VectorProperty>,
+                Unit: VectorMeasurementUnit,
                 Number: ArithmeticOps + serde::Serialize,
             {
                 fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -145,7 +143,7 @@ macro_rules! inner_define_approx_measure_point_2d {
 
             impl<'de, Unit, Number> serde::Deserialize<'de> for ApproxMeasurePoint2d<Unit, Number>
             where
-                Unit: MeasurementUnit<Property: VectorProperty>,
+                Unit: VectorMeasurementUnit,
                 Number: ArithmeticOps + serde::Deserialize<'de>,
             {
                 fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
@@ -161,7 +159,7 @@ macro_rules! inner_define_approx_measure_point_2d {
         // ApproxMeasurePoint2d + ApproxMeasure2d -> ApproxMeasurePoint2d
         impl<Unit, Number> Add<ApproxMeasure2d<Unit, Number>> for ApproxMeasurePoint2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = Self;
@@ -179,7 +177,7 @@ macro_rules! inner_define_approx_measure_point_2d {
         // ApproxMeasurePoint2d += ApproxMeasure2d
         impl<Unit, Number> AddAssign<ApproxMeasure2d<Unit, Number>> for ApproxMeasurePoint2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn add_assign(&mut self, other: ApproxMeasure2d<Unit, Number>) {
@@ -191,7 +189,7 @@ macro_rules! inner_define_approx_measure_point_2d {
         // ApproxMeasurePoint2d - ApproxMeasure2d -> ApproxMeasurePoint2d
         impl<Unit, Number> Sub<ApproxMeasure2d<Unit, Number>> for ApproxMeasurePoint2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = Self;
@@ -209,7 +207,7 @@ macro_rules! inner_define_approx_measure_point_2d {
         // ApproxMeasurePoint2d -= ApproxMeasure2d
         impl<Unit, Number> SubAssign<ApproxMeasure2d<Unit, Number>> for ApproxMeasurePoint2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn sub_assign(&mut self, other: ApproxMeasure2d<Unit, Number>) {
@@ -221,7 +219,7 @@ macro_rules! inner_define_approx_measure_point_2d {
         /// measure point 2d - measure point 2d -> measure 2d
         impl<Unit, Number> Sub<ApproxMeasurePoint2d<Unit, Number>> for ApproxMeasurePoint2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = ApproxMeasure2d<Unit, Number>;
@@ -243,7 +241,7 @@ macro_rules! inner_define_approx_measure_point_2d {
             weight1: Number,
         ) -> ApproxMeasurePoint2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             let weight2 = Number::ONE - weight1;
@@ -262,7 +260,7 @@ macro_rules! inner_define_approx_measure_point_2d {
             p2: ApproxMeasurePoint2d<Unit, Number>,
         ) -> ApproxMeasurePoint2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             ApproxMeasurePoint2d::<Unit, Number>::with_covariances(
@@ -280,7 +278,7 @@ macro_rules! inner_define_approx_measure_point_2d {
             weights: &[Number],
         ) -> ApproxMeasurePoint2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             ApproxMeasurePoint2d::<Unit, Number>::with_covariances(
@@ -304,7 +302,7 @@ macro_rules! inner_define_approx_measure_point_2d {
         impl<Unit, Number> PartialEq<ApproxMeasurePoint2d<Unit, Number>>
             for ApproxMeasurePoint2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn eq(&self, other: &ApproxMeasurePoint2d<Unit, Number>) -> bool {
@@ -315,7 +313,7 @@ macro_rules! inner_define_approx_measure_point_2d {
         // ApproxMeasurePoint2d.clone() -> ApproxMeasurePoint2d
         impl<Unit, Number> Clone for ApproxMeasurePoint2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn clone(&self) -> Self {
@@ -326,7 +324,7 @@ macro_rules! inner_define_approx_measure_point_2d {
         // ApproxMeasurePoint2d = ApproxMeasurePoint2d
         impl<Unit, Number> Copy for ApproxMeasurePoint2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
         }
@@ -335,7 +333,7 @@ macro_rules! inner_define_approx_measure_point_2d {
         /// ApproxMeasurePoint2d.to_string() -> String
         impl<Unit, Number> fmt::Display for ApproxMeasurePoint2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -360,7 +358,7 @@ macro_rules! inner_define_approx_measure_point_2d {
         // format!("{:?}", ApproxMeasurePoint2d)
         impl<Unit, Number> fmt::Debug for ApproxMeasurePoint2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -370,14 +368,11 @@ macro_rules! inner_define_approx_measure_point_2d {
                 formatter.write_str(", ")?;
                 fmt::Display::fmt(&self.values[1], formatter)?;
                 formatter.write_str("), covariances=\n")?;
-                write!(
+                measures::matrix_utils::format_matrix::<2, 2, Number>(
                     formatter,
-                    "{}",
-                    measures::matrix_utils::format_matrix::<2, 2, Number>(
-                        &self.covariances,
-                        Unit::SUFFIX,
-                        1
-                    )
+                    &self.covariances,
+                    Unit::SUFFIX,
+                    1,
                 )
             }
         }
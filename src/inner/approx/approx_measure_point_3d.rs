@@ -15,7 +15,7 @@ macro_rules! inner_define_approx_measure_point_3d {
 
         impl<Unit, Number> ApproxMeasurePoint3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             pub const fn with_covariances(values: [Number; 3], covariances: [[Number; 3]; 3]) -> Self {
@@ -53,10 +53,8 @@ macro_rules! inner_define_approx_measure_point_3d {
 
             pub fn convert<DestUnit>(self) -> ApproxMeasurePoint3d<DestUnit, Number>
             where
-                DestUnit: MeasurementUnit<Property = Unit::Property>,
+                DestUnit: VectorMeasurementUnit<Property = Unit::Property>,
             {
-                debug_assert!(Unit::OFFSET == 0.);
-                debug_assert!(DestUnit::OFFSET == 0.);
                 let ratio = Number::from_f64(Unit::RATIO / DestUnit::RATIO);
                 let r2 = ratio * ratio;
                 ApproxMeasurePoint3d::<DestUnit, Number>::with_covariances(
@@ -147,7 +145,7 @@ macro_rules! inner_define_approx_measure_point_3d {
 
         impl<Unit, Number> Default for ApproxMeasurePoint3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// ApproxMeasurePoint3d::default() -> ApproxMeasurePoint3d
@@ -166,7 +164,7 @@ macro_rules! inner_define_approx_measure_point_3d {
 
         impl<Unit> From<ApproxMeasurePoint3d<Unit, f32>> for ApproxMeasurePoint3d<Unit, f64>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
         {
             fn from(m: ApproxMeasurePoint3d<Unit, f32>) -> Self {
                 Self::with_covariances(
@@ -195,7 +193,7 @@ macro_rules! inner_define_approx_measure_point_3d {
         measures::if_all_true! { { $with_serde }
             impl<Unit, Number> serde::Serialize for ApproxMeasurePoint3d<Unit, Number>
             where
-                Unit: MeasurementUnit<Property: VectorProperty>,
+                Unit: VectorMeasurementUnit,
                 Number: ArithmeticOps + serde::Serialize,
             {
                 fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -208,7 +206,7 @@ macro_rules! inner_define_approx_measure_point_3d {
 
             impl<'de, Unit, Number> serde::Deserialize<'de> for ApproxMeasurePoint3d<Unit, Number>
             where
-                Unit: MeasurementUnit<Property: VectorProperty>,
+                Unit: VectorMeasurementUnit,
                 Number: ArithmeticOps + serde::Deserialize<'de>,
             {
                 fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
@@ -224,7 +222,7 @@ macro_rules! inner_define_approx_measure_point_3d {
         // ApproxMeasurePoint3d + ApproxMeasure3d -> ApproxMeasurePoint3d
         impl<Unit, Number> Add<ApproxMeasure3d<Unit, Number>> for ApproxMeasurePoint3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = Self;
@@ -243,7 +241,7 @@ macro_rules! inner_define_approx_measure_point_3d {
         // ApproxMeasurePoint3d += ApproxMeasure3d
         impl<Unit, Number> AddAssign<ApproxMeasure3d<Unit, Number>> for ApproxMeasurePoint3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn add_assign(&mut self, other: ApproxMeasure3d<Unit, Number>) {
@@ -256,7 +254,7 @@ macro_rules! inner_define_approx_measure_point_3d {
         // ApproxMeasurePoint3d - ApproxMeasure3d -> ApproxMeasurePoint3d
         impl<Unit, Number> Sub<ApproxMeasure3d<Unit, Number>> for ApproxMeasurePoint3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = Self;
@@ -275,7 +273,7 @@ macro_rules! inner_define_approx_measure_point_3d {
         // ApproxMeasurePoint3d -= ApproxMeasure3d
         impl<Unit, Number> SubAssign<ApproxMeasure3d<Unit, Number>> for ApproxMeasurePoint3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn sub_assign(&mut self, other: ApproxMeasure3d<Unit, Number>) {
@@ -288,7 +286,7 @@ macro_rules! inner_define_approx_measure_point_3d {
         /// measure point 3d - measure point 3d -> measure 3d
         impl<Unit, Number> Sub<ApproxMeasurePoint3d<Unit, Number>> for ApproxMeasurePoint3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = ApproxMeasure3d<Unit, Number>;
@@ -311,7 +309,7 @@ macro_rules! inner_define_approx_measure_point_3d {
             weight1: Number,
         ) -> ApproxMeasurePoint3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             let weight2 = Number::ONE - weight1;
@@ -331,7 +329,7 @@ macro_rules! inner_define_approx_measure_point_3d {
             p2: ApproxMeasurePoint3d<Unit, Number>,
         ) -> ApproxMeasurePoint3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             ApproxMeasurePoint3d::<Unit, Number>::with_covariances(
@@ -350,7 +348,7 @@ macro_rules! inner_define_approx_measure_point_3d {
             weights: &[Number],
         ) -> ApproxMeasurePoint3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             ApproxMeasurePoint3d::<Unit, Number>::with_covariances(
@@ -379,7 +377,7 @@ macro_rules! inner_define_approx_measure_point_3d {
         impl<Unit, Number> PartialEq<ApproxMeasurePoint3d<Unit, Number>>
             for ApproxMeasurePoint3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn eq(&self, other: &ApproxMeasurePoint3d<Unit, Number>) -> bool {
@@ -390,7 +388,7 @@ macro_rules! inner_define_approx_measure_point_3d {
         // ApproxMeasurePoint3d.clone() -> ApproxMeasurePoint3d
         impl<Unit, Number> Clone for ApproxMeasurePoint3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn clone(&self) -> Self {
@@ -401,7 +399,7 @@ macro_rules! inner_define_approx_measure_point_3d {
         // ApproxMeasurePoint3d = ApproxMeasurePoint3d
         impl<Unit, Number> Copy for ApproxMeasurePoint3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
         }
@@ -410,7 +408,7 @@ macro_rules! inner_define_approx_measure_point_3d {
         /// ApproxMeasurePoint3d.to_string() -> String
         impl<Unit, Number> fmt::Display for ApproxMeasurePoint3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -447,7 +445,7 @@ macro_rules! inner_define_approx_measure_point_3d {
         // format!("{:?}", ApproxMeasurePoint3d)
         impl<Unit, Number> fmt::Debug for ApproxMeasurePoint3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -459,14 +457,11 @@ macro_rules! inner_define_approx_measure_point_3d {
                 formatter.write_str(", ")?;
                 fmt::Display::fmt(&self.values[2], formatter)?;
                 formatter.write_str("), covariances=\n")?;
-                write!(
+                measures::matrix_utils::format_matrix::<3, 3, Number>(
                     formatter,
-                    "{}",
-                    measures::matrix_utils::format_matrix::<3, 3, Number>(
-                        &self.covariances,
-                        Unit::SUFFIX,
-                        1
-                    )
+                    &self.covariances,
+                    Unit::SUFFIX,
+                    1,
                 )
             }
         }
@@ -0,0 +1,53 @@
+#[macro_export] // Don't add nor remove the first three lines and the last two lines.
+macro_rules! inner_define_differentiation {
+    {} => {
+        /// dual_derivative(function, Measure) -> Derivative
+        /// It returns the exact derivative of `function` at `arg`,
+        /// computed by evaluating `function` on dual numbers.
+        /// The type of the result is the one obtained by dividing a value of `function`
+        /// by a `Measure<ArgUnit>`, and so a relationship between such units must have been declared.
+        pub fn dual_derivative<ArgUnit, ValueUnit, Derivative, Real, F>(
+            function: F,
+            arg: Measure<ArgUnit, Real>,
+        ) -> Derivative
+        where
+            ArgUnit: MeasurementUnit,
+            ValueUnit: MeasurementUnit,
+            Real: ArithmeticOps,
+            Measure<ValueUnit, Real>: Div<Measure<ArgUnit, Real>, Output = Derivative>,
+            F: FnOnce(
+                Measure<ArgUnit, measures::dual::Dual<Real>>,
+            ) -> Measure<ValueUnit, measures::dual::Dual<Real>>,
+        {
+            let value = function(Measure::new(measures::dual::Dual::variable(arg.value, 0)));
+            Measure::<ValueUnit, Real>::new(value.value.derivative())
+                / Measure::<ArgUnit, Real>::new(Real::ONE)
+        }
+
+        /// partial_derivative(Measure, Measure) -> Derivative
+        /// It returns the exact partial derivative of `value` with respect to `variable`,
+        /// where `variable` is an independent variable created by `DualN::variable`,
+        /// and `value` has been computed from it.
+        /// The type of the result is the one obtained by dividing `value`
+        /// by a `Measure<ArgUnit>`, and so a relationship between such units must have been declared.
+        pub fn partial_derivative<ArgUnit, ValueUnit, Derivative, Real, const N: usize>(
+            value: Measure<ValueUnit, measures::dual::DualN<N, Real>>,
+            variable: Measure<ArgUnit, measures::dual::DualN<N, Real>>,
+        ) -> Derivative
+        where
+            ArgUnit: MeasurementUnit,
+            ValueUnit: MeasurementUnit,
+            Real: ArithmeticOps,
+            Measure<ValueUnit, Real>: Div<Measure<ArgUnit, Real>, Output = Derivative>,
+        {
+            let index = variable
+                .value
+                .gradient
+                .iter()
+                .position(|d| *d != Real::ZERO)
+                .expect("the variable of a partial derivative must be created by `DualN::variable`");
+            Measure::<ValueUnit, Real>::new(value.value.gradient[index])
+                / Measure::<ArgUnit, Real>::new(variable.value.gradient[index])
+        }
+    };
+}
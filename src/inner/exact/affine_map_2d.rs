@@ -1,10 +1,10 @@
 #[macro_export] // Don't add nor remove the first three lines and the last two lines.
 macro_rules! inner_define_affine_map_2d {
-    {} => {
+    { $with_serde:ident } => {
         /// Affine transformation of `MeasurePoint2d` objects.
         pub struct AffineMap2d<Unit, Number = f64>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             pub c: [[Number; 3]; 2],
@@ -13,7 +13,7 @@ macro_rules! inner_define_affine_map_2d {
 
         impl<Unit, Number> AffineMap2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// Create an AffineMap2d from its 6 coefficients.
@@ -27,7 +27,7 @@ macro_rules! inner_define_affine_map_2d {
             // Unit conversion.
             pub fn convert<DestUnit>(&self) -> AffineMap2d<DestUnit, Number>
             where
-                DestUnit: MeasurementUnit<Property = Unit::Property>,
+                DestUnit: VectorMeasurementUnit<Property = Unit::Property>,
             {
                 let factor = Number::from_f64(Unit::RATIO / DestUnit::RATIO);
                 AffineMap2d::<DestUnit, Number>::new([
@@ -210,7 +210,7 @@ macro_rules! inner_define_affine_map_2d {
 
         impl<Unit, Number> Default for AffineMap2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// AffineMap2d::default() -> AffineMap2d
@@ -226,7 +226,7 @@ macro_rules! inner_define_affine_map_2d {
         // AffineMap2d == AffineMap2d -> bool
         impl<Unit, Number> PartialEq<AffineMap2d<Unit, Number>> for AffineMap2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn eq(&self, other: &AffineMap2d<Unit, Number>) -> bool {
@@ -237,7 +237,7 @@ macro_rules! inner_define_affine_map_2d {
         // AffineMap2d.clone() -> AffineMap2d
         impl<Unit, Number> Clone for AffineMap2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn clone(&self) -> Self {
@@ -247,7 +247,7 @@ macro_rules! inner_define_affine_map_2d {
 
         impl<Unit> From<AffineMap2d<Unit, f32>> for AffineMap2d<Unit, f64>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
         {
             fn from(m: AffineMap2d<Unit, f32>) -> Self {
                 Self::new([
@@ -261,30 +261,50 @@ macro_rules! inner_define_affine_map_2d {
         /// AffineMap2d.to_string() -> String
         impl<Unit, Number> fmt::Display for AffineMap2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                write!(
-                    formatter,
-                    "{}",
-                    measures::matrix_utils::format_matrix::<2, 3, Number>(&self.c, Unit::SUFFIX, 1)
-                )
+                measures::matrix_utils::format_matrix::<2, 3, Number>(formatter, &self.c, Unit::SUFFIX, 1)
             }
         }
 
         // format!("{:?}", AffineMap2d)
         impl<Unit, Number> fmt::Debug for AffineMap2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                write!(
-                    formatter,
-                    "{}",
-                    measures::matrix_utils::format_matrix::<2, 3, Number>(&self.c, Unit::SUFFIX, 1)
-                )
+                measures::matrix_utils::format_matrix::<2, 3, Number>(formatter, &self.c, Unit::SUFFIX, 1)
+            }
+        }
+
+        measures::if_all_true! { { $with_serde }
+            impl<Unit, Number> serde::Serialize for AffineMap2d<Unit, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps + serde::Serialize,
+            {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: serde::Serializer
+                {
+                    self.c.serialize(serializer)
+                }
+            }
+
+            impl<'de, Unit, Number> serde::Deserialize<'de> for AffineMap2d<Unit, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps + serde::Deserialize<'de>,
+            {
+                fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+                where
+                    De: serde::Deserializer<'de>,
+                {
+                    Ok(Self::new(serde::Deserialize::deserialize(deserializer)?))
+                }
             }
         }
     };
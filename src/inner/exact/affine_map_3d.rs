@@ -1,10 +1,10 @@
 #[macro_export] // Don't add nor remove the first three lines and the last two lines.
 macro_rules! inner_define_affine_map_3d {
-    {} => {
+    { $with_serde:ident } => {
         /// Affine transformation of `MeasurePoint3d` objects.
         pub struct AffineMap3d<Unit, Number = f64>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             pub c: [[Number; 4]; 3],
@@ -13,7 +13,7 @@ macro_rules! inner_define_affine_map_3d {
 
         impl<Unit, Number> AffineMap3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// Create an AffineMap3d from its 12 coefficients.
@@ -27,7 +27,7 @@ macro_rules! inner_define_affine_map_3d {
             // Unit conversion.
             pub fn convert<DestUnit>(&self) -> AffineMap3d<DestUnit, Number>
             where
-                DestUnit: MeasurementUnit<Property = Unit::Property>,
+                DestUnit: VectorMeasurementUnit<Property = Unit::Property>,
             {
                 let factor = Number::from_f64(Unit::RATIO / DestUnit::RATIO);
                 AffineMap3d::<DestUnit, Number>::new([
@@ -74,7 +74,7 @@ macro_rules! inner_define_affine_map_3d {
             ) -> Self
             where
                 AngleUnit: AngleMeasurementUnit,
-                AxisUnit: MeasurementUnit<Property: VectorProperty>,
+                AxisUnit: VectorMeasurementUnit,
             {
                 let fpx = fixed_point.values[0];
                 let fpy = fixed_point.values[1];
@@ -111,7 +111,7 @@ macro_rules! inner_define_affine_map_3d {
                 unit_vector: Measure3d<AxisUnit, Number>,
             ) -> Self
             where
-                AxisUnit: MeasurementUnit<Property: VectorProperty>,
+                AxisUnit: VectorMeasurementUnit,
             {
                 let fpx = fixed_point.values[0];
                 let fpy = fixed_point.values[1];
@@ -149,7 +149,7 @@ macro_rules! inner_define_affine_map_3d {
                 unit_vector: Measure3d<AxisUnit, Number>,
             ) -> Self
             where
-                AxisUnit: MeasurementUnit<Property: VectorProperty>,
+                AxisUnit: VectorMeasurementUnit,
             {
                 let fpx = fixed_point.values[0];
                 let fpy = fixed_point.values[1];
@@ -183,7 +183,7 @@ macro_rules! inner_define_affine_map_3d {
                 unit_vector: Measure3d<AxisUnit, Number>,
             ) -> Self
             where
-                AxisUnit: MeasurementUnit<Property: VectorProperty>,
+                AxisUnit: VectorMeasurementUnit,
             {
                 let two = Number::ONE + Number::ONE;
                 let fpx = fixed_point.values[0];
@@ -216,7 +216,7 @@ macro_rules! inner_define_affine_map_3d {
                 unit_vector: Measure3d<AxisUnit, Number>,
             ) -> Self
             where
-                AxisUnit: MeasurementUnit<Property: VectorProperty>,
+                AxisUnit: VectorMeasurementUnit,
             {
                 let minus_two = -(Number::ONE + Number::ONE);
                 let fpx = fixed_point.values[0];
@@ -377,7 +377,7 @@ macro_rules! inner_define_affine_map_3d {
 
         impl<Unit, Number> Default for AffineMap3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// AffineMap3d::default() -> AffineMap3d
@@ -394,7 +394,7 @@ macro_rules! inner_define_affine_map_3d {
         // AffineMap3d == AffineMap3d -> bool
         impl<Unit, Number> PartialEq<AffineMap3d<Unit, Number>> for AffineMap3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn eq(&self, other: &AffineMap3d<Unit, Number>) -> bool {
@@ -405,7 +405,7 @@ macro_rules! inner_define_affine_map_3d {
         // AffineMap3d.clone() -> AffineMap3d
         impl<Unit, Number> Clone for AffineMap3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn clone(&self) -> Self {
@@ -415,7 +415,7 @@ macro_rules! inner_define_affine_map_3d {
 
         impl<Unit> From<AffineMap3d<Unit, f32>> for AffineMap3d<Unit, f64>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
         {
             fn from(m: AffineMap3d<Unit, f32>) -> Self {
                 Self::new([
@@ -445,30 +445,50 @@ macro_rules! inner_define_affine_map_3d {
         /// AffineMap3d.to_string() -> String
         impl<Unit, Number> fmt::Display for AffineMap3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                write!(
-                    formatter,
-                    "{}",
-                    measures::matrix_utils::format_matrix::<3, 4, Number>(&self.c, Unit::SUFFIX, 1)
-                )
+                measures::matrix_utils::format_matrix::<3, 4, Number>(formatter, &self.c, Unit::SUFFIX, 1)
             }
         }
 
         // format!("{:?}", AffineMap3d)
         impl<Unit, Number> fmt::Debug for AffineMap3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                write!(
-                    formatter,
-                    "{}",
-                    measures::matrix_utils::format_matrix::<3, 4, Number>(&self.c, Unit::SUFFIX, 1)
-                )
+                measures::matrix_utils::format_matrix::<3, 4, Number>(formatter, &self.c, Unit::SUFFIX, 1)
+            }
+        }
+
+        measures::if_all_true! { { $with_serde }
+            impl<Unit, Number> serde::Serialize for AffineMap3d<Unit, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps + serde::Serialize,
+            {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: serde::Serializer
+                {
+                    self.c.serialize(serializer)
+                }
+            }
+
+            impl<'de, Unit, Number> serde::Deserialize<'de> for AffineMap3d<Unit, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps + serde::Deserialize<'de>,
+            {
+                fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+                where
+                    De: serde::Deserializer<'de>,
+                {
+                    Ok(Self::new(serde::Deserialize::deserialize(deserializer)?))
+                }
             }
         }
     };
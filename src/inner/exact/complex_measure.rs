@@ -0,0 +1,490 @@
+#[macro_export] // Don't add nor remove the first three lines and the last two lines.
+macro_rules! inner_define_complex_measure {
+    { $with_directions:ident $with_serde:ident } => {
+        /// 1D relative measure having a complex value,
+        /// with generic unit of measurement, generic value type,
+        /// and with a dynamic real part and a dynamic imaginary part.
+        /// It is useful to represent phasors, like alternating voltages and currents,
+        /// and impedances.
+        /// Its unit must be linear, as a complex value cannot be converted by non-linear functions.
+        pub struct ComplexMeasure<Unit, Number = f64>
+        where
+            Unit: LinearMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            pub re: Number,
+            pub im: Number,
+            phantom: PhantomData<Unit>,
+        }
+
+        impl<Unit, Number> ComplexMeasure<Unit, Number>
+        where
+            Unit: LinearMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// ComplexMeasure::new(Number, Number) -> ComplexMeasure
+            pub const fn new(re: Number, im: Number) -> Self {
+                Self {
+                    re,
+                    im,
+                    phantom: PhantomData,
+                }
+            }
+
+            /// ComplexMeasure::from_parts(Measure, Measure) -> ComplexMeasure
+            pub const fn from_parts(re: Measure<Unit, Number>, im: Measure<Unit, Number>) -> Self {
+                Self::new(re.value, im.value)
+            }
+
+            /// ComplexMeasure.real() -> Measure
+            pub const fn real(self) -> Measure<Unit, Number> {
+                Measure::<Unit, Number>::new(self.re)
+            }
+
+            /// ComplexMeasure.imaginary() -> Measure
+            pub const fn imaginary(self) -> Measure<Unit, Number> {
+                Measure::<Unit, Number>::new(self.im)
+            }
+
+            /// ComplexMeasure.convert() -> ComplexMeasure
+            pub fn convert<DestUnit>(self) -> ComplexMeasure<DestUnit, Number>
+            where
+                DestUnit: LinearMeasurementUnit<Property = Unit::Property>,
+            {
+                let factor = Number::from_f64(Unit::RATIO / DestUnit::RATIO);
+                ComplexMeasure::<DestUnit, Number>::new(self.re * factor, self.im * factor)
+            }
+
+            /// ComplexMeasure.lossless_into() -> ComplexMeasure
+            pub fn lossless_into<DestNumber>(self) -> ComplexMeasure<Unit, DestNumber>
+            where
+                DestNumber: ArithmeticOps + From<Number>,
+            {
+                ComplexMeasure::<Unit, DestNumber>::new(
+                    DestNumber::from(self.re),
+                    DestNumber::from(self.im),
+                )
+            }
+
+            /// ComplexMeasure.lossy_into() -> ComplexMeasure
+            pub fn lossy_into<DestNumber>(self) -> ComplexMeasure<Unit, DestNumber>
+            where
+                DestNumber: ArithmeticOps + LossyFrom<Number>,
+            {
+                ComplexMeasure::<Unit, DestNumber>::new(
+                    DestNumber::lossy_from(self.re),
+                    DestNumber::lossy_from(self.im),
+                )
+            }
+
+            /// ComplexMeasure.magnitude() -> Measure
+            pub fn magnitude(self) -> Measure<Unit, Number> {
+                Measure::<Unit, Number>::new(self.squared_magnitude().sqrt())
+            }
+
+            /// ComplexMeasure.squared_magnitude() -> Number
+            pub fn squared_magnitude(self) -> Number {
+                self.re * self.re + self.im * self.im
+            }
+
+            /// ComplexMeasure.conjugate() -> ComplexMeasure
+            pub fn conjugate(self) -> Self {
+                Self::new(self.re, -self.im)
+            }
+
+            /// ComplexMeasure.polar_formatter() -> PolarComplexMeasureFormatter
+            /// It allows to print the measure in polar form, with the phase in the specified angle unit.
+            pub const fn polar_formatter<AngleUnit>(
+                self,
+            ) -> PolarComplexMeasureFormatter<Unit, AngleUnit, Number>
+            where
+                AngleUnit: AngleMeasurementUnit,
+            {
+                PolarComplexMeasureFormatter(self, PhantomData)
+            }
+
+            measures::if_all_true! { { $with_directions }
+                /// ComplexMeasure::from_polar(Measure, SignedDirection) -> ComplexMeasure
+                pub fn from_polar<AngleUnit>(
+                    magnitude: Measure<Unit, Number>,
+                    phase: SignedDirection<AngleUnit, Number>,
+                ) -> Self
+                where
+                    AngleUnit: AngleMeasurementUnit,
+                {
+                    let (sin_a, cos_a) = phase.convert::<Radian>().value.sin_cos();
+                    Self::new(magnitude.value * cos_a, magnitude.value * sin_a)
+                }
+
+                /// ComplexMeasure.phase() -> SignedDirection<Radian>
+                pub fn phase(self) -> SignedDirection<Radian, Number> {
+                    SignedDirection::<Radian, Number>::new(self.im.atan2(self.re))
+                }
+            }
+        }
+
+        impl<Unit, Number> Default for ComplexMeasure<Unit, Number>
+        where
+            Unit: LinearMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// ComplexMeasure::default() -> ComplexMeasure
+            /// It returns the zero measure.
+            fn default() -> Self {
+                Self::new(Number::ZERO, Number::ZERO)
+            }
+        }
+
+        impl<Unit, Number> From<Measure<Unit, Number>> for ComplexMeasure<Unit, Number>
+        where
+            Unit: LinearMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// ComplexMeasure::from(Measure) -> ComplexMeasure
+            fn from(measure: Measure<Unit, Number>) -> Self {
+                Self::new(measure.value, Number::ZERO)
+            }
+        }
+
+        impl<Unit> From<ComplexMeasure<Unit, f32>> for ComplexMeasure<Unit, f64>
+        where
+            Unit: LinearMeasurementUnit,
+        {
+            /// ComplexMeasure<f64>::from(ComplexMeasure<f32>) -> ComplexMeasure<f64>
+            fn from(measure: ComplexMeasure<Unit, f32>) -> Self {
+                Self::new(measure.re as f64, measure.im as f64)
+            }
+        }
+
+        measures::if_all_true! { { $with_serde }
+            impl<Unit, Number> serde::Serialize for ComplexMeasure<Unit, Number>
+            where
+                Unit: LinearMeasurementUnit,
+                Number: ArithmeticOps + serde::Serialize,
+            {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: serde::Serializer
+                {
+                    [self.re, self.im].serialize(serializer)
+                }
+            }
+
+            impl<'de, Unit, Number> serde::Deserialize<'de> for ComplexMeasure<Unit, Number>
+            where
+                Unit: LinearMeasurementUnit,
+                Number: ArithmeticOps + serde::Deserialize<'de>,
+            {
+                fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+                where
+                    De: serde::Deserializer<'de>,
+                {
+                    let [re, im]: [Number; 2] = serde::Deserialize::deserialize(deserializer)?;
+                    Ok(Self::new(re, im))
+                }
+            }
+        }
+
+        impl<Unit, Number> Neg for ComplexMeasure<Unit, Number>
+        where
+            Unit: LinearMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            type Output = Self;
+
+            /// -ComplexMeasure -> ComplexMeasure
+            fn neg(self) -> Self::Output {
+                Self::new(-self.re, -self.im)
+            }
+        }
+
+        impl<Unit, Number> Add<ComplexMeasure<Unit, Number>> for ComplexMeasure<Unit, Number>
+        where
+            Unit: LinearMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            type Output = Self;
+
+            /// ComplexMeasure + ComplexMeasure -> ComplexMeasure
+            fn add(self, other: ComplexMeasure<Unit, Number>) -> Self::Output {
+                Self::new(self.re + other.re, self.im + other.im)
+            }
+        }
+
+        impl<Unit, Number> AddAssign<ComplexMeasure<Unit, Number>> for ComplexMeasure<Unit, Number>
+        where
+            Unit: LinearMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// ComplexMeasure += ComplexMeasure
+            fn add_assign(&mut self, other: ComplexMeasure<Unit, Number>) {
+                self.re += other.re;
+                self.im += other.im;
+            }
+        }
+
+        impl<Unit, Number> Sub<ComplexMeasure<Unit, Number>> for ComplexMeasure<Unit, Number>
+        where
+            Unit: LinearMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            type Output = Self;
+
+            /// ComplexMeasure - ComplexMeasure -> ComplexMeasure
+            fn sub(self, other: ComplexMeasure<Unit, Number>) -> Self::Output {
+                Self::new(self.re - other.re, self.im - other.im)
+            }
+        }
+
+        impl<Unit, Number> SubAssign<ComplexMeasure<Unit, Number>> for ComplexMeasure<Unit, Number>
+        where
+            Unit: LinearMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// ComplexMeasure -= ComplexMeasure
+            fn sub_assign(&mut self, other: ComplexMeasure<Unit, Number>) {
+                self.re -= other.re;
+                self.im -= other.im;
+            }
+        }
+
+        impl<Unit, Number> Mul<Number> for ComplexMeasure<Unit, Number>
+        where
+            Unit: LinearMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            type Output = Self;
+
+            /// ComplexMeasure * Number -> ComplexMeasure
+            fn mul(self, n: Number) -> Self::Output {
+                Self::new(self.re * n, self.im * n)
+            }
+        }
+
+        impl<Unit, Number> Mul<ComplexMeasure<One, Number>> for ComplexMeasure<Unit, Number>
+        where
+            Unit: LinearMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            type Output = Self;
+
+            /// ComplexMeasure * ComplexMeasure<One> -> ComplexMeasure
+            fn mul(self, other: ComplexMeasure<One, Number>) -> Self::Output {
+                Self::new(
+                    self.re * other.re - self.im * other.im,
+                    self.re * other.im + self.im * other.re,
+                )
+            }
+        }
+
+        impl<Unit> Mul<ComplexMeasure<Unit, f64>> for f64
+        where
+            Unit: LinearMeasurementUnit,
+        {
+            type Output = ComplexMeasure<Unit, f64>;
+
+            /// f64 * ComplexMeasure -> ComplexMeasure
+            fn mul(self, other: ComplexMeasure<Unit, f64>) -> Self::Output {
+                Self::Output::new(self * other.re, self * other.im)
+            }
+        }
+
+        impl<Unit> Mul<ComplexMeasure<Unit, f32>> for f32
+        where
+            Unit: LinearMeasurementUnit,
+        {
+            type Output = ComplexMeasure<Unit, f32>;
+
+            /// f32 * ComplexMeasure -> ComplexMeasure
+            fn mul(self, other: ComplexMeasure<Unit, f32>) -> Self::Output {
+                Self::Output::new(self * other.re, self * other.im)
+            }
+        }
+
+        impl<Unit, Number> MulAssign<Number> for ComplexMeasure<Unit, Number>
+        where
+            Unit: LinearMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// ComplexMeasure *= Number
+            fn mul_assign(&mut self, n: Number) {
+                self.re *= n;
+                self.im *= n;
+            }
+        }
+
+        impl<Unit, Number> Div<Number> for ComplexMeasure<Unit, Number>
+        where
+            Unit: LinearMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            type Output = Self;
+
+            /// ComplexMeasure / Number -> ComplexMeasure
+            fn div(self, n: Number) -> Self::Output {
+                Self::new(self.re / n, self.im / n)
+            }
+        }
+
+        impl<Unit, Number> Div<ComplexMeasure<Unit, Number>> for ComplexMeasure<Unit, Number>
+        where
+            Unit: LinearMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            type Output = ComplexMeasure<One, Number>;
+
+            /// ComplexMeasure / ComplexMeasure -> ComplexMeasure<One>
+            fn div(self, other: ComplexMeasure<Unit, Number>) -> Self::Output {
+                let squared_magnitude = other.squared_magnitude();
+                Self::Output::new(
+                    (self.re * other.re + self.im * other.im) / squared_magnitude,
+                    (self.im * other.re - self.re * other.im) / squared_magnitude,
+                )
+            }
+        }
+
+        impl<Unit, Number> DivAssign<Number> for ComplexMeasure<Unit, Number>
+        where
+            Unit: LinearMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// ComplexMeasure /= Number
+            fn div_assign(&mut self, n: Number) {
+                self.re /= n;
+                self.im /= n;
+            }
+        }
+
+        impl<Unit, Number> PartialEq<ComplexMeasure<Unit, Number>> for ComplexMeasure<Unit, Number>
+        where
+            Unit: LinearMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// ComplexMeasure == ComplexMeasure -> bool
+            fn eq(&self, other: &ComplexMeasure<Unit, Number>) -> bool {
+                self.re == other.re && self.im == other.im
+            }
+        }
+
+        impl<Unit, Number> Clone for ComplexMeasure<Unit, Number>
+        where
+            Unit: LinearMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// ComplexMeasure.clone() -> ComplexMeasure
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        /// ComplexMeasure = ComplexMeasure
+        impl<Unit, Number> Copy for ComplexMeasure<Unit, Number>
+        where
+            Unit: LinearMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+        }
+
+        impl<Unit, Number> fmt::Display for ComplexMeasure<Unit, Number>
+        where
+            Unit: LinearMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// format!("{}", ComplexMeasure) -> String
+            /// ComplexMeasure.to_string() -> String
+            /// The measure is printed in rectangular form, like "(3 + 4i) V".
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("(")?;
+                fmt::Display::fmt(&self.re, formatter)?;
+                formatter.write_str(if self.im < Number::ZERO { " - " } else { " + " })?;
+                fmt::Display::fmt(&self.im.abs(), formatter)?;
+                formatter.write_str("i)")?;
+                formatter.write_str(Unit::SUFFIX)
+            }
+        }
+
+        impl<Unit, Number> fmt::Debug for ComplexMeasure<Unit, Number>
+        where
+            Unit: LinearMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// format!("{:?}", ComplexMeasure)
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(self, formatter)
+            }
+        }
+
+        impl<Unit, Number> fmt::LowerExp for ComplexMeasure<Unit, Number>
+        where
+            Unit: LinearMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// format!("{:e}", ComplexMeasure) -> String
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("(")?;
+                fmt::LowerExp::fmt(&self.re, formatter)?;
+                formatter.write_str(if self.im < Number::ZERO { " - " } else { " + " })?;
+                fmt::LowerExp::fmt(&self.im.abs(), formatter)?;
+                formatter.write_str("i)")?;
+                formatter.write_str(Unit::SUFFIX)
+            }
+        }
+
+        impl<Unit, Number> fmt::UpperExp for ComplexMeasure<Unit, Number>
+        where
+            Unit: LinearMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// format!("{:E}", ComplexMeasure) -> String
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("(")?;
+                fmt::UpperExp::fmt(&self.re, formatter)?;
+                formatter.write_str(if self.im < Number::ZERO { " - " } else { " + " })?;
+                fmt::UpperExp::fmt(&self.im.abs(), formatter)?;
+                formatter.write_str("i)")?;
+                formatter.write_str(Unit::SUFFIX)
+            }
+        }
+
+        /// Wrapper of a `ComplexMeasure<Unit, Number>`, printing it in polar form,
+        /// with the phase in the unit `AngleUnit`, like "5 V ∠ 36.87 deg".
+        pub struct PolarComplexMeasureFormatter<Unit, AngleUnit, Number>(
+            ComplexMeasure<Unit, Number>,
+            PhantomData<AngleUnit>,
+        )
+        where
+            Unit: LinearMeasurementUnit,
+            AngleUnit: AngleMeasurementUnit,
+            Number: ArithmeticOps;
+
+        impl<Unit, AngleUnit, Number> fmt::Display for PolarComplexMeasureFormatter<Unit, AngleUnit, Number>
+        where
+            Unit: LinearMeasurementUnit,
+            AngleUnit: AngleMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// format!("{}", ComplexMeasure.polar_formatter()) -> String
+            /// ComplexMeasure.polar_formatter().to_string() -> String
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.0.magnitude(), formatter)?;
+                formatter.write_str(" \u{2220} ")?; // ∠
+                fmt::Display::fmt(
+                    &Measure::<Radian, Number>::new(self.0.im.atan2(self.0.re)).convert::<AngleUnit>(),
+                    formatter,
+                )
+            }
+        }
+
+        impl<Unit, AngleUnit, Number> fmt::Debug for PolarComplexMeasureFormatter<Unit, AngleUnit, Number>
+        where
+            Unit: LinearMeasurementUnit,
+            AngleUnit: AngleMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// format!("{:?}", ComplexMeasure.polar_formatter())
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(self, formatter)
+            }
+        }
+    };
+}
@@ -1,6 +1,6 @@
 #[macro_export] // Don't add nor remove the first three lines and the last two lines.
 macro_rules! inner_define_linear_map_2d {
-    {} => {
+    { $with_serde:ident } => {
         /// Linear transformation of `Measure2d` objects.
         pub struct LinearMap2d<Number = f64>
         where
@@ -54,7 +54,7 @@ macro_rules! inner_define_linear_map_2d {
             // Precondition: unit_v.squared_norm().value == 1
             pub fn projection_by_unit_vector<Unit>(v: Measure2d<Unit, Number>) -> Self
             where
-                Unit: MeasurementUnit<Property: VectorProperty>,
+                Unit: VectorMeasurementUnit,
             {
                 Self::projection_by_cos_sin(v.values[0], v.values[1])
             }
@@ -73,7 +73,7 @@ macro_rules! inner_define_linear_map_2d {
             // Precondition: v.squared_norm() == 1
             pub fn reflection_by_unit_vector<Unit>(v: Measure2d<Unit, Number>) -> Self
             where
-                Unit: MeasurementUnit<Property: VectorProperty>,
+                Unit: VectorMeasurementUnit,
             {
                 Self::reflection_by_cos_sin(v.values[0], v.values[1])
             }
@@ -125,7 +125,7 @@ macro_rules! inner_define_linear_map_2d {
 
             pub fn apply_to<Unit>(&self, m: Measure2d<Unit, Number>) -> Measure2d<Unit, Number>
             where
-                Unit: MeasurementUnit<Property: VectorProperty>,
+                Unit: VectorMeasurementUnit,
             {
                 Measure2d::<Unit, Number>::new([
                     self.c[0][0] * m.values[0] + self.c[0][1] * m.values[1],
@@ -215,22 +215,40 @@ macro_rules! inner_define_linear_map_2d {
         /// LinearMap2d.to_string() -> String
         impl<Number: ArithmeticOps> fmt::Display for LinearMap2d<Number> {
             fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                write!(
-                    formatter,
-                    "{}",
-                    measures::matrix_utils::format_matrix::<2, 2, Number>(&self.c, "", 1)
-                )
+                measures::matrix_utils::format_matrix::<2, 2, Number>(formatter, &self.c, "", 1)
             }
         }
 
         // format!("{:?}", LinearMap2d)
         impl<Number: ArithmeticOps> fmt::Debug for LinearMap2d<Number> {
             fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                write!(
-                    formatter,
-                    "{}",
-                    measures::matrix_utils::format_matrix::<2, 2, Number>(&self.c, "", 1)
-                )
+                measures::matrix_utils::format_matrix::<2, 2, Number>(formatter, &self.c, "", 1)
+            }
+        }
+
+        measures::if_all_true! { { $with_serde }
+            impl<Number> serde::Serialize for LinearMap2d<Number>
+            where
+                Number: ArithmeticOps + serde::Serialize,
+            {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: serde::Serializer
+                {
+                    self.c.serialize(serializer)
+                }
+            }
+
+            impl<'de, Number> serde::Deserialize<'de> for LinearMap2d<Number>
+            where
+                Number: ArithmeticOps + serde::Deserialize<'de>,
+            {
+                fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+                where
+                    De: serde::Deserializer<'de>,
+                {
+                    Ok(Self::new(serde::Deserialize::deserialize(deserializer)?))
+                }
             }
         }
     };
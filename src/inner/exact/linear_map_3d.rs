@@ -1,6 +1,6 @@
 #[macro_export] // Don't add nor remove the first three lines and the last two lines.
 macro_rules! inner_define_linear_map_3d {
-    {} => {
+    { $with_serde:ident } => {
         /// Linear transformation of `Measure3d` objects.
         pub struct LinearMap3d<Number = f64>
         where
@@ -30,7 +30,7 @@ macro_rules! inner_define_linear_map_3d {
             ) -> Self
             where
                 AngleUnit: AngleMeasurementUnit,
-                AxisUnit: MeasurementUnit<Property: VectorProperty>,
+                AxisUnit: VectorMeasurementUnit,
             {
                 Self::rotation_by_radians_around_unit_vector(
                     angle.convert::<Radian>().value,
@@ -46,7 +46,7 @@ macro_rules! inner_define_linear_map_3d {
             // Precondition: unit_vector.squared_norm().value == 1
             pub fn projection_onto_line<Unit>(unit_vector: Measure3d<Unit, Number>) -> Self
             where
-                Unit: MeasurementUnit<Property: VectorProperty>,
+                Unit: VectorMeasurementUnit,
             {
                 Self {
                     c: [
@@ -73,7 +73,7 @@ macro_rules! inner_define_linear_map_3d {
             // Precondition: unit_vector.squared_norm().value == 1
             pub fn projection_onto_plane<Unit>(unit_vector: Measure3d<Unit, Number>) -> Self
             where
-                Unit: MeasurementUnit<Property: VectorProperty>,
+                Unit: VectorMeasurementUnit,
             {
                 Self {
                     c: [
@@ -102,7 +102,7 @@ macro_rules! inner_define_linear_map_3d {
             // Precondition: unit_vector.squared_norm().value == 1
             pub fn reflection_over_line<AxisUnit>(unit_vector: Measure3d<AxisUnit, Number>) -> Self
             where
-                AxisUnit: MeasurementUnit<Property: VectorProperty>,
+                AxisUnit: VectorMeasurementUnit,
             {
                 let two = Number::ONE + Number::ONE;
                 Self {
@@ -130,7 +130,7 @@ macro_rules! inner_define_linear_map_3d {
             // Precondition: unit_vector.squared_norm().value == 1
             pub fn reflection_over_plane<Unit>(unit_vector: Measure3d<Unit, Number>) -> Self
             where
-                Unit: MeasurementUnit<Property: VectorProperty>,
+                Unit: VectorMeasurementUnit,
             {
                 let minus_two = -(Number::ONE + Number::ONE);
                 Self {
@@ -239,7 +239,7 @@ macro_rules! inner_define_linear_map_3d {
 
             pub fn apply_to<Unit>(&self, m: Measure3d<Unit, Number>) -> Measure3d<Unit, Number>
             where
-                Unit: MeasurementUnit<Property: VectorProperty>,
+                Unit: VectorMeasurementUnit,
             {
                 Measure3d::<Unit, Number>::new([
                     self.c[0][0] * m.values[0] + self.c[0][1] * m.values[1] + self.c[0][2] * m.values[2],
@@ -327,22 +327,40 @@ macro_rules! inner_define_linear_map_3d {
         /// LinearMap3d.to_string() -> String
         impl<Number: ArithmeticOps> fmt::Display for LinearMap3d<Number> {
             fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                write!(
-                    formatter,
-                    "{}",
-                    measures::matrix_utils::format_matrix::<3, 3, Number>(&self.c, "", 1)
-                )
+                measures::matrix_utils::format_matrix::<3, 3, Number>(formatter, &self.c, "", 1)
             }
         }
 
         // format!("{:?}", LinearMap3d)
         impl<Number: ArithmeticOps> fmt::Debug for LinearMap3d<Number> {
             fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                write!(
-                    formatter,
-                    "{}",
-                    measures::matrix_utils::format_matrix::<3, 3, Number>(&self.c, "", 1)
-                )
+                measures::matrix_utils::format_matrix::<3, 3, Number>(formatter, &self.c, "", 1)
+            }
+        }
+
+        measures::if_all_true! { { $with_serde }
+            impl<Number> serde::Serialize for LinearMap3d<Number>
+            where
+                Number: ArithmeticOps + serde::Serialize,
+            {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: serde::Serializer
+                {
+                    self.c.serialize(serializer)
+                }
+            }
+
+            impl<'de, Number> serde::Deserialize<'de> for LinearMap3d<Number>
+            where
+                Number: ArithmeticOps + serde::Deserialize<'de>,
+            {
+                fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+                where
+                    De: serde::Deserializer<'de>,
+                {
+                    Ok(Self::new(serde::Deserialize::deserialize(deserializer)?))
+                }
             }
         }
     };
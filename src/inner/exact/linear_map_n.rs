@@ -0,0 +1,183 @@
+#[macro_export] // Don't add nor remove the first three lines and the last two lines.
+macro_rules! inner_define_linear_map_n {
+    { $with_2d:ident $with_3d:ident } => {
+        /// Linear transformation of `MeasureN` objects.
+        pub struct LinearMapN<const N: usize, Number = f64>
+        where
+            Number: ArithmeticOps,
+        {
+            pub c: [[Number; N]; N],
+        }
+
+        impl<const N: usize, Number> LinearMapN<N, Number>
+        where
+            Number: ArithmeticOps,
+        {
+            /// Create a LinearMapN from its N * N coefficients.
+            pub const fn new(coefficients: [[Number; N]; N]) -> Self {
+                Self { c: coefficients }
+            }
+
+            // Scaling by N factors.
+
+            pub fn scaling(factors: [Number; N]) -> Self {
+                Self {
+                    c: core::array::from_fn(|row| {
+                        core::array::from_fn(|column| {
+                            if row == column {
+                                factors[row]
+                            } else {
+                                Number::ZERO
+                            }
+                        })
+                    }),
+                }
+            }
+
+            // Transposition
+
+            pub fn transposed(&self) -> Self {
+                Self {
+                    c: core::array::from_fn(|row| core::array::from_fn(|column| self.c[column][row])),
+                }
+            }
+
+            // Inversion, computed by Gauss-Jordan elimination with partial pivoting.
+            // Like for the other linear maps, the inversion of a singular map
+            // produces non-finite coefficients.
+
+            pub fn inverted(&self) -> Self {
+                let mut left = self.c;
+                let mut right = Self::default().c;
+                for column in 0..N {
+                    let mut pivot_row = column;
+                    for row in column + 1..N {
+                        if left[row][column].abs() > left[pivot_row][column].abs() {
+                            pivot_row = row;
+                        }
+                    }
+                    left.swap(column, pivot_row);
+                    right.swap(column, pivot_row);
+                    let inv_pivot = Number::ONE / left[column][column];
+                    for k in 0..N {
+                        left[column][k] *= inv_pivot;
+                        right[column][k] *= inv_pivot;
+                    }
+                    for row in 0..N {
+                        if row != column {
+                            let factor = left[row][column];
+                            for k in 0..N {
+                                left[row][k] -= factor * left[column][k];
+                                right[row][k] -= factor * right[column][k];
+                            }
+                        }
+                    }
+                }
+                Self { c: right }
+            }
+
+            // Composition of linear transformations.
+            // Applying the resulting transformation is equivalent to apply first
+            // `other` and then `self`.
+            pub fn combined_with(&self, other: &LinearMapN<N, Number>) -> Self {
+                Self {
+                    c: core::array::from_fn(|row| {
+                        core::array::from_fn(|column| {
+                            (0..N).fold(Number::ZERO, |sum, k| {
+                                sum + other.c[row][k] * self.c[k][column]
+                            })
+                        })
+                    }),
+                }
+            }
+
+            pub fn apply_to<Unit>(&self, m: MeasureN<Unit, N, Number>) -> MeasureN<Unit, N, Number>
+            where
+                Unit: VectorMeasurementUnit,
+            {
+                MeasureN::<Unit, N, Number>::new(core::array::from_fn(|row| {
+                    self.c[row]
+                        .iter()
+                        .zip(m.values)
+                        .fold(Number::ZERO, |sum, (&coefficient, value)| {
+                            sum + coefficient * value
+                        })
+                }))
+            }
+        }
+
+        impl<const N: usize, Number> Default for LinearMapN<N, Number>
+        where
+            Number: ArithmeticOps,
+        {
+            /// LinearMapN::default() -> LinearMapN
+            /// It returns the identity transformation.
+            fn default() -> Self {
+                Self::scaling([Number::ONE; N])
+            }
+        }
+
+        // LinearMapN == LinearMapN -> bool
+        impl<const N: usize, Number> PartialEq<LinearMapN<N, Number>> for LinearMapN<N, Number>
+        where
+            Number: ArithmeticOps,
+        {
+            fn eq(&self, other: &LinearMapN<N, Number>) -> bool {
+                self.c == other.c
+            }
+        }
+
+        // LinearMapN.clone() -> LinearMapN
+        impl<const N: usize, Number> Clone for LinearMapN<N, Number>
+        where
+            Number: ArithmeticOps,
+        {
+            fn clone(&self) -> Self {
+                Self { c: self.c }
+            }
+        }
+
+        impl<const N: usize> From<LinearMapN<N, f32>> for LinearMapN<N, f64> {
+            fn from(m: LinearMapN<N, f32>) -> Self {
+                Self::new(m.c.map(|row| row.map(|coefficient| coefficient as f64)))
+            }
+        }
+
+        measures::if_all_true! { { $with_2d }
+            impl<Number> From<LinearMap2d<Number>> for LinearMapN<2, Number>
+            where
+                Number: ArithmeticOps,
+            {
+                fn from(m: LinearMap2d<Number>) -> Self {
+                    Self::new(m.c)
+                }
+            }
+        }
+
+        measures::if_all_true! { { $with_3d }
+            impl<Number> From<LinearMap3d<Number>> for LinearMapN<3, Number>
+            where
+                Number: ArithmeticOps,
+            {
+                fn from(m: LinearMap3d<Number>) -> Self {
+                    Self::new(m.c)
+                }
+            }
+        }
+
+        /// format!("{}", LinearMapN) -> String
+        /// LinearMapN.to_string() -> String
+        impl<const N: usize, Number: ArithmeticOps> fmt::Display for LinearMapN<N, Number> {
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                measures::matrix_utils::format_matrix::<N, N, Number>(formatter, &self.c, "", 1)
+            }
+        }
+
+        // format!("{:?}", LinearMapN)
+        impl<const N: usize, Number: ArithmeticOps> fmt::Debug for LinearMapN<N, Number> {
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                measures::matrix_utils::format_matrix::<N, N, Number>(formatter, &self.c, "", 1)
+            }
+        }
+    };
+}
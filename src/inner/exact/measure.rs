@@ -3,19 +3,46 @@ macro_rules! inner_define_measure {
     { $with_approx:ident $with_serde:ident } => {
         /// 1D relative measure with generic unit of measurement, generic value type,
         /// and with a dynamic value.
+        #[repr(transparent)]
         pub struct Measure<Unit, Number = f64>
         where
             Unit: MeasurementUnit,
-            Number: ArithmeticOps,
+            Number: BasicArithmeticOps,
         {
             pub value: Number,
             phantom: PhantomData<Unit>,
         }
 
+        // SAFETY: the struct is `repr(transparent)`, and every value of its field is valid.
+        unsafe impl<Unit, Number> measures::traits::Transparent<Number> for Measure<Unit, Number>
+        where
+            Unit: MeasurementUnit,
+            Number: BasicArithmeticOps,
+        {
+        }
+
+        measures::if_bytemuck! {
+            // SAFETY: the struct is `repr(transparent)`, and its field is `Zeroable`.
+            unsafe impl<Unit, Number> measures::bytemuck::Zeroable for Measure<Unit, Number>
+            where
+                Unit: MeasurementUnit,
+                Number: BasicArithmeticOps + measures::bytemuck::Zeroable,
+            {
+            }
+
+            // SAFETY: the struct is `repr(transparent)`, and its field is `Pod`.
+            unsafe impl<Unit, Number> measures::bytemuck::Pod for Measure<Unit, Number>
+            where
+                Unit: MeasurementUnit + 'static,
+                Number: BasicArithmeticOps + measures::bytemuck::Pod,
+            {
+            }
+        }
+
         impl<Unit, Number> Measure<Unit, Number>
         where
             Unit: MeasurementUnit,
-            Number: ArithmeticOps,
+            Number: BasicArithmeticOps,
         {
             /// Measure::new(Number) -> Measure
             pub const fn new(value: Number) -> Self {
@@ -26,19 +53,24 @@ macro_rules! inner_define_measure {
             }
 
             /// Measure.convert() -> Measure
+            /// A relative measure is a difference, and so the offsets of the units are ignored:
+            /// 10 Celsius degrees of difference are 18 Fahrenheit degrees of difference.
+            /// To convert absolute values, like temperatures, `MeasurePoint` must be used.
+            /// For the same reason, the units must be linear, as a difference cannot be converted
+            /// by the functions of a non-linear unit, like decibels,
+            /// and so the measure points must be used also to convert to or from such units.
             pub fn convert<DestUnit>(self) -> Measure<DestUnit, Number>
             where
-                DestUnit: MeasurementUnit<Property = Unit::Property>,
+                Unit: LinearMeasurementUnit,
+                DestUnit: LinearMeasurementUnit<Property = Unit::Property>,
             {
-                Measure::<DestUnit, Number>::new(
-                    self.value * Number::from_f64(Unit::RATIO / DestUnit::RATIO),
-                )
+                Measure::<DestUnit, Number>::new(self.value.scale_by_ratio(Unit::RATIO / DestUnit::RATIO))
             }
 
             /// Measure.lossless_into() -> Measure
             pub fn lossless_into<DestNumber>(self) -> Measure<Unit, DestNumber>
             where
-                DestNumber: ArithmeticOps + From<Number>,
+                DestNumber: BasicArithmeticOps + From<Number>,
             {
                 Measure::<Unit, DestNumber>::new(DestNumber::from(self.value))
             }
@@ -46,7 +78,7 @@ macro_rules! inner_define_measure {
             /// Measure.lossy_into() -> Measure
             pub fn lossy_into<DestNumber>(self) -> Measure<Unit, DestNumber>
             where
-                DestNumber: ArithmeticOps + LossyFrom<Number>,
+                DestNumber: BasicArithmeticOps + LossyFrom<Number>,
             {
                 Measure::<Unit, DestNumber>::new(DestNumber::lossy_from(self.value))
             }
@@ -117,13 +149,13 @@ macro_rules! inner_define_measure {
         }
 
         impl<Unit: MeasurementUnit> Measure<Unit, f32> {
-            pub fn total_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            pub fn total_cmp(&self, other: &Self) -> core::cmp::Ordering {
                 self.value.total_cmp(&other.value)
             }
         }
 
         impl<Unit: MeasurementUnit> Measure<Unit, f64> {
-            pub fn total_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            pub fn total_cmp(&self, other: &Self) -> core::cmp::Ordering {
                 self.value.total_cmp(&other.value)
             }
         }
@@ -131,7 +163,7 @@ macro_rules! inner_define_measure {
         impl<Unit, Number> Default for Measure<Unit, Number>
         where
             Unit: MeasurementUnit,
-            Number: ArithmeticOps,
+            Number: BasicArithmeticOps,
         {
             /// Measure::default() -> Measure
             /// It returns the zero measure.
@@ -172,7 +204,7 @@ macro_rules! inner_define_measure {
             impl<Unit, Number> serde::Serialize for Measure<Unit, Number>
             where
                 Unit: MeasurementUnit,
-                Number: ArithmeticOps + serde::Serialize,
+                Number: BasicArithmeticOps + serde::Serialize,
             {
                 fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
                 where
@@ -185,7 +217,7 @@ macro_rules! inner_define_measure {
             impl<'de, Unit, Number> serde::Deserialize<'de> for Measure<Unit, Number>
             where
                 Unit: MeasurementUnit,
-                Number: ArithmeticOps + serde::Deserialize<'de>,
+                Number: BasicArithmeticOps + serde::Deserialize<'de>,
             {
                 fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
                 where
@@ -199,7 +231,7 @@ macro_rules! inner_define_measure {
         impl<Unit, Number> Neg for Measure<Unit, Number>
         where
             Unit: MeasurementUnit,
-            Number: ArithmeticOps,
+            Number: BasicArithmeticOps,
         {
             type Output = Self;
 
@@ -212,7 +244,7 @@ macro_rules! inner_define_measure {
         impl<Unit, Number> Add<Measure<Unit, Number>> for Measure<Unit, Number>
         where
             Unit: MeasurementUnit,
-            Number: ArithmeticOps,
+            Number: BasicArithmeticOps,
         {
             type Output = Self;
 
@@ -225,7 +257,7 @@ macro_rules! inner_define_measure {
         impl<Unit, Number> AddAssign<Measure<Unit, Number>> for Measure<Unit, Number>
         where
             Unit: MeasurementUnit,
-            Number: ArithmeticOps,
+            Number: BasicArithmeticOps,
         {
             /// Measure += Measure
             fn add_assign(&mut self, other: Measure<Unit, Number>) {
@@ -236,7 +268,7 @@ macro_rules! inner_define_measure {
         impl<Unit, Number> Sub<Measure<Unit, Number>> for Measure<Unit, Number>
         where
             Unit: MeasurementUnit,
-            Number: ArithmeticOps,
+            Number: BasicArithmeticOps,
         {
             type Output = Self;
 
@@ -249,7 +281,7 @@ macro_rules! inner_define_measure {
         impl<Unit, Number> SubAssign<Measure<Unit, Number>> for Measure<Unit, Number>
         where
             Unit: MeasurementUnit,
-            Number: ArithmeticOps,
+            Number: BasicArithmeticOps,
         {
             /// Measure -= Measure
             fn sub_assign(&mut self, other: Measure<Unit, Number>) {
@@ -260,7 +292,7 @@ macro_rules! inner_define_measure {
         impl<Unit, Number> Mul<Number> for Measure<Unit, Number>
         where
             Unit: MeasurementUnit,
-            Number: ArithmeticOps,
+            Number: BasicArithmeticOps,
         {
             type Output = Self;
 
@@ -273,7 +305,7 @@ macro_rules! inner_define_measure {
         impl<Unit, Number> Mul<Measure<One, Number>> for Measure<Unit, Number>
         where
             Unit: MeasurementUnit,
-            Number: ArithmeticOps,
+            Number: BasicArithmeticOps,
         {
             type Output = Self;
 
@@ -286,7 +318,7 @@ macro_rules! inner_define_measure {
         impl<Unit, Number> MulAssign<Number> for Measure<Unit, Number>
         where
             Unit: MeasurementUnit,
-            Number: ArithmeticOps,
+            Number: BasicArithmeticOps,
         {
             /// Measure *= Number
             fn mul_assign(&mut self, n: Number) {
@@ -297,7 +329,7 @@ macro_rules! inner_define_measure {
         impl<Unit, Number> MulAssign<Measure<One, Number>> for Measure<Unit, Number>
         where
             Unit: MeasurementUnit,
-            Number: ArithmeticOps,
+            Number: BasicArithmeticOps,
         {
             /// Measure *= Measure<One>
             fn mul_assign(&mut self, other: Measure<One, Number>) {
@@ -332,7 +364,7 @@ macro_rules! inner_define_measure {
         impl<Unit, Number> Div<Number> for Measure<Unit, Number>
         where
             Unit: MeasurementUnit,
-            Number: ArithmeticOps,
+            Number: BasicArithmeticOps,
         {
             type Output = Self;
 
@@ -345,7 +377,7 @@ macro_rules! inner_define_measure {
         impl<Unit, Number> Div<Measure<Unit, Number>> for Measure<Unit, Number>
         where
             Unit: MeasurementUnit,
-            Number: ArithmeticOps,
+            Number: BasicArithmeticOps,
         {
             type Output = Measure<One, Number>;
 
@@ -358,7 +390,7 @@ macro_rules! inner_define_measure {
         impl<Unit, Number> DivAssign<Number> for Measure<Unit, Number>
         where
             Unit: MeasurementUnit,
-            Number: ArithmeticOps,
+            Number: BasicArithmeticOps,
         {
             /// Measure /= Number
             fn div_assign(&mut self, n: Number) {
@@ -369,7 +401,7 @@ macro_rules! inner_define_measure {
         impl<Unit, Number> DivAssign<Measure<One, Number>> for Measure<Unit, Number>
         where
             Unit: MeasurementUnit,
-            Number: ArithmeticOps,
+            Number: BasicArithmeticOps,
         {
             /// Measure /= Measure<One>
             fn div_assign(&mut self, other: Measure<One, Number>) {
@@ -408,7 +440,7 @@ macro_rules! inner_define_measure {
         impl<Unit, Number> PartialEq<Measure<Unit, Number>> for Measure<Unit, Number>
         where
             Unit: MeasurementUnit,
-            Number: ArithmeticOps,
+            Number: BasicArithmeticOps,
         {
             /// Measure == Measure -> bool
             fn eq(&self, other: &Measure<Unit, Number>) -> bool {
@@ -419,7 +451,7 @@ macro_rules! inner_define_measure {
         impl<Unit, Number> PartialOrd<Measure<Unit, Number>> for Measure<Unit, Number>
         where
             Unit: MeasurementUnit,
-            Number: ArithmeticOps,
+            Number: BasicArithmeticOps,
         {
             /// Measure < Measure -> bool
             fn partial_cmp(&self, other: &Measure<Unit, Number>) -> Option<core::cmp::Ordering> {
@@ -430,7 +462,7 @@ macro_rules! inner_define_measure {
         impl<Unit, Number> Clone for Measure<Unit, Number>
         where
             Unit: MeasurementUnit,
-            Number: ArithmeticOps,
+            Number: BasicArithmeticOps,
         {
             /// Measure.clone() -> Measure
             fn clone(&self) -> Self {
@@ -442,14 +474,14 @@ macro_rules! inner_define_measure {
         impl<Unit, Number> Copy for Measure<Unit, Number>
         where
             Unit: MeasurementUnit,
-            Number: ArithmeticOps,
+            Number: BasicArithmeticOps,
         {
         }
 
         impl<Unit, Number> fmt::Display for Measure<Unit, Number>
         where
             Unit: MeasurementUnit,
-            Number: ArithmeticOps,
+            Number: BasicArithmeticOps,
         {
             /// format!("{}", Measure) -> String
             /// Measure.to_string() -> String
@@ -462,7 +494,7 @@ macro_rules! inner_define_measure {
         impl<Unit, Number> fmt::Debug for Measure<Unit, Number>
         where
             Unit: MeasurementUnit,
-            Number: ArithmeticOps,
+            Number: BasicArithmeticOps,
         {
             /// format!("{:?}", Measure)
             fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -559,5 +591,37 @@ macro_rules! inner_define_measure {
                 formatter.write_str(Unit::SUFFIX)
             }
         }
+
+        measures::if_all_true! { { $with_serde }
+            /// It serializes the measure as the text it prints, like "30 dB W",
+            /// to be read by people. It cannot be deserialized.
+            impl<Unit, Number> serde::Serialize for PowerDecibelsMeasureFormatter<Unit, Number>
+            where
+                Unit: MeasurementUnit<Property: PowerQuantity>,
+                Number: ArithmeticOps,
+            {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: serde::Serializer
+                {
+                    serializer.collect_str(self)
+                }
+            }
+
+            /// It serializes the measure as the text it prints, like "30 dB W",
+            /// to be read by people. It cannot be deserialized.
+            impl<Unit, Number> serde::Serialize for RootPowerDecibelsMeasureFormatter<Unit, Number>
+            where
+                Unit: MeasurementUnit<Property: RootPowerQuantity>,
+                Number: ArithmeticOps,
+            {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: serde::Serializer
+                {
+                    serializer.collect_str(self)
+                }
+            }
+        }
     };
 }
@@ -3,18 +3,45 @@ macro_rules! inner_define_measure_2d {
     { $with_points:tt $with_directions:tt $with_approx:ident $with_serde:ident } => {
         /// 2D relative measure with generic unit of measurement, generic value type,
         /// and with 2 dynamic components.
+        #[repr(transparent)]
         pub struct Measure2d<Unit, Number = f64>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             pub values: [Number; 2],
             phantom: PhantomData<Unit>,
         }
 
+        // SAFETY: the struct is `repr(transparent)`, and every value of its field is valid.
+        unsafe impl<Unit, Number> measures::traits::Transparent<[Number; 2]> for Measure2d<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+        }
+
+        measures::if_bytemuck! {
+            // SAFETY: the struct is `repr(transparent)`, and its field is `Zeroable`.
+            unsafe impl<Unit, Number> measures::bytemuck::Zeroable for Measure2d<Unit, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps + measures::bytemuck::Zeroable,
+            {
+            }
+
+            // SAFETY: the struct is `repr(transparent)`, and its field is `Pod`.
+            unsafe impl<Unit, Number> measures::bytemuck::Pod for Measure2d<Unit, Number>
+            where
+                Unit: VectorMeasurementUnit + 'static,
+                Number: ArithmeticOps + measures::bytemuck::Pod,
+            {
+            }
+        }
+
         impl<Unit, Number> Measure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// Measure2d::new([Number; 2]) -> Measure2d
@@ -38,7 +65,7 @@ macro_rules! inner_define_measure_2d {
             /// Measure2d.convert() -> Measure2d
             pub fn convert<DestUnit>(self) -> Measure2d<DestUnit, Number>
             where
-                DestUnit: MeasurementUnit<Property = Unit::Property>,
+                DestUnit: VectorMeasurementUnit<Property = Unit::Property>,
             {
                 let factor = Number::from_f64(Unit::RATIO / DestUnit::RATIO);
                 Measure2d::<DestUnit, Number>::new([self.values[0] * factor, self.values[1] * factor])
@@ -132,7 +159,7 @@ macro_rules! inner_define_measure_2d {
 
         impl<Unit, Number> Default for Measure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// Measure2d::default() -> Measure2d
@@ -144,7 +171,7 @@ macro_rules! inner_define_measure_2d {
 
         impl<Unit, Number> From<[Measure<Unit, Number>; 2]> for Measure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// Measure2d::from([Measure; 2]) -> Measure2d
@@ -155,7 +182,7 @@ macro_rules! inner_define_measure_2d {
 
         impl<Unit> From<Measure2d<Unit, f32>> for Measure2d<Unit, f64>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
         {
             /// Measure2d<f64>::from(Measure2d<f32>) -> Measure2d<f64>
             fn from(measure: Measure2d<Unit, f32>) -> Self {
@@ -166,7 +193,7 @@ macro_rules! inner_define_measure_2d {
         measures::if_all_true! { { $with_approx }
             impl<Unit, Number> From<ApproxMeasure2d<Unit, Number>> for Measure2d<Unit, Number>
             where
-                Unit: MeasurementUnit<Property: VectorProperty>,
+                Unit: VectorMeasurementUnit,
                 Number: ArithmeticOps,
             {
                 /// Measure2d::from(ApproxMeasure2d) -> Measure2d
@@ -179,7 +206,7 @@ macro_rules! inner_define_measure_2d {
         measures::if_all_true! { { $with_serde }
             impl<Unit, Number> serde::Serialize for Measure2d<Unit, Number>
             where
-                Unit: MeasurementUnit<Property: VectorProperty>,
+                Unit: VectorMeasurementUnit,
                 Number: ArithmeticOps + serde::Serialize,
             {
                 fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -192,7 +219,7 @@ macro_rules! inner_define_measure_2d {
 
             impl<'de, Unit, Number> serde::Deserialize<'de> for Measure2d<Unit, Number>
             where
-                Unit: MeasurementUnit<Property: VectorProperty>,
+                Unit: VectorMeasurementUnit,
                 Number: ArithmeticOps + serde::Deserialize<'de>,
             {
                 fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
@@ -206,7 +233,7 @@ macro_rules! inner_define_measure_2d {
 
         impl<Unit, Number> Neg for Measure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = Self;
@@ -219,7 +246,7 @@ macro_rules! inner_define_measure_2d {
 
         impl<Unit, Number> Add<Measure2d<Unit, Number>> for Measure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = Self;
@@ -235,7 +262,7 @@ macro_rules! inner_define_measure_2d {
 
         impl<Unit, Number> AddAssign<Measure2d<Unit, Number>> for Measure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// Measure2d += Measure2d
@@ -247,7 +274,7 @@ macro_rules! inner_define_measure_2d {
 
         impl<Unit, Number> Sub<Measure2d<Unit, Number>> for Measure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = Self;
@@ -263,7 +290,7 @@ macro_rules! inner_define_measure_2d {
 
         impl<Unit, Number> SubAssign<Measure2d<Unit, Number>> for Measure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// Measure2d -= Measure2d
@@ -275,7 +302,7 @@ macro_rules! inner_define_measure_2d {
 
         impl<Unit, Number> Mul<Number> for Measure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = Self;
@@ -288,7 +315,7 @@ macro_rules! inner_define_measure_2d {
 
         impl<Unit, Number> Mul<Measure<One, Number>> for Measure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = Self;
@@ -301,7 +328,7 @@ macro_rules! inner_define_measure_2d {
 
         impl<Unit, Number> Mul<Measure2d<Unit, Number>> for Measure<One, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = Measure2d<Unit, Number>;
@@ -314,7 +341,7 @@ macro_rules! inner_define_measure_2d {
 
         impl<Unit, Number> MulAssign<Number> for Measure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// Measure2d *= Number
@@ -326,7 +353,7 @@ macro_rules! inner_define_measure_2d {
 
         impl<Unit, Number> MulAssign<Measure<One, Number>> for Measure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// Measure2d *= Measure<One>
@@ -338,7 +365,7 @@ macro_rules! inner_define_measure_2d {
 
         impl<Unit> Mul<Measure2d<Unit, f64>> for f64
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
         {
             type Output = Measure2d<Unit, f64>;
 
@@ -350,7 +377,7 @@ macro_rules! inner_define_measure_2d {
 
         impl<Unit> Mul<Measure2d<Unit, f32>> for f32
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
         {
             type Output = Measure2d<Unit, f32>;
 
@@ -362,7 +389,7 @@ macro_rules! inner_define_measure_2d {
 
         impl<Unit, Number> Div<Number> for Measure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = Self;
@@ -375,7 +402,7 @@ macro_rules! inner_define_measure_2d {
 
         impl<Unit, Number> Div<Measure<Unit, Number>> for Measure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = Measure2d<One, Number>;
@@ -388,7 +415,7 @@ macro_rules! inner_define_measure_2d {
 
         impl<Unit, Number> DivAssign<Number> for Measure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// Measure2d /= Number
@@ -400,7 +427,7 @@ macro_rules! inner_define_measure_2d {
 
         impl<Unit, Number> DivAssign<Measure<One, Number>> for Measure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// Measure2d /= Measure<One>
@@ -412,7 +439,7 @@ macro_rules! inner_define_measure_2d {
 
         impl<Unit, Number> PartialEq<Measure2d<Unit, Number>> for Measure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// Measure2d == Measure2d -> bool
@@ -423,7 +450,7 @@ macro_rules! inner_define_measure_2d {
 
         impl<Unit, Number> Clone for Measure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// Measure2d.clone() -> Measure2d
@@ -435,14 +462,14 @@ macro_rules! inner_define_measure_2d {
         /// Measure2d = Measure2d
         impl<Unit, Number> Copy for Measure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
         }
 
         impl<Unit, Number> fmt::Display for Measure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// format!("{}", Measure2d) -> String
@@ -459,7 +486,7 @@ macro_rules! inner_define_measure_2d {
 
         impl<Unit, Number> fmt::Debug for Measure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// format!("{:?}", Measure2d)
@@ -475,7 +502,7 @@ macro_rules! inner_define_measure_2d {
 
         impl<Unit, Number> fmt::LowerExp for Measure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// format!("{:e}", Measure2d) -> String
@@ -491,7 +518,7 @@ macro_rules! inner_define_measure_2d {
 
         impl<Unit, Number> fmt::UpperExp for Measure2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// format!("{:E}", Measure2d) -> String
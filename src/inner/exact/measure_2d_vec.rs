@@ -0,0 +1,391 @@
+#[macro_export] // Don't add nor remove the first three lines and the last two lines.
+macro_rules! inner_define_measure_2d_vec {
+    {} => {
+        /// Growable sequence of 2D relative measures having the same unit of measurement.
+        /// Every component is stored in a distinct contiguous sequence (a "structure of arrays"),
+        /// and so the components can be viewed as slices of numbers,
+        /// to be passed to numeric libraries without copying them.
+        pub struct Measure2dVec<Unit, Number = f64>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            x: measures::alloc::vec::Vec<Number>,
+            y: measures::alloc::vec::Vec<Number>,
+            phantom: PhantomData<Unit>,
+        }
+
+        impl<Unit, Number> Measure2dVec<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// Measure2dVec::new() -> Measure2dVec
+            /// It returns an empty sequence.
+            pub fn new() -> Self {
+                Self::from_components(
+                    measures::alloc::vec::Vec::new(),
+                    measures::alloc::vec::Vec::new(),
+                )
+            }
+
+            /// Measure2dVec::with_capacity(usize) -> Measure2dVec
+            pub fn with_capacity(capacity: usize) -> Self {
+                Self::from_components(
+                    measures::alloc::vec::Vec::with_capacity(capacity),
+                    measures::alloc::vec::Vec::with_capacity(capacity),
+                )
+            }
+
+            /// Measure2dVec::from_components(Vec<Number>, Vec<Number>) -> Measure2dVec
+            /// The numbers are the components of the measures, in the unit `Unit`.
+            /// It panics if the sequences have different lengths.
+            pub fn from_components(
+                x: measures::alloc::vec::Vec<Number>,
+                y: measures::alloc::vec::Vec<Number>,
+            ) -> Self {
+                assert_eq!(
+                    x.len(),
+                    y.len(),
+                    "the components of the measures have different lengths"
+                );
+                Self {
+                    x,
+                    y,
+                    phantom: PhantomData,
+                }
+            }
+
+            /// Measure2dVec.into_components() -> (Vec<Number>, Vec<Number>)
+            pub fn into_components(
+                self,
+            ) -> (
+                measures::alloc::vec::Vec<Number>,
+                measures::alloc::vec::Vec<Number>,
+            ) {
+                (self.x, self.y)
+            }
+
+            /// Measure2dVec.x_values() -> &[Number]
+            pub fn x_values(&self) -> &[Number] {
+                &self.x
+            }
+
+            /// Measure2dVec.x_values_mut() -> &mut [Number]
+            pub fn x_values_mut(&mut self) -> &mut [Number] {
+                &mut self.x
+            }
+
+            /// Measure2dVec.y_values() -> &[Number]
+            pub fn y_values(&self) -> &[Number] {
+                &self.y
+            }
+
+            /// Measure2dVec.y_values_mut() -> &mut [Number]
+            pub fn y_values_mut(&mut self) -> &mut [Number] {
+                &mut self.y
+            }
+
+            /// Measure2dVec.len() -> usize
+            pub fn len(&self) -> usize {
+                self.x.len()
+            }
+
+            /// Measure2dVec.is_empty() -> bool
+            pub fn is_empty(&self) -> bool {
+                self.x.is_empty()
+            }
+
+            /// Measure2dVec.push(Measure2d)
+            pub fn push(&mut self, measure: Measure2d<Unit, Number>) {
+                self.x.push(measure.values[0]);
+                self.y.push(measure.values[1]);
+            }
+
+            /// Measure2dVec.get(usize) -> Option<Measure2d>
+            pub fn get(&self, index: usize) -> Option<Measure2d<Unit, Number>> {
+                if index < self.len() {
+                    Some(Measure2d::<Unit, Number>::new([
+                        self.x[index],
+                        self.y[index],
+                    ]))
+                } else {
+                    None
+                }
+            }
+
+            /// Measure2dVec.set(usize, Measure2d)
+            /// It panics if the index is out of bounds.
+            pub fn set(&mut self, index: usize, measure: Measure2d<Unit, Number>) {
+                self.x[index] = measure.values[0];
+                self.y[index] = measure.values[1];
+            }
+
+            /// Measure2dVec.iter() -> impl Iterator<Item = Measure2d>
+            pub fn iter(&self) -> impl Iterator<Item = Measure2d<Unit, Number>> + '_ {
+                self.x
+                    .iter()
+                    .zip(&self.y)
+                    .map(|(&x, &y)| Measure2d::<Unit, Number>::new([x, y]))
+            }
+
+            /// Measure2dVec.convert() -> Measure2dVec
+            /// The components are converted in place, without allocating memory.
+            pub fn convert<DestUnit>(mut self) -> Measure2dVec<DestUnit, Number>
+            where
+                DestUnit: VectorMeasurementUnit<Property = Unit::Property>,
+            {
+                let factor = Number::from_f64(Unit::RATIO / DestUnit::RATIO);
+                for value in &mut self.x {
+                    *value *= factor;
+                }
+                for value in &mut self.y {
+                    *value *= factor;
+                }
+                Measure2dVec::<DestUnit, Number>::from_components(self.x, self.y)
+            }
+
+            /// Measure2dVec.norms() -> MeasureVec
+            /// It returns the norms of all the measures.
+            pub fn norms(&self) -> MeasureVec<Unit, Number> {
+                MeasureVec::<Unit, Number>::from_values(
+                    self.x
+                        .iter()
+                        .zip(&self.y)
+                        .map(|(&x, &y)| (x * x + y * y).sqrt())
+                        .collect(),
+                )
+            }
+
+            // It applies the operation to every component and to the corresponding component of `other`.
+            fn zip_apply(&mut self, other: &Self, operation: impl Fn(&mut Number, Number)) {
+                assert_eq!(
+                    self.len(),
+                    other.len(),
+                    "the sequences of measures have different lengths"
+                );
+                for (value, &other_value) in self.x.iter_mut().zip(&other.x) {
+                    operation(value, other_value);
+                }
+                for (value, &other_value) in self.y.iter_mut().zip(&other.y) {
+                    operation(value, other_value);
+                }
+            }
+        }
+
+        impl<Unit, Number> Default for Measure2dVec<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// Measure2dVec::default() -> Measure2dVec
+            /// It returns an empty sequence.
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl<Unit, Number> Clone for Measure2dVec<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            fn clone(&self) -> Self {
+                Self::from_components(self.x.clone(), self.y.clone())
+            }
+        }
+
+        impl<Unit, Number> PartialEq for Measure2dVec<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            fn eq(&self, other: &Self) -> bool {
+                self.x == other.x && self.y == other.y
+            }
+        }
+
+        impl<Unit, Number> core::iter::FromIterator<Measure2d<Unit, Number>> for Measure2dVec<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            fn from_iter<I: IntoIterator<Item = Measure2d<Unit, Number>>>(iter: I) -> Self {
+                let mut result = Self::new();
+                result.extend(iter);
+                result
+            }
+        }
+
+        impl<Unit, Number> Extend<Measure2d<Unit, Number>> for Measure2dVec<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            fn extend<I: IntoIterator<Item = Measure2d<Unit, Number>>>(&mut self, iter: I) {
+                for measure in iter {
+                    self.push(measure);
+                }
+            }
+        }
+
+        impl<Unit, Number> AddAssign<&Measure2dVec<Unit, Number>> for Measure2dVec<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// Measure2dVec += &Measure2dVec
+            /// It panics if the sequences have different lengths.
+            fn add_assign(&mut self, other: &Measure2dVec<Unit, Number>) {
+                self.zip_apply(other, |value, other_value| *value += other_value);
+            }
+        }
+
+        impl<Unit, Number> SubAssign<&Measure2dVec<Unit, Number>> for Measure2dVec<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// Measure2dVec -= &Measure2dVec
+            /// It panics if the sequences have different lengths.
+            fn sub_assign(&mut self, other: &Measure2dVec<Unit, Number>) {
+                self.zip_apply(other, |value, other_value| *value -= other_value);
+            }
+        }
+
+        impl<Unit, Number> Add<&Measure2dVec<Unit, Number>> for Measure2dVec<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            type Output = Self;
+
+            /// Measure2dVec + &Measure2dVec -> Measure2dVec
+            /// It panics if the sequences have different lengths.
+            fn add(mut self, other: &Measure2dVec<Unit, Number>) -> Self::Output {
+                self += other;
+                self
+            }
+        }
+
+        impl<Unit, Number> Sub<&Measure2dVec<Unit, Number>> for Measure2dVec<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            type Output = Self;
+
+            /// Measure2dVec - &Measure2dVec -> Measure2dVec
+            /// It panics if the sequences have different lengths.
+            fn sub(mut self, other: &Measure2dVec<Unit, Number>) -> Self::Output {
+                self -= other;
+                self
+            }
+        }
+
+        impl<Unit, Number> AddAssign<Measure2d<Unit, Number>> for Measure2dVec<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// Measure2dVec += Measure2d
+            /// It adds the measure to every item.
+            fn add_assign(&mut self, other: Measure2d<Unit, Number>) {
+                for value in &mut self.x {
+                    *value += other.values[0];
+                }
+                for value in &mut self.y {
+                    *value += other.values[1];
+                }
+            }
+        }
+
+        impl<Unit, Number> SubAssign<Measure2d<Unit, Number>> for Measure2dVec<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// Measure2dVec -= Measure2d
+            /// It subtracts the measure from every item.
+            fn sub_assign(&mut self, other: Measure2d<Unit, Number>) {
+                for value in &mut self.x {
+                    *value -= other.values[0];
+                }
+                for value in &mut self.y {
+                    *value -= other.values[1];
+                }
+            }
+        }
+
+        impl<Unit, Number> MulAssign<Number> for Measure2dVec<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// Measure2dVec *= Number
+            fn mul_assign(&mut self, n: Number) {
+                for value in &mut self.x {
+                    *value *= n;
+                }
+                for value in &mut self.y {
+                    *value *= n;
+                }
+            }
+        }
+
+        impl<Unit, Number> DivAssign<Number> for Measure2dVec<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// Measure2dVec /= Number
+            fn div_assign(&mut self, n: Number) {
+                for value in &mut self.x {
+                    *value /= n;
+                }
+                for value in &mut self.y {
+                    *value /= n;
+                }
+            }
+        }
+
+        impl<Unit, Number> Mul<Number> for Measure2dVec<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            type Output = Self;
+
+            /// Measure2dVec * Number -> Measure2dVec
+            fn mul(mut self, n: Number) -> Self::Output {
+                self *= n;
+                self
+            }
+        }
+
+        impl<Unit, Number> Div<Number> for Measure2dVec<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            type Output = Self;
+
+            /// Measure2dVec / Number -> Measure2dVec
+            fn div(mut self, n: Number) -> Self::Output {
+                self /= n;
+                self
+            }
+        }
+
+        impl<Unit, Number> fmt::Debug for Measure2dVec<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// format!("{:?}", Measure2dVec)
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.debug_list().entries(self.iter()).finish()
+            }
+        }
+    };
+}
@@ -3,18 +3,45 @@ macro_rules! inner_define_measure_3d {
     { $with_approx:ident $with_serde:ident } => {
         /// 3D relative measure with generic unit of measurement, generic value type,
         /// and with 3 dynamic components.
+        #[repr(transparent)]
         pub struct Measure3d<Unit, Number = f64>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             pub values: [Number; 3],
             phantom: PhantomData<Unit>,
         }
 
+        // SAFETY: the struct is `repr(transparent)`, and every value of its field is valid.
+        unsafe impl<Unit, Number> measures::traits::Transparent<[Number; 3]> for Measure3d<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+        }
+
+        measures::if_bytemuck! {
+            // SAFETY: the struct is `repr(transparent)`, and its field is `Zeroable`.
+            unsafe impl<Unit, Number> measures::bytemuck::Zeroable for Measure3d<Unit, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps + measures::bytemuck::Zeroable,
+            {
+            }
+
+            // SAFETY: the struct is `repr(transparent)`, and its field is `Pod`.
+            unsafe impl<Unit, Number> measures::bytemuck::Pod for Measure3d<Unit, Number>
+            where
+                Unit: VectorMeasurementUnit + 'static,
+                Number: ArithmeticOps + measures::bytemuck::Pod,
+            {
+            }
+        }
+
         impl<Unit, Number> Measure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// Measure3d::new([Number; 3]) -> Measure3d
@@ -43,7 +70,7 @@ macro_rules! inner_define_measure_3d {
             /// Measure3d.convert() -> Measure3d
             pub fn convert<DestUnit>(self) -> Measure3d<DestUnit, Number>
             where
-                DestUnit: MeasurementUnit<Property = Unit::Property>,
+                DestUnit: VectorMeasurementUnit<Property = Unit::Property>,
             {
                 let factor = Number::from_f64(Unit::RATIO / DestUnit::RATIO);
                 Measure3d::<DestUnit, Number>::new([
@@ -103,7 +130,7 @@ macro_rules! inner_define_measure_3d {
 
         impl<Unit, Number> Default for Measure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// Measure3d::default() -> Measure3d
@@ -115,7 +142,7 @@ macro_rules! inner_define_measure_3d {
 
         impl<Unit, Number> From<[Measure<Unit, Number>; 3]> for Measure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// Measure3d::from([Measure; 3]) -> Measure3d
@@ -130,7 +157,7 @@ macro_rules! inner_define_measure_3d {
 
         impl<Unit> From<Measure3d<Unit, f32>> for Measure3d<Unit, f64>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
         {
             /// Measure3d<f64>::from(Measure3d<f32>) -> Measure3d<f64>
             fn from(measure: Measure3d<Unit, f32>) -> Self {
@@ -145,7 +172,7 @@ macro_rules! inner_define_measure_3d {
         measures::if_all_true! { { $with_approx }
             impl<Unit, Number> From<ApproxMeasure3d<Unit, Number>> for Measure3d<Unit, Number>
             where
-                Unit: MeasurementUnit<Property: VectorProperty>,
+                Unit: VectorMeasurementUnit,
                 Number: ArithmeticOps,
             {
                 /// Measure3d::from(ApproxMeasure3d) -> Measure3d
@@ -158,7 +185,7 @@ macro_rules! inner_define_measure_3d {
         measures::if_all_true! { { $with_serde }
             impl<Unit, Number> serde::Serialize for Measure3d<Unit, Number>
             where
-                Unit: MeasurementUnit<Property: VectorProperty>,
+                Unit: VectorMeasurementUnit,
                 Number: ArithmeticOps + serde::Serialize,
             {
                 fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -171,7 +198,7 @@ macro_rules! inner_define_measure_3d {
 
             impl<'de, Unit, Number> serde::Deserialize<'de> for Measure3d<Unit, Number>
             where
-                Unit: MeasurementUnit<Property: VectorProperty>,
+                Unit: VectorMeasurementUnit,
                 Number: ArithmeticOps + serde::Deserialize<'de>,
             {
                 fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
@@ -185,7 +212,7 @@ macro_rules! inner_define_measure_3d {
 
         impl<Unit, Number> Neg for Measure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = Self;
@@ -198,7 +225,7 @@ macro_rules! inner_define_measure_3d {
 
         impl<Unit, Number> Add<Measure3d<Unit, Number>> for Measure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = Self;
@@ -215,7 +242,7 @@ macro_rules! inner_define_measure_3d {
 
         impl<Unit, Number> AddAssign<Measure3d<Unit, Number>> for Measure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// Measure3d += Measure3d
@@ -228,7 +255,7 @@ macro_rules! inner_define_measure_3d {
 
         impl<Unit, Number> Sub<Measure3d<Unit, Number>> for Measure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = Self;
@@ -245,7 +272,7 @@ macro_rules! inner_define_measure_3d {
 
         impl<Unit, Number> SubAssign<Measure3d<Unit, Number>> for Measure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// Measure3d -= Measure3d
@@ -258,7 +285,7 @@ macro_rules! inner_define_measure_3d {
 
         impl<Unit, Number> Mul<Number> for Measure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = Self;
@@ -271,7 +298,7 @@ macro_rules! inner_define_measure_3d {
 
         impl<Unit, Number> Mul<Measure<One, Number>> for Measure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = Self;
@@ -288,7 +315,7 @@ macro_rules! inner_define_measure_3d {
 
         impl<Unit, Number> Mul<Measure3d<Unit, Number>> for Measure<One, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = Measure3d<Unit, Number>;
@@ -305,7 +332,7 @@ macro_rules! inner_define_measure_3d {
 
         impl<Unit, Number> MulAssign<Number> for Measure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// Measure3d *= Number
@@ -318,7 +345,7 @@ macro_rules! inner_define_measure_3d {
 
         impl<Unit, Number> MulAssign<Measure<One, Number>> for Measure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// Measure3d *= Measure<One>
@@ -331,7 +358,7 @@ macro_rules! inner_define_measure_3d {
 
         impl<Unit> Mul<Measure3d<Unit, f64>> for f64
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
         {
             type Output = Measure3d<Unit, f64>;
 
@@ -347,7 +374,7 @@ macro_rules! inner_define_measure_3d {
 
         impl<Unit> Mul<Measure3d<Unit, f32>> for f32
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
         {
             type Output = Measure3d<Unit, f32>;
 
@@ -363,7 +390,7 @@ macro_rules! inner_define_measure_3d {
 
         impl<Unit, Number> Div<Number> for Measure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = Self;
@@ -381,7 +408,7 @@ macro_rules! inner_define_measure_3d {
 
         impl<Unit, Number> DivAssign<Number> for Measure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// Measure3d /= Number
@@ -395,7 +422,7 @@ macro_rules! inner_define_measure_3d {
 
         impl<Unit, Number> Div<Measure<Unit, Number>> for Measure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = Measure3d<One, Number>;
@@ -412,7 +439,7 @@ macro_rules! inner_define_measure_3d {
 
         impl<Unit, Number> DivAssign<Measure<One, Number>> for Measure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// Measure3d /= Measure<One>
@@ -425,7 +452,7 @@ macro_rules! inner_define_measure_3d {
 
         impl<Unit, Number> PartialEq<Measure3d<Unit, Number>> for Measure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// Measure3d == Measure3d -> bool
@@ -436,7 +463,7 @@ macro_rules! inner_define_measure_3d {
 
         impl<Unit, Number> Clone for Measure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// Measure3d.clone() -> Measure3d
@@ -448,14 +475,14 @@ macro_rules! inner_define_measure_3d {
         /// Measure3d = Measure3d
         impl<Unit, Number> Copy for Measure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
         }
 
         impl<Unit, Number> fmt::Display for Measure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// format!("{}", Measure3d) -> String
@@ -474,7 +501,7 @@ macro_rules! inner_define_measure_3d {
 
         impl<Unit, Number> fmt::Debug for Measure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// format!("{:?}", Measure3d)
@@ -492,7 +519,7 @@ macro_rules! inner_define_measure_3d {
 
         impl<Unit, Number> fmt::LowerExp for Measure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// format!("{:e}", Measure3d) -> String
@@ -510,7 +537,7 @@ macro_rules! inner_define_measure_3d {
 
         impl<Unit, Number> fmt::UpperExp for Measure3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// format!("{:E}", Measure3d) -> String
@@ -0,0 +1,435 @@
+#[macro_export] // Don't add nor remove the first three lines and the last two lines.
+macro_rules! inner_define_measure_3d_vec {
+    {} => {
+        /// Growable sequence of 3D relative measures having the same unit of measurement.
+        /// Every component is stored in a distinct contiguous sequence (a "structure of arrays"),
+        /// and so the components can be viewed as slices of numbers,
+        /// to be passed to numeric libraries without copying them.
+        pub struct Measure3dVec<Unit, Number = f64>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            x: measures::alloc::vec::Vec<Number>,
+            y: measures::alloc::vec::Vec<Number>,
+            z: measures::alloc::vec::Vec<Number>,
+            phantom: PhantomData<Unit>,
+        }
+
+        impl<Unit, Number> Measure3dVec<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// Measure3dVec::new() -> Measure3dVec
+            /// It returns an empty sequence.
+            pub fn new() -> Self {
+                Self::from_components(
+                    measures::alloc::vec::Vec::new(),
+                    measures::alloc::vec::Vec::new(),
+                    measures::alloc::vec::Vec::new(),
+                )
+            }
+
+            /// Measure3dVec::with_capacity(usize) -> Measure3dVec
+            pub fn with_capacity(capacity: usize) -> Self {
+                Self::from_components(
+                    measures::alloc::vec::Vec::with_capacity(capacity),
+                    measures::alloc::vec::Vec::with_capacity(capacity),
+                    measures::alloc::vec::Vec::with_capacity(capacity),
+                )
+            }
+
+            /// Measure3dVec::from_components(Vec<Number>, Vec<Number>, Vec<Number>) -> Measure3dVec
+            /// The numbers are the components of the measures, in the unit `Unit`.
+            /// It panics if the sequences have different lengths.
+            pub fn from_components(
+                x: measures::alloc::vec::Vec<Number>,
+                y: measures::alloc::vec::Vec<Number>,
+                z: measures::alloc::vec::Vec<Number>,
+            ) -> Self {
+                assert_eq!(
+                    x.len(),
+                    y.len(),
+                    "the components of the measures have different lengths"
+                );
+                assert_eq!(
+                    x.len(),
+                    z.len(),
+                    "the components of the measures have different lengths"
+                );
+                Self {
+                    x,
+                    y,
+                    z,
+                    phantom: PhantomData,
+                }
+            }
+
+            /// Measure3dVec.into_components() -> (Vec<Number>, Vec<Number>, Vec<Number>)
+            pub fn into_components(
+                self,
+            ) -> (
+                measures::alloc::vec::Vec<Number>,
+                measures::alloc::vec::Vec<Number>,
+                measures::alloc::vec::Vec<Number>,
+            ) {
+                (self.x, self.y, self.z)
+            }
+
+            /// Measure3dVec.x_values() -> &[Number]
+            pub fn x_values(&self) -> &[Number] {
+                &self.x
+            }
+
+            /// Measure3dVec.x_values_mut() -> &mut [Number]
+            pub fn x_values_mut(&mut self) -> &mut [Number] {
+                &mut self.x
+            }
+
+            /// Measure3dVec.y_values() -> &[Number]
+            pub fn y_values(&self) -> &[Number] {
+                &self.y
+            }
+
+            /// Measure3dVec.y_values_mut() -> &mut [Number]
+            pub fn y_values_mut(&mut self) -> &mut [Number] {
+                &mut self.y
+            }
+
+            /// Measure3dVec.z_values() -> &[Number]
+            pub fn z_values(&self) -> &[Number] {
+                &self.z
+            }
+
+            /// Measure3dVec.z_values_mut() -> &mut [Number]
+            pub fn z_values_mut(&mut self) -> &mut [Number] {
+                &mut self.z
+            }
+
+            /// Measure3dVec.len() -> usize
+            pub fn len(&self) -> usize {
+                self.x.len()
+            }
+
+            /// Measure3dVec.is_empty() -> bool
+            pub fn is_empty(&self) -> bool {
+                self.x.is_empty()
+            }
+
+            /// Measure3dVec.push(Measure3d)
+            pub fn push(&mut self, measure: Measure3d<Unit, Number>) {
+                self.x.push(measure.values[0]);
+                self.y.push(measure.values[1]);
+                self.z.push(measure.values[2]);
+            }
+
+            /// Measure3dVec.get(usize) -> Option<Measure3d>
+            pub fn get(&self, index: usize) -> Option<Measure3d<Unit, Number>> {
+                if index < self.len() {
+                    Some(Measure3d::<Unit, Number>::new([
+                        self.x[index],
+                        self.y[index],
+                        self.z[index],
+                    ]))
+                } else {
+                    None
+                }
+            }
+
+            /// Measure3dVec.set(usize, Measure3d)
+            /// It panics if the index is out of bounds.
+            pub fn set(&mut self, index: usize, measure: Measure3d<Unit, Number>) {
+                self.x[index] = measure.values[0];
+                self.y[index] = measure.values[1];
+                self.z[index] = measure.values[2];
+            }
+
+            /// Measure3dVec.iter() -> impl Iterator<Item = Measure3d>
+            pub fn iter(&self) -> impl Iterator<Item = Measure3d<Unit, Number>> + '_ {
+                self.x
+                    .iter()
+                    .zip(&self.y)
+                    .zip(&self.z)
+                    .map(|((&x, &y), &z)| Measure3d::<Unit, Number>::new([x, y, z]))
+            }
+
+            /// Measure3dVec.convert() -> Measure3dVec
+            /// The components are converted in place, without allocating memory.
+            pub fn convert<DestUnit>(mut self) -> Measure3dVec<DestUnit, Number>
+            where
+                DestUnit: VectorMeasurementUnit<Property = Unit::Property>,
+            {
+                let factor = Number::from_f64(Unit::RATIO / DestUnit::RATIO);
+                for value in &mut self.x {
+                    *value *= factor;
+                }
+                for value in &mut self.y {
+                    *value *= factor;
+                }
+                for value in &mut self.z {
+                    *value *= factor;
+                }
+                Measure3dVec::<DestUnit, Number>::from_components(self.x, self.y, self.z)
+            }
+
+            /// Measure3dVec.norms() -> MeasureVec
+            /// It returns the norms of all the measures.
+            pub fn norms(&self) -> MeasureVec<Unit, Number> {
+                MeasureVec::<Unit, Number>::from_values(
+                    self.x
+                        .iter()
+                        .zip(&self.y)
+                        .zip(&self.z)
+                        .map(|((&x, &y), &z)| (x * x + y * y + z * z).sqrt())
+                        .collect(),
+                )
+            }
+
+            // It applies the operation to every component and to the corresponding component of `other`.
+            fn zip_apply(&mut self, other: &Self, operation: impl Fn(&mut Number, Number)) {
+                assert_eq!(
+                    self.len(),
+                    other.len(),
+                    "the sequences of measures have different lengths"
+                );
+                for (value, &other_value) in self.x.iter_mut().zip(&other.x) {
+                    operation(value, other_value);
+                }
+                for (value, &other_value) in self.y.iter_mut().zip(&other.y) {
+                    operation(value, other_value);
+                }
+                for (value, &other_value) in self.z.iter_mut().zip(&other.z) {
+                    operation(value, other_value);
+                }
+            }
+        }
+
+        impl<Unit, Number> Default for Measure3dVec<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// Measure3dVec::default() -> Measure3dVec
+            /// It returns an empty sequence.
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl<Unit, Number> Clone for Measure3dVec<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            fn clone(&self) -> Self {
+                Self::from_components(self.x.clone(), self.y.clone(), self.z.clone())
+            }
+        }
+
+        impl<Unit, Number> PartialEq for Measure3dVec<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            fn eq(&self, other: &Self) -> bool {
+                self.x == other.x && self.y == other.y && self.z == other.z
+            }
+        }
+
+        impl<Unit, Number> core::iter::FromIterator<Measure3d<Unit, Number>> for Measure3dVec<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            fn from_iter<I: IntoIterator<Item = Measure3d<Unit, Number>>>(iter: I) -> Self {
+                let mut result = Self::new();
+                result.extend(iter);
+                result
+            }
+        }
+
+        impl<Unit, Number> Extend<Measure3d<Unit, Number>> for Measure3dVec<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            fn extend<I: IntoIterator<Item = Measure3d<Unit, Number>>>(&mut self, iter: I) {
+                for measure in iter {
+                    self.push(measure);
+                }
+            }
+        }
+
+        impl<Unit, Number> AddAssign<&Measure3dVec<Unit, Number>> for Measure3dVec<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// Measure3dVec += &Measure3dVec
+            /// It panics if the sequences have different lengths.
+            fn add_assign(&mut self, other: &Measure3dVec<Unit, Number>) {
+                self.zip_apply(other, |value, other_value| *value += other_value);
+            }
+        }
+
+        impl<Unit, Number> SubAssign<&Measure3dVec<Unit, Number>> for Measure3dVec<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// Measure3dVec -= &Measure3dVec
+            /// It panics if the sequences have different lengths.
+            fn sub_assign(&mut self, other: &Measure3dVec<Unit, Number>) {
+                self.zip_apply(other, |value, other_value| *value -= other_value);
+            }
+        }
+
+        impl<Unit, Number> Add<&Measure3dVec<Unit, Number>> for Measure3dVec<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            type Output = Self;
+
+            /// Measure3dVec + &Measure3dVec -> Measure3dVec
+            /// It panics if the sequences have different lengths.
+            fn add(mut self, other: &Measure3dVec<Unit, Number>) -> Self::Output {
+                self += other;
+                self
+            }
+        }
+
+        impl<Unit, Number> Sub<&Measure3dVec<Unit, Number>> for Measure3dVec<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            type Output = Self;
+
+            /// Measure3dVec - &Measure3dVec -> Measure3dVec
+            /// It panics if the sequences have different lengths.
+            fn sub(mut self, other: &Measure3dVec<Unit, Number>) -> Self::Output {
+                self -= other;
+                self
+            }
+        }
+
+        impl<Unit, Number> AddAssign<Measure3d<Unit, Number>> for Measure3dVec<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// Measure3dVec += Measure3d
+            /// It adds the measure to every item.
+            fn add_assign(&mut self, other: Measure3d<Unit, Number>) {
+                for value in &mut self.x {
+                    *value += other.values[0];
+                }
+                for value in &mut self.y {
+                    *value += other.values[1];
+                }
+                for value in &mut self.z {
+                    *value += other.values[2];
+                }
+            }
+        }
+
+        impl<Unit, Number> SubAssign<Measure3d<Unit, Number>> for Measure3dVec<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// Measure3dVec -= Measure3d
+            /// It subtracts the measure from every item.
+            fn sub_assign(&mut self, other: Measure3d<Unit, Number>) {
+                for value in &mut self.x {
+                    *value -= other.values[0];
+                }
+                for value in &mut self.y {
+                    *value -= other.values[1];
+                }
+                for value in &mut self.z {
+                    *value -= other.values[2];
+                }
+            }
+        }
+
+        impl<Unit, Number> MulAssign<Number> for Measure3dVec<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// Measure3dVec *= Number
+            fn mul_assign(&mut self, n: Number) {
+                for value in &mut self.x {
+                    *value *= n;
+                }
+                for value in &mut self.y {
+                    *value *= n;
+                }
+                for value in &mut self.z {
+                    *value *= n;
+                }
+            }
+        }
+
+        impl<Unit, Number> DivAssign<Number> for Measure3dVec<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// Measure3dVec /= Number
+            fn div_assign(&mut self, n: Number) {
+                for value in &mut self.x {
+                    *value /= n;
+                }
+                for value in &mut self.y {
+                    *value /= n;
+                }
+                for value in &mut self.z {
+                    *value /= n;
+                }
+            }
+        }
+
+        impl<Unit, Number> Mul<Number> for Measure3dVec<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            type Output = Self;
+
+            /// Measure3dVec * Number -> Measure3dVec
+            fn mul(mut self, n: Number) -> Self::Output {
+                self *= n;
+                self
+            }
+        }
+
+        impl<Unit, Number> Div<Number> for Measure3dVec<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            type Output = Self;
+
+            /// Measure3dVec / Number -> Measure3dVec
+            fn div(mut self, n: Number) -> Self::Output {
+                self /= n;
+                self
+            }
+        }
+
+        impl<Unit, Number> fmt::Debug for Measure3dVec<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// format!("{:?}", Measure3dVec)
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.debug_list().entries(self.iter()).finish()
+            }
+        }
+    };
+}
@@ -0,0 +1,605 @@
+#[macro_export] // Don't add nor remove the first three lines and the last two lines.
+macro_rules! inner_define_measure_n {
+    { $with_2d:ident $with_3d:ident $with_serde:ident } => {
+        /// N-dimensional relative measure with generic unit of measurement,
+        /// with N static components, and generic value type.
+        /// The number of dimensions is a const generic parameter,
+        /// which precedes `Number` only because `Number` has a default value.
+        /// It is useful for state vectors having more than 3 components, all in the same unit.
+        #[repr(transparent)]
+        pub struct MeasureN<Unit, const N: usize, Number = f64>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            pub values: [Number; N],
+            phantom: PhantomData<Unit>,
+        }
+
+        // SAFETY: the struct is `repr(transparent)`, and every value of its field is valid.
+        unsafe impl<Unit, const N: usize, Number> measures::traits::Transparent<[Number; N]>
+            for MeasureN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+        }
+
+        measures::if_bytemuck! {
+            // SAFETY: the struct is `repr(transparent)`, and its field is `Zeroable`.
+            unsafe impl<Unit, const N: usize, Number> measures::bytemuck::Zeroable for MeasureN<Unit, N, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps + measures::bytemuck::Zeroable,
+            {
+            }
+
+            // SAFETY: the struct is `repr(transparent)`, and its field is `Pod`.
+            unsafe impl<Unit, const N: usize, Number> measures::bytemuck::Pod for MeasureN<Unit, N, Number>
+            where
+                Unit: VectorMeasurementUnit + 'static,
+                Number: ArithmeticOps + measures::bytemuck::Pod,
+            {
+            }
+        }
+
+        impl<Unit, const N: usize, Number> MeasureN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// MeasureN::new([Number; N]) -> MeasureN
+            pub const fn new(values: [Number; N]) -> Self {
+                Self {
+                    values,
+                    phantom: PhantomData,
+                }
+            }
+
+            /// MeasureN.component(usize) -> Measure
+            /// It panics if `index` is not less than `N`.
+            pub const fn component(self, index: usize) -> Measure<Unit, Number> {
+                Measure::<Unit, Number>::new(self.values[index])
+            }
+
+            /// MeasureN.convert() -> MeasureN
+            pub fn convert<DestUnit>(self) -> MeasureN<DestUnit, N, Number>
+            where
+                DestUnit: VectorMeasurementUnit<Property = Unit::Property>,
+            {
+                let factor = Number::from_f64(Unit::RATIO / DestUnit::RATIO);
+                MeasureN::<DestUnit, N, Number>::new(self.values.map(|value| value * factor))
+            }
+
+            /// MeasureN.lossless_into() -> MeasureN
+            pub fn lossless_into<DestNumber>(self) -> MeasureN<Unit, N, DestNumber>
+            where
+                DestNumber: ArithmeticOps + From<Number>,
+            {
+                MeasureN::<Unit, N, DestNumber>::new(self.values.map(DestNumber::from))
+            }
+
+            /// MeasureN.lossy_into() -> MeasureN
+            pub fn lossy_into<DestNumber>(self) -> MeasureN<Unit, N, DestNumber>
+            where
+                DestNumber: ArithmeticOps + LossyFrom<Number>,
+            {
+                MeasureN::<Unit, N, DestNumber>::new(self.values.map(DestNumber::lossy_from))
+            }
+
+            /// MeasureN.norm() -> Measure
+            pub fn norm(self) -> Measure<Unit, Number> {
+                Measure::<Unit, Number>::new(self.squared_norm().sqrt())
+            }
+
+            /// MeasureN.squared_norm() -> Number
+            pub fn squared_norm(self) -> Number {
+                self.values
+                    .iter()
+                    .fold(Number::ZERO, |sum, &value| sum + value * value)
+            }
+
+            /// MeasureN.normalized() -> MeasureN
+            pub fn normalized(self) -> Self {
+                let k = Number::ONE / self.squared_norm().sqrt();
+                Self::new(self.values.map(|value| value * k))
+            }
+        }
+
+        impl<Unit, const N: usize, Number> Default for MeasureN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// MeasureN::default() -> MeasureN
+            /// It returns the zero vector.
+            fn default() -> Self {
+                Self::new([Number::ZERO; N])
+            }
+        }
+
+        impl<Unit, const N: usize, Number> From<[Measure<Unit, Number>; N]> for MeasureN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// MeasureN::from([Measure; N]) -> MeasureN
+            fn from(measures: [Measure<Unit, Number>; N]) -> Self {
+                Self::new(measures.map(|measure| measure.value))
+            }
+        }
+
+        impl<Unit, const N: usize> From<MeasureN<Unit, N, f32>> for MeasureN<Unit, N, f64>
+        where
+            Unit: VectorMeasurementUnit,
+        {
+            /// MeasureN<f64>::from(MeasureN<f32>) -> MeasureN<f64>
+            fn from(measure: MeasureN<Unit, N, f32>) -> Self {
+                Self::new(measure.values.map(|value| value as f64))
+            }
+        }
+
+        impl<Unit, Number> From<Measure<Unit, Number>> for MeasureN<Unit, 1, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// MeasureN::<1>::from(Measure) -> MeasureN<1>
+            fn from(measure: Measure<Unit, Number>) -> Self {
+                Self::new([measure.value])
+            }
+        }
+
+        impl<Unit, Number> From<MeasureN<Unit, 1, Number>> for Measure<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// Measure::from(MeasureN<1>) -> Measure
+            fn from(measure: MeasureN<Unit, 1, Number>) -> Self {
+                Self::new(measure.values[0])
+            }
+        }
+
+        measures::if_all_true! { { $with_2d }
+            impl<Unit, Number> From<Measure2d<Unit, Number>> for MeasureN<Unit, 2, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                /// MeasureN::<2>::from(Measure2d) -> MeasureN<2>
+                fn from(measure: Measure2d<Unit, Number>) -> Self {
+                    Self::new(measure.values)
+                }
+            }
+
+            impl<Unit, Number> From<MeasureN<Unit, 2, Number>> for Measure2d<Unit, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                /// Measure2d::from(MeasureN<2>) -> Measure2d
+                fn from(measure: MeasureN<Unit, 2, Number>) -> Self {
+                    Self::new(measure.values)
+                }
+            }
+        }
+
+        measures::if_all_true! { { $with_3d }
+            impl<Unit, Number> From<Measure3d<Unit, Number>> for MeasureN<Unit, 3, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                /// MeasureN::<3>::from(Measure3d) -> MeasureN<3>
+                fn from(measure: Measure3d<Unit, Number>) -> Self {
+                    Self::new(measure.values)
+                }
+            }
+
+            impl<Unit, Number> From<MeasureN<Unit, 3, Number>> for Measure3d<Unit, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                /// Measure3d::from(MeasureN<3>) -> Measure3d
+                fn from(measure: MeasureN<Unit, 3, Number>) -> Self {
+                    Self::new(measure.values)
+                }
+            }
+        }
+
+        measures::if_all_true! { { $with_serde }
+            // Serde implements its traits only for arrays having at most 32 items,
+            // and so a `MeasureN` is serialized as a sequence.
+            impl<Unit, const N: usize, Number> serde::Serialize for MeasureN<Unit, N, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps + serde::Serialize,
+            {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: serde::Serializer
+                {
+                    self.values.as_slice().serialize(serializer)
+                }
+            }
+
+            impl<'de, Unit, const N: usize, Number> serde::Deserialize<'de> for MeasureN<Unit, N, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps + serde::Deserialize<'de>,
+            {
+                fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+                where
+                    De: serde::Deserializer<'de>,
+                {
+                    // It fills an array, so that no memory is allocated.
+                    struct ValuesVisitor<Number, const N: usize>(PhantomData<Number>);
+
+                    impl<'de, Number, const N: usize> serde::de::Visitor<'de> for ValuesVisitor<Number, N>
+                    where
+                        Number: ArithmeticOps + serde::Deserialize<'de>,
+                    {
+                        type Value = [Number; N];
+
+                        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                            formatter.write_str("as many values as the dimensions")
+                        }
+
+                        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+                        where
+                            A: serde::de::SeqAccess<'de>,
+                        {
+                            let mut values = [Number::ZERO; N];
+                            for (i, value) in values.iter_mut().enumerate() {
+                                *value = seq
+                                    .next_element()?
+                                    .ok_or_else(|| serde::de::Error::invalid_length(i, &self))?;
+                            }
+                            if seq.next_element::<Number>()?.is_some() {
+                                return Err(serde::de::Error::invalid_length(N + 1, &self));
+                            }
+                            Ok(values)
+                        }
+                    }
+
+                    Ok(Self::new(deserializer.deserialize_seq(ValuesVisitor::<Number, N>(PhantomData))?))
+                }
+            }
+        }
+
+        impl<Unit, const N: usize, Number> Neg for MeasureN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            type Output = Self;
+
+            /// -MeasureN -> MeasureN
+            fn neg(self) -> Self::Output {
+                Self::new(self.values.map(|value| -value))
+            }
+        }
+
+        impl<Unit, const N: usize, Number> Add<MeasureN<Unit, N, Number>> for MeasureN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            type Output = Self;
+
+            /// MeasureN + MeasureN -> MeasureN
+            fn add(self, other: MeasureN<Unit, N, Number>) -> Self::Output {
+                Self::new(core::array::from_fn(|i| self.values[i] + other.values[i]))
+            }
+        }
+
+        impl<Unit, const N: usize, Number> AddAssign<MeasureN<Unit, N, Number>>
+            for MeasureN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// MeasureN += MeasureN
+            fn add_assign(&mut self, other: MeasureN<Unit, N, Number>) {
+                for (value, other_value) in self.values.iter_mut().zip(other.values) {
+                    *value += other_value;
+                }
+            }
+        }
+
+        impl<Unit, const N: usize, Number> Sub<MeasureN<Unit, N, Number>> for MeasureN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            type Output = Self;
+
+            /// MeasureN - MeasureN -> MeasureN
+            fn sub(self, other: MeasureN<Unit, N, Number>) -> Self::Output {
+                Self::new(core::array::from_fn(|i| self.values[i] - other.values[i]))
+            }
+        }
+
+        impl<Unit, const N: usize, Number> SubAssign<MeasureN<Unit, N, Number>>
+            for MeasureN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// MeasureN -= MeasureN
+            fn sub_assign(&mut self, other: MeasureN<Unit, N, Number>) {
+                for (value, other_value) in self.values.iter_mut().zip(other.values) {
+                    *value -= other_value;
+                }
+            }
+        }
+
+        impl<Unit, const N: usize, Number> Mul<Number> for MeasureN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            type Output = Self;
+
+            /// MeasureN * Number -> MeasureN
+            fn mul(self, n: Number) -> Self::Output {
+                Self::new(self.values.map(|value| value * n))
+            }
+        }
+
+        impl<Unit, const N: usize, Number> MulAssign<Number> for MeasureN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// MeasureN *= Number
+            fn mul_assign(&mut self, n: Number) {
+                for value in self.values.iter_mut() {
+                    *value *= n;
+                }
+            }
+        }
+
+        impl<Unit, const N: usize> Mul<MeasureN<Unit, N, f64>> for f64
+        where
+            Unit: VectorMeasurementUnit,
+        {
+            type Output = MeasureN<Unit, N, f64>;
+
+            /// f64 * MeasureN -> MeasureN
+            fn mul(self, other: MeasureN<Unit, N, f64>) -> Self::Output {
+                Self::Output::new(other.values.map(|value| self * value))
+            }
+        }
+
+        impl<Unit, const N: usize> Mul<MeasureN<Unit, N, f32>> for f32
+        where
+            Unit: VectorMeasurementUnit,
+        {
+            type Output = MeasureN<Unit, N, f32>;
+
+            /// f32 * MeasureN -> MeasureN
+            fn mul(self, other: MeasureN<Unit, N, f32>) -> Self::Output {
+                Self::Output::new(other.values.map(|value| self * value))
+            }
+        }
+
+        impl<Unit, const N: usize, Number> Div<Number> for MeasureN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            type Output = Self;
+
+            /// MeasureN / Number -> MeasureN
+            fn div(self, n: Number) -> Self::Output {
+                Self::new(self.values.map(|value| value / n))
+            }
+        }
+
+        impl<Unit, const N: usize, Number> DivAssign<Number> for MeasureN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// MeasureN /= Number
+            fn div_assign(&mut self, n: Number) {
+                for value in self.values.iter_mut() {
+                    *value /= n;
+                }
+            }
+        }
+
+        // The products and the quotients involving a `MeasureN` are derived
+        // from the ones between 1-dimensional measures,
+        // and so they are available for every declared relationship between units,
+        // without needing to declare relationships for every dimension.
+
+        impl<Unit, OtherUnit, ResultUnit, const N: usize, Number> Mul<Measure<OtherUnit, Number>>
+            for MeasureN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            OtherUnit: MeasurementUnit,
+            ResultUnit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+            Measure<Unit, Number>: Mul<Measure<OtherUnit, Number>, Output = Measure<ResultUnit, Number>>,
+        {
+            type Output = MeasureN<ResultUnit, N, Number>;
+
+            /// MeasureN * Measure -> MeasureN
+            fn mul(self, other: Measure<OtherUnit, Number>) -> Self::Output {
+                Self::Output::new(
+                    self.values
+                        .map(|value| (Measure::<Unit, Number>::new(value) * other).value),
+                )
+            }
+        }
+
+        impl<Unit, OtherUnit, ResultUnit, const N: usize, Number> Mul<MeasureN<OtherUnit, N, Number>>
+            for Measure<Unit, Number>
+        where
+            Unit: MeasurementUnit,
+            OtherUnit: VectorMeasurementUnit,
+            ResultUnit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+            Measure<Unit, Number>: Mul<Measure<OtherUnit, Number>, Output = Measure<ResultUnit, Number>>,
+        {
+            type Output = MeasureN<ResultUnit, N, Number>;
+
+            /// Measure * MeasureN -> MeasureN
+            fn mul(self, other: MeasureN<OtherUnit, N, Number>) -> Self::Output {
+                Self::Output::new(
+                    other
+                        .values
+                        .map(|value| (self * Measure::<OtherUnit, Number>::new(value)).value),
+                )
+            }
+        }
+
+        impl<Unit, OtherUnit, ResultUnit, const N: usize, Number> Div<Measure<OtherUnit, Number>>
+            for MeasureN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            OtherUnit: MeasurementUnit,
+            ResultUnit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+            Measure<Unit, Number>: Div<Measure<OtherUnit, Number>, Output = Measure<ResultUnit, Number>>,
+        {
+            type Output = MeasureN<ResultUnit, N, Number>;
+
+            /// MeasureN / Measure -> MeasureN
+            fn div(self, other: Measure<OtherUnit, Number>) -> Self::Output {
+                Self::Output::new(
+                    self.values
+                        .map(|value| (Measure::<Unit, Number>::new(value) / other).value),
+                )
+            }
+        }
+
+        impl<Unit, OtherUnit, ResultUnit, const N: usize, Number> Mul<MeasureN<OtherUnit, N, Number>>
+            for MeasureN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            OtherUnit: VectorMeasurementUnit,
+            ResultUnit: MeasurementUnit,
+            Number: ArithmeticOps,
+            Measure<Unit, Number>: Mul<Measure<OtherUnit, Number>, Output = Measure<ResultUnit, Number>>,
+        {
+            type Output = Measure<ResultUnit, Number>;
+
+            /// MeasureN * MeasureN -> Measure
+            /// It is the dot product.
+            fn mul(self, other: MeasureN<OtherUnit, N, Number>) -> Self::Output {
+                self.values.iter().zip(other.values).fold(
+                    Measure::<ResultUnit, Number>::new(Number::ZERO),
+                    |sum, (&value, other_value)| {
+                        sum + Measure::<Unit, Number>::new(value)
+                            * Measure::<OtherUnit, Number>::new(other_value)
+                    },
+                )
+            }
+        }
+
+        impl<Unit, const N: usize, Number> PartialEq<MeasureN<Unit, N, Number>>
+            for MeasureN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// MeasureN == MeasureN -> bool
+            fn eq(&self, other: &MeasureN<Unit, N, Number>) -> bool {
+                self.values == other.values
+            }
+        }
+
+        impl<Unit, const N: usize, Number> Clone for MeasureN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// MeasureN.clone() -> MeasureN
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        /// MeasureN = MeasureN
+        impl<Unit, const N: usize, Number> Copy for MeasureN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+        }
+
+        impl<Unit, const N: usize, Number> fmt::Display for MeasureN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// format!("{}", MeasureN) -> String
+            /// MeasureN.to_string() -> String
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("(")?;
+                for (i, value) in self.values.iter().enumerate() {
+                    if i > 0 {
+                        formatter.write_str(", ")?;
+                    }
+                    fmt::Display::fmt(value, formatter)?;
+                }
+                formatter.write_str(")")?;
+                formatter.write_str(Unit::SUFFIX)
+            }
+        }
+
+        impl<Unit, const N: usize, Number> fmt::Debug for MeasureN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// format!("{:?}", MeasureN)
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(self, formatter)
+            }
+        }
+
+        impl<Unit, const N: usize, Number> fmt::LowerExp for MeasureN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// format!("{:e}", MeasureN) -> String
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("(")?;
+                for (i, value) in self.values.iter().enumerate() {
+                    if i > 0 {
+                        formatter.write_str(", ")?;
+                    }
+                    fmt::LowerExp::fmt(value, formatter)?;
+                }
+                formatter.write_str(")")?;
+                formatter.write_str(Unit::SUFFIX)
+            }
+        }
+
+        impl<Unit, const N: usize, Number> fmt::UpperExp for MeasureN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// format!("{:E}", MeasureN) -> String
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("(")?;
+                for (i, value) in self.values.iter().enumerate() {
+                    if i > 0 {
+                        formatter.write_str(", ")?;
+                    }
+                    fmt::UpperExp::fmt(value, formatter)?;
+                }
+                formatter.write_str(")")?;
+                formatter.write_str(Unit::SUFFIX)
+            }
+        }
+    };
+}
@@ -3,19 +3,46 @@ macro_rules! inner_define_measure_point {
     { $with_approx:ident $with_serde:ident } => {
         /// 1D absolute measure with generic unit of measurement, generic value type,
         /// and with a dynamic value.
+        #[repr(transparent)]
         pub struct MeasurePoint<Unit, Number = f64>
         where
             Unit: MeasurementUnit,
-            Number: ArithmeticOps,
+            Number: BasicArithmeticOps,
         {
             pub value: Number,
             phantom: PhantomData<Unit>,
         }
 
+        // SAFETY: the struct is `repr(transparent)`, and every value of its field is valid.
+        unsafe impl<Unit, Number> measures::traits::Transparent<Number> for MeasurePoint<Unit, Number>
+        where
+            Unit: MeasurementUnit,
+            Number: BasicArithmeticOps,
+        {
+        }
+
+        measures::if_bytemuck! {
+            // SAFETY: the struct is `repr(transparent)`, and its field is `Zeroable`.
+            unsafe impl<Unit, Number> measures::bytemuck::Zeroable for MeasurePoint<Unit, Number>
+            where
+                Unit: MeasurementUnit,
+                Number: BasicArithmeticOps + measures::bytemuck::Zeroable,
+            {
+            }
+
+            // SAFETY: the struct is `repr(transparent)`, and its field is `Pod`.
+            unsafe impl<Unit, Number> measures::bytemuck::Pod for MeasurePoint<Unit, Number>
+            where
+                Unit: MeasurementUnit + 'static,
+                Number: BasicArithmeticOps + measures::bytemuck::Pod,
+            {
+            }
+        }
+
         impl<Unit, Number> MeasurePoint<Unit, Number>
         where
             Unit: MeasurementUnit,
-            Number: ArithmeticOps,
+            Number: BasicArithmeticOps,
         {
             /// MeasurePoint::new(Number) -> MeasurePoint
             pub const fn new(value: Number) -> Self {
@@ -26,21 +53,30 @@ macro_rules! inner_define_measure_point {
             }
 
             /// MeasurePoint.convert() -> MeasurePoint
+            /// If a unit isn't linear, the value is converted by the functions of the units.
             pub fn convert<DestUnit>(self) -> MeasurePoint<DestUnit, Number>
             where
                 DestUnit: MeasurementUnit<Property = Unit::Property>,
             {
-                MeasurePoint::<DestUnit, Number> {
-                    value: self.value * Number::from_f64(Unit::RATIO / DestUnit::RATIO)
-                        + Number::from_f64((Unit::OFFSET - DestUnit::OFFSET) / DestUnit::RATIO),
-                    phantom: PhantomData,
+                if Unit::LINEAR && DestUnit::LINEAR {
+                    MeasurePoint::<DestUnit, Number> {
+                        value: self.value.scale_by_ratio(Unit::RATIO / DestUnit::RATIO)
+                            + Number::ONE
+                                .scale_by_ratio((Unit::OFFSET - DestUnit::OFFSET) / DestUnit::RATIO),
+                        phantom: PhantomData,
+                    }
+                } else {
+                    MeasurePoint::<DestUnit, Number>::new(
+                        self.value
+                            .apply_conversion(|value| DestUnit::from_base(Unit::to_base(value))),
+                    )
                 }
             }
 
             /// MeasurePoint.lossless_into() -> MeasurePoint
             pub fn lossless_into<DestNumber>(self) -> MeasurePoint<Unit, DestNumber>
             where
-                DestNumber: ArithmeticOps + From<Number>,
+                DestNumber: BasicArithmeticOps + From<Number>,
             {
                 MeasurePoint::<Unit, DestNumber>::new(DestNumber::from(self.value))
             }
@@ -48,7 +84,7 @@ macro_rules! inner_define_measure_point {
             /// MeasurePoint.lossy_into() -> MeasurePoint
             pub fn lossy_into<DestNumber>(self) -> MeasurePoint<Unit, DestNumber>
             where
-                DestNumber: ArithmeticOps + LossyFrom<Number>,
+                DestNumber: BasicArithmeticOps + LossyFrom<Number>,
             {
                 MeasurePoint::<Unit, DestNumber>::new(DestNumber::lossy_from(self.value))
             }
@@ -80,13 +116,13 @@ macro_rules! inner_define_measure_point {
         }
 
         impl<Unit: MeasurementUnit> MeasurePoint<Unit, f32> {
-            pub fn total_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            pub fn total_cmp(&self, other: &Self) -> core::cmp::Ordering {
                 self.value.total_cmp(&other.value)
             }
         }
 
         impl<Unit: MeasurementUnit> MeasurePoint<Unit, f64> {
-            pub fn total_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            pub fn total_cmp(&self, other: &Self) -> core::cmp::Ordering {
                 self.value.total_cmp(&other.value)
             }
         }
@@ -94,7 +130,7 @@ macro_rules! inner_define_measure_point {
         impl<Unit, Number> Default for MeasurePoint<Unit, Number>
         where
             Unit: MeasurementUnit,
-            Number: ArithmeticOps,
+            Number: BasicArithmeticOps,
         {
             /// MeasurePoint::default() -> MeasurePoint
             /// It returns the origin.
@@ -118,6 +154,26 @@ macro_rules! inner_define_measure_point {
             }
         }
 
+        impl<Unit, Number> MeasurePoint<Unit, Number>
+        where
+            Unit: measures::traits::AffineUnit,
+            Number: BasicArithmeticOps,
+        {
+            /// MeasurePoint.to_absolute_measure() -> Measure
+            /// It returns the difference between this point and the zero of the base unit,
+            /// like an absolute temperature, expressed in the given unit.
+            /// For example, 20 °C become 293.15 K, or 293.15 Celsius degrees of difference.
+            pub fn to_absolute_measure<DestUnit>(self) -> Measure<DestUnit, Number>
+            where
+                DestUnit: LinearMeasurementUnit<Property = Unit::Property>,
+            {
+                Measure::<DestUnit, Number>::new(
+                    self.value.scale_by_ratio(Unit::RATIO / DestUnit::RATIO)
+                        + Number::ONE.scale_by_ratio(Unit::OFFSET / DestUnit::RATIO),
+                )
+            }
+        }
+
         measures::if_all_true! { { $with_approx }
             impl<Unit, Number> From<ApproxMeasurePoint<Unit, Number>> for MeasurePoint<Unit, Number>
             where
@@ -135,7 +191,7 @@ macro_rules! inner_define_measure_point {
             impl<Unit, Number> serde::Serialize for MeasurePoint<Unit, Number>
             where
                 Unit: MeasurementUnit,
-                Number: ArithmeticOps + serde::Serialize,
+                Number: BasicArithmeticOps + serde::Serialize,
             {
                 fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
                 where
@@ -148,7 +204,7 @@ macro_rules! inner_define_measure_point {
             impl<'de, Unit, Number> serde::Deserialize<'de> for MeasurePoint<Unit, Number>
             where
                 Unit: MeasurementUnit,
-                Number: ArithmeticOps + serde::Deserialize<'de>,
+                Number: BasicArithmeticOps + serde::Deserialize<'de>,
             {
                 fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
                 where
@@ -162,7 +218,7 @@ macro_rules! inner_define_measure_point {
         impl<Unit, Number> Add<Measure<Unit, Number>> for MeasurePoint<Unit, Number>
         where
             Unit: MeasurementUnit,
-            Number: ArithmeticOps,
+            Number: BasicArithmeticOps,
         {
             type Output = Self;
 
@@ -175,7 +231,7 @@ macro_rules! inner_define_measure_point {
         impl<Unit, Number> AddAssign<Measure<Unit, Number>> for MeasurePoint<Unit, Number>
         where
             Unit: MeasurementUnit,
-            Number: ArithmeticOps,
+            Number: BasicArithmeticOps,
         {
             /// MeasurePoint += Measure
             fn add_assign(&mut self, other: Measure<Unit, Number>) {
@@ -186,7 +242,7 @@ macro_rules! inner_define_measure_point {
         impl<Unit, Number> Sub<Measure<Unit, Number>> for MeasurePoint<Unit, Number>
         where
             Unit: MeasurementUnit,
-            Number: ArithmeticOps,
+            Number: BasicArithmeticOps,
         {
             type Output = Self;
 
@@ -199,7 +255,7 @@ macro_rules! inner_define_measure_point {
         impl<Unit, Number> SubAssign<Measure<Unit, Number>> for MeasurePoint<Unit, Number>
         where
             Unit: MeasurementUnit,
-            Number: ArithmeticOps,
+            Number: BasicArithmeticOps,
         {
             /// MeasurePoint -= Measure
             fn sub_assign(&mut self, other: Measure<Unit, Number>) {
@@ -210,7 +266,7 @@ macro_rules! inner_define_measure_point {
         impl<Unit, Number> Sub<MeasurePoint<Unit, Number>> for MeasurePoint<Unit, Number>
         where
             Unit: MeasurementUnit,
-            Number: ArithmeticOps,
+            Number: BasicArithmeticOps,
         {
             type Output = Measure<Unit, Number>;
 
@@ -288,7 +344,7 @@ macro_rules! inner_define_measure_point {
         impl<Unit, Number> PartialEq<MeasurePoint<Unit, Number>> for MeasurePoint<Unit, Number>
         where
             Unit: MeasurementUnit,
-            Number: ArithmeticOps,
+            Number: BasicArithmeticOps,
         {
             /// MeasurePoint == MeasurePoint -> bool
             fn eq(&self, other: &MeasurePoint<Unit, Number>) -> bool {
@@ -299,7 +355,7 @@ macro_rules! inner_define_measure_point {
         impl<Unit, Number> PartialOrd<MeasurePoint<Unit, Number>> for MeasurePoint<Unit, Number>
         where
             Unit: MeasurementUnit,
-            Number: ArithmeticOps,
+            Number: BasicArithmeticOps,
         {
             /// MeasurePoint < MeasurePoint -> bool
             fn partial_cmp(&self, other: &MeasurePoint<Unit, Number>) -> Option<core::cmp::Ordering> {
@@ -310,7 +366,7 @@ macro_rules! inner_define_measure_point {
         impl<Unit, Number> Clone for MeasurePoint<Unit, Number>
         where
             Unit: MeasurementUnit,
-            Number: ArithmeticOps,
+            Number: BasicArithmeticOps,
         {
             /// MeasurePoint.clone() -> MeasurePoint
             fn clone(&self) -> Self {
@@ -322,14 +378,14 @@ macro_rules! inner_define_measure_point {
         impl<Unit, Number> Copy for MeasurePoint<Unit, Number>
         where
             Unit: MeasurementUnit,
-            Number: ArithmeticOps,
+            Number: BasicArithmeticOps,
         {
         }
 
         impl<Unit, Number> fmt::Display for MeasurePoint<Unit, Number>
         where
             Unit: MeasurementUnit,
-            Number: ArithmeticOps,
+            Number: BasicArithmeticOps,
         {
             /// format!("{}", MeasurePoint) -> String
             /// MeasurePoint.to_string() -> String
@@ -343,7 +399,7 @@ macro_rules! inner_define_measure_point {
         impl<Unit, Number> fmt::Debug for MeasurePoint<Unit, Number>
         where
             Unit: MeasurementUnit,
-            Number: ArithmeticOps,
+            Number: BasicArithmeticOps,
         {
             /// format!("{:?}", MeasurePoint)
             fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
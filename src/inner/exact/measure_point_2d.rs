@@ -3,20 +3,47 @@ macro_rules! inner_define_measure_point_2d {
     { $with_approx:ident $with_serde:ident } => {
         /// 2D absolute measure with generic unit of measurement, generic value type,
         /// and with 2 dynamic components.
-        /// This type does not make sense for Unit::OFFSET != 0.
-        /// Though, the language does not allow such constraint yet.
+        /// Its unit must be a `VectorMeasurementUnit`, so it cannot have an offset.
+        #[repr(transparent)]
         pub struct MeasurePoint2d<Unit, Number = f64>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             pub values: [Number; 2],
             phantom: PhantomData<Unit>,
         }
 
+        // SAFETY: the struct is `repr(transparent)`, and every value of its field is valid.
+        unsafe impl<Unit, Number> measures::traits::Transparent<[Number; 2]>
+            for MeasurePoint2d<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+        }
+
+        measures::if_bytemuck! {
+            // SAFETY: the struct is `repr(transparent)`, and its field is `Zeroable`.
+            unsafe impl<Unit, Number> measures::bytemuck::Zeroable for MeasurePoint2d<Unit, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps + measures::bytemuck::Zeroable,
+            {
+            }
+
+            // SAFETY: the struct is `repr(transparent)`, and its field is `Pod`.
+            unsafe impl<Unit, Number> measures::bytemuck::Pod for MeasurePoint2d<Unit, Number>
+            where
+                Unit: VectorMeasurementUnit + 'static,
+                Number: ArithmeticOps + measures::bytemuck::Pod,
+            {
+            }
+        }
+
         impl<Unit, Number> MeasurePoint2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// MeasurePoint2d::new([Number; 2]) -> MeasurePoint2d
@@ -40,10 +67,8 @@ macro_rules! inner_define_measure_point_2d {
             /// MeasurePoint2d.convert() -> MeasurePoint2d
             pub fn convert<DestUnit>(self) -> MeasurePoint2d<DestUnit, Number>
             where
-                DestUnit: MeasurementUnit<Property = Unit::Property>,
+                DestUnit: VectorMeasurementUnit<Property = Unit::Property>,
             {
-                debug_assert!(Unit::OFFSET == 0.);
-                debug_assert!(DestUnit::OFFSET == 0.);
                 let factor = Number::from_f64(Unit::RATIO / DestUnit::RATIO);
                 MeasurePoint2d::<DestUnit, Number>::new([self.values[0] * factor, self.values[1] * factor])
             }
@@ -73,7 +98,7 @@ macro_rules! inner_define_measure_point_2d {
 
         impl<Unit, Number> Default for MeasurePoint2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// MeasurePoint2d::default() -> MeasurePoint2d
@@ -85,7 +110,7 @@ macro_rules! inner_define_measure_point_2d {
 
         impl<Unit, Number> From<[MeasurePoint<Unit, Number>; 2]> for MeasurePoint2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// MeasurePoint2d::from([MeasurePoint; 2]) -> MeasurePoint2d
@@ -96,7 +121,7 @@ macro_rules! inner_define_measure_point_2d {
 
         impl<Unit> From<MeasurePoint2d<Unit, f32>> for MeasurePoint2d<Unit, f64>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
         {
             /// MeasurePoint2d<f64>::from(MeasurePoint2d<f32>) -> MeasurePoint2d<f64>
             fn from(m: MeasurePoint2d<Unit, f32>) -> Self {
@@ -107,7 +132,7 @@ macro_rules! inner_define_measure_point_2d {
         measures::if_all_true! { { $with_approx }
             impl<Unit, Number> From<ApproxMeasurePoint2d<Unit, Number>> for MeasurePoint2d<Unit, Number>
             where
-                Unit: MeasurementUnit<Property: VectorProperty>,
+                Unit: VectorMeasurementUnit,
                 Number: ArithmeticOps,
             {
                 /// MeasurePoint2d::from(ApproxMeasurePoint2d) -> MeasurePoint2d
@@ -120,7 +145,7 @@ macro_rules! inner_define_measure_point_2d {
         measures::if_all_true! { { $with_serde }
             impl<Unit, Number> serde::Serialize for MeasurePoint2d<Unit, Number>
             where
-                Unit: MeasurementUnit<Property: VectorProperty>,
+                Unit: VectorMeasurementUnit,
                 Number: ArithmeticOps + serde::Serialize,
             {
                 fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -133,7 +158,7 @@ macro_rules! inner_define_measure_point_2d {
 
             impl<'de, Unit, Number> serde::Deserialize<'de> for MeasurePoint2d<Unit, Number>
             where
-                Unit: MeasurementUnit<Property: VectorProperty>,
+                Unit: VectorMeasurementUnit,
                 Number: ArithmeticOps + serde::Deserialize<'de>,
             {
                 fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
@@ -147,7 +172,7 @@ macro_rules! inner_define_measure_point_2d {
 
         impl<Unit, Number> Add<Measure2d<Unit, Number>> for MeasurePoint2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = Self;
@@ -163,7 +188,7 @@ macro_rules! inner_define_measure_point_2d {
 
         impl<Unit, Number> AddAssign<Measure2d<Unit, Number>> for MeasurePoint2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// MeasurePoint2d += Measure2d
@@ -175,7 +200,7 @@ macro_rules! inner_define_measure_point_2d {
 
         impl<Unit, Number> Sub<Measure2d<Unit, Number>> for MeasurePoint2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = Self;
@@ -191,7 +216,7 @@ macro_rules! inner_define_measure_point_2d {
 
         impl<Unit, Number> SubAssign<Measure2d<Unit, Number>> for MeasurePoint2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// MeasurePoint2d -= Measure2d
@@ -203,7 +228,7 @@ macro_rules! inner_define_measure_point_2d {
 
         impl<Unit, Number> Sub<MeasurePoint2d<Unit, Number>> for MeasurePoint2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = Measure2d<Unit, Number>;
@@ -224,7 +249,7 @@ macro_rules! inner_define_measure_point_2d {
             weight1: Number,
         ) -> MeasurePoint2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             let weight2 = Number::ONE - weight1;
@@ -240,7 +265,7 @@ macro_rules! inner_define_measure_point_2d {
             p2: MeasurePoint2d<Unit, Number>,
         ) -> MeasurePoint2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             MeasurePoint2d::<Unit, Number>::new([
@@ -255,7 +280,7 @@ macro_rules! inner_define_measure_point_2d {
             weights: &[Number],
         ) -> MeasurePoint2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             MeasurePoint2d::<Unit, Number>::new([
@@ -274,7 +299,7 @@ macro_rules! inner_define_measure_point_2d {
 
         impl<Unit, Number> PartialEq<MeasurePoint2d<Unit, Number>> for MeasurePoint2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// MeasurePoint2d == MeasurePoint2d -> bool
@@ -285,7 +310,7 @@ macro_rules! inner_define_measure_point_2d {
 
         impl<Unit, Number> Clone for MeasurePoint2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// MeasurePoint2d.clone() -> MeasurePoint2d
@@ -297,14 +322,14 @@ macro_rules! inner_define_measure_point_2d {
         /// MeasurePoint2d = MeasurePoint2d
         impl<Unit, Number> Copy for MeasurePoint2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
         }
 
         impl<Unit, Number> fmt::Display for MeasurePoint2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// format!("{}", MeasurePoint2d) -> String
@@ -321,7 +346,7 @@ macro_rules! inner_define_measure_point_2d {
 
         impl<Unit, Number> fmt::Debug for MeasurePoint2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// format!("{:?}", MeasurePoint2d)
@@ -337,7 +362,7 @@ macro_rules! inner_define_measure_point_2d {
 
         impl<Unit, Number> fmt::LowerExp for MeasurePoint2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// format!("{:e}", MeasurePoint2d) -> String
@@ -353,7 +378,7 @@ macro_rules! inner_define_measure_point_2d {
 
         impl<Unit, Number> fmt::UpperExp for MeasurePoint2d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// format!("{:E}", MeasurePoint2d) -> String
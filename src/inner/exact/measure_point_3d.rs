@@ -3,20 +3,47 @@ macro_rules! inner_define_measure_point_3d {
     { $with_approx:ident $with_serde:ident } => {
         /// 3D absolute measure with generic unit of measurement, generic value type,
         /// and with 3 dynamic components.
-        /// This type does not make sense for Unit::OFFSET != 0.
-        /// Though, the language does not allow such constraint yet.
+        /// Its unit must be a `VectorMeasurementUnit`, so it cannot have an offset.
+        #[repr(transparent)]
         pub struct MeasurePoint3d<Unit, Number = f64>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             pub values: [Number; 3],
             phantom: PhantomData<Unit>,
         }
 
+        // SAFETY: the struct is `repr(transparent)`, and every value of its field is valid.
+        unsafe impl<Unit, Number> measures::traits::Transparent<[Number; 3]>
+            for MeasurePoint3d<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+        }
+
+        measures::if_bytemuck! {
+            // SAFETY: the struct is `repr(transparent)`, and its field is `Zeroable`.
+            unsafe impl<Unit, Number> measures::bytemuck::Zeroable for MeasurePoint3d<Unit, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps + measures::bytemuck::Zeroable,
+            {
+            }
+
+            // SAFETY: the struct is `repr(transparent)`, and its field is `Pod`.
+            unsafe impl<Unit, Number> measures::bytemuck::Pod for MeasurePoint3d<Unit, Number>
+            where
+                Unit: VectorMeasurementUnit + 'static,
+                Number: ArithmeticOps + measures::bytemuck::Pod,
+            {
+            }
+        }
+
         impl<Unit, Number> MeasurePoint3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// MeasurePoint3d::new([Number; 3]) -> MeasurePoint3d
@@ -44,10 +71,8 @@ macro_rules! inner_define_measure_point_3d {
 
             pub fn convert<DestUnit>(self) -> MeasurePoint3d<DestUnit, Number>
             where
-                DestUnit: MeasurementUnit<Property = Unit::Property>,
+                DestUnit: VectorMeasurementUnit<Property = Unit::Property>,
             {
-                debug_assert!(Unit::OFFSET == 0.);
-                debug_assert!(DestUnit::OFFSET == 0.);
                 let factor = Number::from_f64(Unit::RATIO / DestUnit::RATIO);
                 MeasurePoint3d::<DestUnit, Number>::new([
                     self.values[0] * factor,
@@ -81,7 +106,7 @@ macro_rules! inner_define_measure_point_3d {
 
         impl<Unit, Number> Default for MeasurePoint3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// MeasurePoint3d::default() -> MeasurePoint3d
@@ -93,7 +118,7 @@ macro_rules! inner_define_measure_point_3d {
 
         impl<Unit, Number> From<[MeasurePoint<Unit, Number>; 3]> for MeasurePoint3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// MeasurePoint3d::from([MeasurePoint; 3]) -> MeasurePoint3d
@@ -108,7 +133,7 @@ macro_rules! inner_define_measure_point_3d {
 
         impl<Unit> From<MeasurePoint3d<Unit, f32>> for MeasurePoint3d<Unit, f64>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
         {
             fn from(m: MeasurePoint3d<Unit, f32>) -> Self {
                 Self::new([m.values[0] as f64, m.values[1] as f64, m.values[2] as f64])
@@ -118,7 +143,7 @@ macro_rules! inner_define_measure_point_3d {
         measures::if_all_true! { { $with_approx }
             impl<Unit, Number> From<ApproxMeasurePoint3d<Unit, Number>> for MeasurePoint3d<Unit, Number>
             where
-                Unit: MeasurementUnit<Property: VectorProperty>,
+                Unit: VectorMeasurementUnit,
                 Number: ArithmeticOps,
             {
                 /// MeasurePoint3d::from(ApproxMeasurePoint3d) -> MeasurePoint3d
@@ -131,7 +156,7 @@ macro_rules! inner_define_measure_point_3d {
         measures::if_all_true! { { $with_serde }
             impl<Unit, Number> serde::Serialize for MeasurePoint3d<Unit, Number>
             where
-                Unit: MeasurementUnit<Property: VectorProperty>,
+                Unit: VectorMeasurementUnit,
                 Number: ArithmeticOps + serde::Serialize,
             {
                 fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -144,7 +169,7 @@ macro_rules! inner_define_measure_point_3d {
 
             impl<'de, Unit, Number> serde::Deserialize<'de> for MeasurePoint3d<Unit, Number>
             where
-                Unit: MeasurementUnit<Property: VectorProperty>,
+                Unit: VectorMeasurementUnit,
                 Number: ArithmeticOps + serde::Deserialize<'de>,
             {
                 fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
@@ -159,7 +184,7 @@ macro_rules! inner_define_measure_point_3d {
         // MeasurePoint3d + Measure3d -> MeasurePoint3d
         impl<Unit, Number> Add<Measure3d<Unit, Number>> for MeasurePoint3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = Self;
@@ -175,7 +200,7 @@ macro_rules! inner_define_measure_point_3d {
         // MeasurePoint3d += Measure3d
         impl<Unit, Number> AddAssign<Measure3d<Unit, Number>> for MeasurePoint3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn add_assign(&mut self, other: Measure3d<Unit, Number>) {
@@ -188,7 +213,7 @@ macro_rules! inner_define_measure_point_3d {
         // MeasurePoint3d - Measure3d -> MeasurePoint3d
         impl<Unit, Number> Sub<Measure3d<Unit, Number>> for MeasurePoint3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = Self;
@@ -204,7 +229,7 @@ macro_rules! inner_define_measure_point_3d {
         // MeasurePoint3d -= Measure3d
         impl<Unit, Number> SubAssign<Measure3d<Unit, Number>> for MeasurePoint3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn sub_assign(&mut self, other: Measure3d<Unit, Number>) {
@@ -217,7 +242,7 @@ macro_rules! inner_define_measure_point_3d {
         /// measure point 3d - measure point 3d -> measure 3d
         impl<Unit, Number> Sub<MeasurePoint3d<Unit, Number>> for MeasurePoint3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             type Output = Measure3d<Unit, Number>;
@@ -237,7 +262,7 @@ macro_rules! inner_define_measure_point_3d {
             weight1: Number,
         ) -> MeasurePoint3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             let weight2 = Number::ONE - weight1;
@@ -254,7 +279,7 @@ macro_rules! inner_define_measure_point_3d {
             p2: MeasurePoint3d<Unit, Number>,
         ) -> MeasurePoint3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             MeasurePoint3d::<Unit, Number>::new([
@@ -270,7 +295,7 @@ macro_rules! inner_define_measure_point_3d {
             weights: &[Number],
         ) -> MeasurePoint3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             MeasurePoint3d::<Unit, Number>::new([
@@ -295,7 +320,7 @@ macro_rules! inner_define_measure_point_3d {
         // MeasurePoint3d == MeasurePoint3d -> bool
         impl<Unit, Number> PartialEq<MeasurePoint3d<Unit, Number>> for MeasurePoint3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn eq(&self, other: &MeasurePoint3d<Unit, Number>) -> bool {
@@ -306,7 +331,7 @@ macro_rules! inner_define_measure_point_3d {
         // MeasurePoint3d.clone() -> MeasurePoint3d
         impl<Unit, Number> Clone for MeasurePoint3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn clone(&self) -> Self {
@@ -317,14 +342,14 @@ macro_rules! inner_define_measure_point_3d {
         // MeasurePoint3d = MeasurePoint3d
         impl<Unit, Number> Copy for MeasurePoint3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
         }
 
         impl<Unit, Number> fmt::Display for MeasurePoint3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// format!("{}", MeasurePoint3d) -> String
@@ -344,7 +369,7 @@ macro_rules! inner_define_measure_point_3d {
         // format!("{:?}", MeasurePoint3d)
         impl<Unit, Number> fmt::Debug for MeasurePoint3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -361,7 +386,7 @@ macro_rules! inner_define_measure_point_3d {
 
         impl<Unit, Number> fmt::LowerExp for MeasurePoint3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// format!("{:e}", MeasurePoint3d) -> String
@@ -379,7 +404,7 @@ macro_rules! inner_define_measure_point_3d {
 
         impl<Unit, Number> fmt::UpperExp for MeasurePoint3d<Unit, Number>
         where
-            Unit: MeasurementUnit<Property: VectorProperty>,
+            Unit: VectorMeasurementUnit,
             Number: ArithmeticOps,
         {
             /// format!("{:E}", MeasurePoint3d) -> String
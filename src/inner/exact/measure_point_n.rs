@@ -0,0 +1,398 @@
+#[macro_export] // Don't add nor remove the first three lines and the last two lines.
+macro_rules! inner_define_measure_point_n {
+    { $with_2d:ident $with_3d:ident $exact:ident $with_serde:ident } => {
+        /// N-dimensional absolute measure with generic unit of measurement,
+        /// with N static components, and generic value type.
+        /// Its unit must be a `VectorMeasurementUnit`, so it cannot have an offset.
+        #[repr(transparent)]
+        pub struct MeasurePointN<Unit, const N: usize, Number = f64>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            pub values: [Number; N],
+            phantom: PhantomData<Unit>,
+        }
+
+        // SAFETY: the struct is `repr(transparent)`, and every value of its field is valid.
+        unsafe impl<Unit, const N: usize, Number> measures::traits::Transparent<[Number; N]>
+            for MeasurePointN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+        }
+
+        measures::if_bytemuck! {
+            // SAFETY: the struct is `repr(transparent)`, and its field is `Zeroable`.
+            unsafe impl<Unit, const N: usize, Number> measures::bytemuck::Zeroable for MeasurePointN<Unit, N, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps + measures::bytemuck::Zeroable,
+            {
+            }
+
+            // SAFETY: the struct is `repr(transparent)`, and its field is `Pod`.
+            unsafe impl<Unit, const N: usize, Number> measures::bytemuck::Pod for MeasurePointN<Unit, N, Number>
+            where
+                Unit: VectorMeasurementUnit + 'static,
+                Number: ArithmeticOps + measures::bytemuck::Pod,
+            {
+            }
+        }
+
+        impl<Unit, const N: usize, Number> MeasurePointN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// MeasurePointN::new([Number; N]) -> MeasurePointN
+            pub const fn new(values: [Number; N]) -> Self {
+                Self {
+                    values,
+                    phantom: PhantomData,
+                }
+            }
+
+            /// MeasurePointN.component(usize) -> MeasurePoint
+            /// It panics if `index` is not less than `N`.
+            pub const fn component(self, index: usize) -> MeasurePoint<Unit, Number> {
+                MeasurePoint::<Unit, Number>::new(self.values[index])
+            }
+
+            /// MeasurePointN.convert() -> MeasurePointN
+            pub fn convert<DestUnit>(self) -> MeasurePointN<DestUnit, N, Number>
+            where
+                DestUnit: VectorMeasurementUnit<Property = Unit::Property>,
+            {
+                let factor = Number::from_f64(Unit::RATIO / DestUnit::RATIO);
+                MeasurePointN::<DestUnit, N, Number>::new(self.values.map(|value| value * factor))
+            }
+
+            /// MeasurePointN.lossless_into() -> MeasurePointN
+            pub fn lossless_into<DestNumber>(self) -> MeasurePointN<Unit, N, DestNumber>
+            where
+                DestNumber: ArithmeticOps + From<Number>,
+            {
+                MeasurePointN::<Unit, N, DestNumber>::new(self.values.map(DestNumber::from))
+            }
+
+            /// MeasurePointN.lossy_into() -> MeasurePointN
+            pub fn lossy_into<DestNumber>(self) -> MeasurePointN<Unit, N, DestNumber>
+            where
+                DestNumber: ArithmeticOps + LossyFrom<Number>,
+            {
+                MeasurePointN::<Unit, N, DestNumber>::new(self.values.map(DestNumber::lossy_from))
+            }
+        }
+
+        impl<Unit, const N: usize, Number> Default for MeasurePointN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// MeasurePointN::default() -> MeasurePointN
+            /// It returns the origin.
+            fn default() -> Self {
+                Self::new([Number::ZERO; N])
+            }
+        }
+
+        impl<Unit, const N: usize, Number> From<[MeasurePoint<Unit, Number>; N]>
+            for MeasurePointN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// MeasurePointN::from([MeasurePoint; N]) -> MeasurePointN
+            fn from(points: [MeasurePoint<Unit, Number>; N]) -> Self {
+                Self::new(points.map(|point| point.value))
+            }
+        }
+
+        impl<Unit, const N: usize> From<MeasurePointN<Unit, N, f32>> for MeasurePointN<Unit, N, f64>
+        where
+            Unit: VectorMeasurementUnit,
+        {
+            /// MeasurePointN<f64>::from(MeasurePointN<f32>) -> MeasurePointN<f64>
+            fn from(point: MeasurePointN<Unit, N, f32>) -> Self {
+                Self::new(point.values.map(|value| value as f64))
+            }
+        }
+
+        impl<Unit, Number> From<MeasurePoint<Unit, Number>> for MeasurePointN<Unit, 1, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// MeasurePointN::<1>::from(MeasurePoint) -> MeasurePointN<1>
+            fn from(point: MeasurePoint<Unit, Number>) -> Self {
+                Self::new([point.value])
+            }
+        }
+
+        impl<Unit, Number> From<MeasurePointN<Unit, 1, Number>> for MeasurePoint<Unit, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// MeasurePoint::from(MeasurePointN<1>) -> MeasurePoint
+            fn from(point: MeasurePointN<Unit, 1, Number>) -> Self {
+                Self::new(point.values[0])
+            }
+        }
+
+        measures::if_all_true! { { $with_2d $exact }
+            impl<Unit, Number> From<MeasurePoint2d<Unit, Number>> for MeasurePointN<Unit, 2, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                /// MeasurePointN::<2>::from(MeasurePoint2d) -> MeasurePointN<2>
+                fn from(point: MeasurePoint2d<Unit, Number>) -> Self {
+                    Self::new(point.values)
+                }
+            }
+
+            impl<Unit, Number> From<MeasurePointN<Unit, 2, Number>> for MeasurePoint2d<Unit, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                /// MeasurePoint2d::from(MeasurePointN<2>) -> MeasurePoint2d
+                fn from(point: MeasurePointN<Unit, 2, Number>) -> Self {
+                    Self::new(point.values)
+                }
+            }
+        }
+
+        measures::if_all_true! { { $with_3d }
+            impl<Unit, Number> From<MeasurePoint3d<Unit, Number>> for MeasurePointN<Unit, 3, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                /// MeasurePointN::<3>::from(MeasurePoint3d) -> MeasurePointN<3>
+                fn from(point: MeasurePoint3d<Unit, Number>) -> Self {
+                    Self::new(point.values)
+                }
+            }
+
+            impl<Unit, Number> From<MeasurePointN<Unit, 3, Number>> for MeasurePoint3d<Unit, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                /// MeasurePoint3d::from(MeasurePointN<3>) -> MeasurePoint3d
+                fn from(point: MeasurePointN<Unit, 3, Number>) -> Self {
+                    Self::new(point.values)
+                }
+            }
+        }
+
+        measures::if_all_true! { { $with_serde }
+            // Serde implements its traits only for arrays having at most 32 items,
+            // and so a `MeasurePointN` is serialized as a sequence.
+            impl<Unit, const N: usize, Number> serde::Serialize for MeasurePointN<Unit, N, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps + serde::Serialize,
+            {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: serde::Serializer
+                {
+                    self.values.as_slice().serialize(serializer)
+                }
+            }
+
+            impl<'de, Unit, const N: usize, Number> serde::Deserialize<'de> for MeasurePointN<Unit, N, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps + serde::Deserialize<'de>,
+            {
+                fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+                where
+                    De: serde::Deserializer<'de>,
+                {
+                    let measure: MeasureN<Unit, N, Number> = serde::Deserialize::deserialize(deserializer)?;
+                    Ok(Self::new(measure.values))
+                }
+            }
+        }
+
+        // MeasurePointN + MeasureN -> MeasurePointN
+        impl<Unit, const N: usize, Number> Add<MeasureN<Unit, N, Number>> for MeasurePointN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            type Output = Self;
+            fn add(self, other: MeasureN<Unit, N, Number>) -> Self::Output {
+                Self::new(core::array::from_fn(|i| self.values[i] + other.values[i]))
+            }
+        }
+
+        // MeasurePointN += MeasureN
+        impl<Unit, const N: usize, Number> AddAssign<MeasureN<Unit, N, Number>>
+            for MeasurePointN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            fn add_assign(&mut self, other: MeasureN<Unit, N, Number>) {
+                for (value, other_value) in self.values.iter_mut().zip(other.values) {
+                    *value += other_value;
+                }
+            }
+        }
+
+        // MeasurePointN - MeasureN -> MeasurePointN
+        impl<Unit, const N: usize, Number> Sub<MeasureN<Unit, N, Number>> for MeasurePointN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            type Output = Self;
+            fn sub(self, other: MeasureN<Unit, N, Number>) -> Self::Output {
+                Self::new(core::array::from_fn(|i| self.values[i] - other.values[i]))
+            }
+        }
+
+        // MeasurePointN -= MeasureN
+        impl<Unit, const N: usize, Number> SubAssign<MeasureN<Unit, N, Number>>
+            for MeasurePointN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            fn sub_assign(&mut self, other: MeasureN<Unit, N, Number>) {
+                for (value, other_value) in self.values.iter_mut().zip(other.values) {
+                    *value -= other_value;
+                }
+            }
+        }
+
+        /// measure point n - measure point n -> measure n
+        impl<Unit, const N: usize, Number> Sub<MeasurePointN<Unit, N, Number>>
+            for MeasurePointN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            type Output = MeasureN<Unit, N, Number>;
+            fn sub(self, other: MeasurePointN<Unit, N, Number>) -> Self::Output {
+                Self::Output::new(core::array::from_fn(|i| self.values[i] - other.values[i]))
+            }
+        }
+
+        /// weighted_midpoint_n(measure point n, measure point n, weight) -> measure point n
+        pub fn weighted_midpoint_n<Unit, const N: usize, Number>(
+            p1: MeasurePointN<Unit, N, Number>,
+            p2: MeasurePointN<Unit, N, Number>,
+            weight1: Number,
+        ) -> MeasurePointN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            let weight2 = Number::ONE - weight1;
+            MeasurePointN::<Unit, N, Number>::new(core::array::from_fn(|i| {
+                p1.values[i] * weight1 + p2.values[i] * weight2
+            }))
+        }
+
+        /// midpoint_n(measure point n, measure point n) -> measure point n
+        pub fn midpoint_n<Unit, const N: usize, Number>(
+            p1: MeasurePointN<Unit, N, Number>,
+            p2: MeasurePointN<Unit, N, Number>,
+        ) -> MeasurePointN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            MeasurePointN::<Unit, N, Number>::new(core::array::from_fn(|i| {
+                (p1.values[i] + p2.values[i]) * Number::HALF
+            }))
+        }
+
+        // MeasurePointN == MeasurePointN -> bool
+        impl<Unit, const N: usize, Number> PartialEq<MeasurePointN<Unit, N, Number>>
+            for MeasurePointN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            fn eq(&self, other: &MeasurePointN<Unit, N, Number>) -> bool {
+                self.values == other.values
+            }
+        }
+
+        // MeasurePointN.clone() -> MeasurePointN
+        impl<Unit, const N: usize, Number> Clone for MeasurePointN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        // MeasurePointN = MeasurePointN
+        impl<Unit, const N: usize, Number> Copy for MeasurePointN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+        }
+
+        impl<Unit, const N: usize, Number> fmt::Display for MeasurePointN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// format!("{}", MeasurePointN) -> String
+            /// MeasurePointN.to_string() -> String
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("at ")?;
+                fmt::Display::fmt(&MeasureN::<Unit, N, Number>::new(self.values), formatter)
+            }
+        }
+
+        // format!("{:?}", MeasurePointN)
+        impl<Unit, const N: usize, Number> fmt::Debug for MeasurePointN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(self, formatter)
+            }
+        }
+
+        // format!("{:e}", MeasurePointN)
+        impl<Unit, const N: usize, Number> fmt::LowerExp for MeasurePointN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("at ")?;
+                fmt::LowerExp::fmt(&MeasureN::<Unit, N, Number>::new(self.values), formatter)
+            }
+        }
+
+        // format!("{:E}", MeasurePointN)
+        impl<Unit, const N: usize, Number> fmt::UpperExp for MeasurePointN<Unit, N, Number>
+        where
+            Unit: VectorMeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("at ")?;
+                fmt::UpperExp::fmt(&MeasureN::<Unit, N, Number>::new(self.values), formatter)
+            }
+        }
+    };
+}
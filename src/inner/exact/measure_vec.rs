@@ -0,0 +1,317 @@
+#[macro_export] // Don't add nor remove the first three lines and the last two lines.
+macro_rules! inner_define_measure_vec {
+    {} => {
+        /// Growable sequence of 1D relative measures having the same unit of measurement.
+        /// The values are stored contiguously, and so they can be viewed as a slice of numbers,
+        /// to be passed to numeric libraries without copying them.
+        pub struct MeasureVec<Unit, Number = f64>
+        where
+            Unit: MeasurementUnit,
+            Number: BasicArithmeticOps,
+        {
+            values: measures::alloc::vec::Vec<Number>,
+            phantom: PhantomData<Unit>,
+        }
+
+        impl<Unit, Number> MeasureVec<Unit, Number>
+        where
+            Unit: MeasurementUnit,
+            Number: BasicArithmeticOps,
+        {
+            /// MeasureVec::new() -> MeasureVec
+            /// It returns an empty sequence.
+            pub const fn new() -> Self {
+                Self::from_values(measures::alloc::vec::Vec::new())
+            }
+
+            /// MeasureVec::with_capacity(usize) -> MeasureVec
+            pub fn with_capacity(capacity: usize) -> Self {
+                Self::from_values(measures::alloc::vec::Vec::with_capacity(capacity))
+            }
+
+            /// MeasureVec::from_values(Vec<Number>) -> MeasureVec
+            /// The numbers are the values of the measures, in the unit `Unit`.
+            pub const fn from_values(values: measures::alloc::vec::Vec<Number>) -> Self {
+                Self {
+                    values,
+                    phantom: PhantomData,
+                }
+            }
+
+            /// MeasureVec.into_values() -> Vec<Number>
+            pub fn into_values(self) -> measures::alloc::vec::Vec<Number> {
+                self.values
+            }
+
+            /// MeasureVec.values() -> &[Number]
+            pub fn values(&self) -> &[Number] {
+                &self.values
+            }
+
+            /// MeasureVec.values_mut() -> &mut [Number]
+            pub fn values_mut(&mut self) -> &mut [Number] {
+                &mut self.values
+            }
+
+            /// MeasureVec.len() -> usize
+            pub fn len(&self) -> usize {
+                self.values.len()
+            }
+
+            /// MeasureVec.is_empty() -> bool
+            pub fn is_empty(&self) -> bool {
+                self.values.is_empty()
+            }
+
+            /// MeasureVec.push(Measure)
+            pub fn push(&mut self, measure: Measure<Unit, Number>) {
+                self.values.push(measure.value);
+            }
+
+            /// MeasureVec.get(usize) -> Option<Measure>
+            pub fn get(&self, index: usize) -> Option<Measure<Unit, Number>> {
+                self.values
+                    .get(index)
+                    .map(|&value| Measure::<Unit, Number>::new(value))
+            }
+
+            /// MeasureVec.set(usize, Measure)
+            /// It panics if the index is out of bounds.
+            pub fn set(&mut self, index: usize, measure: Measure<Unit, Number>) {
+                self.values[index] = measure.value;
+            }
+
+            /// MeasureVec.iter() -> impl Iterator<Item = Measure>
+            pub fn iter(&self) -> impl Iterator<Item = Measure<Unit, Number>> + '_ {
+                self.values
+                    .iter()
+                    .map(|&value| Measure::<Unit, Number>::new(value))
+            }
+
+            /// MeasureVec.convert() -> MeasureVec
+            /// The values are converted in place, without allocating memory.
+            /// Like for `Measure`, the units must be linear.
+            pub fn convert<DestUnit>(mut self) -> MeasureVec<DestUnit, Number>
+            where
+                Unit: LinearMeasurementUnit,
+                DestUnit: LinearMeasurementUnit<Property = Unit::Property>,
+            {
+                Number::scale_all_by_ratio(&mut self.values, Unit::RATIO / DestUnit::RATIO);
+                MeasureVec::<DestUnit, Number>::from_values(self.values)
+            }
+
+            // It applies the operation to every value and to the corresponding value of `other`.
+            fn zip_apply(&mut self, other: &Self, operation: impl Fn(&mut Number, Number)) {
+                assert_eq!(
+                    self.len(),
+                    other.len(),
+                    "the sequences of measures have different lengths"
+                );
+                for (value, &other_value) in self.values.iter_mut().zip(&other.values) {
+                    operation(value, other_value);
+                }
+            }
+        }
+
+        impl<Unit, Number> Default for MeasureVec<Unit, Number>
+        where
+            Unit: MeasurementUnit,
+            Number: BasicArithmeticOps,
+        {
+            /// MeasureVec::default() -> MeasureVec
+            /// It returns an empty sequence.
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl<Unit, Number> Clone for MeasureVec<Unit, Number>
+        where
+            Unit: MeasurementUnit,
+            Number: BasicArithmeticOps,
+        {
+            fn clone(&self) -> Self {
+                Self::from_values(self.values.clone())
+            }
+        }
+
+        impl<Unit, Number> PartialEq for MeasureVec<Unit, Number>
+        where
+            Unit: MeasurementUnit,
+            Number: BasicArithmeticOps,
+        {
+            fn eq(&self, other: &Self) -> bool {
+                self.values == other.values
+            }
+        }
+
+        impl<Unit, Number> core::iter::FromIterator<Measure<Unit, Number>> for MeasureVec<Unit, Number>
+        where
+            Unit: MeasurementUnit,
+            Number: BasicArithmeticOps,
+        {
+            fn from_iter<I: IntoIterator<Item = Measure<Unit, Number>>>(iter: I) -> Self {
+                Self::from_values(iter.into_iter().map(|measure| measure.value).collect())
+            }
+        }
+
+        impl<Unit, Number> Extend<Measure<Unit, Number>> for MeasureVec<Unit, Number>
+        where
+            Unit: MeasurementUnit,
+            Number: BasicArithmeticOps,
+        {
+            fn extend<I: IntoIterator<Item = Measure<Unit, Number>>>(&mut self, iter: I) {
+                self.values
+                    .extend(iter.into_iter().map(|measure| measure.value));
+            }
+        }
+
+        impl<Unit, Number> AddAssign<&MeasureVec<Unit, Number>> for MeasureVec<Unit, Number>
+        where
+            Unit: MeasurementUnit,
+            Number: BasicArithmeticOps,
+        {
+            /// MeasureVec += &MeasureVec
+            /// It panics if the sequences have different lengths.
+            fn add_assign(&mut self, other: &MeasureVec<Unit, Number>) {
+                self.zip_apply(other, |value, other_value| *value += other_value);
+            }
+        }
+
+        impl<Unit, Number> SubAssign<&MeasureVec<Unit, Number>> for MeasureVec<Unit, Number>
+        where
+            Unit: MeasurementUnit,
+            Number: BasicArithmeticOps,
+        {
+            /// MeasureVec -= &MeasureVec
+            /// It panics if the sequences have different lengths.
+            fn sub_assign(&mut self, other: &MeasureVec<Unit, Number>) {
+                self.zip_apply(other, |value, other_value| *value -= other_value);
+            }
+        }
+
+        impl<Unit, Number> Add<&MeasureVec<Unit, Number>> for MeasureVec<Unit, Number>
+        where
+            Unit: MeasurementUnit,
+            Number: BasicArithmeticOps,
+        {
+            type Output = Self;
+
+            /// MeasureVec + &MeasureVec -> MeasureVec
+            /// It panics if the sequences have different lengths.
+            fn add(mut self, other: &MeasureVec<Unit, Number>) -> Self::Output {
+                self += other;
+                self
+            }
+        }
+
+        impl<Unit, Number> Sub<&MeasureVec<Unit, Number>> for MeasureVec<Unit, Number>
+        where
+            Unit: MeasurementUnit,
+            Number: BasicArithmeticOps,
+        {
+            type Output = Self;
+
+            /// MeasureVec - &MeasureVec -> MeasureVec
+            /// It panics if the sequences have different lengths.
+            fn sub(mut self, other: &MeasureVec<Unit, Number>) -> Self::Output {
+                self -= other;
+                self
+            }
+        }
+
+        impl<Unit, Number> AddAssign<Measure<Unit, Number>> for MeasureVec<Unit, Number>
+        where
+            Unit: MeasurementUnit,
+            Number: BasicArithmeticOps,
+        {
+            /// MeasureVec += Measure
+            /// It adds the measure to every item.
+            fn add_assign(&mut self, other: Measure<Unit, Number>) {
+                for value in &mut self.values {
+                    *value += other.value;
+                }
+            }
+        }
+
+        impl<Unit, Number> SubAssign<Measure<Unit, Number>> for MeasureVec<Unit, Number>
+        where
+            Unit: MeasurementUnit,
+            Number: BasicArithmeticOps,
+        {
+            /// MeasureVec -= Measure
+            /// It subtracts the measure from every item.
+            fn sub_assign(&mut self, other: Measure<Unit, Number>) {
+                for value in &mut self.values {
+                    *value -= other.value;
+                }
+            }
+        }
+
+        impl<Unit, Number> MulAssign<Number> for MeasureVec<Unit, Number>
+        where
+            Unit: MeasurementUnit,
+            Number: BasicArithmeticOps,
+        {
+            /// MeasureVec *= Number
+            fn mul_assign(&mut self, n: Number) {
+                for value in &mut self.values {
+                    *value *= n;
+                }
+            }
+        }
+
+        impl<Unit, Number> DivAssign<Number> for MeasureVec<Unit, Number>
+        where
+            Unit: MeasurementUnit,
+            Number: BasicArithmeticOps,
+        {
+            /// MeasureVec /= Number
+            fn div_assign(&mut self, n: Number) {
+                for value in &mut self.values {
+                    *value /= n;
+                }
+            }
+        }
+
+        impl<Unit, Number> Mul<Number> for MeasureVec<Unit, Number>
+        where
+            Unit: MeasurementUnit,
+            Number: BasicArithmeticOps,
+        {
+            type Output = Self;
+
+            /// MeasureVec * Number -> MeasureVec
+            fn mul(mut self, n: Number) -> Self::Output {
+                self *= n;
+                self
+            }
+        }
+
+        impl<Unit, Number> Div<Number> for MeasureVec<Unit, Number>
+        where
+            Unit: MeasurementUnit,
+            Number: BasicArithmeticOps,
+        {
+            type Output = Self;
+
+            /// MeasureVec / Number -> MeasureVec
+            fn div(mut self, n: Number) -> Self::Output {
+                self /= n;
+                self
+            }
+        }
+
+        impl<Unit, Number> fmt::Debug for MeasureVec<Unit, Number>
+        where
+            Unit: MeasurementUnit,
+            Number: BasicArithmeticOps,
+        {
+            /// format!("{:?}", MeasureVec)
+            /// It prints the measures like "[1.5 m, 2 m]".
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.debug_list().entries(self.iter()).finish()
+            }
+        }
+    };
+}
@@ -1,5 +1,6 @@
 pub mod measure;
 pub mod measure_point;
+pub mod measure_vec;
 
 pub mod signed_direction;
 pub mod unsigned_direction;
@@ -7,9 +8,17 @@ pub mod unsigned_direction;
 pub mod affine_map_2d;
 pub mod linear_map_2d;
 pub mod measure_2d;
+pub mod measure_2d_vec;
 pub mod measure_point_2d;
 
 pub mod affine_map_3d;
 pub mod linear_map_3d;
 pub mod measure_3d;
+pub mod measure_3d_vec;
 pub mod measure_point_3d;
+
+pub mod linear_map_n;
+pub mod measure_n;
+pub mod measure_point_n;
+
+pub mod complex_measure;
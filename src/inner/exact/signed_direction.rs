@@ -5,6 +5,7 @@ macro_rules! inner_define_signed_direction {
         /// between minus half cycle (included) and plus half cycle (excluded),
         /// with generic angular unit of measurement, generic value type,
         /// and with a dynamic value.
+        #[repr(transparent)]
         pub struct SignedDirection<Unit, Number = f64>
         where
             Unit: AngleMeasurementUnit,
@@ -87,6 +88,97 @@ macro_rules! inner_define_signed_direction {
                     phantom: PhantomData,
                 }
             }
+
+            // It returns the sums of the sines and of the cosines of the given directions,
+            // and their count.
+            fn sums<I>(directions: I) -> (Number, Number, Number)
+            where
+                I: IntoIterator<Item = Self>,
+            {
+                let mut sum_sin = Number::ZERO;
+                let mut sum_cos = Number::ZERO;
+                let mut count = Number::ZERO;
+                for direction in directions {
+                    let (sin, cos) = direction.sin_cos();
+                    sum_sin += sin;
+                    sum_cos += cos;
+                    count += Number::ONE;
+                }
+                (sum_sin, sum_cos, count)
+            }
+
+            /// SignedDirection::circular_mean(impl IntoIterator<Item = SignedDirection>) -> Option<SignedDirection>
+            /// It returns the direction of the sum of the unit vectors of the given directions,
+            /// so that the mean of 350 degrees and 10 degrees is 0 degrees, and not 180 degrees.
+            /// It returns `None` if there are no directions,
+            /// or if they cancel out, as their mean resultant length is less than one millionth.
+            pub fn circular_mean<I>(directions: I) -> Option<Self>
+            where
+                I: IntoIterator<Item = Self>,
+            {
+                let (sum_sin, sum_cos, count) = Self::sums(directions);
+                if count == Number::ZERO
+                    || (sum_sin * sum_sin + sum_cos * sum_cos).sqrt() < count * Number::from_f64(1e-6)
+                {
+                    return None;
+                }
+                let radians = measures::traits::InverseTrigonometry::atan2(sum_sin, sum_cos);
+                Some(Self::new(
+                    Measure::<Radian, Number>::new(radians)
+                        .convert::<Unit>()
+                        .value,
+                ))
+            }
+
+            /// SignedDirection::circular_variance(impl IntoIterator<Item = SignedDirection>) -> Option<Number>
+            /// It returns one minus the mean resultant length of the given directions,
+            /// which is zero for equal directions, and one for directions cancelling out.
+            /// It returns `None` if there are no directions.
+            pub fn circular_variance<I>(directions: I) -> Option<Number>
+            where
+                I: IntoIterator<Item = Self>,
+            {
+                let (sum_sin, sum_cos, count) = Self::sums(directions);
+                if count == Number::ZERO {
+                    return None;
+                }
+                Some(Number::ONE - (sum_sin * sum_sin + sum_cos * sum_cos).sqrt() / count)
+            }
+
+            /// SignedDirection::angular_spread(impl IntoIterator<Item = SignedDirection>) -> Option<Measure>
+            /// It returns the circular standard deviation of the given directions,
+            /// which is the square root of minus twice the logarithm of their mean resultant length.
+            /// For directions near to their mean, it is near to their standard deviation.
+            /// It returns `None` if there are no directions,
+            /// or if they cancel out, as their mean resultant length is less than one millionth,
+            /// like `circular_mean`, as the spread would be unbounded.
+            pub fn angular_spread<I>(directions: I) -> Option<Measure<Unit, Number>>
+            where
+                I: IntoIterator<Item = Self>,
+            {
+                let (sum_sin, sum_cos, count) = Self::sums(directions);
+                if count == Number::ZERO {
+                    return None;
+                }
+                let length = (sum_sin * sum_sin + sum_cos * sum_cos).sqrt() / count;
+                if length < Number::from_f64(1e-6) {
+                    return None;
+                }
+                let radians = if length < Number::ONE {
+                    let ln_length = measures::traits::Logarithm::ln(length);
+                    (-(ln_length + ln_length)).sqrt()
+                } else {
+                    Number::ZERO
+                };
+                Some(Measure::<Radian, Number>::new(radians).convert::<Unit>())
+            }
+
+            /// SignedDirection.lerp_shortest(SignedDirection, Number) -> SignedDirection
+            /// It interpolates between the two directions along the shortest arc,
+            /// so that `t` equal to zero gives `self`, and `t` equal to one gives `other`.
+            pub fn lerp_shortest(self, other: Self, t: Number) -> Self {
+                self + (other - self) * t
+            }
         }
 
         impl<Unit, Number> Default for SignedDirection<Unit, Number>
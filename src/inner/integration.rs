@@ -0,0 +1,495 @@
+#[macro_export] // Don't add nor remove the first three lines and the last two lines.
+macro_rules! inner_define_integration {
+    { $with_2d:ident $with_3d:ident $with_nd:ident $exact:ident } => {
+        /// State of a system of ordinary differential equations,
+        /// having `Derivative` as the type of its derivative with respect to a variable
+        /// measured in `TimeUnit`, typically the time.
+        /// It is implemented by measures, by measure points,
+        /// and by tuples of 2 or 3 of them, whose derivatives are the tuples of their derivatives.
+        /// The unit of `Derivative` multiplied by `TimeUnit` must be declared as a relationship,
+        /// and so a mismatch between a state and its derivative is a compile-time error.
+        pub trait IntegrationState<TimeUnit, Derivative, Number>: Copy
+        where
+            TimeUnit: MeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// Type of the maximum error allowed for a state by adaptive integrators.
+            type Tolerance: Copy;
+
+            /// IntegrationState.add_scaled_derivative(Derivative, Measure) -> IntegrationState
+            /// It returns `self + derivative * step`.
+            fn add_scaled_derivative(self, derivative: Derivative, step: Measure<TimeUnit, Number>)
+                -> Self;
+
+            /// IntegrationState.error_ratio(IntegrationState, Tolerance) -> Number
+            /// It returns the largest ratio between the distance of `self` from `other`
+            /// and the corresponding tolerance.
+            fn error_ratio(self, other: Self, tolerance: Self::Tolerance) -> Number;
+        }
+
+        impl<TimeUnit, Unit, Derivative, Number> IntegrationState<TimeUnit, Derivative, Number>
+            for Measure<Unit, Number>
+        where
+            TimeUnit: MeasurementUnit,
+            Unit: MeasurementUnit,
+            Number: ArithmeticOps,
+            Derivative: Mul<Measure<TimeUnit, Number>, Output = Measure<Unit, Number>>,
+        {
+            type Tolerance = Measure<Unit, Number>;
+
+            fn add_scaled_derivative(
+                self,
+                derivative: Derivative,
+                step: Measure<TimeUnit, Number>,
+            ) -> Self {
+                self + derivative * step
+            }
+
+            fn error_ratio(self, other: Self, tolerance: Self::Tolerance) -> Number {
+                (self - other).norm().value / tolerance.value
+            }
+        }
+
+        impl<TimeUnit, Unit, Derivative, Number> IntegrationState<TimeUnit, Derivative, Number>
+            for MeasurePoint<Unit, Number>
+        where
+            TimeUnit: MeasurementUnit,
+            Unit: MeasurementUnit,
+            Number: ArithmeticOps,
+            Derivative: Mul<Measure<TimeUnit, Number>, Output = Measure<Unit, Number>>,
+        {
+            type Tolerance = Measure<Unit, Number>;
+
+            fn add_scaled_derivative(
+                self,
+                derivative: Derivative,
+                step: Measure<TimeUnit, Number>,
+            ) -> Self {
+                self + derivative * step
+            }
+
+            fn error_ratio(self, other: Self, tolerance: Self::Tolerance) -> Number {
+                (self - other).norm().value / tolerance.value
+            }
+        }
+
+        measures::if_all_true! { { $with_2d }
+            impl<TimeUnit, Unit, Derivative, Number> IntegrationState<TimeUnit, Derivative, Number>
+                for Measure2d<Unit, Number>
+            where
+                TimeUnit: MeasurementUnit,
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps,
+                Derivative: Mul<Measure<TimeUnit, Number>, Output = Measure2d<Unit, Number>>,
+            {
+                type Tolerance = Measure<Unit, Number>;
+
+                fn add_scaled_derivative(self, derivative: Derivative, step: Measure<TimeUnit, Number>) -> Self {
+                    self + derivative * step
+                }
+
+                fn error_ratio(self, other: Self, tolerance: Self::Tolerance) -> Number {
+                    (self - other).norm().value / tolerance.value
+                }
+            }
+        }
+
+        measures::if_all_true! { { $with_2d $exact }
+            impl<TimeUnit, Unit, Derivative, Number> IntegrationState<TimeUnit, Derivative, Number>
+                for MeasurePoint2d<Unit, Number>
+            where
+                TimeUnit: MeasurementUnit,
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps,
+                Derivative: Mul<Measure<TimeUnit, Number>, Output = Measure2d<Unit, Number>>,
+            {
+                type Tolerance = Measure<Unit, Number>;
+
+                fn add_scaled_derivative(self, derivative: Derivative, step: Measure<TimeUnit, Number>) -> Self {
+                    self + derivative * step
+                }
+
+                fn error_ratio(self, other: Self, tolerance: Self::Tolerance) -> Number {
+                    (self - other).norm().value / tolerance.value
+                }
+            }
+        }
+
+        measures::if_all_true! { { $with_3d }
+            impl<TimeUnit, Unit, Derivative, Number> IntegrationState<TimeUnit, Derivative, Number>
+                for Measure3d<Unit, Number>
+            where
+                TimeUnit: MeasurementUnit,
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps,
+                Derivative: Mul<Measure<TimeUnit, Number>, Output = Measure3d<Unit, Number>>,
+            {
+                type Tolerance = Measure<Unit, Number>;
+
+                fn add_scaled_derivative(self, derivative: Derivative, step: Measure<TimeUnit, Number>) -> Self {
+                    self + derivative * step
+                }
+
+                fn error_ratio(self, other: Self, tolerance: Self::Tolerance) -> Number {
+                    (self - other).norm().value / tolerance.value
+                }
+            }
+
+            impl<TimeUnit, Unit, Derivative, Number> IntegrationState<TimeUnit, Derivative, Number>
+                for MeasurePoint3d<Unit, Number>
+            where
+                TimeUnit: MeasurementUnit,
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps,
+                Derivative: Mul<Measure<TimeUnit, Number>, Output = Measure3d<Unit, Number>>,
+            {
+                type Tolerance = Measure<Unit, Number>;
+
+                fn add_scaled_derivative(self, derivative: Derivative, step: Measure<TimeUnit, Number>) -> Self {
+                    self + derivative * step
+                }
+
+                fn error_ratio(self, other: Self, tolerance: Self::Tolerance) -> Number {
+                    (self - other).norm().value / tolerance.value
+                }
+            }
+        }
+
+        measures::if_all_true! { { $with_nd }
+            impl<TimeUnit, Unit, Derivative, const N: usize, Number> IntegrationState<TimeUnit, Derivative, Number>
+                for MeasureN<Unit, N, Number>
+            where
+                TimeUnit: MeasurementUnit,
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps,
+                Derivative: Mul<Measure<TimeUnit, Number>, Output = MeasureN<Unit, N, Number>>,
+            {
+                type Tolerance = Measure<Unit, Number>;
+
+                fn add_scaled_derivative(self, derivative: Derivative, step: Measure<TimeUnit, Number>) -> Self {
+                    self + derivative * step
+                }
+
+                fn error_ratio(self, other: Self, tolerance: Self::Tolerance) -> Number {
+                    (self - other).norm().value / tolerance.value
+                }
+            }
+
+            impl<TimeUnit, Unit, Derivative, const N: usize, Number> IntegrationState<TimeUnit, Derivative, Number>
+                for MeasurePointN<Unit, N, Number>
+            where
+                TimeUnit: MeasurementUnit,
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps,
+                Derivative: Mul<Measure<TimeUnit, Number>, Output = MeasureN<Unit, N, Number>>,
+            {
+                type Tolerance = Measure<Unit, Number>;
+
+                fn add_scaled_derivative(self, derivative: Derivative, step: Measure<TimeUnit, Number>) -> Self {
+                    self + derivative * step
+                }
+
+                fn error_ratio(self, other: Self, tolerance: Self::Tolerance) -> Number {
+                    (self - other).norm().value / tolerance.value
+                }
+            }
+        }
+
+        impl<TimeUnit, State1, Derivative1, State2, Derivative2, Number>
+            IntegrationState<TimeUnit, (Derivative1, Derivative2), Number> for (State1, State2)
+        where
+            TimeUnit: MeasurementUnit,
+            Number: ArithmeticOps,
+            State1: IntegrationState<TimeUnit, Derivative1, Number>,
+            State2: IntegrationState<TimeUnit, Derivative2, Number>,
+        {
+            type Tolerance = (State1::Tolerance, State2::Tolerance);
+
+            fn add_scaled_derivative(
+                self,
+                derivative: (Derivative1, Derivative2),
+                step: Measure<TimeUnit, Number>,
+            ) -> Self {
+                (
+                    self.0.add_scaled_derivative(derivative.0, step),
+                    self.1.add_scaled_derivative(derivative.1, step),
+                )
+            }
+
+            fn error_ratio(self, other: Self, tolerance: Self::Tolerance) -> Number {
+                let ratio0 = self.0.error_ratio(other.0, tolerance.0);
+                let ratio1 = self.1.error_ratio(other.1, tolerance.1);
+                if ratio0 >= ratio1 {
+                    ratio0
+                } else {
+                    ratio1
+                }
+            }
+        }
+
+        impl<TimeUnit, State1, Derivative1, State2, Derivative2, State3, Derivative3, Number>
+            IntegrationState<TimeUnit, (Derivative1, Derivative2, Derivative3), Number>
+            for (State1, State2, State3)
+        where
+            TimeUnit: MeasurementUnit,
+            Number: ArithmeticOps,
+            State1: IntegrationState<TimeUnit, Derivative1, Number>,
+            State2: IntegrationState<TimeUnit, Derivative2, Number>,
+            State3: IntegrationState<TimeUnit, Derivative3, Number>,
+        {
+            type Tolerance = (State1::Tolerance, State2::Tolerance, State3::Tolerance);
+
+            fn add_scaled_derivative(
+                self,
+                derivative: (Derivative1, Derivative2, Derivative3),
+                step: Measure<TimeUnit, Number>,
+            ) -> Self {
+                (
+                    self.0.add_scaled_derivative(derivative.0, step),
+                    self.1.add_scaled_derivative(derivative.1, step),
+                    self.2.add_scaled_derivative(derivative.2, step),
+                )
+            }
+
+            fn error_ratio(self, other: Self, tolerance: Self::Tolerance) -> Number {
+                let ratio0 = self.0.error_ratio(other.0, tolerance.0);
+                let ratio1 = self.1.error_ratio(other.1, tolerance.1);
+                let ratio2 = self.2.error_ratio(other.2, tolerance.2);
+                let ratio01 = if ratio0 >= ratio1 { ratio0 } else { ratio1 };
+                if ratio01 >= ratio2 {
+                    ratio01
+                } else {
+                    ratio2
+                }
+            }
+        }
+
+        /// euler_step(derivative function, MeasurePoint, IntegrationState, Measure) -> IntegrationState
+        /// It advances the given state by the given step, using the explicit Euler method.
+        /// The function `derivative` receives the time and the state, and returns the derivative of the state.
+        pub fn euler_step<TimeUnit, State, Derivative, Number, F>(
+            mut derivative: F,
+            time: MeasurePoint<TimeUnit, Number>,
+            state: State,
+            step: Measure<TimeUnit, Number>,
+        ) -> State
+        where
+            TimeUnit: MeasurementUnit,
+            Number: ArithmeticOps,
+            State: IntegrationState<TimeUnit, Derivative, Number>,
+            F: FnMut(MeasurePoint<TimeUnit, Number>, State) -> Derivative,
+        {
+            state.add_scaled_derivative(derivative(time, state), step)
+        }
+
+        /// runge_kutta_4_step(derivative function, MeasurePoint, IntegrationState, Measure) -> IntegrationState
+        /// It advances the given state by the given step, using the classical fourth-order Runge-Kutta method.
+        /// The function `derivative` receives the time and the state, and returns the derivative of the state.
+        pub fn runge_kutta_4_step<TimeUnit, State, Derivative, Number, F>(
+            mut derivative: F,
+            time: MeasurePoint<TimeUnit, Number>,
+            state: State,
+            step: Measure<TimeUnit, Number>,
+        ) -> State
+        where
+            TimeUnit: MeasurementUnit,
+            Number: ArithmeticOps,
+            State: IntegrationState<TimeUnit, Derivative, Number>,
+            Derivative: Copy,
+            F: FnMut(MeasurePoint<TimeUnit, Number>, State) -> Derivative,
+        {
+            let half_step = step * Number::HALF;
+            let sixth_step = step / Number::from_f64(6.);
+            let third_step = step / Number::from_f64(3.);
+            let k1 = derivative(time, state);
+            let k2 = derivative(time + half_step, state.add_scaled_derivative(k1, half_step));
+            let k3 = derivative(time + half_step, state.add_scaled_derivative(k2, half_step));
+            let k4 = derivative(time + step, state.add_scaled_derivative(k3, step));
+            state
+                .add_scaled_derivative(k1, sixth_step)
+                .add_scaled_derivative(k2, third_step)
+                .add_scaled_derivative(k3, third_step)
+                .add_scaled_derivative(k4, sixth_step)
+        }
+
+        /// velocity_verlet_step(acceleration function, Position, Velocity, Measure) -> (Position, Velocity)
+        /// It advances the given position and velocity by the given step,
+        /// using the velocity Verlet method, which is symplectic,
+        /// and so it is suited for long simulations of conservative systems.
+        /// The function `acceleration` receives the position, and returns the derivative of the velocity.
+        pub fn velocity_verlet_step<TimeUnit, Position, Velocity, Acceleration, Number, F>(
+            mut acceleration: F,
+            position: Position,
+            velocity: Velocity,
+            step: Measure<TimeUnit, Number>,
+        ) -> (Position, Velocity)
+        where
+            TimeUnit: MeasurementUnit,
+            Number: ArithmeticOps,
+            Position: IntegrationState<TimeUnit, Velocity, Number>,
+            Velocity: IntegrationState<TimeUnit, Acceleration, Number>,
+            F: FnMut(Position) -> Acceleration,
+        {
+            let half_step = step * Number::HALF;
+            let half_velocity = velocity.add_scaled_derivative(acceleration(position), half_step);
+            let new_position = position.add_scaled_derivative(half_velocity, step);
+            let new_velocity = half_velocity.add_scaled_derivative(acceleration(new_position), half_step);
+            (new_position, new_velocity)
+        }
+
+        /// runge_kutta_45_step(derivative function, MeasurePoint, IntegrationState, Measure, Tolerance)
+        /// -> Option<(Measure, IntegrationState, Measure)>
+        /// It advances the given state using the adaptive Dormand-Prince method,
+        /// whose local error is estimated by the difference between a fifth-order
+        /// and a fourth-order Runge-Kutta solution.
+        /// The given step, which must be positive, is reduced until such error
+        /// is not larger than `tolerance`, which must be positive too.
+        /// It returns the step actually taken, the new state, and the step suggested for the next call.
+        /// It returns `None` if the error cannot be computed, typically because it is NaN,
+        /// or if the step has become smaller than the resolution of `time`
+        /// or than the given step multiplied by the epsilon of the number type.
+        pub fn runge_kutta_45_step<TimeUnit, State, Derivative, Number, F>(
+            mut derivative: F,
+            time: MeasurePoint<TimeUnit, Number>,
+            state: State,
+            step: Measure<TimeUnit, Number>,
+            tolerance: State::Tolerance,
+        ) -> Option<(Measure<TimeUnit, Number>, State, Measure<TimeUnit, Number>)>
+        where
+            TimeUnit: MeasurementUnit,
+            Number: ArithmeticOps,
+            State: IntegrationState<TimeUnit, Derivative, Number>,
+            Derivative: Copy,
+            F: FnMut(MeasurePoint<TimeUnit, Number>, State) -> Derivative,
+        {
+            assert!(
+                step.value > Number::ZERO,
+                "The step of the integration must be positive."
+            );
+            let n = Number::from_f64;
+            let min_step = step * Number::EPSILON;
+            let mut step = step;
+            let k1 = derivative(time, state);
+            loop {
+                let k2 = derivative(
+                    time + step * n(1. / 5.),
+                    state.add_scaled_derivative(k1, step * n(1. / 5.)),
+                );
+                let k3 = derivative(
+                    time + step * n(3. / 10.),
+                    state
+                        .add_scaled_derivative(k1, step * n(3. / 40.))
+                        .add_scaled_derivative(k2, step * n(9. / 40.)),
+                );
+                let k4 = derivative(
+                    time + step * n(4. / 5.),
+                    state
+                        .add_scaled_derivative(k1, step * n(44. / 45.))
+                        .add_scaled_derivative(k2, step * n(-56. / 15.))
+                        .add_scaled_derivative(k3, step * n(32. / 9.)),
+                );
+                let k5 = derivative(
+                    time + step * n(8. / 9.),
+                    state
+                        .add_scaled_derivative(k1, step * n(19372. / 6561.))
+                        .add_scaled_derivative(k2, step * n(-25360. / 2187.))
+                        .add_scaled_derivative(k3, step * n(64448. / 6561.))
+                        .add_scaled_derivative(k4, step * n(-212. / 729.)),
+                );
+                let k6 = derivative(
+                    time + step,
+                    state
+                        .add_scaled_derivative(k1, step * n(9017. / 3168.))
+                        .add_scaled_derivative(k2, step * n(-355. / 33.))
+                        .add_scaled_derivative(k3, step * n(46732. / 5247.))
+                        .add_scaled_derivative(k4, step * n(49. / 176.))
+                        .add_scaled_derivative(k5, step * n(-5103. / 18656.)),
+                );
+                let fifth_order = state
+                    .add_scaled_derivative(k1, step * n(35. / 384.))
+                    .add_scaled_derivative(k3, step * n(500. / 1113.))
+                    .add_scaled_derivative(k4, step * n(125. / 192.))
+                    .add_scaled_derivative(k5, step * n(-2187. / 6784.))
+                    .add_scaled_derivative(k6, step * n(11. / 84.));
+                let k7 = derivative(time + step, fifth_order);
+                let fourth_order = state
+                    .add_scaled_derivative(k1, step * n(5179. / 57600.))
+                    .add_scaled_derivative(k3, step * n(7571. / 16695.))
+                    .add_scaled_derivative(k4, step * n(393. / 640.))
+                    .add_scaled_derivative(k5, step * n(-92097. / 339200.))
+                    .add_scaled_derivative(k6, step * n(187. / 2100.))
+                    .add_scaled_derivative(k7, step * n(1. / 40.));
+                let error_ratio = fifth_order.error_ratio(fourth_order, tolerance);
+
+                // The step is multiplied by a factor between 0.2 and 5,
+                // aiming at an error ratio a bit less than 1.
+                let mut factor = n(5.);
+                if error_ratio > Number::ZERO {
+                    factor = n(0.9) * error_ratio.powf(n(-0.2));
+                    if factor < n(0.2) {
+                        factor = n(0.2);
+                    } else if factor > n(5.) {
+                        factor = n(5.);
+                    }
+                }
+                if error_ratio <= Number::ONE {
+                    return Some((step, fifth_order, step * factor));
+                }
+                // A NaN error ratio, caused for example by a zero tolerance,
+                // is neither acceptable nor larger than 1.
+                let is_larger = error_ratio > Number::ONE;
+                if !is_larger {
+                    return None;
+                }
+                step *= factor;
+                if step < min_step || time + step == time {
+                    return None;
+                }
+            }
+        }
+
+        /// runge_kutta_45_integrate(derivative function, MeasurePoint, IntegrationState, MeasurePoint,
+        /// Measure, Tolerance) -> Option<IntegrationState>
+        /// It advances the given state from `start_time` to `end_time`,
+        /// by calling repeatedly `runge_kutta_45_step`, starting with `initial_step`,
+        /// which must be positive.
+        /// `end_time` must not precede `start_time`.
+        /// It returns `None` if any call to `runge_kutta_45_step` returns `None`.
+        pub fn runge_kutta_45_integrate<TimeUnit, State, Derivative, Number, F>(
+            mut derivative: F,
+            start_time: MeasurePoint<TimeUnit, Number>,
+            state: State,
+            end_time: MeasurePoint<TimeUnit, Number>,
+            initial_step: Measure<TimeUnit, Number>,
+            tolerance: State::Tolerance,
+        ) -> Option<State>
+        where
+            TimeUnit: MeasurementUnit,
+            Number: ArithmeticOps,
+            State: IntegrationState<TimeUnit, Derivative, Number>,
+            Derivative: Copy,
+            F: FnMut(MeasurePoint<TimeUnit, Number>, State) -> Derivative,
+        {
+            assert!(
+                initial_step.value > Number::ZERO,
+                "The initial step of the integration must be positive."
+            );
+            let mut time = start_time;
+            let mut state = state;
+            let mut step = initial_step;
+            while time < end_time {
+                let remaining = end_time - time;
+                let (taken_step, new_state, next_step) =
+                    runge_kutta_45_step(&mut derivative, time, state, step.min(remaining), tolerance)?;
+                time = if taken_step < remaining {
+                    time + taken_step
+                } else {
+                    end_time
+                };
+                state = new_state;
+                step = next_step;
+            }
+            Some(state)
+        }
+    };
+}
@@ -0,0 +1,232 @@
+#[macro_export] // Don't add nor remove the first three lines and the last two lines.
+macro_rules! inner_define_interpolation {
+    {} => {
+        /// Piecewise-linear interpolator of a sequence of values sampled at some measure points,
+        /// typically a time series.
+        /// The values can be measures or measure points, having 1, 2, or 3 dimensions.
+        pub struct LinearInterpolator<ArgUnit, Value, Number = f64>
+        where
+            ArgUnit: MeasurementUnit,
+            Number: ArithmeticOps,
+            Value: measures::traits::Affine<Number>,
+        {
+            args: measures::alloc::vec::Vec<MeasurePoint<ArgUnit, Number>>,
+            values: measures::alloc::vec::Vec<Value>,
+        }
+
+        impl<ArgUnit, Value, Number> LinearInterpolator<ArgUnit, Value, Number>
+        where
+            ArgUnit: MeasurementUnit,
+            Number: ArithmeticOps,
+            Value: measures::traits::Affine<Number>,
+        {
+            /// LinearInterpolator::new(&[MeasurePoint], &[Value]) -> LinearInterpolator
+            /// It panics if the two slices have different lengths,
+            /// if they contain less than two items,
+            /// or if `args` is not strictly increasing.
+            pub fn new(args: &[MeasurePoint<ArgUnit, Number>], values: &[Value]) -> Self {
+                check_interpolation_knots(args, values.len());
+                Self {
+                    args: args.to_vec(),
+                    values: values.to_vec(),
+                }
+            }
+
+            /// LinearInterpolator.args() -> &[MeasurePoint]
+            pub fn args(&self) -> &[MeasurePoint<ArgUnit, Number>] {
+                &self.args
+            }
+
+            /// LinearInterpolator.values() -> &[Value]
+            pub fn values(&self) -> &[Value] {
+                &self.values
+            }
+
+            /// LinearInterpolator.value_at(MeasurePoint) -> Value
+            /// Outside of the range of the knots, the first or the last segment is extended.
+            pub fn value_at(&self, arg: MeasurePoint<ArgUnit, Number>) -> Value {
+                let i = interpolation_segment(&self.args, arg);
+                let fraction = (arg - self.args[i]).value / (self.args[i + 1] - self.args[i]).value;
+                self.values[i] + (self.values[i + 1] - self.values[i]) * fraction
+            }
+
+            /// LinearInterpolator.derivative_at(MeasurePoint) -> Derivative
+            /// The type of the result is the one obtained by dividing
+            /// a difference of values by a `Measure<ArgUnit>`,
+            /// and so a relationship between such units must have been declared.
+            /// At knots, the derivative of the following segment is returned.
+            pub fn derivative_at<Derivative>(&self, arg: MeasurePoint<ArgUnit, Number>) -> Derivative
+            where
+                Value::Vector: Div<Measure<ArgUnit, Number>, Output = Derivative>,
+            {
+                let i = interpolation_segment(&self.args, arg);
+                (self.values[i + 1] - self.values[i]) / (self.args[i + 1] - self.args[i])
+            }
+        }
+
+        /// Natural cubic spline interpolator of a sequence of values sampled at some measure points,
+        /// typically a time series.
+        /// The values can be measures or measure points, having 1, 2, or 3 dimensions.
+        /// The resulting curve passes through every knot, it has continuous first and second derivatives,
+        /// and its second derivative is zero at the first and at the last knot.
+        pub struct CubicSplineInterpolator<ArgUnit, Value, Number = f64>
+        where
+            ArgUnit: MeasurementUnit,
+            Number: ArithmeticOps,
+            Value: measures::traits::Affine<Number>,
+        {
+            args: measures::alloc::vec::Vec<MeasurePoint<ArgUnit, Number>>,
+            values: measures::alloc::vec::Vec<Value>,
+            // Second derivatives at the knots, as values per squared `ArgUnit`.
+            second_derivatives: measures::alloc::vec::Vec<Value::Vector>,
+        }
+
+        impl<ArgUnit, Value, Number> CubicSplineInterpolator<ArgUnit, Value, Number>
+        where
+            ArgUnit: MeasurementUnit,
+            Number: ArithmeticOps,
+            Value: measures::traits::Affine<Number>,
+        {
+            /// CubicSplineInterpolator::new(&[MeasurePoint], &[Value]) -> CubicSplineInterpolator
+            /// It panics if the two slices have different lengths,
+            /// if they contain less than two items,
+            /// or if `args` is not strictly increasing.
+            pub fn new(args: &[MeasurePoint<ArgUnit, Number>], values: &[Value]) -> Self {
+                check_interpolation_knots(args, values.len());
+                let n = args.len();
+                let zero = Value::Vector::default();
+                let two = Number::from_f64(2.);
+                let six = Number::from_f64(6.);
+                let slope = |i: usize| (values[i + 1] - values[i]) / (args[i + 1] - args[i]).value;
+
+                // Tridiagonal system solved by the Thomas algorithm,
+                // with `upper` and `rhs` being the modified coefficients.
+                let mut upper = measures::alloc::vec![Number::ZERO; n];
+                let mut rhs = measures::alloc::vec![zero; n];
+                for i in 1..n - 1 {
+                    let h0 = (args[i] - args[i - 1]).value;
+                    let h1 = (args[i + 1] - args[i]).value;
+                    let pivot = two * (h0 + h1) - h0 * upper[i - 1];
+                    upper[i] = h1 / pivot;
+                    rhs[i] = ((slope(i) - slope(i - 1)) * six - rhs[i - 1] * h0) / pivot;
+                }
+                let mut second_derivatives = measures::alloc::vec![zero; n];
+                for i in (1..n - 1).rev() {
+                    second_derivatives[i] = rhs[i] - second_derivatives[i + 1] * upper[i];
+                }
+                Self {
+                    args: args.to_vec(),
+                    values: values.to_vec(),
+                    second_derivatives,
+                }
+            }
+
+            /// CubicSplineInterpolator.args() -> &[MeasurePoint]
+            pub fn args(&self) -> &[MeasurePoint<ArgUnit, Number>] {
+                &self.args
+            }
+
+            /// CubicSplineInterpolator.values() -> &[Value]
+            pub fn values(&self) -> &[Value] {
+                &self.values
+            }
+
+            /// CubicSplineInterpolator.value_at(MeasurePoint) -> Value
+            /// Outside of the range of the knots, the polynomial of the first or of the last segment is used.
+            pub fn value_at(&self, arg: MeasurePoint<ArgUnit, Number>) -> Value {
+                let (i, h, a, b) = self.segment(arg);
+                let six = Number::from_f64(6.);
+                let m0 = self.second_derivatives[i];
+                let m1 = self.second_derivatives[i + 1];
+                self.values[i]
+                    + ((self.values[i + 1] - self.values[i]) * (b / h)
+                        + m0 * ((a * a * a / h - a * h) / six)
+                        + m1 * ((b * b * b / h - b * h) / six))
+            }
+
+            /// CubicSplineInterpolator.derivative_at(MeasurePoint) -> Derivative
+            /// The type of the result is the one obtained by dividing
+            /// a difference of values by a `Measure<ArgUnit>`,
+            /// and so a relationship between such units must have been declared.
+            pub fn derivative_at<Derivative>(&self, arg: MeasurePoint<ArgUnit, Number>) -> Derivative
+            where
+                Value::Vector: Div<Measure<ArgUnit, Number>, Output = Derivative>,
+            {
+                let (i, h, a, b) = self.segment(arg);
+                let two = Number::from_f64(2.);
+                let m0 = self.second_derivatives[i];
+                let m1 = self.second_derivatives[i + 1];
+                let per_arg_unit = (self.values[i + 1] - self.values[i]) * (Number::ONE / h)
+                    + m1 * (b * b / (two * h))
+                    - m0 * (a * a / (two * h))
+                    - (m1 - m0) * (h / Number::from_f64(6.));
+                per_arg_unit / Measure::<ArgUnit, Number>::new(Number::ONE)
+            }
+
+            /// CubicSplineInterpolator.second_derivative_at(MeasurePoint) -> SecondDerivative
+            /// The type of the result is the one obtained by dividing twice
+            /// a difference of values by a `Measure<ArgUnit>`,
+            /// and so the relationships between such units must have been declared.
+            pub fn second_derivative_at<Derivative, SecondDerivative>(
+                &self,
+                arg: MeasurePoint<ArgUnit, Number>,
+            ) -> SecondDerivative
+            where
+                Value::Vector: Div<Measure<ArgUnit, Number>, Output = Derivative>,
+                Derivative: Div<Measure<ArgUnit, Number>, Output = SecondDerivative>,
+            {
+                let (i, h, a, b) = self.segment(arg);
+                let per_squared_arg_unit =
+                    self.second_derivatives[i] * (a / h) + self.second_derivatives[i + 1] * (b / h);
+                let one = Measure::<ArgUnit, Number>::new(Number::ONE);
+                per_squared_arg_unit / one / one
+            }
+
+            // It returns the index of the segment containing `arg`,
+            // the length of such segment, the distance of `arg` from its end,
+            // and the distance of `arg` from its start.
+            fn segment(&self, arg: MeasurePoint<ArgUnit, Number>) -> (usize, Number, Number, Number) {
+                let i = interpolation_segment(&self.args, arg);
+                (
+                    i,
+                    (self.args[i + 1] - self.args[i]).value,
+                    (self.args[i + 1] - arg).value,
+                    (arg - self.args[i]).value,
+                )
+            }
+        }
+
+        // It panics if the knots are not valid for an interpolator.
+        fn check_interpolation_knots<ArgUnit, Number>(
+            args: &[MeasurePoint<ArgUnit, Number>],
+            value_count: usize,
+        ) where
+            ArgUnit: MeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            assert!(
+                args.len() == value_count,
+                "An interpolator needs as many arguments as values."
+            );
+            assert!(args.len() >= 2, "An interpolator needs at least two knots.");
+            assert!(
+                args.windows(2).all(|w| w[0] < w[1]),
+                "The arguments of an interpolator must be strictly increasing."
+            );
+        }
+
+        // It returns the index of the first knot of the segment to use to interpolate at `arg`.
+        fn interpolation_segment<ArgUnit, Number>(
+            args: &[MeasurePoint<ArgUnit, Number>],
+            arg: MeasurePoint<ArgUnit, Number>,
+        ) -> usize
+        where
+            ArgUnit: MeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            args.partition_point(|a| *a <= arg)
+                .saturating_sub(1)
+                .min(args.len() - 2)
+        }
+    };
+}
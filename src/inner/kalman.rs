@@ -0,0 +1,505 @@
+#[macro_export] // Don't add nor remove the first three lines and the last two lines.
+macro_rules! inner_define_kalman_filters {
+    { $with_points:ident $with_3d:ident $with_transformations:ident } => {
+        /// Linear Kalman filter estimating a scalar quantity,
+        /// by fusing approximate readings of it.
+        /// Its state is an approximate measure, whose variance is the uncertainty of the estimate.
+        pub struct KalmanFilter<Unit, Number = f64>
+        where
+            Unit: MeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            pub state: ApproxMeasure<Unit, Number>,
+        }
+
+        impl<Unit, Number> KalmanFilter<Unit, Number>
+        where
+            Unit: MeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            /// KalmanFilter::new(ApproxMeasure) -> KalmanFilter
+            pub const fn new(initial_state: ApproxMeasure<Unit, Number>) -> Self {
+                Self {
+                    state: initial_state,
+                }
+            }
+
+            /// KalmanFilter.predict(ApproxMeasure)
+            /// It adds to the state a change, whose variance is the process noise.
+            pub fn predict(&mut self, change: ApproxMeasure<Unit, Number>) {
+                self.state = ApproxMeasure::<Unit, Number>::with_variance(
+                    self.state.value + change.value,
+                    self.state.variance + change.variance,
+                );
+            }
+
+            /// KalmanFilter.predict_with_rate(Measure, ApproxMeasure)
+            /// It adds to the state the change caused by a rate of change,
+            /// like a velocity, lasting for a step, typically of time.
+            /// The product of the unit of the rate by the unit of the step
+            /// must be declared as a relationship.
+            pub fn predict_with_rate<StepUnit, RateUnit>(
+                &mut self,
+                step: Measure<StepUnit, Number>,
+                rate: ApproxMeasure<RateUnit, Number>,
+            ) where
+                StepUnit: MeasurementUnit,
+                RateUnit: MeasurementUnit,
+                Measure<RateUnit, Number>: Mul<Measure<StepUnit, Number>, Output = Measure<Unit, Number>>,
+            {
+                self.predict(ApproxMeasure::<Unit, Number>::with_variance(
+                    rate.value * step.value,
+                    rate.variance * step.value * step.value,
+                ));
+            }
+
+            /// KalmanFilter.update(ApproxMeasure) -> bool
+            /// It fuses an approximate reading into the state.
+            /// It returns `false`, leaving the state unchanged,
+            /// if the reading cannot be fused, because both it and the state have zero variance.
+            pub fn update(&mut self, observation: ApproxMeasure<Unit, Number>) -> bool {
+                let mut values = [self.state.value];
+                let mut covariances = [[self.state.variance]];
+                let is_fused = measures::matrix_utils::kalman_update(
+                    &mut values,
+                    &mut covariances,
+                    &[[Number::ONE]],
+                    &[observation.value],
+                    &[[observation.variance]],
+                );
+                self.state = ApproxMeasure::<Unit, Number>::with_variance(values[0], covariances[0][0]);
+                is_fused
+            }
+        }
+
+        impl<Unit, Number> Clone for KalmanFilter<Unit, Number>
+        where
+            Unit: MeasurementUnit,
+            Number: ArithmeticOps,
+        {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<Unit, Number> Copy for KalmanFilter<Unit, Number>
+        where
+            Unit: MeasurementUnit,
+            Number: ArithmeticOps,
+        {
+        }
+
+        measures::if_all_true! { { $with_points $with_3d }
+            /// Linear Kalman filter estimating a position in 3d-space,
+            /// by fusing approximate observations of it.
+            /// Its state is an approximate measure point, whose covariances are
+            /// the uncertainty of the estimate.
+            pub struct KalmanFilter3d<Unit, Number = f64>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                pub state: ApproxMeasurePoint3d<Unit, Number>,
+            }
+
+            impl<Unit, Number> KalmanFilter3d<Unit, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                /// KalmanFilter3d::new(ApproxMeasurePoint3d) -> KalmanFilter3d
+                pub const fn new(initial_state: ApproxMeasurePoint3d<Unit, Number>) -> Self {
+                    Self {
+                        state: initial_state,
+                    }
+                }
+
+                /// KalmanFilter3d.predict(ApproxMeasure3d)
+                /// It moves the state by a displacement, whose covariances are the process noise.
+                pub fn predict(&mut self, displacement: ApproxMeasure3d<Unit, Number>) {
+                    self.predict_with_matrix(&measures::matrix_utils::identity_matrix(), [Number::ZERO; 3], displacement);
+                }
+
+                /// KalmanFilter3d.predict_with_velocity(Measure, ApproxMeasure3d)
+                /// It moves the state by the displacement caused by a velocity lasting for a step of time.
+                /// The product of the unit of the velocity by the unit of the step
+                /// must be declared as a relationship.
+                pub fn predict_with_velocity<TimeUnit, VelocityUnit>(
+                    &mut self,
+                    step: Measure<TimeUnit, Number>,
+                    velocity: ApproxMeasure3d<VelocityUnit, Number>,
+                ) where
+                    TimeUnit: MeasurementUnit,
+                    VelocityUnit: VectorMeasurementUnit,
+                    Measure<VelocityUnit, Number>: Mul<Measure<TimeUnit, Number>, Output = Measure<Unit, Number>>,
+                {
+                    let squared_step = step.value * step.value;
+                    self.predict(ApproxMeasure3d::<Unit, Number>::with_covariances(
+                        velocity.values.map(|v| v * step.value),
+                        velocity.covariances.map(|row| row.map(|c| c * squared_step)),
+                    ));
+                }
+
+                /// KalmanFilter3d.update(ApproxMeasurePoint3d) -> bool
+                /// It fuses an approximate observation of the position into the state.
+                /// It returns `false`, leaving the state unchanged, if the observation cannot be fused,
+                /// because the sum of its covariance matrix and the one of the state is singular.
+                pub fn update(&mut self, observation: ApproxMeasurePoint3d<Unit, Number>) -> bool {
+                    let mut values = self.state.values;
+                    let mut covariances = self.state.covariances;
+                    let is_fused = measures::matrix_utils::kalman_update(
+                        &mut values,
+                        &mut covariances,
+                        &measures::matrix_utils::identity_matrix(),
+                        &observation.values,
+                        &observation.covariances,
+                    );
+                    self.state = ApproxMeasurePoint3d::<Unit, Number>::with_covariances(values, covariances);
+                    is_fused
+                }
+
+                // It applies to the state the transformation `matrix·x + translation`,
+                // and then it moves the state by `displacement`.
+                fn predict_with_matrix(
+                    &mut self,
+                    matrix: &[[Number; 3]; 3],
+                    translation: [Number; 3],
+                    displacement: ApproxMeasure3d<Unit, Number>,
+                ) {
+                    let transformed = measures::matrix_utils::matrix_product(
+                        matrix,
+                        &self.state.values.map(|v| [v]),
+                    );
+                    let mut covariances = measures::matrix_utils::transformed_covariances(
+                        matrix,
+                        &self.state.covariances,
+                    );
+                    for row in 0..3 {
+                        for column in 0..3 {
+                            covariances[row][column] += displacement.covariances[row][column];
+                        }
+                    }
+                    self.state = ApproxMeasurePoint3d::<Unit, Number>::with_covariances(
+                        core::array::from_fn(|i| {
+                            transformed[i][0] + translation[i] + displacement.values[i]
+                        }),
+                        covariances,
+                    );
+                }
+            }
+
+            impl<Unit, Number> Clone for KalmanFilter3d<Unit, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                fn clone(&self) -> Self {
+                    *self
+                }
+            }
+
+            impl<Unit, Number> Copy for KalmanFilter3d<Unit, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps,
+            {
+            }
+
+            /// Linear Kalman filter estimating a position and a velocity in 3d-space,
+            /// according to a constant velocity model,
+            /// by fusing approximate observations of the position or of the velocity.
+            /// The `values` contain first the three components of the position,
+            /// and then the three components of the velocity,
+            /// and the `covariances` contain also the covariances between position and velocity.
+            pub struct KinematicKalmanFilter3d<Unit, VelocityUnit, Number = f64>
+            where
+                Unit: VectorMeasurementUnit,
+                VelocityUnit: VectorMeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                pub values: [Number; 6],
+                pub covariances: [[Number; 6]; 6],
+                phantom: PhantomData<(Unit, VelocityUnit)>,
+            }
+
+            impl<Unit, VelocityUnit, Number> KinematicKalmanFilter3d<Unit, VelocityUnit, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                VelocityUnit: VectorMeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                /// KinematicKalmanFilter3d::new(ApproxMeasurePoint3d, ApproxMeasure3d) -> KinematicKalmanFilter3d
+                /// The initial position and the initial velocity are considered uncorrelated.
+                pub fn new(
+                    position: ApproxMeasurePoint3d<Unit, Number>,
+                    velocity: ApproxMeasure3d<VelocityUnit, Number>,
+                ) -> Self {
+                    Self {
+                        values: core::array::from_fn(|i| {
+                            if i < 3 {
+                                position.values[i]
+                            } else {
+                                velocity.values[i - 3]
+                            }
+                        }),
+                        covariances: core::array::from_fn(|row| {
+                            core::array::from_fn(|column| {
+                                if row < 3 && column < 3 {
+                                    position.covariances[row][column]
+                                } else if row >= 3 && column >= 3 {
+                                    velocity.covariances[row - 3][column - 3]
+                                } else {
+                                    Number::ZERO
+                                }
+                            })
+                        }),
+                        phantom: PhantomData,
+                    }
+                }
+
+                /// KinematicKalmanFilter3d.position() -> ApproxMeasurePoint3d
+                pub fn position(&self) -> ApproxMeasurePoint3d<Unit, Number> {
+                    ApproxMeasurePoint3d::<Unit, Number>::with_covariances(
+                        [self.values[0], self.values[1], self.values[2]],
+                        core::array::from_fn(|row| core::array::from_fn(|column| self.covariances[row][column])),
+                    )
+                }
+
+                /// KinematicKalmanFilter3d.velocity() -> ApproxMeasure3d
+                pub fn velocity(&self) -> ApproxMeasure3d<VelocityUnit, Number> {
+                    ApproxMeasure3d::<VelocityUnit, Number>::with_covariances(
+                        [self.values[3], self.values[4], self.values[5]],
+                        core::array::from_fn(|row| core::array::from_fn(|column| self.covariances[row + 3][column + 3])),
+                    )
+                }
+
+                /// KinematicKalmanFilter3d.position_velocity_covariances() -> [[Number; 3]; 3]
+                /// The item at row `i` and column `j` is the covariance between
+                /// the component `i` of the position and the component `j` of the velocity.
+                pub fn position_velocity_covariances(&self) -> [[Number; 3]; 3] {
+                    core::array::from_fn(|row| core::array::from_fn(|column| self.covariances[row][column + 3]))
+                }
+
+                /// KinematicKalmanFilter3d.predict(Measure, ApproxMeasure3d)
+                /// It advances the state by a step of time.
+                /// During the step, the velocity changes uniformly by `velocity_change`,
+                /// whose covariances are the process noise.
+                /// The product of the unit of the velocity by the unit of the step
+                /// must be declared as a relationship.
+                pub fn predict<TimeUnit>(
+                    &mut self,
+                    step: Measure<TimeUnit, Number>,
+                    velocity_change: ApproxMeasure3d<VelocityUnit, Number>,
+                ) where
+                    TimeUnit: MeasurementUnit,
+                    Measure<VelocityUnit, Number>: Mul<Measure<TimeUnit, Number>, Output = Measure<Unit, Number>>,
+                {
+                    self.predict_with_matrix(step.value, &measures::matrix_utils::identity_matrix(), velocity_change);
+                }
+
+                /// KinematicKalmanFilter3d.update(ApproxMeasurePoint3d) -> bool
+                /// It fuses an approximate observation of the position into the state.
+                /// It returns `false`, leaving the state unchanged, if the observation cannot be fused,
+                /// because the sum of its covariance matrix and the one of the position is singular.
+                pub fn update(&mut self, observation: ApproxMeasurePoint3d<Unit, Number>) -> bool {
+                    self.update_block(0, &observation.values, &observation.covariances)
+                }
+
+                /// KinematicKalmanFilter3d.update_velocity(ApproxMeasure3d) -> bool
+                /// It fuses an approximate observation of the velocity into the state.
+                /// It returns `false`, leaving the state unchanged, if the observation cannot be fused,
+                /// because the sum of its covariance matrix and the one of the velocity is singular.
+                pub fn update_velocity(&mut self, observation: ApproxMeasure3d<VelocityUnit, Number>) -> bool {
+                    self.update_block(3, &observation.values, &observation.covariances)
+                }
+
+                // It fuses an observation of the three values starting from `first_index`.
+                // It returns `false`, leaving the state unchanged, if the observation cannot be fused.
+                fn update_block(
+                    &mut self,
+                    first_index: usize,
+                    observation_values: &[Number; 3],
+                    observation_covariances: &[[Number; 3]; 3],
+                ) -> bool {
+                    measures::matrix_utils::kalman_update(
+                        &mut self.values,
+                        &mut self.covariances,
+                        &core::array::from_fn(|row| {
+                            core::array::from_fn(|column| {
+                                if column == row + first_index { Number::ONE } else { Number::ZERO }
+                            })
+                        }),
+                        observation_values,
+                        observation_covariances,
+                    )
+                }
+
+                // It advances the state by a step of time,
+                // during which the velocity is transformed by `velocity_matrix`
+                // and it changes uniformly by `velocity_change`.
+                // The position is advanced by the mean of the initial and the final velocity.
+                fn predict_with_matrix(
+                    &mut self,
+                    step: Number,
+                    velocity_matrix: &[[Number; 3]; 3],
+                    velocity_change: ApproxMeasure3d<VelocityUnit, Number>,
+                ) {
+                    let half_step = step * Number::HALF;
+                    // Jacobian of the new state with respect to the old state.
+                    let transition: [[Number; 6]; 6] = core::array::from_fn(|row| {
+                        core::array::from_fn(|column| {
+                            let identity = if row % 3 == column % 3 { Number::ONE } else { Number::ZERO };
+                            if row < 3 && column < 3 {
+                                identity
+                            } else if row < 3 {
+                                half_step * (identity + velocity_matrix[row][column - 3])
+                            } else if column < 3 {
+                                Number::ZERO
+                            } else {
+                                velocity_matrix[row - 3][column - 3]
+                            }
+                        })
+                    });
+                    // Jacobian of the new state with respect to the velocity change.
+                    let change_effect: [[Number; 3]; 6] = core::array::from_fn(|row| {
+                        core::array::from_fn(|column| {
+                            if row % 3 != column {
+                                Number::ZERO
+                            } else if row < 3 {
+                                half_step
+                            } else {
+                                Number::ONE
+                            }
+                        })
+                    });
+                    let transformed = measures::matrix_utils::matrix_product(
+                        &transition,
+                        &self.values.map(|v| [v]),
+                    );
+                    let mut covariances = measures::matrix_utils::transformed_covariances(
+                        &transition,
+                        &self.covariances,
+                    );
+                    let noise = measures::matrix_utils::transformed_covariances(
+                        &change_effect,
+                        &velocity_change.covariances,
+                    );
+                    for row in 0..6 {
+                        self.values[row] = transformed[row][0]
+                            + (0..3).fold(Number::ZERO, |sum, k| {
+                                sum + change_effect[row][k] * velocity_change.values[k]
+                            });
+                        for column in 0..6 {
+                            covariances[row][column] += noise[row][column];
+                        }
+                    }
+                    self.covariances = covariances;
+                }
+            }
+
+            impl<Unit, VelocityUnit, Number> Clone for KinematicKalmanFilter3d<Unit, VelocityUnit, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                VelocityUnit: VectorMeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                fn clone(&self) -> Self {
+                    *self
+                }
+            }
+
+            impl<Unit, VelocityUnit, Number> Copy for KinematicKalmanFilter3d<Unit, VelocityUnit, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                VelocityUnit: VectorMeasurementUnit,
+                Number: ArithmeticOps,
+            {
+            }
+        }
+
+        measures::if_all_true! { { $with_points $with_3d $with_transformations }
+            impl<Unit, Number> KalmanFilter3d<Unit, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                /// KalmanFilter3d.predict_with_linear_map(LinearMap3d, ApproxMeasure3d)
+                /// It transforms the state by a linear map, around the origin,
+                /// and then it moves it by a displacement, whose covariances are the process noise.
+                pub fn predict_with_linear_map(
+                    &mut self,
+                    transition: &LinearMap3d<Number>,
+                    displacement: ApproxMeasure3d<Unit, Number>,
+                ) {
+                    self.predict_with_matrix(&transition.c, [Number::ZERO; 3], displacement);
+                }
+
+                /// KalmanFilter3d.predict_with_affine_map(AffineMap3d, ApproxMeasure3d)
+                /// It transforms the state by an affine map,
+                /// and then it moves it by a displacement, whose covariances are the process noise.
+                pub fn predict_with_affine_map(
+                    &mut self,
+                    transition: &AffineMap3d<Unit, Number>,
+                    displacement: ApproxMeasure3d<Unit, Number>,
+                ) {
+                    self.predict_with_matrix(
+                        &core::array::from_fn(|row| core::array::from_fn(|column| transition.c[row][column])),
+                        core::array::from_fn(|row| transition.c[row][3]),
+                        displacement,
+                    );
+                }
+            }
+
+            impl<Unit, VelocityUnit, Number> KinematicKalmanFilter3d<Unit, VelocityUnit, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                VelocityUnit: VectorMeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                /// KinematicKalmanFilter3d.predict_with_linear_map(Measure, LinearMap3d, ApproxMeasure3d)
+                /// It advances the state by a step of time, during which the velocity
+                /// is transformed by a linear map, like a rotation,
+                /// and it changes uniformly by `velocity_change`,
+                /// whose covariances are the process noise.
+                /// The position is advanced by the mean of the initial and the final velocity.
+                pub fn predict_with_linear_map<TimeUnit>(
+                    &mut self,
+                    step: Measure<TimeUnit, Number>,
+                    velocity_transition: &LinearMap3d<Number>,
+                    velocity_change: ApproxMeasure3d<VelocityUnit, Number>,
+                ) where
+                    TimeUnit: MeasurementUnit,
+                    Measure<VelocityUnit, Number>: Mul<Measure<TimeUnit, Number>, Output = Measure<Unit, Number>>,
+                {
+                    self.predict_with_matrix(step.value, &velocity_transition.c, velocity_change);
+                }
+
+                /// KinematicKalmanFilter3d.transform(AffineMap3d)
+                /// It applies an affine map to the position, and its linear part to the velocity,
+                /// like when changing the reference frame.
+                pub fn transform(&mut self, map: &AffineMap3d<Unit, Number>) {
+                    let transition: [[Number; 6]; 6] = core::array::from_fn(|row| {
+                        core::array::from_fn(|column| {
+                            if row / 3 == column / 3 {
+                                map.c[row % 3][column % 3]
+                            } else {
+                                Number::ZERO
+                            }
+                        })
+                    });
+                    let transformed = measures::matrix_utils::matrix_product(
+                        &transition,
+                        &self.values.map(|v| [v]),
+                    );
+                    self.values = core::array::from_fn(|row| {
+                        transformed[row][0] + if row < 3 { map.c[row][3] } else { Number::ZERO }
+                    });
+                    self.covariances = measures::matrix_utils::transformed_covariances(
+                        &transition,
+                        &self.covariances,
+                    );
+                }
+            }
+        }
+    };
+}
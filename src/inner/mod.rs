@@ -1,2 +1,10 @@
 pub mod approx;
+pub mod differentiation;
 pub mod exact;
+pub mod integration;
+pub mod interpolation;
+pub mod kalman;
+#[cfg(feature = "nalgebra")]
+pub mod nalgebra;
+pub mod serde_formats;
+pub mod solvers;
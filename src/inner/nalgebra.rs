@@ -0,0 +1,326 @@
+#[macro_export] // Don't add nor remove the first three lines and the last two lines.
+macro_rules! inner_define_nalgebra_conversions {
+    { $with_points:ident $with_2d:ident $with_3d:ident $with_transformations:ident $with_approx:ident } => {
+        measures::if_all_true! { { $with_2d }
+            impl<Unit, Number> From<measures::nalgebra::Vector2<Number>> for Measure2d<Unit, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps + measures::nalgebra::Scalar,
+            {
+                /// Measure2d::from(nalgebra::Vector2) -> Measure2d
+                /// The components of the vector are meant to be in the unit `Unit`.
+                fn from(v: measures::nalgebra::Vector2<Number>) -> Self {
+                    Self::new([v[0], v[1]])
+                }
+            }
+
+            impl<Unit, Number> Measure2d<Unit, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps + measures::nalgebra::Scalar,
+            {
+                /// Measure2d.to_nalgebra_in::<DestUnit>() -> nalgebra::Vector2
+                /// The components of the vector are expressed in the unit `DestUnit`,
+                /// as the types of `nalgebra` have no unit.
+                pub fn to_nalgebra_in<DestUnit>(self) -> measures::nalgebra::Vector2<Number>
+                where
+                    DestUnit: VectorMeasurementUnit<Property = Unit::Property>,
+                {
+                    let values = self.convert::<DestUnit>().values;
+                    measures::nalgebra::Vector2::new(values[0], values[1])
+                }
+            }
+        }
+
+        measures::if_all_true! { { $with_2d $with_points }
+            impl<Unit, Number> From<measures::nalgebra::Point2<Number>> for MeasurePoint2d<Unit, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps + measures::nalgebra::Scalar,
+            {
+                /// MeasurePoint2d::from(nalgebra::Point2) -> MeasurePoint2d
+                /// The coordinates of the point are meant to be in the unit `Unit`.
+                fn from(p: measures::nalgebra::Point2<Number>) -> Self {
+                    Self::new([p[0], p[1]])
+                }
+            }
+
+            impl<Unit, Number> MeasurePoint2d<Unit, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps + measures::nalgebra::Scalar,
+            {
+                /// MeasurePoint2d.to_nalgebra_in::<DestUnit>() -> nalgebra::Point2
+                /// The coordinates of the point are expressed in the unit `DestUnit`,
+                /// as the types of `nalgebra` have no unit.
+                pub fn to_nalgebra_in<DestUnit>(self) -> measures::nalgebra::Point2<Number>
+                where
+                    DestUnit: VectorMeasurementUnit<Property = Unit::Property>,
+                {
+                    let values = self.convert::<DestUnit>().values;
+                    measures::nalgebra::Point2::new(values[0], values[1])
+                }
+            }
+        }
+
+        measures::if_all_true! { { $with_2d $with_transformations }
+            impl<Number> From<measures::nalgebra::Matrix2<Number>> for LinearMap2d<Number>
+            where
+                Number: ArithmeticOps + measures::nalgebra::Scalar,
+            {
+                /// LinearMap2d::from(nalgebra::Matrix2) -> LinearMap2d
+                fn from(m: measures::nalgebra::Matrix2<Number>) -> Self {
+                    Self::new(core::array::from_fn(|i| core::array::from_fn(|j| m[(i, j)])))
+                }
+            }
+
+            impl<Number> From<LinearMap2d<Number>> for measures::nalgebra::Matrix2<Number>
+            where
+                Number: ArithmeticOps + measures::nalgebra::Scalar,
+            {
+                /// nalgebra::Matrix2::from(LinearMap2d) -> nalgebra::Matrix2
+                fn from(map: LinearMap2d<Number>) -> Self {
+                    Self::from_fn(|i, j| map.c[i][j])
+                }
+            }
+        }
+
+        measures::if_all_true! { { $with_2d $with_transformations $with_points }
+            impl<Unit, Number> From<measures::nalgebra::Affine2<Number>> for AffineMap2d<Unit, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps + measures::nalgebra::RealField,
+            {
+                /// AffineMap2d::from(nalgebra::Affine2) -> AffineMap2d
+                /// The translation of the transformation is meant to be in the unit `Unit`.
+                fn from(transform: measures::nalgebra::Affine2<Number>) -> Self {
+                    let m = transform.matrix();
+                    Self::new(core::array::from_fn(|i| core::array::from_fn(|j| m[(i, j)])))
+                }
+            }
+
+            impl<Unit, Number> From<measures::nalgebra::Isometry2<Number>> for AffineMap2d<Unit, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps + measures::nalgebra::RealField,
+            {
+                /// AffineMap2d::from(nalgebra::Isometry2) -> AffineMap2d
+                /// The translation of the isometry is meant to be in the unit `Unit`.
+                fn from(isometry: measures::nalgebra::Isometry2<Number>) -> Self {
+                    let m = isometry.to_homogeneous();
+                    Self::new(core::array::from_fn(|i| core::array::from_fn(|j| m[(i, j)])))
+                }
+            }
+
+            impl<Unit, Number> AffineMap2d<Unit, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps + measures::nalgebra::RealField,
+            {
+                /// AffineMap2d.to_nalgebra_in::<DestUnit>() -> nalgebra::Affine2
+                /// The translation of the transformation is expressed in the unit `DestUnit`,
+                /// as the types of `nalgebra` have no unit.
+                pub fn to_nalgebra_in<DestUnit>(&self) -> measures::nalgebra::Affine2<Number>
+                where
+                    DestUnit: VectorMeasurementUnit<Property = Unit::Property>,
+                {
+                    let map = self.convert::<DestUnit>();
+                    measures::nalgebra::Affine2::from_matrix_unchecked(measures::nalgebra::Matrix3::from_fn(|i, j| {
+                        if i < 2 {
+                            map.c[i][j]
+                        } else if j < 2 {
+                            Number::ZERO
+                        } else {
+                            Number::ONE
+                        }
+                    }))
+                }
+            }
+        }
+
+        measures::if_all_true! { { $with_2d $with_approx }
+            impl<Unit, Number> ApproxMeasure2d<Unit, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps + measures::nalgebra::Scalar,
+            {
+                /// ApproxMeasure2d::from_nalgebra(nalgebra::Vector2, nalgebra::Matrix2) -> ApproxMeasure2d
+                /// The components of the vector are meant to be in the unit `Unit`,
+                /// and the covariances in the square of such unit.
+                pub fn from_nalgebra(values: measures::nalgebra::Vector2<Number>, covariances: measures::nalgebra::Matrix2<Number>) -> Self {
+                    Self::with_covariances(
+                        core::array::from_fn(|i| values[i]),
+                        core::array::from_fn(|i| core::array::from_fn(|j| covariances[(i, j)])),
+                    )
+                }
+
+                /// ApproxMeasure2d.covariance_matrix() -> nalgebra::Matrix2
+                /// The covariances are in the square of the unit of the measure.
+                pub fn covariance_matrix(&self) -> measures::nalgebra::Matrix2<Number> {
+                    measures::nalgebra::Matrix2::from_fn(|i, j| self.covariances[i][j])
+                }
+            }
+        }
+
+        measures::if_all_true! { { $with_3d }
+            impl<Unit, Number> From<measures::nalgebra::Vector3<Number>> for Measure3d<Unit, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps + measures::nalgebra::Scalar,
+            {
+                /// Measure3d::from(nalgebra::Vector3) -> Measure3d
+                /// The components of the vector are meant to be in the unit `Unit`.
+                fn from(v: measures::nalgebra::Vector3<Number>) -> Self {
+                    Self::new([v[0], v[1], v[2]])
+                }
+            }
+
+            impl<Unit, Number> Measure3d<Unit, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps + measures::nalgebra::Scalar,
+            {
+                /// Measure3d.to_nalgebra_in::<DestUnit>() -> nalgebra::Vector3
+                /// The components of the vector are expressed in the unit `DestUnit`,
+                /// as the types of `nalgebra` have no unit.
+                pub fn to_nalgebra_in<DestUnit>(self) -> measures::nalgebra::Vector3<Number>
+                where
+                    DestUnit: VectorMeasurementUnit<Property = Unit::Property>,
+                {
+                    let values = self.convert::<DestUnit>().values;
+                    measures::nalgebra::Vector3::new(values[0], values[1], values[2])
+                }
+            }
+        }
+
+        measures::if_all_true! { { $with_3d $with_points }
+            impl<Unit, Number> From<measures::nalgebra::Point3<Number>> for MeasurePoint3d<Unit, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps + measures::nalgebra::Scalar,
+            {
+                /// MeasurePoint3d::from(nalgebra::Point3) -> MeasurePoint3d
+                /// The coordinates of the point are meant to be in the unit `Unit`.
+                fn from(p: measures::nalgebra::Point3<Number>) -> Self {
+                    Self::new([p[0], p[1], p[2]])
+                }
+            }
+
+            impl<Unit, Number> MeasurePoint3d<Unit, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps + measures::nalgebra::Scalar,
+            {
+                /// MeasurePoint3d.to_nalgebra_in::<DestUnit>() -> nalgebra::Point3
+                /// The coordinates of the point are expressed in the unit `DestUnit`,
+                /// as the types of `nalgebra` have no unit.
+                pub fn to_nalgebra_in<DestUnit>(self) -> measures::nalgebra::Point3<Number>
+                where
+                    DestUnit: VectorMeasurementUnit<Property = Unit::Property>,
+                {
+                    let values = self.convert::<DestUnit>().values;
+                    measures::nalgebra::Point3::new(values[0], values[1], values[2])
+                }
+            }
+        }
+
+        measures::if_all_true! { { $with_3d $with_transformations }
+            impl<Number> From<measures::nalgebra::Matrix3<Number>> for LinearMap3d<Number>
+            where
+                Number: ArithmeticOps + measures::nalgebra::Scalar,
+            {
+                /// LinearMap3d::from(nalgebra::Matrix3) -> LinearMap3d
+                fn from(m: measures::nalgebra::Matrix3<Number>) -> Self {
+                    Self::new(core::array::from_fn(|i| core::array::from_fn(|j| m[(i, j)])))
+                }
+            }
+
+            impl<Number> From<LinearMap3d<Number>> for measures::nalgebra::Matrix3<Number>
+            where
+                Number: ArithmeticOps + measures::nalgebra::Scalar,
+            {
+                /// nalgebra::Matrix3::from(LinearMap3d) -> nalgebra::Matrix3
+                fn from(map: LinearMap3d<Number>) -> Self {
+                    Self::from_fn(|i, j| map.c[i][j])
+                }
+            }
+        }
+
+        measures::if_all_true! { { $with_3d $with_transformations $with_points }
+            impl<Unit, Number> From<measures::nalgebra::Affine3<Number>> for AffineMap3d<Unit, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps + measures::nalgebra::RealField,
+            {
+                /// AffineMap3d::from(nalgebra::Affine3) -> AffineMap3d
+                /// The translation of the transformation is meant to be in the unit `Unit`.
+                fn from(transform: measures::nalgebra::Affine3<Number>) -> Self {
+                    let m = transform.matrix();
+                    Self::new(core::array::from_fn(|i| core::array::from_fn(|j| m[(i, j)])))
+                }
+            }
+
+            impl<Unit, Number> From<measures::nalgebra::Isometry3<Number>> for AffineMap3d<Unit, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps + measures::nalgebra::RealField,
+            {
+                /// AffineMap3d::from(nalgebra::Isometry3) -> AffineMap3d
+                /// The translation of the isometry is meant to be in the unit `Unit`.
+                fn from(isometry: measures::nalgebra::Isometry3<Number>) -> Self {
+                    let m = isometry.to_homogeneous();
+                    Self::new(core::array::from_fn(|i| core::array::from_fn(|j| m[(i, j)])))
+                }
+            }
+
+            impl<Unit, Number> AffineMap3d<Unit, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps + measures::nalgebra::RealField,
+            {
+                /// AffineMap3d.to_nalgebra_in::<DestUnit>() -> nalgebra::Affine3
+                /// The translation of the transformation is expressed in the unit `DestUnit`,
+                /// as the types of `nalgebra` have no unit.
+                pub fn to_nalgebra_in<DestUnit>(&self) -> measures::nalgebra::Affine3<Number>
+                where
+                    DestUnit: VectorMeasurementUnit<Property = Unit::Property>,
+                {
+                    let map = self.convert::<DestUnit>();
+                    measures::nalgebra::Affine3::from_matrix_unchecked(measures::nalgebra::Matrix4::from_fn(|i, j| {
+                        if i < 3 {
+                            map.c[i][j]
+                        } else if j < 3 {
+                            Number::ZERO
+                        } else {
+                            Number::ONE
+                        }
+                    }))
+                }
+            }
+        }
+
+        measures::if_all_true! { { $with_3d $with_approx }
+            impl<Unit, Number> ApproxMeasure3d<Unit, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps + measures::nalgebra::Scalar,
+            {
+                /// ApproxMeasure3d::from_nalgebra(nalgebra::Vector3, nalgebra::Matrix3) -> ApproxMeasure3d
+                /// The components of the vector are meant to be in the unit `Unit`,
+                /// and the covariances in the square of such unit.
+                pub fn from_nalgebra(values: measures::nalgebra::Vector3<Number>, covariances: measures::nalgebra::Matrix3<Number>) -> Self {
+                    Self::with_covariances(
+                        core::array::from_fn(|i| values[i]),
+                        core::array::from_fn(|i| core::array::from_fn(|j| covariances[(i, j)])),
+                    )
+                }
+
+                /// ApproxMeasure3d.covariance_matrix() -> nalgebra::Matrix3
+                /// The covariances are in the square of the unit of the measure.
+                pub fn covariance_matrix(&self) -> measures::nalgebra::Matrix3<Number> {
+                    measures::nalgebra::Matrix3::from_fn(|i, j| self.covariances[i][j])
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,759 @@
+#[macro_export] // Don't add nor remove the first three lines and the last two lines.
+macro_rules! inner_define_serde_formats {
+    { $with_points:ident $with_approx:ident $with_2d:ident $with_3d:ident $with_transformations:ident } => {
+        /// Measurement property whose units are known at runtime,
+        /// so that the modules `serde_tagged` and `serde_text` can deserialize
+        /// measures expressed in any unit of the property.
+        pub trait PropertyUnits {
+            const UNITS: &'static [measures::traits::UnitDescription];
+        }
+
+        /// Measure types which can be serialized together with their unit,
+        /// by the module `serde_tagged`.
+        pub trait MeasureWithUnit: Sized {
+            type Unit: MeasurementUnit;
+            type Number: BasicArithmeticOps;
+
+            /// Whether the measure has a variance, serialized in the field "variance".
+            const HAS_VARIANCE: bool;
+
+            /// MeasureWithUnit.to_parts() -> (value, variance)
+            /// The variance of exact measures is zero.
+            fn to_parts(&self) -> (Self::Number, Self::Number);
+
+            /// MeasureWithUnit::from_parts(value, variance, &UnitDescription) -> Option<MeasureWithUnit>
+            /// The value and the variance are expressed in the given unit,
+            /// and they are converted to the unit of the measure.
+            /// It returns `None` for the relative measures if a unit isn't linear,
+            /// as only the measure points can be converted by the functions of the units.
+            fn from_parts(
+                value: Self::Number,
+                variance: Self::Number,
+                unit: &measures::traits::UnitDescription,
+            ) -> Option<Self>;
+        }
+
+        /// Exact measure types which can be serialized as text, by the module `serde_text`.
+        pub trait ExactMeasureWithUnit: MeasureWithUnit {}
+
+        impl<Unit, Number> MeasureWithUnit for Measure<Unit, Number>
+        where
+            Unit: MeasurementUnit,
+            Number: BasicArithmeticOps,
+        {
+            type Unit = Unit;
+            type Number = Number;
+            const HAS_VARIANCE: bool = false;
+
+            fn to_parts(&self) -> (Number, Number) {
+                (self.value, Number::ZERO)
+            }
+
+            fn from_parts(
+                value: Number,
+                _variance: Number,
+                unit: &measures::traits::UnitDescription,
+            ) -> Option<Self> {
+                if unit.linear && Unit::LINEAR {
+                    Some(Self::new(value.scale_by_ratio(unit.ratio / Unit::RATIO)))
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl<Unit, Number> ExactMeasureWithUnit for Measure<Unit, Number>
+        where
+            Unit: MeasurementUnit,
+            Number: BasicArithmeticOps,
+        {
+        }
+
+        measures::if_all_true! { { $with_points }
+            impl<Unit, Number> MeasureWithUnit for MeasurePoint<Unit, Number>
+            where
+                Unit: MeasurementUnit,
+                Number: BasicArithmeticOps,
+            {
+                type Unit = Unit;
+                type Number = Number;
+                const HAS_VARIANCE: bool = false;
+
+                fn to_parts(&self) -> (Number, Number) {
+                    (self.value, Number::ZERO)
+                }
+
+                fn from_parts(value: Number, _variance: Number, unit: &measures::traits::UnitDescription) -> Option<Self> {
+                    if unit.linear && Unit::LINEAR {
+                        Some(Self::new(
+                            value.scale_by_ratio(unit.ratio / Unit::RATIO)
+                                + Number::ONE.scale_by_ratio((unit.offset - Unit::OFFSET) / Unit::RATIO),
+                        ))
+                    } else {
+                        Some(Self::new(value.apply_conversion(|value| Unit::from_base((unit.to_base)(value)))))
+                    }
+                }
+            }
+
+            impl<Unit, Number> ExactMeasureWithUnit for MeasurePoint<Unit, Number>
+            where
+                Unit: MeasurementUnit,
+                Number: BasicArithmeticOps,
+            {
+            }
+        }
+
+        measures::if_all_true! { { $with_approx }
+            impl<Unit, Number> MeasureWithUnit for ApproxMeasure<Unit, Number>
+            where
+                Unit: MeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                type Unit = Unit;
+                type Number = Number;
+                const HAS_VARIANCE: bool = true;
+
+                fn to_parts(&self) -> (Number, Number) {
+                    (self.value, self.variance)
+                }
+
+                fn from_parts(value: Number, variance: Number, unit: &measures::traits::UnitDescription) -> Option<Self> {
+                    if unit.linear && Unit::LINEAR {
+                        let ratio = unit.ratio / Unit::RATIO;
+                        Some(Self::with_variance(value.scale_by_ratio(ratio), variance.scale_by_ratio(ratio * ratio)))
+                    } else {
+                        None
+                    }
+                }
+            }
+        }
+
+        measures::if_all_true! { { $with_approx $with_points }
+            impl<Unit, Number> MeasureWithUnit for ApproxMeasurePoint<Unit, Number>
+            where
+                Unit: MeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                type Unit = Unit;
+                type Number = Number;
+                const HAS_VARIANCE: bool = true;
+
+                fn to_parts(&self) -> (Number, Number) {
+                    (self.value, self.variance)
+                }
+
+                fn from_parts(value: Number, variance: Number, unit: &measures::traits::UnitDescription) -> Option<Self> {
+                    if unit.linear && Unit::LINEAR {
+                        let ratio = unit.ratio / Unit::RATIO;
+                        Some(Self::with_variance(
+                            value.scale_by_ratio(ratio)
+                                + Number::ONE.scale_by_ratio((unit.offset - Unit::OFFSET) / Unit::RATIO),
+                            variance.scale_by_ratio(ratio * ratio),
+                        ))
+                    } else {
+                        let conversion = |value| Unit::from_base((unit.to_base)(value));
+                        let derivative = value
+                            .apply_conversion(|value| measures::traits::conversion_derivative(conversion, value));
+                        Some(Self::with_variance(value.apply_conversion(conversion), variance * derivative * derivative))
+                    }
+                }
+            }
+        }
+
+        /// Serialization of measures as structs having the fields "value" and "unit",
+        /// and, for approximate measures, "variance", like `{"value":10.0,"unit":"m"}`.
+        /// To be used by the attribute `#[serde(with = "serde_tagged")]` on a field.
+        /// The deserialization accepts any unit of the property of the measure,
+        /// and converts the value to the unit of the measure.
+        pub mod serde_tagged {
+            use super::{MeasureWithUnit, PropertyUnits};
+            use core::fmt;
+            use core::marker::PhantomData;
+            use measures::traits::{MeasurementUnit, UnitDescription};
+            use serde::de::{self, DeserializeSeed, MapAccess, Visitor};
+            use serde::ser::SerializeStruct;
+
+            const FIELDS: &[&str] = &["value", "variance", "unit"];
+
+            /// serde_tagged::serialize(&MeasureWithUnit, Serializer) -> Result
+            pub fn serialize<M, S>(measure: &M, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                M: MeasureWithUnit,
+                M::Number: serde::Serialize,
+                S: serde::Serializer,
+            {
+                let (value, variance) = measure.to_parts();
+                let mut state =
+                    serializer.serialize_struct("Measure", if M::HAS_VARIANCE { 3 } else { 2 })?;
+                state.serialize_field("value", &value)?;
+                if M::HAS_VARIANCE {
+                    state.serialize_field("variance", &variance)?;
+                }
+                state.serialize_field("unit", <M::Unit as MeasurementUnit>::SUFFIX.trim())?;
+                state.end()
+            }
+
+            /// serde_tagged::deserialize(Deserializer) -> Result<MeasureWithUnit>
+            pub fn deserialize<'de, M, De>(deserializer: De) -> Result<M, De::Error>
+            where
+                M: MeasureWithUnit,
+                <M::Unit as MeasurementUnit>::Property: PropertyUnits,
+                M::Number: serde::Deserialize<'de>,
+                De: serde::Deserializer<'de>,
+            {
+                deserializer.deserialize_struct("Measure", FIELDS, TaggedVisitor(PhantomData::<M>))
+            }
+
+            enum Field {
+                Value,
+                Variance,
+                Unit,
+            }
+
+            impl<'de> serde::Deserialize<'de> for Field {
+                fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+                where
+                    De: serde::Deserializer<'de>,
+                {
+                    deserializer.deserialize_identifier(FieldVisitor)
+                }
+            }
+
+            struct FieldVisitor;
+
+            impl<'de> Visitor<'de> for FieldVisitor {
+                type Value = Field;
+
+                fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    formatter.write_str("`value`, `variance`, or `unit`")
+                }
+
+                fn visit_str<E: de::Error>(self, name: &str) -> Result<Field, E> {
+                    match name {
+                        "value" => Ok(Field::Value),
+                        "variance" => Ok(Field::Variance),
+                        "unit" => Ok(Field::Unit),
+                        _ => Err(E::unknown_field(name, FIELDS)),
+                    }
+                }
+            }
+
+            /// It deserializes a unit symbol, looking for it among the given units.
+            struct UnitSeed(&'static [UnitDescription]);
+
+            impl<'de> DeserializeSeed<'de> for UnitSeed {
+                type Value = &'static UnitDescription;
+
+                fn deserialize<De>(self, deserializer: De) -> Result<Self::Value, De::Error>
+                where
+                    De: serde::Deserializer<'de>,
+                {
+                    deserializer.deserialize_str(self)
+                }
+            }
+
+            impl<'de> Visitor<'de> for UnitSeed {
+                type Value = &'static UnitDescription;
+
+                fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(
+                        formatter,
+                        "one of the units {}",
+                        UnitDescription::symbols(self.0)
+                    )
+                }
+
+                fn visit_str<E: de::Error>(self, symbol: &str) -> Result<Self::Value, E> {
+                    UnitDescription::find(self.0, symbol).ok_or_else(|| {
+                        E::custom(format_args!(
+                            "unknown unit `{}`, expected one of the units {}",
+                            symbol,
+                            UnitDescription::symbols(self.0)
+                        ))
+                    })
+                }
+            }
+
+            struct TaggedVisitor<M>(PhantomData<M>);
+
+            impl<'de, M> Visitor<'de> for TaggedVisitor<M>
+            where
+                M: MeasureWithUnit,
+                <M::Unit as MeasurementUnit>::Property: PropertyUnits,
+                M::Number: serde::Deserialize<'de>,
+            {
+                type Value = M;
+
+                fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    if M::HAS_VARIANCE {
+                        formatter.write_str("a measure with the fields `value`, `variance`, and `unit`")
+                    } else {
+                        formatter.write_str("a measure with the fields `value` and `unit`")
+                    }
+                }
+
+                fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<M, A::Error> {
+                    let units = <<M::Unit as MeasurementUnit>::Property as PropertyUnits>::UNITS;
+                    let mut value: Option<M::Number> = None;
+                    let mut variance: Option<M::Number> = None;
+                    let mut unit: Option<&UnitDescription> = None;
+                    while let Some(field) = map.next_key::<Field>()? {
+                        match field {
+                            Field::Value if value.is_none() => value = Some(map.next_value()?),
+                            Field::Value => return Err(de::Error::duplicate_field("value")),
+                            Field::Variance if !M::HAS_VARIANCE => {
+                                return Err(de::Error::unknown_field("variance", &["value", "unit"]))
+                            }
+                            Field::Variance if variance.is_none() => variance = Some(map.next_value()?),
+                            Field::Variance => return Err(de::Error::duplicate_field("variance")),
+                            Field::Unit if unit.is_none() => {
+                                unit = Some(map.next_value_seed(UnitSeed(units))?)
+                            }
+                            Field::Unit => return Err(de::Error::duplicate_field("unit")),
+                        }
+                    }
+                    let value = value.ok_or_else(|| de::Error::missing_field("value"))?;
+                    let unit = unit.ok_or_else(|| de::Error::missing_field("unit"))?;
+                    let variance = match variance {
+                        Some(variance) => variance,
+                        None if M::HAS_VARIANCE => return Err(de::Error::missing_field("variance")),
+                        None => <M::Number as measures::traits::HasZero>::ZERO,
+                    };
+                    M::from_parts(value, variance, unit).ok_or_else(|| {
+                        de::Error::custom(format_args!(
+                            "the unit `{}` is not linear, and so it can be used only for measure points",
+                            unit.symbol()
+                        ))
+                    })
+                }
+            }
+        }
+
+        /// Serialization of exact measures as strings containing the value and the unit, like "10 m".
+        /// To be used by the attribute `#[serde(with = "serde_text")]` on a field.
+        /// The deserialization accepts any unit of the property of the measure,
+        /// and converts the value to the unit of the measure.
+        pub mod serde_text {
+            use super::{ExactMeasureWithUnit, PropertyUnits};
+            use core::fmt;
+            use core::marker::PhantomData;
+            use measures::traits::{MeasurementUnit, UnitDescription};
+            use serde::de::{self, Visitor};
+
+            /// serde_text::serialize(&ExactMeasureWithUnit, Serializer) -> Result
+            pub fn serialize<M, S>(measure: &M, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                M: ExactMeasureWithUnit,
+                S: serde::Serializer,
+            {
+                let (value, _) = measure.to_parts();
+                serializer.collect_str(&format_args!(
+                    "{}{}",
+                    value,
+                    <M::Unit as MeasurementUnit>::SUFFIX
+                ))
+            }
+
+            /// serde_text::deserialize(Deserializer) -> Result<ExactMeasureWithUnit>
+            pub fn deserialize<'de, M, De>(deserializer: De) -> Result<M, De::Error>
+            where
+                M: ExactMeasureWithUnit,
+                <M::Unit as MeasurementUnit>::Property: PropertyUnits,
+                M::Number: core::str::FromStr,
+                De: serde::Deserializer<'de>,
+            {
+                deserializer.deserialize_str(TextVisitor(PhantomData::<M>))
+            }
+
+            struct TextVisitor<M>(PhantomData<M>);
+
+            impl<'de, M> Visitor<'de> for TextVisitor<M>
+            where
+                M: ExactMeasureWithUnit,
+                <M::Unit as MeasurementUnit>::Property: PropertyUnits,
+                M::Number: core::str::FromStr,
+            {
+                type Value = M;
+
+                fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    formatter.write_str("a string containing a number followed by a unit")
+                }
+
+                fn visit_str<E: de::Error>(self, text: &str) -> Result<M, E> {
+                    let units = <<M::Unit as MeasurementUnit>::Property as PropertyUnits>::UNITS;
+                    let (value, unit) = UnitDescription::split(units, text)
+                        .and_then(|(number, unit)| Some((number.parse().ok()?, unit)))
+                        .ok_or_else(|| {
+                            E::custom(format_args!(
+                                "cannot parse `{}` as a number followed by one of the units {}",
+                                text,
+                                UnitDescription::symbols(units)
+                            ))
+                        })?;
+                    M::from_parts(value, <M::Number as measures::traits::HasZero>::ZERO, unit).ok_or_else(|| {
+                                        E::custom(format_args!(
+                                            "the unit `{}` is not linear, and so it can be used only for measure points",
+                                            unit.symbol()
+                                        ))
+                                    })
+                }
+            }
+        }
+
+        /// Transformations which can be serialized as a rotation angle,
+        /// around an axis for the transformations in the space,
+        /// followed, for the affine transformations, by a translation,
+        /// by the module `serde_axis_angle`.
+        pub trait AxisAngleMap: Sized {
+            type Number: ArithmeticOps;
+
+            /// 2 for the transformations in a plane, 3 for the ones in the space.
+            const DIMENSIONS: usize;
+
+            /// Whether the transformation has a translation, serialized in the field "translation".
+            const HAS_TRANSLATION: bool;
+
+            /// AxisAngleMap.to_axis_angle() -> Option<(axis, radians, translation)>
+            /// It returns `None` if the transformation is not a rotation, followed by a translation.
+            /// The axis of the transformations in a plane is the z axis,
+            /// and the last component of their translation is zero.
+            fn to_axis_angle(&self) -> Option<([Self::Number; 3], Self::Number, [Self::Number; 3])>;
+
+            /// AxisAngleMap::from_axis_angle(axis, radians, translation) -> AxisAngleMap
+            /// Precondition: the axis is a unit vector.
+            fn from_axis_angle(
+                axis: [Self::Number; 3],
+                radians: Self::Number,
+                translation: [Self::Number; 3],
+            ) -> Self;
+        }
+
+        measures::if_all_true! { { $with_2d $with_transformations }
+            impl<Number: ArithmeticOps> AxisAngleMap for LinearMap2d<Number> {
+                type Number = Number;
+                const DIMENSIONS: usize = 2;
+                const HAS_TRANSLATION: bool = false;
+
+                fn to_axis_angle(&self) -> Option<([Number; 3], Number, [Number; 3])> {
+                    let angle = measures::matrix_utils::rotation_angle_2d(&self.c)?;
+                    Some(([Number::ZERO, Number::ZERO, Number::ONE], angle, [Number::ZERO; 3]))
+                }
+
+                fn from_axis_angle(_axis: [Number; 3], radians: Number, _translation: [Number; 3]) -> Self {
+                    Self::rotation(Measure::<Radian, Number>::new(radians))
+                }
+            }
+        }
+
+        measures::if_all_true! { { $with_3d $with_transformations }
+            impl<Number: ArithmeticOps> AxisAngleMap for LinearMap3d<Number> {
+                type Number = Number;
+                const DIMENSIONS: usize = 3;
+                const HAS_TRANSLATION: bool = false;
+
+                fn to_axis_angle(&self) -> Option<([Number; 3], Number, [Number; 3])> {
+                    let (axis, angle) = measures::matrix_utils::rotation_axis_angle_3d(&self.c)?;
+                    Some((axis, angle, [Number::ZERO; 3]))
+                }
+
+                fn from_axis_angle(axis: [Number; 3], radians: Number, _translation: [Number; 3]) -> Self {
+                    Self::rotation(Measure::<Radian, Number>::new(radians), Measure3d::<One, Number>::new(axis))
+                }
+            }
+        }
+
+        measures::if_all_true! { { $with_2d $with_transformations $with_points }
+            impl<Unit, Number> AxisAngleMap for AffineMap2d<Unit, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                type Number = Number;
+                const DIMENSIONS: usize = 2;
+                const HAS_TRANSLATION: bool = true;
+
+                fn to_axis_angle(&self) -> Option<([Number; 3], Number, [Number; 3])> {
+                    let c = &self.c;
+                    let angle = measures::matrix_utils::rotation_angle_2d(&[[c[0][0], c[0][1]], [c[1][0], c[1][1]]])?;
+                    Some((
+                        [Number::ZERO, Number::ZERO, Number::ONE],
+                        angle,
+                        [c[0][2], c[1][2], Number::ZERO],
+                    ))
+                }
+
+                fn from_axis_angle(_axis: [Number; 3], radians: Number, translation: [Number; 3]) -> Self {
+                    let r = LinearMap2d::rotation(Measure::<Radian, Number>::new(radians)).c;
+                    Self::new([
+                        [r[0][0], r[0][1], translation[0]],
+                        [r[1][0], r[1][1], translation[1]],
+                    ])
+                }
+            }
+        }
+
+        measures::if_all_true! { { $with_3d $with_transformations $with_points }
+            impl<Unit, Number> AxisAngleMap for AffineMap3d<Unit, Number>
+            where
+                Unit: VectorMeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                type Number = Number;
+                const DIMENSIONS: usize = 3;
+                const HAS_TRANSLATION: bool = true;
+
+                fn to_axis_angle(&self) -> Option<([Number; 3], Number, [Number; 3])> {
+                    let c = &self.c;
+                    let (axis, angle) = measures::matrix_utils::rotation_axis_angle_3d(&[
+                        [c[0][0], c[0][1], c[0][2]],
+                        [c[1][0], c[1][1], c[1][2]],
+                        [c[2][0], c[2][1], c[2][2]],
+                    ])?;
+                    Some((axis, angle, [c[0][3], c[1][3], c[2][3]]))
+                }
+
+                fn from_axis_angle(axis: [Number; 3], radians: Number, translation: [Number; 3]) -> Self {
+                    let r = LinearMap3d::rotation(
+                        Measure::<Radian, Number>::new(radians),
+                        Measure3d::<One, Number>::new(axis),
+                    )
+                    .c;
+                    Self::new([
+                        [r[0][0], r[0][1], r[0][2], translation[0]],
+                        [r[1][0], r[1][1], r[1][2], translation[1]],
+                        [r[2][0], r[2][1], r[2][2], translation[2]],
+                    ])
+                }
+            }
+        }
+
+        /// Serialization of the transformations which are rotations, optionally followed by a translation,
+        /// as structs having the fields "axis", only for the transformations in the space,
+        /// "angle", in radians, and "translation", only for affine transformations,
+        /// like `{"axis":[0.0,0.0,1.0],"angle":0.5,"translation":[1.0,2.0,3.0]}`.
+        /// To be used by the attribute `#[serde(with = "serde_axis_angle")]` on a field.
+        /// The serialization fails if the transformation is not a rotation,
+        /// allowing only the rounding errors of the number type,
+        /// so that a transformation is never altered by serializing it.
+        /// The deserialization normalizes the axis.
+        pub mod serde_axis_angle {
+            use super::AxisAngleMap;
+            use core::fmt;
+            use core::marker::PhantomData;
+            use serde::de::{self, MapAccess, SeqAccess, Visitor};
+            use serde::ser::{self, SerializeStruct};
+
+            const FIELDS: &[&str] = &["axis", "angle", "translation"];
+
+            const NOT_A_ROTATION: &str = concat!(
+                "the transformation is not a rotation, ",
+                "and so it cannot be serialized as an axis and an angle",
+            );
+
+            // The fields used by a type of transformation.
+            fn fields<M: AxisAngleMap>() -> &'static [&'static str] {
+                match (M::DIMENSIONS, M::HAS_TRANSLATION) {
+                    (3, true) => FIELDS,
+                    (3, false) => &FIELDS[..2],
+                    (_, true) => &FIELDS[1..],
+                    (_, false) => &FIELDS[1..2],
+                }
+            }
+
+            /// serde_axis_angle::serialize(&AxisAngleMap, Serializer) -> Result
+            pub fn serialize<M, S>(map: &M, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                M: AxisAngleMap,
+                M::Number: serde::Serialize,
+                S: serde::Serializer,
+            {
+                let (axis, angle, translation) = map
+                    .to_axis_angle()
+                    .ok_or_else(|| ser::Error::custom(NOT_A_ROTATION))?;
+                let mut state = serializer.serialize_struct("AxisAngle", fields::<M>().len())?;
+                if M::DIMENSIONS == 3 {
+                    state.serialize_field("axis", &axis)?;
+                }
+                state.serialize_field("angle", &angle)?;
+                if M::HAS_TRANSLATION {
+                    if M::DIMENSIONS == 3 {
+                        state.serialize_field("translation", &translation)?;
+                    } else {
+                        state.serialize_field("translation", &[translation[0], translation[1]])?;
+                    }
+                }
+                state.end()
+            }
+
+            /// serde_axis_angle::deserialize(Deserializer) -> Result<AxisAngleMap>
+            pub fn deserialize<'de, M, De>(deserializer: De) -> Result<M, De::Error>
+            where
+                M: AxisAngleMap,
+                M::Number: serde::Deserialize<'de>,
+                De: serde::Deserializer<'de>,
+            {
+                deserializer.deserialize_struct(
+                    "AxisAngle",
+                    fields::<M>(),
+                    AxisAngleVisitor(PhantomData::<M>),
+                )
+            }
+
+            enum Field {
+                Axis,
+                Angle,
+                Translation,
+            }
+
+            impl<'de> serde::Deserialize<'de> for Field {
+                fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+                where
+                    De: serde::Deserializer<'de>,
+                {
+                    deserializer.deserialize_identifier(FieldVisitor)
+                }
+            }
+
+            struct FieldVisitor;
+
+            impl<'de> Visitor<'de> for FieldVisitor {
+                type Value = Field;
+
+                fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    formatter.write_str("`axis`, `angle`, or `translation`")
+                }
+
+                fn visit_str<E: de::Error>(self, name: &str) -> Result<Field, E> {
+                    match name {
+                        "axis" => Ok(Field::Axis),
+                        "angle" => Ok(Field::Angle),
+                        "translation" => Ok(Field::Translation),
+                        _ => Err(E::unknown_field(name, FIELDS)),
+                    }
+                }
+            }
+
+            // It reads a vector having as many components as the dimensions of the transformation.
+            fn next_vector<'de, M, A>(seq: &mut A) -> Result<Option<[M::Number; 3]>, A::Error>
+            where
+                M: AxisAngleMap,
+                M::Number: serde::Deserialize<'de>,
+                A: SeqAccess<'de>,
+            {
+                Ok(if M::DIMENSIONS == 3 {
+                    seq.next_element::<[M::Number; 3]>()?
+                } else {
+                    seq.next_element::<[M::Number; 2]>()?
+                        .map(|v| [v[0], v[1], <M::Number as measures::traits::HasZero>::ZERO])
+                })
+            }
+
+            // It builds the transformation, normalizing the axis.
+            fn build<M: AxisAngleMap, E: de::Error>(
+                axis: [M::Number; 3],
+                angle: M::Number,
+                translation: [M::Number; 3],
+            ) -> Result<M, E> {
+                let norm =
+                    measures::traits::Sqrt::sqrt(axis[0] * axis[0] + axis[1] * axis[1] + axis[2] * axis[2]);
+                match norm.partial_cmp(&<M::Number as measures::traits::HasZero>::ZERO) {
+                    Some(core::cmp::Ordering::Greater) => Ok(M::from_axis_angle(
+                        axis.map(|x| x / norm),
+                        angle,
+                        translation,
+                    )),
+                    _ => Err(E::custom(
+                        "the axis of the rotation must not be a zero vector",
+                    )),
+                }
+            }
+
+            struct AxisAngleVisitor<M>(PhantomData<M>);
+
+            impl<'de, M> Visitor<'de> for AxisAngleVisitor<M>
+            where
+                M: AxisAngleMap,
+                M::Number: serde::Deserialize<'de>,
+            {
+                type Value = M;
+
+                fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    formatter.write_str("a rotation with the fields ")?;
+                    for (i, field) in fields::<M>().iter().enumerate() {
+                        if i > 0 {
+                            formatter.write_str(", ")?;
+                        }
+                        write!(formatter, "`{}`", field)?;
+                    }
+                    Ok(())
+                }
+
+                fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<M, A::Error> {
+                    let zero = <M::Number as measures::traits::HasZero>::ZERO;
+                    let mut index = 0;
+                    let axis = if M::DIMENSIONS == 3 {
+                        index += 1;
+                        seq.next_element()?
+                            .ok_or_else(|| de::Error::invalid_length(0, &self))?
+                    } else {
+                        [zero, zero, <M::Number as measures::traits::HasOne>::ONE]
+                    };
+                    let angle = seq
+                        .next_element()?
+                        .ok_or_else(|| de::Error::invalid_length(index, &self))?;
+                    index += 1;
+                    let translation = if M::HAS_TRANSLATION {
+                        next_vector::<M, A>(&mut seq)?
+                            .ok_or_else(|| de::Error::invalid_length(index, &self))?
+                    } else {
+                        [zero; 3]
+                    };
+                    build(axis, angle, translation)
+                }
+
+                fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<M, A::Error> {
+                    let zero = <M::Number as measures::traits::HasZero>::ZERO;
+                    let mut axis: Option<[M::Number; 3]> = None;
+                    let mut angle: Option<M::Number> = None;
+                    let mut translation: Option<[M::Number; 3]> = None;
+                    while let Some(field) = map.next_key::<Field>()? {
+                        match field {
+                            Field::Axis if M::DIMENSIONS != 3 => {
+                                return Err(de::Error::unknown_field("axis", fields::<M>()))
+                            }
+                            Field::Axis if axis.is_none() => axis = Some(map.next_value()?),
+                            Field::Axis => return Err(de::Error::duplicate_field("axis")),
+                            Field::Angle if angle.is_none() => angle = Some(map.next_value()?),
+                            Field::Angle => return Err(de::Error::duplicate_field("angle")),
+                            Field::Translation if !M::HAS_TRANSLATION => {
+                                return Err(de::Error::unknown_field("translation", fields::<M>()))
+                            }
+                            Field::Translation if translation.is_some() => {
+                                return Err(de::Error::duplicate_field("translation"))
+                            }
+                            Field::Translation if M::DIMENSIONS == 3 => {
+                                translation = Some(map.next_value()?)
+                            }
+                            Field::Translation => {
+                                let v: [M::Number; 2] = map.next_value()?;
+                                translation = Some([v[0], v[1], zero]);
+                            }
+                        }
+                    }
+                    let axis = match axis {
+                        Some(axis) => axis,
+                        None if M::DIMENSIONS == 3 => return Err(de::Error::missing_field("axis")),
+                        None => [zero, zero, <M::Number as measures::traits::HasOne>::ONE],
+                    };
+                    let angle = angle.ok_or_else(|| de::Error::missing_field("angle"))?;
+                    let translation = match translation {
+                        Some(translation) => translation,
+                        None if M::HAS_TRANSLATION => return Err(de::Error::missing_field("translation")),
+                        None => [zero; 3],
+                    };
+                    build(axis, angle, translation)
+                }
+            }
+        }
+    };
+}
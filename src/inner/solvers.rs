@@ -0,0 +1,294 @@
+#[macro_export] // Don't add nor remove the first three lines and the last two lines.
+macro_rules! inner_define_solvers {
+    {} => {
+        /// central_difference(function, Arg, Measure) -> Derivative
+        /// It returns an approximation of the derivative of `function` at `arg`,
+        /// computed as `(function(arg + step) - function(arg - step)) / (2 * step)`.
+        /// The argument can be a measure or a measure point, and the values of `function`
+        /// can be measures or measure points of any dimension.
+        /// The type of the result is the one obtained by dividing a difference of values
+        /// by a `Measure<ArgUnit>`, and so a relationship between such units must have been declared.
+        pub fn central_difference<Arg, ArgUnit, Value, Derivative, Number, F>(
+            mut function: F,
+            arg: Arg,
+            step: Measure<ArgUnit, Number>,
+        ) -> Derivative
+        where
+            ArgUnit: MeasurementUnit,
+            Number: ArithmeticOps,
+            Arg: measures::traits::Affine<Number, Vector = Measure<ArgUnit, Number>>,
+            Value: measures::traits::Affine<Number>,
+            Value::Vector: Div<Measure<ArgUnit, Number>, Output = Derivative>,
+            F: FnMut(Arg) -> Value,
+        {
+            (function(arg + step) - function(arg + -step)) * Number::HALF / step
+        }
+
+        /// bisection_root(function, Arg, Arg, Measure, usize) -> Option<Arg>
+        /// It returns an argument between `bound1` and `bound2`, closer than `tolerance`
+        /// to a zero of `function`, using the bisection method.
+        /// The argument can be a measure or a measure point.
+        /// If `tolerance` is below the resolution of the arguments,
+        /// it returns the argument found when the bounds cannot be brought closer.
+        /// It returns `None` if the values of `function` at the bounds have the same sign,
+        /// or if the method has not converged in `max_iterations` iterations.
+        pub fn bisection_root<Arg, ArgUnit, ValueUnit, Number, F>(
+            mut function: F,
+            bound1: Arg,
+            bound2: Arg,
+            tolerance: Measure<ArgUnit, Number>,
+            max_iterations: usize,
+        ) -> Option<Arg>
+        where
+            ArgUnit: MeasurementUnit,
+            ValueUnit: MeasurementUnit,
+            Number: ArithmeticOps,
+            Arg: measures::traits::Affine<Number, Vector = Measure<ArgUnit, Number>>,
+            F: FnMut(Arg) -> Measure<ValueUnit, Number>,
+        {
+            let mut low = bound1;
+            let mut high = bound2;
+            let low_value = function(low).value;
+            let high_value = function(high).value;
+            if low_value == Number::ZERO {
+                return Some(low);
+            }
+            if high_value == Number::ZERO {
+                return Some(high);
+            }
+            if low_value.signum() == high_value.signum() {
+                return None;
+            }
+            for _ in 0..max_iterations {
+                let half_width = (high - low) * Number::HALF;
+                let middle = low + half_width;
+                // When the bounds are adjacent numbers, the middle coincides with one of them.
+                let is_resolution =
+                    (middle - low).value == Number::ZERO || (high - middle).value == Number::ZERO;
+                if half_width.norm() <= tolerance || is_resolution {
+                    return Some(middle);
+                }
+                let middle_value = function(middle).value;
+                if middle_value == Number::ZERO {
+                    return Some(middle);
+                }
+                if middle_value.signum() == low_value.signum() {
+                    low = middle;
+                } else {
+                    high = middle;
+                }
+            }
+            None
+        }
+
+        /// brent_root(function, Arg, Arg, Measure, usize) -> Option<Arg>
+        /// It returns an argument between `bound1` and `bound2`, closer than `tolerance`
+        /// to a zero of `function`, using the Brent method,
+        /// which combines bisection, secant and inverse quadratic interpolation.
+        /// The argument can be a measure or a measure point.
+        /// The tolerance is increased by the rounding error of the current estimate,
+        /// and so a zero tolerance is allowed.
+        /// It returns `None` if the values of `function` at the bounds have the same sign,
+        /// or if the method has not converged in `max_iterations` iterations.
+        pub fn brent_root<Arg, ArgUnit, ValueUnit, Number, F>(
+            mut function: F,
+            bound1: Arg,
+            bound2: Arg,
+            tolerance: Measure<ArgUnit, Number>,
+            max_iterations: usize,
+        ) -> Option<Arg>
+        where
+            ArgUnit: MeasurementUnit,
+            ValueUnit: MeasurementUnit,
+            Number: ArithmeticOps,
+            Arg: measures::traits::Affine<Number, Vector = Measure<ArgUnit, Number>>,
+            F: FnMut(Arg) -> Measure<ValueUnit, Number>,
+        {
+            // The computations are performed on the offsets from `bound1`, expressed in `ArgUnit`.
+            let mut f = |offset: Number| function(bound1 + Measure::<ArgUnit, Number>::new(offset)).value;
+            let root = |offset: Number| Some(bound1 + Measure::<ArgUnit, Number>::new(offset));
+            let zero = Number::ZERO;
+            let two = Number::from_f64(2.);
+            let three = Number::from_f64(3.);
+            let mut a = zero;
+            let mut b = (bound2 - bound1).value;
+            let mut fa = f(a);
+            let mut fb = f(b);
+            if fa == zero {
+                return root(a);
+            }
+            if fb == zero {
+                return root(b);
+            }
+            if fa.signum() == fb.signum() {
+                return None;
+            }
+            let mut c = b;
+            let mut fc = fb;
+            let mut d = b - a;
+            let mut e = d;
+            for _ in 0..max_iterations {
+                if fb.signum() == fc.signum() {
+                    c = a;
+                    fc = fa;
+                    d = b - a;
+                    e = d;
+                }
+                if fc.abs() < fb.abs() {
+                    a = b;
+                    b = c;
+                    c = a;
+                    fa = fb;
+                    fb = fc;
+                    fc = fa;
+                }
+                let half_tolerance = two * Number::EPSILON * b.abs() + tolerance.value.abs() * Number::HALF;
+                let middle = (c - b) * Number::HALF;
+                if middle.abs() <= half_tolerance || fb == zero {
+                    return root(b);
+                }
+                if e.abs() >= half_tolerance && fa.abs() > fb.abs() {
+                    // Attempt of interpolation.
+                    let s = fb / fa;
+                    let (mut p, mut q) = if a == c {
+                        // Secant method.
+                        (two * middle * s, Number::ONE - s)
+                    } else {
+                        // Inverse quadratic interpolation.
+                        let q = fa / fc;
+                        let r = fb / fc;
+                        (
+                            s * (two * middle * q * (q - r) - (b - a) * (r - Number::ONE)),
+                            (q - Number::ONE) * (r - Number::ONE) * (s - Number::ONE),
+                        )
+                    };
+                    if p > zero {
+                        q = -q;
+                    }
+                    p = p.abs();
+                    let limit1 = three * middle * q - (half_tolerance * q).abs();
+                    let limit2 = (e * q).abs();
+                    let limit = if limit1 < limit2 { limit1 } else { limit2 };
+                    if two * p < limit {
+                        e = d;
+                        d = p / q;
+                    } else {
+                        d = middle;
+                        e = d;
+                    }
+                } else {
+                    // Bisection.
+                    d = middle;
+                    e = d;
+                }
+                a = b;
+                fa = fb;
+                b += if d.abs() > half_tolerance {
+                    d
+                } else {
+                    half_tolerance * middle.signum()
+                };
+                fb = f(b);
+            }
+            None
+        }
+
+        /// newton_root(function, derivative function, Arg, Measure, usize) -> Option<Arg>
+        /// It returns an argument closer than `tolerance` to a zero of `function`,
+        /// using the Newton-Raphson method, starting from `start`.
+        /// The argument can be a measure or a measure point.
+        /// The function `derivative` must return the derivative of `function`,
+        /// possibly computed by `central_difference`.
+        /// It returns `None` if the method has not converged in `max_iterations` iterations,
+        /// or if a zero derivative has been encountered.
+        pub fn newton_root<Arg, ArgUnit, ValueUnit, Derivative, Number, F, DF>(
+            mut function: F,
+            mut derivative: DF,
+            start: Arg,
+            tolerance: Measure<ArgUnit, Number>,
+            max_iterations: usize,
+        ) -> Option<Arg>
+        where
+            ArgUnit: MeasurementUnit,
+            ValueUnit: MeasurementUnit,
+            Number: ArithmeticOps,
+            Arg: measures::traits::Affine<Number, Vector = Measure<ArgUnit, Number>>,
+            Measure<ValueUnit, Number>: Div<Derivative, Output = Measure<ArgUnit, Number>>,
+            F: FnMut(Arg) -> Measure<ValueUnit, Number>,
+            Derivative: Default + PartialEq,
+            DF: FnMut(Arg) -> Derivative,
+        {
+            let mut arg = start;
+            for _ in 0..max_iterations {
+                let slope = derivative(arg);
+                // It is checked before dividing, as some number types panic when divided by zero.
+                if slope == Derivative::default() {
+                    return None;
+                }
+                let correction = function(arg) / slope;
+                arg = arg + -correction;
+                if correction.norm() <= tolerance {
+                    return Some(arg);
+                }
+            }
+            None
+        }
+
+        /// golden_section_minimum(function, Arg, Arg, Measure, usize) -> Option<Arg>
+        /// It returns an argument between `bound1` and `bound2`, closer than `tolerance`
+        /// to a local minimum of `function`, using the golden-section search.
+        /// The argument can be a measure or a measure point,
+        /// and the values of `function` can be of any ordered type, typically a `Measure`.
+        /// If `function` is unimodal between the bounds, the found minimum is the global one.
+        /// If `tolerance` is below the resolution of the arguments,
+        /// it returns the argument found when the bounds cannot be brought closer.
+        /// It returns `None` if the method has not converged in `max_iterations` iterations.
+        pub fn golden_section_minimum<Arg, ArgUnit, Value, Number, F>(
+            mut function: F,
+            bound1: Arg,
+            bound2: Arg,
+            tolerance: Measure<ArgUnit, Number>,
+            max_iterations: usize,
+        ) -> Option<Arg>
+        where
+            ArgUnit: MeasurementUnit,
+            Number: ArithmeticOps,
+            Arg: measures::traits::Affine<Number, Vector = Measure<ArgUnit, Number>>,
+            Value: PartialOrd,
+            F: FnMut(Arg) -> Value,
+        {
+            // The inverse of the golden ratio.
+            let ratio = Number::from_f64(0.618_033_988_749_894_9);
+            let complement = Number::ONE - ratio;
+            let mut low = bound1;
+            let mut high = bound2;
+            let mut inner_low = low + (high - low) * complement;
+            let mut inner_high = low + (high - low) * ratio;
+            let mut inner_low_value = function(inner_low);
+            let mut inner_high_value = function(inner_high);
+            for _ in 0..max_iterations {
+                // When the bounds are so close that the inner points coincide with them,
+                // the interval cannot be narrowed anymore.
+                let is_resolution =
+                    (inner_low - low).value == Number::ZERO || (high - inner_high).value == Number::ZERO;
+                if (high - low).norm() <= tolerance || is_resolution {
+                    return Some(low + (high - low) * Number::HALF);
+                }
+                if inner_low_value < inner_high_value {
+                    high = inner_high;
+                    inner_high = inner_low;
+                    inner_high_value = inner_low_value;
+                    inner_low = low + (high - low) * complement;
+                    inner_low_value = function(inner_low);
+                } else {
+                    low = inner_low;
+                    inner_low = inner_high;
+                    inner_low_value = inner_high_value;
+                    inner_high = low + (high - low) * ratio;
+                    inner_high_value = function(inner_high);
+                }
+            }
+            None
+        }
+    };
+}
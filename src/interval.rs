@@ -0,0 +1,688 @@
+//! Interval-arithmetic number type, available with the feature `interval`.
+//!
+//! An `Interval<Real>` represents an unknown real number by a lower bound and an upper bound,
+//! and every operation returns an interval containing all the possible results,
+//! so that the bounds are guaranteed, unlike the standard deviations of `ApproxMeasure`.
+//! It can be used as `Number` type of measures, like in `Measure<Metre, Interval>`.
+//!
+//! The bounds are rounded outward: the results of the arithmetic operations and of the
+//! square root are widened by one unit in the last place, and the results of the other functions,
+//! which are not correctly rounded, by two units in the last place.
+//! The functions which are not monotonic, like the trigonometric functions,
+//! return the bounds of the function over the whole interval.
+//!
+//! The comparison operators have the following semantics:
+//! * Two intervals are equal if they have the same bounds.
+//! * An interval is less than another one if its upper bound is less than the lower bound
+//!   of the other one, i.e. if every number of the first interval is less than every
+//!   number of the other interval. Similarly for the relation "greater than".
+//! * Otherwise, the two intervals overlap, and `partial_cmp` returns `None`,
+//!   and so every comparison operator returns `false`.
+//!
+//! Therefore, the operations of measures which choose a result by comparing numbers,
+//! like the normalization of directions, are guaranteed only if such comparisons
+//! are not affected by the uncertainty.
+
+use crate::float_math::FloatFunctions;
+use crate::traits::{
+    ArithmeticOps, CubicRoot, FromF64, HasEpsilon, HasHalf, HasOne, HasSign, HasZero,
+    InverseTrigonometry, Logarithm, LossyFrom, Pow, PowerDecibel, RootPowerDecibel, ScaleByRatio,
+    Sqrt, Trigonometry,
+};
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+/// Floating-point numbers which can be the bounds of an `Interval`.
+pub trait IntervalBound: ArithmeticOps {
+    const INFINITY: Self;
+
+    /// It returns the least number greater than `self`.
+    fn next_up(self) -> Self;
+
+    /// It returns the greatest number less than `self`.
+    fn next_down(self) -> Self;
+
+    /// It returns the nearest `f64`.
+    fn to_f64(self) -> f64;
+
+    /// It returns the greatest number not greater than `n`.
+    fn from_f64_down(n: f64) -> Self;
+
+    /// It returns the least number not less than `n`.
+    fn from_f64_up(n: f64) -> Self;
+}
+
+impl IntervalBound for f32 {
+    const INFINITY: Self = f32::INFINITY;
+    fn next_up(self) -> Self {
+        f32::next_up(self)
+    }
+    fn next_down(self) -> Self {
+        f32::next_down(self)
+    }
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+    fn from_f64_down(n: f64) -> Self {
+        let x = n as f32;
+        if (x as f64) > n {
+            x.next_down()
+        } else {
+            x
+        }
+    }
+    fn from_f64_up(n: f64) -> Self {
+        let x = n as f32;
+        if (x as f64) < n {
+            x.next_up()
+        } else {
+            x
+        }
+    }
+}
+
+impl IntervalBound for f64 {
+    const INFINITY: Self = f64::INFINITY;
+    fn next_up(self) -> Self {
+        f64::next_up(self)
+    }
+    fn next_down(self) -> Self {
+        f64::next_down(self)
+    }
+    fn to_f64(self) -> f64 {
+        self
+    }
+    fn from_f64_down(n: f64) -> Self {
+        n
+    }
+    fn from_f64_up(n: f64) -> Self {
+        n
+    }
+}
+
+fn min<Real: IntervalBound>(a: Real, b: Real) -> Real {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+fn max<Real: IntervalBound>(a: Real, b: Real) -> Real {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+// It returns true if the interval from `lo` to `hi` may contain a number
+// equal to `phase` plus an integer multiple of `period`.
+// The interval is slightly widened, to compensate for the rounding errors.
+fn contains_periodic_point(lo: f64, hi: f64, phase: f64, period: f64) -> bool {
+    let tolerance = 1e-12 * (1. + lo.abs().max(hi.abs()));
+    let k = FloatFunctions::floor((lo - tolerance - phase) / period) + 1.;
+    phase + k * period <= hi + tolerance
+}
+
+/// Number whose value is known only to be between a lower bound and an upper bound.
+#[derive(Clone, Copy)]
+pub struct Interval<Real = f64> {
+    lo: Real,
+    hi: Real,
+}
+
+impl<Real: IntervalBound> Interval<Real> {
+    /// Interval::new(Real, Real) -> Interval
+    /// It panics if `lo` is greater than `hi`, or if any of them is NaN.
+    pub fn new(lo: Real, hi: Real) -> Self {
+        assert!(
+            lo <= hi,
+            "the lower bound of an interval cannot be greater than the upper bound"
+        );
+        Self { lo, hi }
+    }
+
+    /// Interval::point(Real) -> Interval
+    /// It returns the interval containing only the specified number.
+    pub const fn point(value: Real) -> Self {
+        Self {
+            lo: value,
+            hi: value,
+        }
+    }
+
+    /// Interval::entire() -> Interval
+    /// It returns the interval containing all the numbers.
+    pub fn entire() -> Self {
+        Self {
+            lo: -Real::INFINITY,
+            hi: Real::INFINITY,
+        }
+    }
+
+    /// Interval.lo() -> Real
+    pub const fn lo(self) -> Real {
+        self.lo
+    }
+
+    /// Interval.hi() -> Real
+    pub const fn hi(self) -> Real {
+        self.hi
+    }
+
+    /// Interval.midpoint() -> Real
+    pub fn midpoint(self) -> Real {
+        self.lo * Real::HALF + self.hi * Real::HALF
+    }
+
+    /// Interval.width() -> Real
+    /// It returns an upper bound of the difference between the bounds.
+    pub fn width(self) -> Real {
+        (self.hi - self.lo).next_up()
+    }
+
+    /// Interval.contains(Real) -> bool
+    pub fn contains(self, value: Real) -> bool {
+        self.lo <= value && value <= self.hi
+    }
+
+    /// Interval.hull(Interval) -> Interval
+    /// It returns the smallest interval containing both intervals.
+    pub fn hull(self, other: Self) -> Self {
+        Self {
+            lo: min(self.lo, other.lo),
+            hi: max(self.hi, other.hi),
+        }
+    }
+
+    // The interval from `lo` to `hi`, widened by one unit in the last place.
+    fn rounded_outward(lo: Real, hi: Real) -> Self {
+        Self {
+            lo: lo.next_down(),
+            hi: hi.next_up(),
+        }
+    }
+
+    // The interval from `lo` to `hi`, widened by two units in the last place,
+    // to contain the result of a function which is not correctly rounded.
+    fn widened(lo: Real, hi: Real) -> Self {
+        Self {
+            lo: lo.next_down().next_down(),
+            hi: hi.next_up().next_up(),
+        }
+    }
+
+    // The interval having the given bounds in any order, widened by two units in the last place.
+    fn widened_unordered(a: Real, b: Real) -> Self {
+        Self::widened(min(a, b), max(a, b))
+    }
+
+    // The intersection with the interval from `lo` to `hi`.
+    fn clamped(self, lo: Real, hi: Real) -> Self {
+        Self {
+            lo: max(self.lo, lo),
+            hi: min(self.hi, hi),
+        }
+    }
+
+    // The product of two bounds, which is zero when a bound is zero, even if the other one is infinite,
+    // as, according to the standard IEEE 1788, an infinite bound is not a member of the interval.
+    fn bound_product(a: Real, b: Real) -> Real {
+        if a == Real::ZERO || b == Real::ZERO {
+            Real::ZERO
+        } else {
+            a * b
+        }
+    }
+
+    // The least and the greatest of the given values.
+    fn bounds(values: [Real; 4]) -> (Real, Real) {
+        let mut lo = values[0];
+        let mut hi = values[0];
+        for &value in &values[1..] {
+            lo = min(lo, value);
+            hi = max(hi, value);
+        }
+        (lo, hi)
+    }
+
+    // The smallest interval containing the given values, widened by two units in the last place.
+    fn widened_hull(values: [Real; 4]) -> Self {
+        let (lo, hi) = Self::bounds(values);
+        Self::widened(lo, hi)
+    }
+
+    // The bounds of a function having period 2π, a maximum equal to one at `max_phase`,
+    // and a minimum equal to minus one at `max_phase + π`,
+    // as the sine and cosine functions, over this interval.
+    fn periodic_bounds(self, function: fn(Real) -> Real, max_phase: f64) -> Self {
+        let (lo, hi) = (self.lo.to_f64(), self.hi.to_f64());
+        if !lo.is_finite() || !hi.is_finite() {
+            return Self::new(-Real::ONE, Real::ONE);
+        }
+        let period = 2. * core::f64::consts::PI;
+        let mut result = Self::widened_unordered(function(self.lo), function(self.hi));
+        if contains_periodic_point(lo, hi, max_phase, period) {
+            result.hi = Real::ONE;
+        }
+        if contains_periodic_point(lo, hi, max_phase + core::f64::consts::PI, period) {
+            result.lo = -Real::ONE;
+        }
+        result.clamped(-Real::ONE, Real::ONE)
+    }
+}
+
+impl<Real: IntervalBound> Default for Interval<Real> {
+    fn default() -> Self {
+        Self::point(Real::ZERO)
+    }
+}
+
+impl<Real: IntervalBound> From<Real> for Interval<Real> {
+    fn from(value: Real) -> Self {
+        Self::point(value)
+    }
+}
+
+impl<Real: IntervalBound> PartialEq for Interval<Real> {
+    /// Two intervals are equal if they have the same bounds.
+    fn eq(&self, other: &Self) -> bool {
+        self.lo == other.lo && self.hi == other.hi
+    }
+}
+
+impl<Real: IntervalBound> PartialOrd for Interval<Real> {
+    /// It returns `None` if the intervals overlap, without having the same bounds.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self == other {
+            Some(Ordering::Equal)
+        } else if self.hi < other.lo {
+            Some(Ordering::Less)
+        } else if self.lo > other.hi {
+            Some(Ordering::Greater)
+        } else {
+            None
+        }
+    }
+}
+
+impl<Real: IntervalBound> Neg for Interval<Real> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self {
+            lo: -self.hi,
+            hi: -self.lo,
+        }
+    }
+}
+
+impl<Real: IntervalBound> Add for Interval<Real> {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Self::rounded_outward(self.lo + other.lo, self.hi + other.hi)
+    }
+}
+
+impl<Real: IntervalBound> AddAssign for Interval<Real> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<Real: IntervalBound> Sub for Interval<Real> {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Self::rounded_outward(self.lo - other.hi, self.hi - other.lo)
+    }
+}
+
+impl<Real: IntervalBound> SubAssign for Interval<Real> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<Real: IntervalBound> Mul for Interval<Real> {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        let products = [
+            Self::bound_product(self.lo, other.lo),
+            Self::bound_product(self.lo, other.hi),
+            Self::bound_product(self.hi, other.lo),
+            Self::bound_product(self.hi, other.hi),
+        ];
+        let (lo, hi) = Self::bounds(products);
+        Self::rounded_outward(lo, hi)
+    }
+}
+
+impl<Real: IntervalBound> MulAssign for Interval<Real> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl<Real: IntervalBound> Div for Interval<Real> {
+    type Output = Self;
+    /// If the divisor contains zero, the result is the entire real line.
+    fn div(self, other: Self) -> Self {
+        if other.contains(Real::ZERO) {
+            return Self::entire();
+        }
+        let quotients = [
+            self.lo / other.lo,
+            self.lo / other.hi,
+            self.hi / other.lo,
+            self.hi / other.hi,
+        ];
+        let (lo, hi) = Self::bounds(quotients);
+        Self::rounded_outward(lo, hi)
+    }
+}
+
+impl<Real: IntervalBound> DivAssign for Interval<Real> {
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl<Real: IntervalBound> Rem for Interval<Real> {
+    type Output = Self;
+    /// The remainder of the division by a single number is computed exactly
+    /// if all the numbers of the dividend have the same truncated quotient.
+    /// Otherwise, the result is bounded by the magnitude of the divisor.
+    fn rem(self, other: Self) -> Self {
+        if other.lo == other.hi && other.lo != Real::ZERO {
+            let lo_remainder = self.lo % other.lo;
+            let hi_remainder = self.hi % other.lo;
+            if self.lo - lo_remainder == self.hi - hi_remainder
+                && (self.lo >= Real::ZERO || self.hi <= Real::ZERO)
+            {
+                // The remainder of floating-point numbers is always exact.
+                return Self::new(lo_remainder, hi_remainder);
+            }
+        }
+        let bound = max(other.lo.abs(), other.hi.abs());
+        Self {
+            lo: if self.lo >= Real::ZERO {
+                Real::ZERO
+            } else {
+                -bound
+            },
+            hi: if self.hi <= Real::ZERO {
+                Real::ZERO
+            } else {
+                bound
+            },
+        }
+    }
+}
+
+impl<Real: IntervalBound> core::iter::Sum for Interval<Real> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |a, b| a + b)
+    }
+}
+
+impl<Real: IntervalBound> Pow for Interval<Real> {
+    /// If the base can be negative, the exponent should be a single integer number.
+    fn powf(self, exponent: Self) -> Self {
+        let mut result = Self::widened_hull([
+            self.lo.powf(exponent.lo),
+            self.lo.powf(exponent.hi),
+            self.hi.powf(exponent.lo),
+            self.hi.powf(exponent.hi),
+        ]);
+        // An even power of an interval containing zero has zero as minimum.
+        if exponent.lo == exponent.hi
+            && exponent.lo > Real::ZERO
+            && (exponent.lo * Real::HALF) % Real::ONE == Real::ZERO
+            && self.contains(Real::ZERO)
+        {
+            result.lo = Real::ZERO;
+        }
+        result
+    }
+}
+
+impl<Real: IntervalBound> FromF64 for Interval<Real> {
+    fn from_f64(n: f64) -> Self {
+        Self {
+            lo: Real::from_f64_down(n),
+            hi: Real::from_f64_up(n),
+        }
+    }
+}
+
+impl<Real: IntervalBound> ScaleByRatio for Interval<Real> {
+    fn scale_by_ratio(self, ratio: f64) -> Self {
+        self * Self::from_f64(ratio)
+    }
+    /// The conversion functions are monotonic, so the bounds are mapped to bounds.
+    fn apply_conversion<F: Fn(f64) -> f64>(self, function: F) -> Self {
+        let (a, b) = (function(self.lo.to_f64()), function(self.hi.to_f64()));
+        Self {
+            lo: Real::from_f64_down(a.min(b)).next_down(),
+            hi: Real::from_f64_up(a.max(b)).next_up(),
+        }
+    }
+}
+
+impl<Real: IntervalBound> Sqrt for Interval<Real> {
+    type Output = Self;
+    /// The negative part of the interval is ignored.
+    fn sqrt(self) -> Self {
+        let lo = if self.lo > Real::ZERO {
+            self.lo.sqrt().next_down()
+        } else {
+            Real::ZERO
+        };
+        Self {
+            lo,
+            hi: self.hi.sqrt().next_up(),
+        }
+    }
+}
+
+impl<Real: IntervalBound> CubicRoot for Interval<Real> {
+    type Output = Self;
+    fn cubic_root(self) -> Self {
+        Self::widened(self.lo.cubic_root(), self.hi.cubic_root())
+    }
+}
+
+impl<Real: IntervalBound> Trigonometry for Interval<Real> {
+    type Output = Self;
+    fn cos(self) -> Self::Output {
+        self.periodic_bounds(Real::cos, 0.)
+    }
+    fn sin(self) -> Self::Output {
+        self.periodic_bounds(Real::sin, core::f64::consts::FRAC_PI_2)
+    }
+    /// If the interval contains a pole, the result is the entire real line.
+    fn tan(self) -> Self::Output {
+        let (lo, hi) = (self.lo.to_f64(), self.hi.to_f64());
+        if !lo.is_finite()
+            || !hi.is_finite()
+            || contains_periodic_point(lo, hi, core::f64::consts::FRAC_PI_2, core::f64::consts::PI)
+        {
+            return Self::entire();
+        }
+        Self::widened(self.lo.tan(), self.hi.tan())
+    }
+    fn sin_cos(self) -> (Self::Output, Self::Output) {
+        (self.sin(), self.cos())
+    }
+}
+
+impl<Real: IntervalBound> InverseTrigonometry for Interval<Real> {
+    type Output = Self;
+    /// The part of the interval outside of the domain from -1 to 1 is ignored.
+    fn acos(self) -> Self::Output {
+        let x = self.clamped(-Real::ONE, Real::ONE);
+        Self::widened(x.hi.acos(), x.lo.acos())
+    }
+    /// The part of the interval outside of the domain from -1 to 1 is ignored.
+    fn asin(self) -> Self::Output {
+        let x = self.clamped(-Real::ONE, Real::ONE);
+        Self::widened(x.lo.asin(), x.hi.asin())
+    }
+    /// If the rectangle having the two intervals as sides contains the origin,
+    /// or touches the negative X axis, the result is from -π to π.
+    fn atan2(self, other: Self) -> Self::Output {
+        let (y, x) = (self, other);
+        if y.contains(Real::ZERO) && x.lo <= Real::ZERO {
+            let pi = Self::from_f64(core::f64::consts::PI);
+            return Self {
+                lo: -pi.hi,
+                hi: pi.hi,
+            };
+        }
+        // Otherwise, the function is monotonic with respect to each argument.
+        Self::widened_hull([
+            y.lo.atan2(x.lo),
+            y.lo.atan2(x.hi),
+            y.hi.atan2(x.lo),
+            y.hi.atan2(x.hi),
+        ])
+    }
+}
+
+impl<Real: IntervalBound> Logarithm for Interval<Real> {
+    fn ln(self) -> Self {
+        Self::widened(self.lo.ln(), self.hi.ln())
+    }
+}
+
+impl<Real: IntervalBound> PowerDecibel for Interval<Real> {
+    fn to_power_decibels(self) -> Self {
+        Self::widened(self.lo.to_power_decibels(), self.hi.to_power_decibels())
+    }
+    fn power_decibels_to_value(self) -> Self {
+        Self::widened(
+            self.lo.power_decibels_to_value(),
+            self.hi.power_decibels_to_value(),
+        )
+    }
+}
+
+impl<Real: IntervalBound> RootPowerDecibel for Interval<Real> {
+    fn to_root_power_decibels(self) -> Self {
+        Self::widened(
+            self.lo.to_root_power_decibels(),
+            self.hi.to_root_power_decibels(),
+        )
+    }
+    fn root_power_decibels_to_value(self) -> Self {
+        Self::widened(
+            self.lo.root_power_decibels_to_value(),
+            self.hi.root_power_decibels_to_value(),
+        )
+    }
+}
+
+impl<Real: IntervalBound> HasZero for Interval<Real> {
+    const ZERO: Self = Self::point(Real::ZERO);
+}
+
+impl<Real: IntervalBound> HasOne for Interval<Real> {
+    const ONE: Self = Self::point(Real::ONE);
+}
+
+impl<Real: IntervalBound> HasHalf for Interval<Real> {
+    const HALF: Self = Self::point(Real::HALF);
+}
+
+impl<Real: IntervalBound> HasEpsilon for Interval<Real> {
+    const EPSILON: Self = Self::point(Real::EPSILON);
+}
+
+impl<Real: IntervalBound> HasSign for Interval<Real> {
+    fn signum(self) -> Self {
+        Self {
+            lo: self.lo.signum(),
+            hi: self.hi.signum(),
+        }
+    }
+    fn abs(self) -> Self {
+        if self.lo >= Real::ZERO {
+            self
+        } else if self.hi <= Real::ZERO {
+            -self
+        } else {
+            Self {
+                lo: Real::ZERO,
+                hi: max(-self.lo, self.hi),
+            }
+        }
+    }
+}
+
+impl<Real: IntervalBound> LossyFrom<Interval<Real>> for Interval<Real> {
+    fn lossy_from(n: Interval<Real>) -> Self {
+        n
+    }
+}
+impl LossyFrom<f32> for Interval<f64> {
+    fn lossy_from(n: f32) -> Self {
+        Self::point(n as f64)
+    }
+}
+impl LossyFrom<f64> for Interval<f64> {
+    fn lossy_from(n: f64) -> Self {
+        Self::point(n)
+    }
+}
+impl LossyFrom<Interval<f64>> for f32 {
+    /// It returns the nearest number to the midpoint.
+    fn lossy_from(n: Interval<f64>) -> Self {
+        n.midpoint() as f32
+    }
+}
+impl LossyFrom<Interval<f64>> for f64 {
+    /// It returns the midpoint.
+    fn lossy_from(n: Interval<f64>) -> Self {
+        n.midpoint()
+    }
+}
+
+impl<Real: IntervalBound> Interval<Real> {
+    // It writes the interval like "[1.5, 2]", formatting every bound by the given function.
+    fn format(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+        format_bound: fn(&Real, &mut fmt::Formatter<'_>) -> fmt::Result,
+    ) -> fmt::Result {
+        formatter.write_str("[")?;
+        format_bound(&self.lo, formatter)?;
+        formatter.write_str(", ")?;
+        format_bound(&self.hi, formatter)?;
+        formatter.write_str("]")
+    }
+}
+
+impl<Real: IntervalBound> fmt::Display for Interval<Real> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.format(formatter, fmt::Display::fmt)
+    }
+}
+
+impl<Real: IntervalBound> fmt::Debug for Interval<Real> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.format(formatter, fmt::Debug::fmt)
+    }
+}
+
+impl<Real: IntervalBound> fmt::LowerExp for Interval<Real> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.format(formatter, fmt::LowerExp::fmt)
+    }
+}
+
+impl<Real: IntervalBound> fmt::UpperExp for Interval<Real> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.format(formatter, fmt::UpperExp::fmt)
+    }
+}
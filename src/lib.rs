@@ -1,7 +1,11 @@
 //! Set of declarative macros to define types to handle quantities provided of units of measurement, and optional uncertainty.
-//! The only macro to be used in application code is `define_measure_types`, which must be called at most once per module.
+//! The main macro to be used in application code is `define_measure_types`, which must be called at most once per module.
 //! It is recommended to be called only once per application.
 //! It is suggested to called it in a module named `units`.
+//! In the same module, the macro `define_typed_vector` can be called to define
+//! vectors of measures having different units, with their covariance and Jacobian matrices.
+//! The macro `define_typed_jacobian` can be called to define the Jacobian matrix
+//! between two such vectors having different sizes.
 //!
 //! Here is an extensive [**tutorial**](https://github.com/carlomilanesi/measures-rs/blob/main/docs/Tutorial.md) for the last version of the library.
 //!
@@ -70,8 +74,11 @@
 //!     [with_directions]
 //!     [with_2d]
 //!     [with_3d]
+//!     [with_nd]
 //!     [with_transformations]
 //!     [exact]
+//!     [with_complex]
+//!     [with_nalgebra]
 //!     [with_approx],
 //!     [
 //!     scalar_properties \[
@@ -154,11 +161,48 @@
 //!     ]
 //! }
 //! ```
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "f128", feature(f128))]
+
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub extern crate alloc;
+
+#[cfg(feature = "bytemuck")]
+#[doc(hidden)]
+pub extern crate bytemuck;
+
+#[cfg(feature = "nalgebra")]
+#[doc(hidden)]
+pub extern crate nalgebra;
+
 pub mod angle;
+#[cfg(feature = "catalog_si")]
+pub mod catalog;
+#[cfg(feature = "decimal")]
+pub mod decimal;
 pub mod define_measure_types;
+pub mod define_typed_vector;
 pub mod define_units_relationship;
 pub mod dimensionless;
+#[cfg(feature = "double_double")]
+pub mod double_double;
+#[cfg(feature = "dual")]
+pub mod dual;
+#[cfg(feature = "faer")]
+pub mod faer;
+#[cfg(feature = "fixed")]
+pub mod fixed;
+#[cfg(feature = "f128")]
+pub mod float128;
+pub mod float_math;
 pub mod inner;
+#[cfg(feature = "interval")]
+pub mod interval;
 pub mod matrix_utils;
+#[cfg(feature = "ndarray")]
+pub mod ndarray;
+#[cfg(feature = "simd")]
+pub mod simd;
 pub mod test_utils;
 pub mod traits;
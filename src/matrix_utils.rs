@@ -1,30 +1,41 @@
 use crate::traits::ArithmeticOps;
+use core::fmt;
 
-// It receives a matrix of numbers, a string to be display as suffix unit of measurement,
+// It receives a formatter, a matrix of numbers, a string to be display as suffix unit of measurement,
 // and a number of spaces to be used as indentation,
-// and it returns a string in which the matrix is formatted in lines, with its columns aligned.
+// and it writes the matrix to the formatter in lines, with its columns aligned.
+// The numbers are written twice, first to measure them and then to output them,
+// so that no memory is allocated.
 pub fn format_matrix<const ROW_COUNT: usize, const COLUMN_COUNT: usize, Number: ArithmeticOps>(
+    formatter: &mut fmt::Formatter<'_>,
     matrix: &[[Number; COLUMN_COUNT]; ROW_COUNT],
     unit_suffix: &str,
     indent: usize,
-) -> String {
-    const EMPTY_STRING: String = String::new();
-    let mut rows = [EMPTY_STRING; ROW_COUNT];
-    for column_index in 0..COLUMN_COUNT {
-        let column = format_column::<ROW_COUNT, COLUMN_COUNT, Number>(matrix, column_index);
-        for row_index in 0..ROW_COUNT {
-            if column_index == 0 {
-                rows[row_index] += &" ".repeat(indent);
-                rows[row_index] += if row_index == 0 {
-                    "⎡ "
-                } else if row_index == ROW_COUNT - 1 {
-                    "⎣ "
-                } else {
-                    "⎢ "
-                };
-            }
-            rows[row_index] += &column[row_index];
-            rows[row_index] += if column_index < COLUMN_COUNT - 1 {
+) -> fmt::Result {
+    let layouts = core::array::from_fn::<_, COLUMN_COUNT, _>(|column_index| {
+        ColumnLayout::new(matrix, column_index)
+    });
+    for (row_index, row) in matrix.iter().enumerate() {
+        if row_index > 0 {
+            formatter.write_str("\n")?;
+        }
+        write_spaces(formatter, indent)?;
+        formatter.write_str(if row_index == 0 {
+            "⎡ "
+        } else if row_index == ROW_COUNT - 1 {
+            "⎣ "
+        } else {
+            "⎢ "
+        })?;
+        for (column_index, (cell, layout)) in row.iter().zip(&layouts).enumerate() {
+            let size = CellSize::of(cell);
+            write_spaces(formatter, layout.max_dot_position - size.dot_position)?;
+            write!(formatter, "{}", cell)?;
+            write_spaces(
+                formatter,
+                layout.max_fractional_len + size.dot_position - size.len,
+            )?;
+            formatter.write_str(if column_index < COLUMN_COUNT - 1 {
                 " "
             } else if row_index == 0 {
                 " ⎤"
@@ -32,51 +43,323 @@ pub fn format_matrix<const ROW_COUNT: usize, const COLUMN_COUNT: usize, Number:
                 " ⎦"
             } else {
                 " ⎥"
-            };
-            if row_index == 0 && column_index == COLUMN_COUNT - 1 {
-                rows[row_index] += unit_suffix;
+            })?;
+        }
+        if row_index == 0 {
+            formatter.write_str(unit_suffix)?;
+        }
+    }
+    Ok(())
+}
+
+fn write_spaces(formatter: &mut fmt::Formatter<'_>, count: usize) -> fmt::Result {
+    for _ in 0..count {
+        formatter.write_str(" ")?;
+    }
+    Ok(())
+}
+
+// Length of a formatted number, and position of its decimal point,
+// or its length, if it has no decimal point.
+struct CellSize {
+    len: usize,
+    dot_position: usize,
+}
+
+impl CellSize {
+    fn of<Number: fmt::Display>(cell: &Number) -> Self {
+        let mut counter = CellSizeCounter {
+            len: 0,
+            dot_position: None,
+        };
+        // Writing to the counter never fails.
+        let _ = fmt::Write::write_fmt(&mut counter, format_args!("{}", cell));
+        Self {
+            len: counter.len,
+            dot_position: counter.dot_position.unwrap_or(counter.len),
+        }
+    }
+}
+
+// Writer which discards the text, keeping its length and the position of its decimal point.
+struct CellSizeCounter {
+    len: usize,
+    dot_position: Option<usize>,
+}
+
+impl fmt::Write for CellSizeCounter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.dot_position.is_none() {
+            if let Some(position) = s.bytes().position(|c| c == b'.') {
+                self.dot_position = Some(self.len + position);
             }
         }
+        self.len += s.len();
+        Ok(())
+    }
+}
+
+// Alignment of the numbers of a column,
+// having the decimal points lined up.
+struct ColumnLayout {
+    max_dot_position: usize,
+    max_fractional_len: usize,
+}
+
+impl ColumnLayout {
+    fn new<const ROW_COUNT: usize, const COLUMN_COUNT: usize, Number: ArithmeticOps>(
+        matrix: &[[Number; COLUMN_COUNT]; ROW_COUNT],
+        column_index: usize,
+    ) -> Self {
+        let mut layout = Self {
+            max_dot_position: 0,
+            max_fractional_len: 0,
+        };
+        for row in matrix {
+            let size = CellSize::of(&row[column_index]);
+            layout.max_dot_position = core::cmp::max(layout.max_dot_position, size.dot_position);
+            layout.max_fractional_len =
+                core::cmp::max(layout.max_fractional_len, size.len - size.dot_position);
+        }
+        layout
     }
-    rows.join("\n")
 }
 
-// It receives a matrix of numbers, and a column index,
-// and it returns an array of strings
-// displaying the lined up formatted numbers of the specified column.
-fn format_column<const ROW_COUNT: usize, const COLUMN_COUNT: usize, Number: ArithmeticOps>(
+// It receives two matrices of numbers,
+// and it returns their row-by-column product.
+pub fn matrix_product<
+    const ROW_COUNT: usize,
+    const INNER_COUNT: usize,
+    const COLUMN_COUNT: usize,
+    Number: ArithmeticOps,
+>(
+    left: &[[Number; INNER_COUNT]; ROW_COUNT],
+    right: &[[Number; COLUMN_COUNT]; INNER_COUNT],
+) -> [[Number; COLUMN_COUNT]; ROW_COUNT] {
+    core::array::from_fn(|row| {
+        core::array::from_fn(|column| {
+            (0..INNER_COUNT).fold(Number::ZERO, |sum, k| sum + left[row][k] * right[k][column])
+        })
+    })
+}
+
+// It receives a matrix of numbers,
+// and it returns its transpose.
+pub fn transposed_matrix<
+    const ROW_COUNT: usize,
+    const COLUMN_COUNT: usize,
+    Number: ArithmeticOps,
+>(
+    matrix: &[[Number; COLUMN_COUNT]; ROW_COUNT],
+) -> [[Number; ROW_COUNT]; COLUMN_COUNT] {
+    core::array::from_fn(|row| core::array::from_fn(|column| matrix[column][row]))
+}
+
+// It receives a matrix, J, and a covariance matrix, C,
+// and it returns the covariance matrix of the transformed quantities, J·C·Jᵀ.
+pub fn transformed_covariances<
+    const ROW_COUNT: usize,
+    const COLUMN_COUNT: usize,
+    Number: ArithmeticOps,
+>(
     matrix: &[[Number; COLUMN_COUNT]; ROW_COUNT],
-    column_index: usize,
-) -> [String; ROW_COUNT] {
-    const EMPTY_STRING: String = String::new();
-    let mut padded_cells = [EMPTY_STRING; ROW_COUNT];
-    let mut max_dot_pos = 0;
-    let mut max_fractional_len = 0;
-    let mut formatted_cells = [EMPTY_STRING; ROW_COUNT];
-    let mut dot_positions = [0; ROW_COUNT];
-    for row_index in 0..ROW_COUNT {
-        formatted_cells[row_index] = format!("{}", matrix[row_index][column_index]);
-        dot_positions[row_index] =
-            if let Some(pos) = formatted_cells[row_index].bytes().position(|c| c == b'.') {
-                pos
+    covariances: &[[Number; COLUMN_COUNT]; COLUMN_COUNT],
+) -> [[Number; ROW_COUNT]; ROW_COUNT] {
+    matrix_product(
+        &matrix_product(matrix, covariances),
+        &transposed_matrix(matrix),
+    )
+}
+
+// It returns the identity matrix.
+pub fn identity_matrix<const SIZE: usize, Number: ArithmeticOps>() -> [[Number; SIZE]; SIZE] {
+    core::array::from_fn(|row| {
+        core::array::from_fn(|column| {
+            if row == column {
+                Number::ONE
             } else {
-                formatted_cells[row_index].len()
-            };
-        max_dot_pos = core::cmp::max(max_dot_pos, dot_positions[row_index]);
-        max_fractional_len = core::cmp::max(
-            max_fractional_len,
-            formatted_cells[row_index].len() - dot_positions[row_index],
-        );
+                Number::ZERO
+            }
+        })
+    })
+}
+
+// It receives a square matrix of numbers,
+// and it returns its inverse, computed by Gauss-Jordan elimination with partial pivoting.
+// If the matrix is singular, so that a zero pivot is found, it returns `None`.
+pub fn inverted_matrix<const SIZE: usize, Number: ArithmeticOps>(
+    matrix: &[[Number; SIZE]; SIZE],
+) -> Option<[[Number; SIZE]; SIZE]> {
+    let mut left = *matrix;
+    let mut right = identity_matrix::<SIZE, Number>();
+    for column in 0..SIZE {
+        let mut pivot_row = column;
+        for row in column + 1..SIZE {
+            if left[row][column].abs() > left[pivot_row][column].abs() {
+                pivot_row = row;
+            }
+        }
+        left.swap(column, pivot_row);
+        right.swap(column, pivot_row);
+        // The comparison is false also for a NaN pivot.
+        let is_nonzero = left[column][column].abs() > Number::ZERO;
+        if !is_nonzero {
+            return None;
+        }
+        let inv_pivot = Number::ONE / left[column][column];
+        for k in 0..SIZE {
+            left[column][k] *= inv_pivot;
+            right[column][k] *= inv_pivot;
+        }
+        for row in 0..SIZE {
+            if row != column {
+                let factor = left[row][column];
+                for k in 0..SIZE {
+                    left[row][k] -= factor * left[column][k];
+                    right[row][k] -= factor * right[column][k];
+                }
+            }
+        }
     }
-    for row_index in 0..ROW_COUNT {
-        padded_cells[row_index] = format!(
-            "{}{}{}",
-            " ".repeat(max_dot_pos - dot_positions[row_index]),
-            formatted_cells[row_index],
-            " ".repeat(
-                max_fractional_len + dot_positions[row_index] - formatted_cells[row_index].len()
-            ),
-        );
+    Some(right)
+}
+
+// Largest difference allowed between the products of the rows of a matrix
+// and the ones of an orthonormal matrix, to consider it a rotation,
+// as a multiple of the epsilon of the number type.
+// It allows only the rounding errors of the computation of a rotation,
+// so that a matrix which is not exactly a rotation is not serialized as a rotation.
+const ROTATION_TOLERANCE: f64 = 64.;
+
+// It receives a 2x2 matrix of numbers,
+// and, if it is a rotation, it returns its angle in radians, in -π..=π.
+pub fn rotation_angle_2d<Number: ArithmeticOps>(matrix: &[[Number; 2]; 2]) -> Option<Number> {
+    let tolerance = Number::EPSILON * Number::from_f64(ROTATION_TOLERANCE);
+    let cos_a = matrix[0][0];
+    let sin_a = matrix[1][0];
+    if (matrix[1][1] - cos_a).abs() > tolerance
+        || (matrix[0][1] + sin_a).abs() > tolerance
+        || (cos_a * cos_a + sin_a * sin_a - Number::ONE).abs() > tolerance
+    {
+        return None;
+    }
+    Some(sin_a.atan2(cos_a))
+}
+
+// It receives a 3x3 matrix of numbers,
+// and, if it is a rotation, it returns its axis, as a unit vector, and its angle in radians, in 0..=π.
+// The axis of the identity matrix is the z axis.
+pub fn rotation_axis_angle_3d<Number: ArithmeticOps>(
+    matrix: &[[Number; 3]; 3],
+) -> Option<([Number; 3], Number)> {
+    let tolerance = Number::EPSILON * Number::from_f64(ROTATION_TOLERANCE);
+    let product = matrix_product(matrix, &transposed_matrix(matrix));
+    let identity = identity_matrix::<3, Number>();
+    for row in 0..3 {
+        for column in 0..3 {
+            if (product[row][column] - identity[row][column]).abs() > tolerance {
+                return None;
+            }
+        }
+    }
+    let m = matrix;
+    let determinant = m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+        - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+        + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0]);
+    if determinant < Number::ZERO {
+        return None;
+    }
+
+    // The antisymmetric part of the matrix is sin(a) [u]×, and its trace is 1 + 2 cos(a).
+    let cos_a = (m[0][0] + m[1][1] + m[2][2] - Number::ONE) * Number::HALF;
+    let skew = [m[2][1] - m[1][2], m[0][2] - m[2][0], m[1][0] - m[0][1]];
+    let skew_norm = (skew[0] * skew[0] + skew[1] * skew[1] + skew[2] * skew[2]).sqrt();
+    let angle = (skew_norm * Number::HALF).atan2(cos_a);
+    if cos_a < Number::ZERO {
+        // Near a half turn, the antisymmetric part is too small,
+        // and so the axis is computed from the symmetric part, which is cos(a) I + (1 - cos(a)) u uᵀ,
+        // using its column having the largest diagonal item.
+        let mut k = 0;
+        for i in 1..3 {
+            if m[i][i] > m[k][k] {
+                k = i;
+            }
+        }
+        let mut axis: [Number; 3] = core::array::from_fn(|i| (m[i][k] + m[k][i]) * Number::HALF);
+        axis[k] -= cos_a;
+        let norm = (axis[0] * axis[0] + axis[1] * axis[1] + axis[2] * axis[2]).sqrt();
+        let sign = if axis[0] * skew[0] + axis[1] * skew[1] + axis[2] * skew[2] < Number::ZERO {
+            -Number::ONE
+        } else {
+            Number::ONE
+        };
+        Some((axis.map(|x| sign * x / norm), angle))
+    } else if skew_norm > Number::ZERO {
+        Some((skew.map(|x| x / skew_norm), angle))
+    } else {
+        Some(([Number::ZERO, Number::ZERO, Number::ONE], Number::ZERO))
+    }
+}
+
+// It receives the values and the covariance matrix of the state of a linear Kalman filter,
+// a matrix which maps the state to the observed quantities,
+// and the values and the covariance matrix of an observation,
+// and it updates the state by fusing the observation into it.
+// The covariance matrix is updated using the Joseph form,
+// which keeps it symmetric and positive semi-definite.
+// If the covariance matrix of the innovation is singular,
+// as when both the state and the observation have no uncertainty,
+// it returns `false` and it leaves the state unchanged.
+pub fn kalman_update<
+    const STATE_SIZE: usize,
+    const OBSERVATION_SIZE: usize,
+    Number: ArithmeticOps,
+>(
+    values: &mut [Number; STATE_SIZE],
+    covariances: &mut [[Number; STATE_SIZE]; STATE_SIZE],
+    observation_matrix: &[[Number; STATE_SIZE]; OBSERVATION_SIZE],
+    observation_values: &[Number; OBSERVATION_SIZE],
+    observation_covariances: &[[Number; OBSERVATION_SIZE]; OBSERVATION_SIZE],
+) -> bool {
+    let observation_matrix_t = transposed_matrix(observation_matrix);
+    let covariances_by_observation_t = matrix_product(covariances, &observation_matrix_t);
+    let mut innovation_covariances =
+        matrix_product(observation_matrix, &covariances_by_observation_t);
+    for row in 0..OBSERVATION_SIZE {
+        for column in 0..OBSERVATION_SIZE {
+            innovation_covariances[row][column] += observation_covariances[row][column];
+        }
+    }
+    let inverted_innovation_covariances = match inverted_matrix(&innovation_covariances) {
+        Some(inverted) => inverted,
+        None => return false,
+    };
+    let gain = matrix_product(
+        &covariances_by_observation_t,
+        &inverted_innovation_covariances,
+    );
+    let predicted_observation = matrix_product(observation_matrix, &values.map(|v| [v]));
+    for row in 0..STATE_SIZE {
+        for k in 0..OBSERVATION_SIZE {
+            values[row] += gain[row][k] * (observation_values[k] - predicted_observation[k][0]);
+        }
+    }
+    let gain_by_observation = matrix_product(&gain, observation_matrix);
+    let complement: [[Number; STATE_SIZE]; STATE_SIZE] = core::array::from_fn(|row| {
+        core::array::from_fn(|column| {
+            if row == column {
+                Number::ONE - gain_by_observation[row][column]
+            } else {
+                -gain_by_observation[row][column]
+            }
+        })
+    });
+    let corrected = transformed_covariances(&complement, covariances);
+    let noise = transformed_covariances(&gain, observation_covariances);
+    for row in 0..STATE_SIZE {
+        for column in 0..STATE_SIZE {
+            covariances[row][column] = corrected[row][column] + noise[row][column];
+        }
     }
-    padded_cells
+    true
 }
@@ -0,0 +1,129 @@
+//! Interoperability with the arrays of the crate `ndarray`, available with the feature `ndarray`.
+//!
+//! The functions `peel_view` and `wrap_view`, and their mutable versions,
+//! view an array of measures as an array of numbers, and vice versa, without copying it.
+//! They work for the measure types which implement the trait `Transparent`,
+//! like `Measure<Metre, f64>`, which can be viewed as `f64`.
+//!
+//! The functions `matrix_vector_product`, `matrix_product` and `inner_product`
+//! multiply arrays of measures using the optimized products of `ndarray`,
+//! and type their results through the relationships declared between the units.
+//! For example, if the relationship `Joule 1 == Newton 1 * Metre 1` is declared,
+//! the product of an `Array2<Measure<Newton>>` by an `Array1<Measure<Metre>>`
+//! is an `Array1<Measure<Joule>>`.
+
+use crate::traits::Transparent;
+use core::ops::Mul;
+use ndarray::{
+    Array, Array1, Array2, ArrayBase, ArrayView, ArrayViewMut, Data, DataMut, Dimension, Ix1, Ix2,
+    LinalgScalar,
+};
+
+/// Views an array of measures as an array of their numbers, without copying it.
+pub fn peel_view<Outer, Inner, S, D>(array: &ArrayBase<S, D>) -> ArrayView<'_, Inner, D>
+where
+    Outer: Transparent<Inner>,
+    S: Data<Elem = Outer>,
+    D: Dimension,
+{
+    // SAFETY: `Outer` has the same layout and validity of `Inner`, as required by `Transparent`,
+    // and the returned view borrows `array`.
+    unsafe { array.raw_view().cast::<Inner>().deref_into_view() }
+}
+
+/// Views an array of numbers as an array of measures, without copying it.
+pub fn wrap_view<Outer, Inner, S, D>(array: &ArrayBase<S, D>) -> ArrayView<'_, Outer, D>
+where
+    Outer: Transparent<Inner>,
+    S: Data<Elem = Inner>,
+    D: Dimension,
+{
+    // SAFETY: `Outer` has the same layout and validity of `Inner`, as required by `Transparent`,
+    // and the returned view borrows `array`.
+    unsafe { array.raw_view().cast::<Outer>().deref_into_view() }
+}
+
+/// Views a mutable array of measures as a mutable array of their numbers, without copying it.
+pub fn peel_view_mut<Outer, Inner, S, D>(array: &mut ArrayBase<S, D>) -> ArrayViewMut<'_, Inner, D>
+where
+    Outer: Transparent<Inner>,
+    S: DataMut<Elem = Outer>,
+    D: Dimension,
+{
+    // SAFETY: `Outer` has the same layout and validity of `Inner`, as required by `Transparent`,
+    // and the returned view borrows mutably `array`.
+    unsafe { array.raw_view_mut().cast::<Inner>().deref_into_view_mut() }
+}
+
+/// Views a mutable array of numbers as a mutable array of measures, without copying it.
+pub fn wrap_view_mut<Outer, Inner, S, D>(array: &mut ArrayBase<S, D>) -> ArrayViewMut<'_, Outer, D>
+where
+    Outer: Transparent<Inner>,
+    S: DataMut<Elem = Inner>,
+    D: Dimension,
+{
+    // SAFETY: `Outer` has the same layout and validity of `Inner`, as required by `Transparent`,
+    // and the returned view borrows mutably `array`.
+    unsafe { array.raw_view_mut().cast::<Outer>().deref_into_view_mut() }
+}
+
+/// Wraps every number of an array, getting an owned array of measures.
+fn wrap_array<Outer, Inner, D>(array: Array<Inner, D>) -> Array<Outer, D>
+where
+    Outer: Transparent<Inner>,
+    Inner: Copy,
+    D: Dimension,
+{
+    array.mapv(Outer::wrap)
+}
+
+/// Matrix of measures * vector of measures -> vector of measures.
+/// It panics if the number of columns of `lhs` is different from the length of `rhs`.
+pub fn matrix_vector_product<Lhs, Rhs, Output, Number, SL, SR>(
+    lhs: &ArrayBase<SL, Ix2>,
+    rhs: &ArrayBase<SR, Ix1>,
+) -> Array1<Output>
+where
+    Lhs: Transparent<Number> + Mul<Rhs, Output = Output>,
+    Rhs: Transparent<Number>,
+    Output: Transparent<Number>,
+    Number: LinalgScalar,
+    SL: Data<Elem = Lhs>,
+    SR: Data<Elem = Rhs>,
+{
+    wrap_array(peel_view(lhs).dot(&peel_view(rhs)))
+}
+
+/// Matrix of measures * matrix of measures -> matrix of measures.
+/// It panics if the number of columns of `lhs` is different from the number of rows of `rhs`.
+pub fn matrix_product<Lhs, Rhs, Output, Number, SL, SR>(
+    lhs: &ArrayBase<SL, Ix2>,
+    rhs: &ArrayBase<SR, Ix2>,
+) -> Array2<Output>
+where
+    Lhs: Transparent<Number> + Mul<Rhs, Output = Output>,
+    Rhs: Transparent<Number>,
+    Output: Transparent<Number>,
+    Number: LinalgScalar,
+    SL: Data<Elem = Lhs>,
+    SR: Data<Elem = Rhs>,
+{
+    wrap_array(peel_view(lhs).dot(&peel_view(rhs)))
+}
+
+/// Vector of measures * vector of measures -> measure.
+/// It panics if the lengths of `lhs` and `rhs` are different.
+pub fn inner_product<Lhs, Rhs, Output, Number, SL, SR>(
+    lhs: &ArrayBase<SL, Ix1>,
+    rhs: &ArrayBase<SR, Ix1>,
+) -> Output
+where
+    Lhs: Transparent<Number> + Mul<Rhs, Output = Output>,
+    Rhs: Transparent<Number>,
+    Output: Transparent<Number>,
+    Number: LinalgScalar,
+    SL: Data<Elem = Lhs>,
+    SR: Data<Elem = Rhs>,
+{
+    Output::wrap(peel_view(lhs).dot(&peel_view(rhs)))
+}
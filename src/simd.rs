@@ -0,0 +1,381 @@
+//! Number type containing several lanes, to process batches of values, available with the feature `simd`.
+//!
+//! A `Lanes<Real, N>` contains `N` independent numbers of type `Real`,
+//! and every operation is applied to every lane, like in the SIMD (Single Instruction, Multiple Data)
+//! instructions of processors. It can be used as `Number` type of measures,
+//! like in `Measure<Metre, f32x8>`, so that every arithmetic operation, and every conversion
+//! between units, is applied to eight values at once.
+//! The lanes are stored in an array, and the operations are simple loops over such array,
+//! which, when compiled with optimizations, are vectorized by the compiler using the SIMD
+//! instructions of the target, without needing a nightly compiler or unsafe code.
+//!
+//! The type aliases `f32x4`, `f32x8`, `f64x2` and `f64x4` are the most common lane types.
+//!
+//! The comparison operators compare all the lanes:
+//! * Two values are equal if all their lanes are equal.
+//! * A value is less than another one if all its lanes are less than the corresponding lanes
+//!   of the other one. Similarly for the other relations.
+//! * Otherwise, `partial_cmp` returns `None`, and so every comparison operator returns `false`.
+//!
+//! The lane-wise comparisons are performed by the methods `lanes_eq`, `lanes_lt`, `lanes_le`,
+//! `lanes_gt` and `lanes_ge`, which return an array of booleans.
+//! The formatting traits print all the lanes, like in `[1.5, 2, 0.25, 4] m`,
+//! applying the format specification to every lane.
+
+use crate::traits::{
+    ArithmeticOps, CubicRoot, FromF64, HasEpsilon, HasHalf, HasOne, HasSign, HasZero,
+    InverseTrigonometry, Logarithm, LossyFrom, Pow, PowerDecibel, RootPowerDecibel, ScaleByRatio,
+    Sqrt, Trigonometry,
+};
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+/// Batch of `N` numbers of type `Real`, on which every operation is applied lane-wise.
+#[derive(Clone, Copy)]
+pub struct Lanes<Real, const N: usize>(pub [Real; N]);
+
+/// Four lanes of type `f32`, fitting a 128-bit SIMD register.
+#[allow(non_camel_case_types)]
+pub type f32x4 = Lanes<f32, 4>;
+
+/// Eight lanes of type `f32`, fitting a 256-bit SIMD register.
+#[allow(non_camel_case_types)]
+pub type f32x8 = Lanes<f32, 8>;
+
+/// Two lanes of type `f64`, fitting a 128-bit SIMD register.
+#[allow(non_camel_case_types)]
+pub type f64x2 = Lanes<f64, 2>;
+
+/// Four lanes of type `f64`, fitting a 256-bit SIMD register.
+#[allow(non_camel_case_types)]
+pub type f64x4 = Lanes<f64, 4>;
+
+impl<Real: ArithmeticOps, const N: usize> Lanes<Real, N> {
+    /// Lanes::from_array([Real; N]) -> Lanes
+    pub const fn from_array(values: [Real; N]) -> Self {
+        Self(values)
+    }
+
+    /// Lanes::splat(Real) -> Lanes
+    /// It returns the value having all the lanes equal to the specified number.
+    pub const fn splat(value: Real) -> Self {
+        Self([value; N])
+    }
+
+    /// Lanes.to_array() -> [Real; N]
+    pub const fn to_array(self) -> [Real; N] {
+        self.0
+    }
+
+    /// Lanes.lane(usize) -> Real
+    /// It panics if the index is not less than `N`.
+    pub fn lane(self, index: usize) -> Real {
+        self.0[index]
+    }
+
+    /// Lanes.sum_lanes() -> Real
+    /// It returns the sum of all the lanes.
+    pub fn sum_lanes(self) -> Real {
+        self.0.iter().fold(Real::ZERO, |sum, &value| sum + value)
+    }
+
+    /// Lanes.map(impl Fn(Real) -> Real) -> Lanes
+    /// It returns the value whose lanes are computed applying the function to the lanes of `self`.
+    pub fn map(self, function: impl Fn(Real) -> Real) -> Self {
+        Self(core::array::from_fn(|i| function(self.0[i])))
+    }
+
+    /// Lanes.zip_map(Lanes, impl Fn(Real, Real) -> Real) -> Lanes
+    /// It returns the value whose lanes are computed applying the function
+    /// to the corresponding lanes of `self` and of `other`.
+    pub fn zip_map(self, other: Self, function: impl Fn(Real, Real) -> Real) -> Self {
+        Self(core::array::from_fn(|i| function(self.0[i], other.0[i])))
+    }
+
+    // The results of a comparison of the corresponding lanes.
+    fn compare_lanes(self, other: Self, predicate: impl Fn(&Real, &Real) -> bool) -> [bool; N] {
+        core::array::from_fn(|i| predicate(&self.0[i], &other.0[i]))
+    }
+
+    /// Lanes.lanes_eq(Lanes) -> [bool; N]
+    pub fn lanes_eq(self, other: Self) -> [bool; N] {
+        self.compare_lanes(other, Real::eq)
+    }
+
+    /// Lanes.lanes_lt(Lanes) -> [bool; N]
+    pub fn lanes_lt(self, other: Self) -> [bool; N] {
+        self.compare_lanes(other, Real::lt)
+    }
+
+    /// Lanes.lanes_le(Lanes) -> [bool; N]
+    pub fn lanes_le(self, other: Self) -> [bool; N] {
+        self.compare_lanes(other, Real::le)
+    }
+
+    /// Lanes.lanes_gt(Lanes) -> [bool; N]
+    pub fn lanes_gt(self, other: Self) -> [bool; N] {
+        self.compare_lanes(other, Real::gt)
+    }
+
+    /// Lanes.lanes_ge(Lanes) -> [bool; N]
+    pub fn lanes_ge(self, other: Self) -> [bool; N] {
+        self.compare_lanes(other, Real::ge)
+    }
+}
+
+impl<Real: ArithmeticOps, const N: usize> Default for Lanes<Real, N> {
+    fn default() -> Self {
+        Self::splat(Real::ZERO)
+    }
+}
+
+impl<Real: ArithmeticOps, const N: usize> From<[Real; N]> for Lanes<Real, N> {
+    fn from(values: [Real; N]) -> Self {
+        Self(values)
+    }
+}
+
+impl<Real: ArithmeticOps, const N: usize> From<Lanes<Real, N>> for [Real; N] {
+    fn from(lanes: Lanes<Real, N>) -> Self {
+        lanes.0
+    }
+}
+
+impl<Real: ArithmeticOps, const N: usize> PartialEq for Lanes<Real, N> {
+    /// Two values are equal if all their lanes are equal.
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<Real: ArithmeticOps, const N: usize> PartialOrd for Lanes<Real, N> {
+    /// It returns `None` if the lanes have different orderings.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let mut result = Some(Ordering::Equal);
+        for (i, (a, b)) in self.0.iter().zip(&other.0).enumerate() {
+            let ordering = a.partial_cmp(b);
+            if i == 0 {
+                result = ordering;
+            } else if ordering != result {
+                return None;
+            }
+        }
+        result
+    }
+}
+
+impl<Real: ArithmeticOps, const N: usize> Neg for Lanes<Real, N> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        self.map(Real::neg)
+    }
+}
+
+// It implements a binary operator, and its assignment version, applying them lane-wise.
+macro_rules! impl_lane_wise_operator {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) => {
+        impl<Real: ArithmeticOps, const N: usize> $trait for Lanes<Real, N> {
+            type Output = Self;
+            fn $method(self, other: Self) -> Self {
+                self.zip_map(other, Real::$method)
+            }
+        }
+
+        impl<Real: ArithmeticOps, const N: usize> $assign_trait for Lanes<Real, N> {
+            fn $assign_method(&mut self, other: Self) {
+                *self = self.$method(other);
+            }
+        }
+    };
+}
+
+impl_lane_wise_operator!(Add, add, AddAssign, add_assign);
+impl_lane_wise_operator!(Sub, sub, SubAssign, sub_assign);
+impl_lane_wise_operator!(Mul, mul, MulAssign, mul_assign);
+impl_lane_wise_operator!(Div, div, DivAssign, div_assign);
+
+impl<Real: ArithmeticOps, const N: usize> Rem for Lanes<Real, N> {
+    type Output = Self;
+    fn rem(self, other: Self) -> Self {
+        self.zip_map(other, Real::rem)
+    }
+}
+
+impl<Real: ArithmeticOps, const N: usize> core::iter::Sum for Lanes<Real, N> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |a, b| a + b)
+    }
+}
+
+impl<Real: ArithmeticOps, const N: usize> Pow for Lanes<Real, N> {
+    fn powf(self, exponent: Self) -> Self {
+        self.zip_map(exponent, Real::powf)
+    }
+}
+
+impl<Real: ArithmeticOps, const N: usize> FromF64 for Lanes<Real, N> {
+    /// It returns the value having all the lanes equal to the specified number.
+    fn from_f64(n: f64) -> Self {
+        Self::splat(Real::from_f64(n))
+    }
+}
+
+impl<Real: ArithmeticOps, const N: usize> ScaleByRatio for Lanes<Real, N> {
+    fn scale_by_ratio(self, ratio: f64) -> Self {
+        self.map(|value| value.scale_by_ratio(ratio))
+    }
+    fn apply_conversion<F: Fn(f64) -> f64>(self, function: F) -> Self {
+        self.map(|value| value.apply_conversion(&function))
+    }
+}
+
+impl<Real: ArithmeticOps, const N: usize> Sqrt for Lanes<Real, N> {
+    type Output = Self;
+    fn sqrt(self) -> Self {
+        self.map(Real::sqrt)
+    }
+}
+
+impl<Real: ArithmeticOps, const N: usize> CubicRoot for Lanes<Real, N> {
+    type Output = Self;
+    fn cubic_root(self) -> Self {
+        self.map(Real::cubic_root)
+    }
+}
+
+impl<Real: ArithmeticOps, const N: usize> Trigonometry for Lanes<Real, N> {
+    type Output = Self;
+    fn cos(self) -> Self::Output {
+        self.map(Real::cos)
+    }
+    fn sin(self) -> Self::Output {
+        self.map(Real::sin)
+    }
+    fn tan(self) -> Self::Output {
+        self.map(Real::tan)
+    }
+    fn sin_cos(self) -> (Self::Output, Self::Output) {
+        (self.sin(), self.cos())
+    }
+}
+
+impl<Real: ArithmeticOps, const N: usize> InverseTrigonometry for Lanes<Real, N> {
+    type Output = Self;
+    fn acos(self) -> Self::Output {
+        self.map(Real::acos)
+    }
+    fn asin(self) -> Self::Output {
+        self.map(Real::asin)
+    }
+    fn atan2(self, other: Self) -> Self::Output {
+        self.zip_map(other, Real::atan2)
+    }
+}
+
+impl<Real: ArithmeticOps, const N: usize> Logarithm for Lanes<Real, N> {
+    fn ln(self) -> Self {
+        self.map(Real::ln)
+    }
+}
+
+impl<Real: ArithmeticOps, const N: usize> PowerDecibel for Lanes<Real, N> {
+    fn to_power_decibels(self) -> Self {
+        self.map(Real::to_power_decibels)
+    }
+    fn power_decibels_to_value(self) -> Self {
+        self.map(Real::power_decibels_to_value)
+    }
+}
+
+impl<Real: ArithmeticOps, const N: usize> RootPowerDecibel for Lanes<Real, N> {
+    fn to_root_power_decibels(self) -> Self {
+        self.map(Real::to_root_power_decibels)
+    }
+    fn root_power_decibels_to_value(self) -> Self {
+        self.map(Real::root_power_decibels_to_value)
+    }
+}
+
+impl<Real: ArithmeticOps, const N: usize> HasZero for Lanes<Real, N> {
+    const ZERO: Self = Self::splat(Real::ZERO);
+}
+
+impl<Real: ArithmeticOps, const N: usize> HasOne for Lanes<Real, N> {
+    const ONE: Self = Self::splat(Real::ONE);
+}
+
+impl<Real: ArithmeticOps, const N: usize> HasHalf for Lanes<Real, N> {
+    const HALF: Self = Self::splat(Real::HALF);
+}
+
+impl<Real: ArithmeticOps, const N: usize> HasEpsilon for Lanes<Real, N> {
+    const EPSILON: Self = Self::splat(Real::EPSILON);
+}
+
+impl<Real: ArithmeticOps, const N: usize> HasSign for Lanes<Real, N> {
+    fn signum(self) -> Self {
+        self.map(Real::signum)
+    }
+    fn abs(self) -> Self {
+        self.map(Real::abs)
+    }
+}
+
+impl<Real: ArithmeticOps, const N: usize> LossyFrom<Lanes<Real, N>> for Lanes<Real, N> {
+    fn lossy_from(n: Lanes<Real, N>) -> Self {
+        n
+    }
+}
+impl<const N: usize> LossyFrom<Lanes<f32, N>> for Lanes<f64, N> {
+    fn lossy_from(n: Lanes<f32, N>) -> Self {
+        Self(n.0.map(f64::from))
+    }
+}
+impl<const N: usize> LossyFrom<Lanes<f64, N>> for Lanes<f32, N> {
+    fn lossy_from(n: Lanes<f64, N>) -> Self {
+        Self(n.0.map(|value| value as f32))
+    }
+}
+
+impl<Real: ArithmeticOps, const N: usize> Lanes<Real, N> {
+    // It writes the lanes like "[1.5, 2, 3]", formatting every lane by the given function.
+    fn format(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+        format_lane: fn(&Real, &mut fmt::Formatter<'_>) -> fmt::Result,
+    ) -> fmt::Result {
+        formatter.write_str("[")?;
+        for (i, value) in self.0.iter().enumerate() {
+            if i > 0 {
+                formatter.write_str(", ")?;
+            }
+            format_lane(value, formatter)?;
+        }
+        formatter.write_str("]")
+    }
+}
+
+impl<Real: ArithmeticOps, const N: usize> fmt::Display for Lanes<Real, N> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.format(formatter, fmt::Display::fmt)
+    }
+}
+
+impl<Real: ArithmeticOps, const N: usize> fmt::Debug for Lanes<Real, N> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.format(formatter, fmt::Debug::fmt)
+    }
+}
+
+impl<Real: ArithmeticOps, const N: usize> fmt::LowerExp for Lanes<Real, N> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.format(formatter, fmt::LowerExp::fmt)
+    }
+}
+
+impl<Real: ArithmeticOps, const N: usize> fmt::UpperExp for Lanes<Real, N> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.format(formatter, fmt::UpperExp::fmt)
+    }
+}
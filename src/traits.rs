@@ -1,3 +1,5 @@
+use crate::float_math::FloatFunctions;
+use core::convert::TryFrom;
 use core::fmt;
 use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
 
@@ -6,12 +8,12 @@ pub trait Pow {
 }
 impl Pow for f32 {
     fn powf(self, exponent: Self) -> Self {
-        self.powf(exponent)
+        FloatFunctions::powf(self, exponent)
     }
 }
 impl Pow for f64 {
     fn powf(self, exponent: Self) -> Self {
-        self.powf(exponent)
+        FloatFunctions::powf(self, exponent)
     }
 }
 
@@ -36,13 +38,13 @@ pub trait Sqrt {
 impl Sqrt for f32 {
     type Output = f32;
     fn sqrt(self) -> Self {
-        self.sqrt()
+        FloatFunctions::sqrt(self)
     }
 }
 impl Sqrt for f64 {
     type Output = f64;
     fn sqrt(self) -> Self {
-        self.sqrt()
+        FloatFunctions::sqrt(self)
     }
 }
 
@@ -53,13 +55,13 @@ pub trait CubicRoot {
 impl CubicRoot for f32 {
     type Output = f32;
     fn cubic_root(self) -> Self {
-        self.powf(1. / 3.)
+        FloatFunctions::powf(self, 1. / 3.)
     }
 }
 impl CubicRoot for f64 {
     type Output = f64;
     fn cubic_root(self) -> Self {
-        self.powf(1. / 3.)
+        FloatFunctions::powf(self, 1. / 3.)
     }
 }
 
@@ -73,31 +75,31 @@ pub trait Trigonometry {
 impl Trigonometry for f32 {
     type Output = f32;
     fn cos(self) -> Self::Output {
-        self.cos()
+        FloatFunctions::cos(self)
     }
     fn sin(self) -> Self::Output {
-        self.sin()
+        FloatFunctions::sin(self)
     }
     fn tan(self) -> Self::Output {
-        self.tan()
+        FloatFunctions::tan(self)
     }
     fn sin_cos(self) -> (Self::Output, Self::Output) {
-        self.sin_cos()
+        FloatFunctions::sin_cos(self)
     }
 }
 impl Trigonometry for f64 {
     type Output = f64;
     fn cos(self) -> Self::Output {
-        self.cos()
+        FloatFunctions::cos(self)
     }
     fn sin(self) -> Self::Output {
-        self.sin()
+        FloatFunctions::sin(self)
     }
     fn tan(self) -> Self::Output {
-        self.tan()
+        FloatFunctions::tan(self)
     }
     fn sin_cos(self) -> (Self::Output, Self::Output) {
-        self.sin_cos()
+        FloatFunctions::sin_cos(self)
     }
 }
 
@@ -110,25 +112,39 @@ pub trait InverseTrigonometry {
 impl InverseTrigonometry for f32 {
     type Output = f32;
     fn acos(self) -> Self::Output {
-        self.acos()
+        FloatFunctions::acos(self)
     }
     fn asin(self) -> Self::Output {
-        self.asin()
+        FloatFunctions::asin(self)
     }
     fn atan2(self, other: Self) -> Self::Output {
-        self.atan2(other)
+        FloatFunctions::atan2(self, other)
     }
 }
 impl InverseTrigonometry for f64 {
     type Output = f64;
     fn acos(self) -> Self::Output {
-        self.acos()
+        FloatFunctions::acos(self)
     }
     fn asin(self) -> Self::Output {
-        self.asin()
+        FloatFunctions::asin(self)
     }
     fn atan2(self, other: Self) -> Self::Output {
-        self.atan2(other)
+        FloatFunctions::atan2(self, other)
+    }
+}
+
+pub trait Logarithm {
+    fn ln(self) -> Self;
+}
+impl Logarithm for f32 {
+    fn ln(self) -> Self {
+        FloatFunctions::ln(self)
+    }
+}
+impl Logarithm for f64 {
+    fn ln(self) -> Self {
+        FloatFunctions::ln(self)
     }
 }
 
@@ -138,18 +154,18 @@ pub trait PowerDecibel {
 }
 impl PowerDecibel for f32 {
     fn to_power_decibels(self) -> Self {
-        self.log10() * 10.
+        FloatFunctions::log10(self) * 10.
     }
     fn power_decibels_to_value(self) -> Self {
-        10_f32.powf(self * 0.1)
+        FloatFunctions::powf(10_f32, self * 0.1)
     }
 }
 impl PowerDecibel for f64 {
     fn to_power_decibels(self) -> Self {
-        self.log10() * 10.
+        FloatFunctions::log10(self) * 10.
     }
     fn power_decibels_to_value(self) -> Self {
-        10_f64.powf(self * 0.1)
+        FloatFunctions::powf(10_f64, self * 0.1)
     }
 }
 
@@ -159,18 +175,18 @@ pub trait RootPowerDecibel {
 }
 impl RootPowerDecibel for f32 {
     fn to_root_power_decibels(self) -> Self {
-        self.log10() * 20.
+        FloatFunctions::log10(self) * 20.
     }
     fn root_power_decibels_to_value(self) -> Self {
-        10_f32.powf(self * 0.05)
+        FloatFunctions::powf(10_f32, self * 0.05)
     }
 }
 impl RootPowerDecibel for f64 {
     fn to_root_power_decibels(self) -> Self {
-        self.log10() * 20.
+        FloatFunctions::log10(self) * 20.
     }
     fn root_power_decibels_to_value(self) -> Self {
-        10_f64.powf(self * 0.05)
+        FloatFunctions::powf(10_f64, self * 0.05)
     }
 }
 
@@ -204,6 +220,18 @@ impl HasHalf for f64 {
     const HALF: Self = 0.5;
 }
 
+/// The difference between 1 and the next representable number,
+/// used to bound the tolerances of the numerical algorithms.
+pub trait HasEpsilon {
+    const EPSILON: Self;
+}
+impl HasEpsilon for f32 {
+    const EPSILON: Self = f32::EPSILON;
+}
+impl HasEpsilon for f64 {
+    const EPSILON: Self = f64::EPSILON;
+}
+
 pub trait HasSign {
     fn signum(self) -> Self;
     fn abs(self) -> Self;
@@ -225,7 +253,186 @@ impl HasSign for f64 {
     }
 }
 
-pub trait ArithmeticOps:
+/// Multiplication by the ratio between two units of measurement,
+/// used to convert measures from a unit to another.
+/// Integer and fixed-point numbers approximate the ratio by a fraction
+/// whose numerator and denominator are not greater than 10^18,
+/// and round the result to the nearest representable number.
+/// So, a ratio smaller than 10^-18 is approximated by zero,
+/// and a product overflowing `i128` panics.
+/// The conversion functions of non-linear units of measurement, defined on `f64`,
+/// are applied through `apply_conversion`.
+pub trait ScaleByRatio {
+    fn scale_by_ratio(self, ratio: f64) -> Self;
+    fn apply_conversion<F: Fn(f64) -> f64>(self, function: F) -> Self;
+
+    /// It multiplies every value by the ratio.
+    /// Integer and fixed-point numbers approximate the ratio by a fraction only once.
+    fn scale_all_by_ratio(values: &mut [Self], ratio: f64)
+    where
+        Self: Sized + Copy,
+    {
+        for value in values {
+            *value = value.scale_by_ratio(ratio);
+        }
+    }
+}
+impl ScaleByRatio for f32 {
+    fn scale_by_ratio(self, ratio: f64) -> Self {
+        self * ratio as f32
+    }
+    fn apply_conversion<F: Fn(f64) -> f64>(self, function: F) -> Self {
+        function(self as f64) as f32
+    }
+}
+impl ScaleByRatio for f64 {
+    fn scale_by_ratio(self, ratio: f64) -> Self {
+        self * ratio
+    }
+    fn apply_conversion<F: Fn(f64) -> f64>(self, function: F) -> Self {
+        function(self)
+    }
+}
+
+/// conversion_derivative(function, value) -> f64
+/// It returns the derivative of the given conversion function at the given value,
+/// approximated by a central difference,
+/// to propagate the variance through the conversions of non-linear units.
+pub fn conversion_derivative<F: Fn(f64) -> f64>(function: F, value: f64) -> f64 {
+    // The cubic root of the machine epsilon minimizes the error of a central difference.
+    let step = 6.055_454_452_393_343e-6 * value.abs().max(1.);
+    (function(value + step) - function(value - step)) / (2. * step)
+}
+
+// The largest numerator and denominator of the fraction approximating a ratio.
+// The product of such a numerator by any `i64` value fits in an `i128`.
+const MAX_FRACTION_TERM: i128 = 1_000_000_000_000_000_000;
+
+// The fraction nearest to `ratio` having a numerator and a denominator
+// not greater than `MAX_FRACTION_TERM`, computed by expanding `ratio` as a continued fraction.
+pub(crate) fn rational_approximation(ratio: f64) -> (i128, i128) {
+    let target = ratio.abs();
+    let (mut previous_numerator, mut numerator) = (0_i128, 1_i128);
+    let (mut previous_denominator, mut denominator) = (1_i128, 0_i128);
+    let mut x = target;
+    while x.is_finite() && x < 1e18 {
+        let integer_part = FloatFunctions::floor(x);
+        let term = integer_part as i128;
+        let next_numerator = term * numerator + previous_numerator;
+        let next_denominator = term * denominator + previous_denominator;
+        if next_numerator > MAX_FRACTION_TERM || next_denominator > MAX_FRACTION_TERM {
+            break;
+        }
+        (previous_numerator, numerator) = (numerator, next_numerator);
+        (previous_denominator, denominator) = (denominator, next_denominator);
+        if x == integer_part
+            || (numerator as f64 / denominator as f64 - target).abs() <= target * f64::EPSILON
+        {
+            break;
+        }
+        x = 1. / (x - integer_part);
+    }
+    if denominator == 0 {
+        (FloatFunctions::round(target) as i128, 1)
+    } else if ratio < 0. {
+        (-numerator, denominator)
+    } else {
+        (numerator, denominator)
+    }
+}
+
+/// It returns `value * numerator / denominator`, rounded to the nearest integer,
+/// with halves rounded away from zero, or `None` if the result overflows `i128`.
+pub(crate) fn scale_integer_by_fraction(
+    value: i128,
+    (numerator, denominator): (i128, i128),
+) -> Option<i128> {
+    let product = value.checked_mul(numerator)?;
+    if product >= 0 {
+        Some(product.checked_add(denominator / 2)? / denominator)
+    } else {
+        Some(product.checked_sub(denominator / 2)? / denominator)
+    }
+}
+
+// Implementations of the traits required by `BasicArithmeticOps` for signed integer types.
+macro_rules! impl_integer_number_traits {
+    ($($integer:ty),*) => {
+        $(
+            impl ScaleByRatio for $integer {
+                fn scale_by_ratio(self, ratio: f64) -> Self {
+                    let mut values = [self];
+                    Self::scale_all_by_ratio(&mut values, ratio);
+                    values[0]
+                }
+                fn scale_all_by_ratio(values: &mut [Self], ratio: f64) {
+                    let fraction = rational_approximation(ratio);
+                    for value in values {
+                        *value = scale_integer_by_fraction(*value as i128, fraction)
+                            .and_then(|scaled| <$integer>::try_from(scaled).ok())
+                            .expect("integer overflow in the conversion of a measure");
+                    }
+                }
+                fn apply_conversion<F: Fn(f64) -> f64>(self, function: F) -> Self {
+                    let value = FloatFunctions::round(function(self as f64));
+                    assert!(
+                        value >= <$integer>::MIN as f64 && value <= <$integer>::MAX as f64,
+                        "integer overflow in the conversion of a measure"
+                    );
+                    value as $integer
+                }
+            }
+            impl HasZero for $integer {
+                const ZERO: Self = 0;
+            }
+            impl HasOne for $integer {
+                const ONE: Self = 1;
+            }
+            impl HasSign for $integer {
+                fn signum(self) -> Self {
+                    self.signum()
+                }
+                fn abs(self) -> Self {
+                    self.abs()
+                }
+            }
+            impl LossyFrom<$integer> for $integer {
+                fn lossy_from(n: $integer) -> Self {
+                    n
+                }
+            }
+            impl LossyFrom<$integer> for f32 {
+                fn lossy_from(n: $integer) -> Self {
+                    n as Self
+                }
+            }
+            impl LossyFrom<$integer> for f64 {
+                fn lossy_from(n: $integer) -> Self {
+                    n as Self
+                }
+            }
+            impl LossyFrom<f32> for $integer {
+                fn lossy_from(n: f32) -> Self {
+                    FloatFunctions::round(n) as Self
+                }
+            }
+            impl LossyFrom<f64> for $integer {
+                fn lossy_from(n: f64) -> Self {
+                    FloatFunctions::round(n) as Self
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_number_traits!(i8, i16, i32, i64);
+
+/// Numbers supporting the operations which don't need real-number functions,
+/// like signed integers and fixed-point numbers.
+/// They can be used as value type of `Measure` and of `MeasurePoint`,
+/// to add, subtract, scale, compare, format and convert measures.
+/// It is automatically implemented by any type having the required operations.
+pub trait BasicArithmeticOps:
     Neg<Output = Self>
     + Add<Self, Output = Self>
     + AddAssign<Self>
@@ -235,34 +442,22 @@ pub trait ArithmeticOps:
     + MulAssign<Self>
     + Div<Self, Output = Self>
     + DivAssign<Self>
-    + Rem<Self, Output = Self>
-    + Pow
-    + FromF64
-    + Sqrt<Output = Self>
-    + CubicRoot<Output = Self>
-    + Trigonometry<Output = Self>
-    + InverseTrigonometry<Output = Self>
-    + PowerDecibel
-    + RootPowerDecibel
+    + ScaleByRatio
     + HasZero
     + HasOne
-    + HasHalf
     + HasSign
-    + std::iter::Sum
     + fmt::Display
     + fmt::Debug
-    + fmt::LowerExp
-    + fmt::UpperExp
     + Clone
     + Copy
     + PartialOrd
     + PartialEq
 where
-    Self: std::marker::Sized,
+    Self: core::marker::Sized,
 {
 }
 
-impl<T> ArithmeticOps for T where
+impl<T> BasicArithmeticOps for T where
     T: Neg<Output = Self>
         + Add<T, Output = T>
         + AddAssign<T>
@@ -272,6 +467,46 @@ impl<T> ArithmeticOps for T where
         + MulAssign<T>
         + Div<T, Output = Self>
         + DivAssign<T>
+        + ScaleByRatio
+        + HasZero
+        + HasOne
+        + HasSign
+        + fmt::Display
+        + fmt::Debug
+        + Clone
+        + Copy
+        + PartialOrd
+        + PartialEq
+{
+}
+
+/// Numbers supporting also the real-number functions, like `f32` and `f64`.
+/// They can be used as value type of every generated type.
+/// It is automatically implemented by any type having the required operations.
+pub trait ArithmeticOps:
+    BasicArithmeticOps
+    + Rem<Self, Output = Self>
+    + Pow
+    + FromF64
+    + Sqrt<Output = Self>
+    + CubicRoot<Output = Self>
+    + Trigonometry<Output = Self>
+    + InverseTrigonometry<Output = Self>
+    + Logarithm
+    + PowerDecibel
+    + RootPowerDecibel
+    + HasHalf
+    + HasEpsilon
+    + core::iter::Sum
+    + fmt::LowerExp
+    + fmt::UpperExp
+where
+    Self: core::marker::Sized,
+{
+}
+
+impl<T> ArithmeticOps for T where
+    T: BasicArithmeticOps
         + Rem<T, Output = Self>
         + Pow
         + FromF64
@@ -279,43 +514,115 @@ impl<T> ArithmeticOps for T where
         + CubicRoot<Output = Self>
         + Trigonometry<Output = Self>
         + InverseTrigonometry<Output = Self>
+        + Logarithm
         + PowerDecibel
         + RootPowerDecibel
-        + HasZero
-        + HasOne
         + HasHalf
-        + HasSign
-        + std::iter::Sum
-        + fmt::Display
-        + fmt::Debug
+        + HasEpsilon
+        + core::iter::Sum
         + fmt::LowerExp
         + fmt::UpperExp
-        + Clone
-        + Copy
-        + PartialOrd
-        + PartialEq
 {
 }
 
-/*
-trait Vector<Number>:
+/// Vectors of a vector space over `Number`,
+/// like `Measure`, `Measure2d` and `Measure3d`.
+/// It is automatically implemented by any type having the required operations.
+pub trait Vector<Number>:
     Add<Self, Output = Self>
-    + AddAssign<Self>
     + Sub<Self, Output = Self>
-    + SubAssign<Self>
     + Mul<Number, Output = Self>
-    + MulAssign<Number>
     + Div<Number, Output = Self>
-    + DivAssign<Number>
-    + Sized
+    + Default
+    + Clone
+    + Copy
 {
 }
 
-trait Affine<Number, V: Vector<Number>>:
-    Add<V, Output = Self> + AddAssign<V> + Sub<V, Output = Self> + SubAssign<V> + Sized
+impl<T, Number> Vector<Number> for T where
+    T: Add<T, Output = T>
+        + Sub<T, Output = T>
+        + Mul<Number, Output = T>
+        + Div<Number, Output = T>
+        + Default
+        + Clone
+        + Copy
 {
 }
-*/
+
+/// Points of an affine space over `Number`,
+/// like `MeasurePoint`, `MeasurePoint2d` and `MeasurePoint3d`.
+/// The difference between two points is a vector of type `Self::Vector`.
+/// Every `Vector` is also an `Affine` whose difference type is itself.
+/// It is automatically implemented by any type having the required operations.
+pub trait Affine<Number>:
+    Add<Self::Vector, Output = Self> + Sub<Self, Output = Self::Vector> + Clone + Copy
+{
+    type Vector: Vector<Number>;
+}
+
+impl<T, V, Number> Affine<Number> for T
+where
+    T: Add<V, Output = T> + Sub<T, Output = V> + Clone + Copy,
+    V: Vector<Number>,
+{
+    type Vector = V;
+}
+
+/// Types having the same memory layout of the type `Inner`,
+/// like `Measure<Unit, Number>` with respect to `Number`,
+/// and `Measure3d<Unit, Number>` with respect to `[Number; 3]`.
+/// It allows to view slices of numbers as slices of measures, and vice versa, without copying them,
+/// like when reading memory-mapped files or filling buffers to be uploaded to a GPU.
+/// It is implemented by the exact measure types generated by `define_measure_types`,
+/// except the directions, whose values are constrained.
+///
+/// # Safety
+///
+/// It must be implemented only by structs declared as `#[repr(transparent)]`,
+/// having a field of type `Inner` and no other fields except zero-sized ones,
+/// and for which every value of type `Inner` is a valid value.
+pub unsafe trait Transparent<Inner>: Sized {
+    /// Type::wrap(Inner) -> Type
+    fn wrap(inner: Inner) -> Self {
+        let inner = core::mem::ManuallyDrop::new(inner);
+        // SAFETY: `Self` has the same layout and validity of `Inner`, as required by this trait,
+        // and `inner` is not dropped.
+        unsafe { core::ptr::read(&*inner as *const Inner as *const Self) }
+    }
+
+    /// Type::peel(Type) -> Inner
+    fn peel(outer: Self) -> Inner {
+        let outer = core::mem::ManuallyDrop::new(outer);
+        // SAFETY: `Self` has the same layout and validity of `Inner`, as required by this trait,
+        // and `outer` is not dropped.
+        unsafe { core::ptr::read(&*outer as *const Self as *const Inner) }
+    }
+
+    /// Type::wrap_slice(&[Inner]) -> &[Type]
+    fn wrap_slice(inner: &[Inner]) -> &[Self] {
+        // SAFETY: `Self` has the same layout and validity of `Inner`, as required by this trait.
+        unsafe { core::slice::from_raw_parts(inner.as_ptr() as *const Self, inner.len()) }
+    }
+
+    /// Type::wrap_slice_mut(&mut [Inner]) -> &mut [Type]
+    fn wrap_slice_mut(inner: &mut [Inner]) -> &mut [Self] {
+        // SAFETY: `Self` has the same layout and validity of `Inner`, as required by this trait.
+        unsafe { core::slice::from_raw_parts_mut(inner.as_mut_ptr() as *mut Self, inner.len()) }
+    }
+
+    /// Type::peel_slice(&[Type]) -> &[Inner]
+    fn peel_slice(outer: &[Self]) -> &[Inner] {
+        // SAFETY: `Self` has the same layout and validity of `Inner`, as required by this trait.
+        unsafe { core::slice::from_raw_parts(outer.as_ptr() as *const Inner, outer.len()) }
+    }
+
+    /// Type::peel_slice_mut(&mut [Type]) -> &mut [Inner]
+    fn peel_slice_mut(outer: &mut [Self]) -> &mut [Inner] {
+        // SAFETY: `Self` has the same layout and validity of `Inner`, as required by this trait.
+        unsafe { core::slice::from_raw_parts_mut(outer.as_mut_ptr() as *mut Inner, outer.len()) }
+    }
+}
 
 pub trait LossyFrom<Source> {
     fn lossy_from(n: Source) -> Self;
@@ -353,12 +660,143 @@ pub trait MeasurementUnit {
     const RATIO: f64;
     const OFFSET: f64;
     const SUFFIX: &'static str;
+
+    /// It is false for the units whose values aren't a linear function of the values
+    /// of the other units of the same property, like decibels or stellar magnitudes.
+    /// The measures having such units are converted by `to_base` and `from_base`.
+    const LINEAR: bool = true;
+
+    /// Unit::to_base(f64) -> f64
+    /// It converts a value of this unit to the unit having ratio 1 and offset 0.
+    fn to_base(value: f64) -> f64 {
+        value * Self::RATIO + Self::OFFSET
+    }
+
+    /// Unit::from_base(f64) -> f64
+    /// It converts a value of the unit having ratio 1 and offset 0 to this unit.
+    fn from_base(value: f64) -> f64 {
+        (value - Self::OFFSET) / Self::RATIO
+    }
+}
+
+/// Constants of a unit of measurement, available at runtime,
+/// to parse and to convert measures whose unit is known only when the program runs.
+#[derive(Clone, Copy, Debug)]
+pub struct UnitDescription {
+    pub suffix: &'static str,
+    pub ratio: f64,
+    pub offset: f64,
+    pub linear: bool,
+    pub to_base: fn(f64) -> f64,
+    pub from_base: fn(f64) -> f64,
+}
+
+// The conversion functions aren't compared, as their addresses aren't meaningful.
+impl PartialEq for UnitDescription {
+    fn eq(&self, other: &Self) -> bool {
+        self.suffix == other.suffix
+            && self.ratio == other.ratio
+            && self.offset == other.offset
+            && self.linear == other.linear
+    }
 }
 
-pub trait AngleMeasurementUnit: MeasurementUnit<Property = crate::angle::Angle> {
+impl UnitDescription {
+    /// UnitDescription::of::<Unit>() -> UnitDescription
+    pub const fn of<Unit: MeasurementUnit>() -> Self {
+        Self {
+            suffix: Unit::SUFFIX,
+            ratio: Unit::RATIO,
+            offset: Unit::OFFSET,
+            linear: Unit::LINEAR,
+            to_base: Unit::to_base,
+            from_base: Unit::from_base,
+        }
+    }
+
+    /// The suffix without the surrounding whitespace, like "m" for the suffix " m".
+    pub fn symbol(&self) -> &'static str {
+        self.suffix.trim()
+    }
+
+    /// UnitDescription::find(units, "m") -> Option<&UnitDescription>
+    /// It finds the unit having the given symbol.
+    pub fn find(units: &'static [Self], symbol: &str) -> Option<&'static Self> {
+        units.iter().find(|unit| unit.symbol() == symbol)
+    }
+
+    /// UnitDescription::split(units, "10 m") -> Option<("10", &UnitDescription)>
+    /// It finds the unit having the longest symbol ending the given text,
+    /// and it returns the rest of the text, without the surrounding whitespace, and that unit.
+    /// A unit having an empty symbol matches only if no other unit matches.
+    pub fn split<'a>(units: &'static [Self], text: &'a str) -> Option<(&'a str, &'static Self)> {
+        let text = text.trim();
+        units
+            .iter()
+            .filter(|unit| text.ends_with(unit.symbol()))
+            .max_by_key(|unit| unit.symbol().len())
+            .map(|unit| (text[..text.len() - unit.symbol().len()].trim_end(), unit))
+    }
+
+    /// format!("{}", UnitDescription::symbols(units)) -> String
+    /// It lists the symbols of the given units, like "`m`, `mm`", for error messages.
+    pub fn symbols(units: &'static [Self]) -> impl fmt::Display {
+        struct Symbols(&'static [UnitDescription]);
+        impl fmt::Display for Symbols {
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                for (i, unit) in self.0.iter().enumerate() {
+                    if i > 0 {
+                        formatter.write_str(", ")?;
+                    }
+                    write!(formatter, "`{}`", unit.symbol())?;
+                }
+                Ok(())
+            }
+        }
+        Symbols(units)
+    }
+}
+
+/// Component of a typed vector generated by the macro `define_typed_vector`.
+/// It is implemented by a marker type for every component,
+/// and it specifies the unit of the component and its position in the vector.
+pub trait TypedVectorComponent<Vector> {
+    type Unit: MeasurementUnit;
+    const INDEX: usize;
+}
+
+pub trait AngleMeasurementUnit: LinearMeasurementUnit<Property = crate::angle::Angle> {
     const CYCLE_FRACTION: f64;
 }
 
+/// Units of measurement converted by their ratio and offset,
+/// that is all the units except the ones defined by `to_base` and `from_base`, like decibels.
+/// Only these units can be used by the types whose conversion has no meaning for non-linear units,
+/// like `ComplexMeasure`, and by the conversions of the relative measures, like `Measure::convert`,
+/// as a difference cannot be converted by the functions of a non-linear unit.
+/// It is implemented by the macro `measurement_unit!` for the units not having `to_base`,
+/// and by all the angle units.
+pub trait LinearMeasurementUnit: MeasurementUnit {}
+
+/// Units of measurement having a non-zero offset, like the degree Celsius,
+/// whose zero is not the zero of the base unit.
+/// Only the measure points, like `MeasurePoint<Celsius>`, are converted applying the offset,
+/// while the relative measures, like `Measure<Celsius>`, are converted as differences.
+/// To get the relative measure of a point from the zero of the base unit,
+/// like an absolute temperature to be used in a relationship,
+/// the measure points having these units have the method `to_absolute_measure`.
+/// It is implemented by the macro `measurement_unit!` for the units having the field `offset`,
+/// which must not be zero.
+pub trait AffineUnit: LinearMeasurementUnit {}
+
+/// Units of measurement of vector properties.
+/// The macro `measurement_unit!` does not accept the fields `offset` and `to_base`
+/// for vector properties, and so these units are always linear and have no offset.
+/// Only these units can be used by the vector measures, like `Measure2d` and `MeasureN`,
+/// and by the linear maps, so the units having an offset are rejected at compile time.
+/// It is implemented by the macro `measurement_unit!` for the units of vector properties.
+pub trait VectorMeasurementUnit: LinearMeasurementUnit<Property: VectorProperty> {}
+
 pub trait MeasurementProperty {}
 
 pub trait ScalarProperty: MeasurementProperty {}
@@ -3,6 +3,7 @@ mod test_affine_map_3d;
 mod test_derived_1d;
 mod test_derived_2d;
 mod test_derived_3d;
+mod test_kalman;
 mod test_linear_map_2d;
 mod test_linear_map_3d;
 mod test_measure_1d;
@@ -0,0 +1,392 @@
+use measures::dimensionless::One;
+use measures::{assert_eq_64, assert_eq_tolerance};
+use units::{
+    observation, state, AffineMap3d, ApproxMeasure, ApproxMeasure3d, ApproxMeasurePoint3d,
+    KalmanFilter, KalmanFilter3d, KinematicKalmanFilter3d, LinearMap3d, Measure, Measure3d, Metre,
+    MetrePerSecond, Observation, ObservationCovariance, ObservationJacobian, Second, State,
+    StateCovariance, StateJacobian,
+};
+
+mod units {
+    measures::define_measure_types! {
+        with_points with_3d with_transformations exact with_approx,
+        scalar_properties [
+            Time [
+                Second {
+                    suffix: " s",
+                }
+            ]
+        ]
+        vector_properties [
+            Length [
+                Metre {
+                    suffix: " m",
+                }
+            ]
+            Velocity [
+                MetrePerSecond {
+                    suffix: " m/s",
+                }
+            ]
+        ]
+        relationships [
+            Metre 1 == MetrePerSecond 1 * Second 1,
+        ]
+    }
+
+    measures::define_typed_vector! {
+        vector: State,
+        covariance: StateCovariance,
+        jacobian: StateJacobian,
+        components: state,
+        fields [
+            position: Metre,
+            velocity: MetrePerSecond,
+            heading: Radian,
+        ]
+    }
+
+    measures::define_typed_vector! {
+        vector: Observation,
+        covariance: ObservationCovariance,
+        jacobian: ObservationTransition,
+        components: observation,
+        fields [
+            position: Metre,
+        ]
+    }
+
+    measures::define_typed_jacobian! {
+        jacobian: ObservationJacobian,
+        from [
+            vector: State,
+            covariance: StateCovariance,
+            components: state,
+        ],
+        to [
+            vector: Observation,
+            covariance: ObservationCovariance,
+            components: observation,
+        ],
+    }
+}
+
+fn isotropic(variance: f64) -> [[f64; 3]; 3] {
+    [[variance, 0., 0.], [0., variance, 0.], [0., 0., variance]]
+}
+
+#[test]
+fn kalman_filter_scalar() {
+    let mut filter = KalmanFilter::new(ApproxMeasure::<Metre>::with_variance(10., 4.));
+
+    // Fusing a reading having the same variance gives the mean, with half variance.
+    assert!(filter.update(ApproxMeasure::with_variance(12., 4.)));
+    assert_eq_64!(filter.state.value, 11.);
+    assert_eq_64!(filter.state.variance, 2.);
+
+    // A more precise reading weighs more.
+    filter.update(ApproxMeasure::with_variance(14., 1.));
+    assert_eq_64!(filter.state.value, 11. + 3. * 2. / 3.);
+    assert_eq_64!(filter.state.variance, 2. / 3.);
+
+    filter.predict(ApproxMeasure::with_variance(1., 0.5));
+    assert_eq_64!(filter.state.value, 14.);
+    assert_eq_64!(filter.state.variance, 2. / 3. + 0.5);
+
+    filter.predict_with_rate(
+        Measure::<Second>::new(2.),
+        ApproxMeasure::<MetrePerSecond>::with_variance(3., 0.25),
+    );
+    assert_eq_64!(filter.state.value, 20.);
+    assert_eq_64!(filter.state.variance, 2. / 3. + 1.5);
+}
+
+#[test]
+fn kalman_filter_singular_update() {
+    // A reading without uncertainty cannot be fused into a state without uncertainty.
+    let mut filter = KalmanFilter::new(ApproxMeasure::<Metre>::with_variance(10., 0.));
+    assert!(!filter.update(ApproxMeasure::with_variance(12., 0.)));
+    assert_eq!(filter.state.value, 10.);
+    assert_eq!(filter.state.variance, 0.);
+
+    // The same for a single component of a position.
+    let mut filter = KalmanFilter3d::new(ApproxMeasurePoint3d::<Metre>::with_covariances(
+        [1., 2., 3.],
+        [[4., 0., 0.], [0., 4., 0.], [0., 0., 0.]],
+    ));
+    assert!(!filter.update(ApproxMeasurePoint3d::with_covariances(
+        [2., 4., 6.],
+        [[4., 0., 0.], [0., 4., 0.], [0., 0., 0.]],
+    )));
+    assert_eq!(filter.state.values, [1., 2., 3.]);
+    assert_eq!(filter.state.covariances[0], [4., 0., 0.]);
+
+    let mut filter = KinematicKalmanFilter3d::new(
+        ApproxMeasurePoint3d::<Metre>::with_covariances([0., 0., 0.], isotropic(1.)),
+        ApproxMeasure3d::<MetrePerSecond>::with_covariances([1., 0., 0.], isotropic(0.)),
+    );
+    assert!(!filter.update_velocity(ApproxMeasure3d::with_covariances(
+        [2., 0., 0.],
+        isotropic(0.),
+    )));
+    assert_eq!(filter.velocity().values, [1., 0., 0.]);
+    assert!(filter.update(ApproxMeasurePoint3d::with_covariances(
+        [1., 0., 0.],
+        isotropic(1.),
+    )));
+    assert_eq_64!(filter.position().values, [0.5, 0., 0.]);
+}
+
+#[test]
+fn kalman_filter_3d() {
+    let mut filter = KalmanFilter3d::new(ApproxMeasurePoint3d::<Metre>::with_covariances(
+        [0., 0., 0.],
+        isotropic(4.),
+    ));
+    filter.update(ApproxMeasurePoint3d::with_covariances(
+        [2., 4., 6.],
+        [[4., 0., 0.], [0., 12., 0.], [0., 0., 0.]],
+    ));
+    assert_eq_64!(filter.state.values, [1., 1., 6.]);
+    assert_eq_64!(filter.state.covariances[0], [2., 0., 0.]);
+    assert_eq_64!(filter.state.covariances[1], [0., 3., 0.]);
+    assert_eq_64!(filter.state.covariances[2], [0., 0., 0.]);
+
+    filter.predict(ApproxMeasure3d::with_covariances(
+        [1., 0., 0.],
+        isotropic(1.),
+    ));
+    assert_eq_64!(filter.state.values, [2., 1., 6.]);
+    assert_eq_64!(filter.state.covariances[2], [0., 0., 1.]);
+
+    filter.predict_with_velocity(
+        Measure::<Second>::new(2.),
+        ApproxMeasure3d::<MetrePerSecond>::with_covariances([1., 2., 3.], isotropic(0.25)),
+    );
+    assert_eq_64!(filter.state.values, [4., 5., 12.]);
+    assert_eq_64!(filter.state.covariances[0], [4., 0., 0.]);
+    assert_eq_64!(filter.state.covariances[2], [0., 0., 2.]);
+}
+
+#[test]
+fn kalman_filter_3d_with_maps() {
+    let mut filter = KalmanFilter3d::new(ApproxMeasurePoint3d::<Metre>::with_covariances(
+        [1., 2., 3.],
+        [[1., 0., 0.], [0., 4., 0.], [0., 0., 9.]],
+    ));
+    filter.predict_with_linear_map(
+        &LinearMap3d::scaling([2., 1., 1.]),
+        ApproxMeasure3d::default(),
+    );
+    assert_eq_64!(filter.state.values, [2., 2., 3.]);
+    assert_eq_64!(filter.state.covariances[0], [4., 0., 0.]);
+
+    filter.predict_with_affine_map(
+        &AffineMap3d::translation(Measure3d::new([10., 20., 30.])),
+        ApproxMeasure3d::with_covariances([0., 0., 0.], isotropic(1.)),
+    );
+    assert_eq_64!(filter.state.values, [12., 22., 33.]);
+    assert_eq_64!(filter.state.covariances[0], [5., 0., 0.]);
+    assert_eq_64!(filter.state.covariances[1], [0., 5., 0.]);
+    assert_eq_64!(filter.state.covariances[2], [0., 0., 10.]);
+}
+
+#[test]
+fn kinematic_kalman_filter_3d_predict() {
+    let mut filter = KinematicKalmanFilter3d::new(
+        ApproxMeasurePoint3d::<Metre>::with_covariances([0., 0., 0.], isotropic(1.)),
+        ApproxMeasure3d::<MetrePerSecond>::with_covariances([1., 2., 0.], isotropic(0.25)),
+    );
+    assert_eq!(filter.position_velocity_covariances(), isotropic(0.));
+
+    filter.predict(Measure::<Second>::new(2.), ApproxMeasure3d::default());
+    assert_eq_64!(filter.position().values, [2., 4., 0.]);
+    assert_eq_64!(filter.velocity().values, [1., 2., 0.]);
+    // Var(x + 2 v) = Var(x) + 4 Var(v).
+    assert_eq_64!(filter.position().covariances[0], [2., 0., 0.]);
+    // Cov(x + 2 v, v) = 2 Var(v).
+    assert_eq_64!(filter.position_velocity_covariances()[1], [0., 0.5, 0.]);
+    assert_eq_64!(filter.velocity().covariances[1], [0., 0.25, 0.]);
+
+    // A velocity change is applied uniformly during the step.
+    filter.predict(
+        Measure::<Second>::new(2.),
+        ApproxMeasure3d::with_covariances([0., 0., 2.], isotropic(1.)),
+    );
+    assert_eq_64!(filter.position().values, [4., 8., 2.]);
+    assert_eq_64!(filter.velocity().values, [1., 2., 2.]);
+    // Var(x + 2 v + dv) = Var(x) + 4 Var(v) + 4 Cov(x, v) + Var(dv).
+    assert_eq_64!(filter.position().covariances[2][2], 2. + 1. + 2. + 1.);
+    assert_eq_64!(filter.velocity().covariances[2][2], 1.25);
+
+    // A half turn of the velocity around the z axis.
+    let mut filter = KinematicKalmanFilter3d::new(
+        ApproxMeasurePoint3d::<Metre>::default(),
+        ApproxMeasure3d::<MetrePerSecond>::with_covariances([1., 0., 0.], isotropic(0.)),
+    );
+    filter.predict_with_linear_map(
+        Measure::<Second>::new(1.),
+        &LinearMap3d::new([[-1., 0., 0.], [0., -1., 0.], [0., 0., 1.]]),
+        ApproxMeasure3d::default(),
+    );
+    assert_eq_64!(filter.position().values, [0., 0., 0.]);
+    assert_eq_64!(filter.velocity().values, [-1., 0., 0.]);
+}
+
+#[test]
+fn kinematic_kalman_filter_3d_update() {
+    let mut filter = KinematicKalmanFilter3d::new(
+        ApproxMeasurePoint3d::<Metre>::with_covariances([0., 0., 0.], isotropic(100.)),
+        ApproxMeasure3d::<MetrePerSecond>::with_covariances([0., 0., 0.], isotropic(100.)),
+    );
+    let step = Measure::<Second>::new(1.);
+    let process_noise = ApproxMeasure3d::with_covariances([0., 0., 0.], isotropic(1e-6));
+
+    // Exact observations of an object moving at 3 m/s along x.
+    for i in 0..20 {
+        let t = i as f64;
+        filter.update(ApproxMeasurePoint3d::with_covariances(
+            [3. * t, 1., -2.],
+            isotropic(0.01),
+        ));
+        filter.predict(step, process_noise);
+    }
+    let velocity = filter.velocity();
+    assert_eq_tolerance!(velocity.values[0], 3., 1e-3);
+    assert_eq_tolerance!(velocity.values[1], 0., 1e-3);
+    assert!(velocity.covariances[0][0] < 0.01);
+    assert_eq_tolerance!(filter.position().values[0], 60., 1e-2);
+    assert_eq_tolerance!(filter.position().values[1], 1., 1e-3);
+    assert_eq_tolerance!(filter.position().values[2], -2., 1e-3);
+
+    // An exact observation of the velocity replaces the estimated velocity.
+    filter.update_velocity(ApproxMeasure3d::with_covariances(
+        [4., 0., 0.],
+        isotropic(0.),
+    ));
+    assert_eq_tolerance!(filter.velocity().values[0], 4., 1e-9);
+    assert_eq_tolerance!(filter.velocity().covariances[0][0], 0., 1e-9);
+    for row in 0..6 {
+        for column in 0..6 {
+            assert_eq_tolerance!(
+                filter.covariances[row][column],
+                filter.covariances[column][row],
+                1e-12
+            );
+        }
+    }
+}
+
+#[test]
+fn kinematic_kalman_filter_3d_transform() {
+    let mut filter = KinematicKalmanFilter3d::new(
+        ApproxMeasurePoint3d::<Metre>::with_covariances(
+            [1., 0., 0.],
+            [[1., 0., 0.], [0., 4., 0.], [0., 0., 9.]],
+        ),
+        ApproxMeasure3d::<MetrePerSecond>::with_covariances([0., 2., 0.], isotropic(0.)),
+    );
+    // Swap of the x axis and the y axis, and then translation.
+    let mut map = AffineMap3d::translation(Measure3d::new([10., 0., 0.]));
+    map.c[0][0] = 0.;
+    map.c[0][1] = 1.;
+    map.c[1][0] = 1.;
+    map.c[1][1] = 0.;
+    filter.transform(&map);
+    assert_eq_64!(filter.position().values, [10., 1., 0.]);
+    assert_eq_64!(filter.velocity().values, [2., 0., 0.]);
+    assert_eq_64!(filter.position().covariances[0], [4., 0., 0.]);
+    assert_eq_64!(filter.position().covariances[1], [0., 1., 0.]);
+}
+
+// Observation of the position, but not of the velocity nor of the heading.
+fn observation_jacobian() -> ObservationJacobian {
+    let mut jacobian = ObservationJacobian::default();
+    jacobian.set::<observation::position, state::position, One>(Measure::new(1.));
+    jacobian
+}
+
+#[test]
+fn typed_observation_jacobian() {
+    let jacobian = observation_jacobian();
+    assert_eq!(
+        jacobian.get::<observation::position, state::position, One>(),
+        Measure::<One>::new(1.)
+    );
+    assert_eq!(
+        jacobian.get::<observation::position, state::velocity, Second>(),
+        Measure::<Second>::new(0.)
+    );
+    let observed = jacobian.apply_to(State::new(
+        Measure::new(100.),
+        Measure::new(2.),
+        Measure::new(0.5),
+    ));
+    assert_eq!(observed.values(), [100.]);
+
+    let covariance = jacobian.transform_covariance(&StateCovariance::from_standard_deviations(
+        State::new(Measure::new(3.), Measure::new(0.5), Measure::new(0.1)),
+    ));
+    assert_eq_64!(covariance.variance::<observation::position>(), 9.);
+    assert_eq!(jacobian.to_string(), " ⎡ 1 0 0 ⎤");
+}
+
+#[test]
+fn typed_kalman_update() {
+    let mut state = State::new(Measure::new(100.), Measure::new(2.), Measure::new(0.5));
+    let mut covariance = StateCovariance::from_standard_deviations(State::new(
+        Measure::new(3.),
+        Measure::new(0.5),
+        Measure::new(0.1),
+    ));
+
+    // Prediction for a time step of 2 s, with constant velocity.
+    let mut transition = StateJacobian::default();
+    transition.set::<state::position, state::velocity, _>(Measure::<Second>::new(2.));
+    state = transition.apply_to(state);
+    covariance = covariance.transformed(&transition);
+
+    // A reading of the position only.
+    let reading = ApproxMeasure::<Metre>::with_variance(106., 10.);
+    let mut observation_covariance = ObservationCovariance::default();
+    observation_covariance
+        .set_covariance::<observation::position, observation::position>(reading.variance);
+    assert!(observation_jacobian().kalman_update(
+        &mut state,
+        &mut covariance,
+        Observation::new(Measure::new(reading.value)),
+        &observation_covariance,
+    ));
+
+    // The position reading and the predicted position have the same variance.
+    let position = ApproxMeasure::<Metre>::from_measure_and_variance(
+        state.position,
+        covariance.variance::<state::position>(),
+    );
+    assert_eq_64!(position.value, 105.);
+    assert_eq_64!(position.variance, 5.);
+    // The velocity is corrected through its correlation with the position.
+    let velocity = ApproxMeasure::<MetrePerSecond>::from_measure_and_variance(
+        state.velocity,
+        covariance.variance::<state::velocity>(),
+    );
+    assert_eq_64!(velocity.value, 2.05);
+    assert_eq_64!(velocity.variance, 0.2375);
+    assert_eq_64!(
+        covariance.covariance::<state::position, state::velocity>(),
+        0.25
+    );
+    // The heading is uncorrelated with the position, and so it is unchanged.
+    assert_eq_64!(state.heading.value, 0.5);
+    assert_eq_64!(covariance.variance::<state::heading>(), 0.01);
+
+    // Readings without uncertainty cannot be fused into a state without uncertainty.
+    let mut covariance = StateCovariance::default();
+    assert!(!observation_jacobian().kalman_update(
+        &mut state,
+        &mut covariance,
+        Observation::default(),
+        &ObservationCovariance::default(),
+    ));
+    assert_eq_64!(state.position.value, 105.);
+}
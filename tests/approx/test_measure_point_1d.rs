@@ -1,4 +1,5 @@
-use units::{ApproxMeasurePoint, Celsius, Fahrenheit};
+use measures::{assert_eq_64, assert_eq_tolerance};
+use units::{ApproxMeasurePoint, Celsius, DecibelMilliwatt, Degree, Fahrenheit, Watt};
 
 mod units {
     measures::define_measure_types! {
@@ -16,6 +17,22 @@ mod units {
                     offset: 273.15 - 32. * 5. / 9.,
                 }
             ]
+            Power [
+                Watt {
+                    suffix: " W",
+                }
+                DecibelMilliwatt {
+                    suffix: " dBm",
+                    to_base: |value| 10_f64.powf(value / 10.) * 1e-3,
+                    from_base: |value| (value * 1e3).log10() * 10.,
+                }
+            ]
+        ]
+        angle_measurement_units [
+            Degree {
+                suffix: " deg",
+                cycle_fraction: 360.,
+            }
         ]
     }
 }
@@ -54,6 +71,20 @@ fn approx_measure_point_convert() {
     assert_eq!(mp4.variance, 0.2 * 0.2);
 }
 
+#[test]
+fn approx_measure_point_convert_non_linear() {
+    // The derivative of 10 log10(1000 x) at 1 W is 10 / ln(10) dBm/W.
+    let mp1 = ApproxMeasurePoint::<Watt>::with_variance(1., 0.0001);
+    let mp2: ApproxMeasurePoint<DecibelMilliwatt> = mp1.convert::<DecibelMilliwatt>();
+    let derivative = 10. / core::f64::consts::LN_10;
+    assert_eq_64!(mp2.value, 30.);
+    assert_eq_tolerance!(mp2.variance, 0.0001 * derivative * derivative, 1e-10);
+
+    let mp3: ApproxMeasurePoint<Watt> = mp2.convert::<Watt>();
+    assert_eq_tolerance!(mp3.value, 1., 1e-12);
+    assert_eq_tolerance!(mp3.variance, 0.0001, 1e-10);
+}
+
 /* TODO
 #[test]
 fn approx_measure_point_lossless_into_32_to_32() {
@@ -231,3 +262,45 @@ fn approx_measure_point_formatting_for_debug_in_celsius_one_fractional_digit() {
     assert_eq!(format!("{:.1?}", mp), "at 12.2 °C");
 }
 */
+
+#[test]
+fn approx_measure_point_circular_mean() {
+    // The mean of two independent directions having variance 4 has variance 2.
+    let directions = [
+        ApproxMeasurePoint::<Degree>::with_variance(350., 4.),
+        ApproxMeasurePoint::<Degree>::with_variance(10., 4.),
+    ];
+    let mean = ApproxMeasurePoint::circular_mean(directions).unwrap();
+    assert!(mean.value.abs() < 1e-12);
+    assert_eq_64!(mean.variance, 2.);
+
+    let directions = [
+        ApproxMeasurePoint::<Degree>::with_variance(350., 1.),
+        ApproxMeasurePoint::<Degree>::with_variance(10., 4.),
+        ApproxMeasurePoint::<Degree>::with_variance(20., 9.),
+    ];
+    let mean = ApproxMeasurePoint::circular_mean(directions).unwrap();
+    assert_eq_64!(mean.value, 6.70495327058324);
+    assert_eq_64!(mean.variance, 1.5648669495614105);
+
+    assert!(ApproxMeasurePoint::<Degree>::circular_mean([]).is_none());
+
+    // Directions cancelling out have no mean.
+    let directions = [
+        ApproxMeasurePoint::<Degree>::with_variance(90., 1.),
+        ApproxMeasurePoint::<Degree>::with_variance(-90., 1.),
+    ];
+    assert!(ApproxMeasurePoint::circular_mean(directions).is_none());
+}
+
+#[test]
+fn approx_measure_point_lerp_shortest() {
+    let amp1 = ApproxMeasurePoint::<Degree>::with_variance(350., 4.);
+    let amp2 = ApproxMeasurePoint::<Degree>::with_variance(10., 16.);
+    let amp3 = amp1.lerp_shortest(amp2, 0.25);
+    assert_eq_64!(amp3.value, 355.);
+    assert_eq_64!(amp3.variance, 0.75 * 0.75 * 4. + 0.25 * 0.25 * 16.);
+    let amp4 = amp2.lerp_shortest(amp1, 0.5);
+    assert_eq_64!(amp4.value, 0.);
+    assert_eq_64!(amp4.variance, 5.);
+}
@@ -18,6 +18,17 @@ fn tests() {
     compile_fail(&t, "difference_with_different_unit_type");
     compile_fail(&t, "decrement_with_different_number_type");
     compile_fail(&t, "decrement_with_different_unit_type");
+    compile_fail(&t, "jacobian_item_with_wrong_unit");
+    compile_fail(&t, "measure_2d_with_affine_unit");
+    compile_fail(&t, "measure_3d_with_affine_unit");
+    compile_fail(&t, "measure_n_with_affine_unit");
+    compile_fail(&t, "measure_n_with_scalar_unit");
+    compile_fail(&t, "measure_point_n_with_affine_unit");
+    compile_fail(&t, "linear_map_with_affine_unit");
+    compile_fail(&t, "unit_with_zero_offset");
+    compile_fail(&t, "complex_measure_with_non_linear_unit");
+    compile_fail(&t, "vector_property_with_non_linear_unit");
+    compile_fail(&t, "measure_convert_to_non_linear_unit");
 
     /*TODO: Add tests for the following disallowed operations:
         - Conversion between types of different property (kilogram and second)
@@ -0,0 +1,22 @@
+use units::{ComplexMeasure, DecibelWatt};
+
+mod units {
+    measures::define_measure_types! {
+        exact with_complex,
+        scalar_properties [
+            Power [
+                Watt { suffix: " W" }
+                DecibelWatt {
+                    suffix: " dBW",
+                    to_base: |value| 10_f64.powf(value / 10.),
+                    from_base: |value| value.log10() * 10.,
+                }
+            ]
+        ]
+    }
+}
+
+fn main() {
+    // A complex value cannot be converted by non-linear functions.
+    let _: Option<ComplexMeasure<DecibelWatt>> = None;
+}
@@ -0,0 +1,40 @@
+use units::{state, Measure, Second, StateJacobian};
+
+mod units {
+    measures::define_measure_types! {
+        exact,
+        scalar_properties [
+            Time [
+                Second { suffix: " s" }
+            ]
+        ]
+        vector_properties [
+            Length [
+                Metre { suffix: " m" }
+            ]
+            Velocity [
+                MetrePerSecond { suffix: " m/s" }
+            ]
+        ]
+        relationships [
+            Metre 1 == MetrePerSecond 1 * Second 1,
+        ]
+    }
+
+    measures::define_typed_vector! {
+        vector: State,
+        covariance: StateCovariance,
+        jacobian: StateJacobian,
+        components: state,
+        fields [
+            position: Metre,
+            velocity: MetrePerSecond,
+        ]
+    }
+}
+
+fn main() {
+    let mut jacobian = StateJacobian::default();
+    // The derivative of a velocity with respect to a position is not a time.
+    jacobian.set::<state::velocity, state::position, _>(Measure::<Second>::new(1.));
+}
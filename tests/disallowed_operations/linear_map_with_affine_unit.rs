@@ -0,0 +1,23 @@
+use units::{Celsius, LinearMap2d};
+
+mod units {
+    measures::define_measure_types! {
+        exact with_points with_2d with_transformations,
+        scalar_properties [
+            Temperature [
+                Kelvin { suffix: " K" }
+                Celsius { suffix: " °C", ratio: 1., offset: 273.15 }
+            ]
+        ]
+        vector_properties [
+            Length [
+                Metre { suffix: " m" }
+            ]
+        ]
+    }
+}
+
+fn main() {
+    // A linear map cannot be applied to measures having a unit with an offset.
+    _ = LinearMap2d::<f64>::apply_to::<Celsius>;
+}
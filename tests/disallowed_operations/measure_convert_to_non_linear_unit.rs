@@ -0,0 +1,22 @@
+use units::{DecibelWatt, Measure, Watt};
+
+mod units {
+    measures::define_measure_types! {
+        exact,
+        scalar_properties [
+            Power [
+                Watt { suffix: " W" }
+                DecibelWatt {
+                    suffix: " dBW",
+                    to_base: |value| 10_f64.powf(value / 10.),
+                    from_base: |value| value.log10() * 10.,
+                }
+            ]
+        ]
+    }
+}
+
+fn main() {
+    // A difference of powers cannot be converted by non-linear functions.
+    let _ = Measure::<Watt>::new(2.).convert::<DecibelWatt>();
+}
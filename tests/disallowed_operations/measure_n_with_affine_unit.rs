@@ -0,0 +1,18 @@
+use units::{Celsius, MeasureN};
+
+mod units {
+    measures::define_measure_types! {
+        exact with_nd,
+        scalar_properties [
+            Temperature [
+                Kelvin { suffix: " K" }
+                Celsius { suffix: " °C", ratio: 1., offset: 273.15 }
+            ]
+        ]
+    }
+}
+
+fn main() {
+    // A unit having an offset cannot be the unit of a vector measure.
+    let _: Option<MeasureN<Celsius, 4>> = None;
+}
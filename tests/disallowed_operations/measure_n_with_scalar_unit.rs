@@ -0,0 +1,17 @@
+use units::{MeasureN, Watt};
+
+mod units {
+    measures::define_measure_types! {
+        exact with_nd,
+        scalar_properties [
+            Power [
+                Watt { suffix: " W" }
+            ]
+        ]
+    }
+}
+
+fn main() {
+    // A unit of a scalar property cannot be the unit of a vector measure.
+    let _: Option<MeasureN<Watt, 2>> = None;
+}
@@ -1,16 +1,18 @@
-use units::{Measure, U1};
+use units::{Measure, Watt};
 
 mod units {
     measures::define_measure_types! {
         exact,
-        vector_properties [
-            P1 [
-                U1 { suffix: " u1" }
+        scalar_properties [
+            Power [
+                Watt { suffix: " W" }
             ]
         ]
     }
+    impl measures::traits::PowerQuantity for Power {}
 }
 
 fn main() {
-    _ = Measure::<U1, i32>::new(1.);
+    // Integer measures support only the operations which don't need real-number functions.
+    _ = Measure::<Watt, i32>::new(1).power_decibels_formatter();
 }
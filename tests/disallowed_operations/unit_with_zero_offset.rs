@@ -0,0 +1,13 @@
+mod units {
+    measures::define_measure_types! {
+        exact,
+        scalar_properties [
+            Temperature [
+                Kelvin { suffix: " K" }
+                OtherKelvin { suffix: " K", ratio: 1., offset: 0. }
+            ]
+        ]
+    }
+}
+
+fn main() {}
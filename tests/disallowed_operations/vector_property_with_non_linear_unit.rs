@@ -0,0 +1,17 @@
+mod units {
+    measures::define_measure_types! {
+        exact with_2d,
+        vector_properties [
+            Length [
+                Metre { suffix: " m" }
+                DecibelMetre {
+                    suffix: " dBm",
+                    to_base: |value| 10_f64.powf(value / 10.),
+                    from_base: |value| value.log10() * 10.,
+                }
+            ]
+        ]
+    }
+}
+
+fn main() {}
@@ -1,15 +1,33 @@
 mod test_affine_map_2d;
 mod test_affine_map_3d;
+#[cfg(feature = "catalog_si")]
+mod test_catalog;
+mod test_complex_measure;
 mod test_derived_1d;
 mod test_derived_2d;
 mod test_derived_3d;
+#[cfg(feature = "faer")]
+mod test_faer;
+mod test_integration;
+mod test_interpolation;
 mod test_linear_map_2d;
 mod test_linear_map_3d;
 mod test_measure_1d;
 mod test_measure_2d;
 mod test_measure_3d;
+mod test_measure_n;
 mod test_measure_point_1d;
 mod test_measure_point_2d;
 mod test_measure_point_3d;
+mod test_measure_vec;
+#[cfg(feature = "nalgebra")]
+mod test_nalgebra;
+#[cfg(feature = "ndarray")]
+mod test_ndarray;
+mod test_serde_formats;
+mod test_serde_maps;
 mod test_signed_directions;
+mod test_solvers;
+mod test_transparent;
+mod test_typed_vector;
 mod test_unsigned_directions;
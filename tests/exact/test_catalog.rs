@@ -0,0 +1,370 @@
+use measures::assert_eq_64;
+
+mod si_units {
+    measures::catalog::si::define_measure_types! {
+        exact with_approx with_points with_2d with_3d,
+        scalar_properties [
+            Information [
+                Bit {
+                    suffix: " b",
+                }
+                Byte {
+                    suffix: " B",
+                    ratio: 8.,
+                }
+            ]
+            InformationRate [
+                BitPerSecond {
+                    suffix: " b/s",
+                }
+            ]
+        ]
+        relationships [
+            Bit 1 == BitPerSecond 1 * Second 1,
+        ]
+    }
+
+    measures::measurement_unit! {
+        name: Furlong,
+        property: Length,
+        suffix: " fur",
+        ratio: 201.168,
+        with_2d: true,
+        with_3d: true,
+        vector: true,
+    }
+}
+
+#[cfg(feature = "catalog_imperial")]
+mod imperial_units {
+    measures::catalog::imperial::define_measure_types! {
+        exact with_points with_2d,
+    }
+}
+
+#[cfg(feature = "catalog_cgs")]
+mod cgs_units {
+    measures::catalog::cgs::define_measure_types! {
+        exact with_3d,
+    }
+}
+
+#[cfg(feature = "catalog_astronomy")]
+mod astronomy_units {
+    measures::catalog::astronomy::define_measure_types! {
+        exact with_2d,
+    }
+}
+
+#[cfg(feature = "catalog_electrical")]
+mod electrical_units {
+    measures::catalog::electrical::define_measure_types! {
+        exact,
+    }
+}
+
+#[cfg(all(
+    feature = "catalog_imperial",
+    feature = "catalog_cgs",
+    feature = "catalog_astronomy",
+    feature = "catalog_electrical"
+))]
+mod all_units {
+    measures::catalog::define_measure_types! {
+        catalogues [imperial cgs astronomy electrical]
+        exact with_approx with_points with_2d with_3d,
+        vector_properties [
+            Jerk [
+                MetrePerCubicSecond {
+                    suffix: " m/s\u{b3}",
+                }
+            ]
+        ]
+        relationships [
+            MetrePerSquareSecond 1 == MetrePerCubicSecond 1 * Second 1,
+        ]
+    }
+}
+
+fn is_power_quantity<Property: measures::traits::PowerQuantity>() {}
+
+fn is_root_power_quantity<Property: measures::traits::RootPowerQuantity>() {}
+
+#[test]
+fn si_catalogue() {
+    use si_units::*;
+    assert_eq_64!(
+        Measure::<Kilometre>::new(1.5).convert::<Metre>().value,
+        1500.
+    );
+    assert_eq_64!(Measure::<Hour>::new(2.).convert::<Second>().value, 7200.);
+    assert_eq_64!(
+        MeasurePoint::<Celsius>::new(100.).convert::<Kelvin>().value,
+        373.15
+    );
+    let work = Measure2d::<Newton>::new([3., 4.]) * Measure2d::<Metre>::new([2., 1.]);
+    assert_eq_64!(work.value, 10.);
+    let energy = Measure::<Kilowatt>::new(2.) * Measure::<Second>::new(3.);
+    assert_eq_64!(energy.convert::<Joule>().value, 6000.);
+    let momentum = Measure::<Kilogram>::new(2.) * Measure3d::<MetrePerSecond>::new([1., 2., 3.]);
+    assert_eq_64!(momentum.values, [2., 4., 6.]);
+    assert_eq!(format!("{}", Measure::<Kelvin>::new(3.)), "3 K");
+    assert_eq!(format!("{}", Measure::<Day>::new(3.)), "3 d");
+    assert_eq_64!(
+        Measure::<Degree>::new(180.)
+            .convert::<measures::angle::Radian>()
+            .value,
+        core::f64::consts::PI
+    );
+    is_power_quantity::<Power>();
+    is_root_power_quantity::<ElectricPotential>();
+    is_root_power_quantity::<ElectricCurrent>();
+    assert_eq_64!(
+        MeasurePoint::<Watt>::new(1.)
+            .convert::<DecibelMilliwatt>()
+            .value,
+        30.
+    );
+    assert_eq_64!(
+        MeasurePoint::<DecibelWatt>::new(30.)
+            .convert::<Kilowatt>()
+            .value,
+        1.
+    );
+}
+
+#[test]
+fn si_catalogue_extended() {
+    use si_units::*;
+    let data = Measure::<BitPerSecond>::new(8.) * Measure::<Second>::new(2.);
+    assert_eq_64!(data.convert::<Byte>().value, 2.);
+    assert_eq_64!(
+        Measure::<Furlong>::new(1.).convert::<Metre>().value,
+        201.168
+    );
+    assert_eq_64!(
+        Measure2d::<Furlong>::new([1., 2.])
+            .convert::<Metre>()
+            .values,
+        [201.168, 402.336]
+    );
+}
+
+#[cfg(feature = "catalog_imperial")]
+#[test]
+fn imperial_catalogue() {
+    use imperial_units::*;
+    assert_eq_64!(Measure::<Mile>::new(1.).convert::<Foot>().value, 5280.);
+    assert_eq_64!(Measure::<Gallon>::new(1.).convert::<Pint>().value, 8.);
+    assert_eq_64!(
+        Measure::<UsGallon>::new(1.).convert::<CubicInch>().value,
+        231.
+    );
+    assert_eq_64!(Measure::<Stone>::new(1.).convert::<Pound>().value, 14.);
+    assert_eq_64!(
+        MeasurePoint::<Fahrenheit>::new(212.)
+            .convert::<Celsius>()
+            .value,
+        100.
+    );
+    assert_eq_64!(
+        MeasurePoint::<Rankine>::new(491.67)
+            .convert::<Kelvin>()
+            .value,
+        273.15
+    );
+    let work = Measure2d::<PoundForce>::new([1., 0.]) * Measure2d::<Foot>::new([2., 5.]);
+    assert_eq_64!(work.convert::<Joule>().value, 2.7116358966628007);
+    let pressure = Measure::<PoundForce>::new(1.) / Measure::<SquareInch>::new(1.);
+    assert_eq_64!(pressure.convert::<Pascal>().value, 6894.757293168361);
+    let distance = Measure::<MilePerHour>::new(60.) * Measure::<Hour>::new(0.5);
+    assert_eq_64!(distance.value, 30.);
+    let wire = MeasurePoint::<AmericanWireGauge>::new(10.).convert::<SquareMillimetre>();
+    measures::assert_eq_tolerance!(wire.value, 5.261154954510372, 1e-12);
+    measures::assert_eq_tolerance!(wire.convert::<AmericanWireGauge>().value, 10., 1e-12);
+}
+
+#[cfg(feature = "catalog_cgs")]
+#[test]
+fn cgs_catalogue() {
+    use cgs_units::*;
+    let force =
+        Measure::<Gram>::new(2.) * Measure3d::<CentimetrePerSquareSecond>::new([1., 2., 3.]);
+    assert_eq_64!(force.values, [2., 4., 6.]);
+    let work = force * Measure3d::<Centimetre>::new([1., 1., 1.]);
+    assert_eq_64!(work.value, 12.);
+    assert_eq_64!(work.convert::<Joule>().value, 12e-7);
+    let force = Measure::<Barye>::new(5.) * Measure::<SquareCentimetre>::new(2.);
+    assert_eq_64!(force.convert::<Newton>().value, 1e-4);
+    assert_eq_64!(
+        Measure::<Poise>::new(1.).convert::<Centipoise>().value,
+        100.
+    );
+    let flux = Measure::<Gauss>::new(3.) * Measure::<SquareCentimetre>::new(2.);
+    assert_eq_64!(flux.convert::<Weber>().value, 6e-8);
+}
+
+#[cfg(feature = "catalog_astronomy")]
+#[test]
+fn astronomy_catalogue() {
+    use astronomy_units::*;
+    let distance = Measure::<LightSpeed>::new(1.) * Measure::<JulianYear>::new(2.);
+    assert_eq_64!(distance.value, 2.);
+    assert_eq_64!(distance.convert::<Metre>().value, 1.89214609451616e16);
+    let distance = Measure2d::<LightSpeed>::new([1., 0.]) * Measure::<JulianYear>::new(1.);
+    assert_eq_64!(distance.values, [1., 0.]);
+    assert_eq_64!(
+        Measure::<Parsec>::new(1.)
+            .convert::<AstronomicalUnit>()
+            .value,
+        648_000. / core::f64::consts::PI
+    );
+    assert_eq_64!(
+        Measure::<ArcSecond>::new(1.)
+            .convert::<Milliarcsecond>()
+            .value,
+        1000.
+    );
+    assert_eq_64!(
+        Measure::<SolarMass>::new(1.).convert::<EarthMass>().value,
+        332946.07832806994
+    );
+}
+
+#[cfg(feature = "catalog_electrical")]
+#[test]
+fn electrical_catalogue() {
+    use electrical_units::*;
+    let charge = Measure::<Ampere>::new(2.) * Measure::<Hour>::new(3.);
+    assert_eq_64!(charge.value, 6.);
+    assert_eq_64!(charge.convert::<Coulomb>().value, 21600.);
+    let charge = Measure::<Milliampere>::new(500.) * Measure::<Hour>::new(2.);
+    assert_eq_64!(charge.convert::<AmpereHour>().value, 1.);
+    let potential = Measure::<Kiloohm>::new(4.7) * Measure::<Milliampere>::new(2.);
+    assert_eq_64!(potential.value, 9.4);
+    let energy = Measure::<Kilowatt>::new(3.) * Measure::<Hour>::new(2.);
+    assert_eq_64!(energy.convert::<Megajoule>().value, 21.6);
+    assert_eq_64!(
+        Measure::<Nanofarad>::new(1.).convert::<Picofarad>().value,
+        1000.
+    );
+}
+
+#[cfg(all(
+    feature = "catalog_imperial",
+    feature = "catalog_cgs",
+    feature = "catalog_astronomy",
+    feature = "catalog_electrical"
+))]
+#[test]
+fn combined_catalogues() {
+    use all_units::*;
+    assert_eq_64!(
+        Measure::<Parsec>::new(1.).convert::<Mile>().value,
+        1.917351157671304e13
+    );
+    assert_eq_64!(
+        Measure::<Erg>::new(1e7).convert::<FootPound>().value,
+        0.7375621492772653
+    );
+    let velocity = Measure::<MetrePerCubicSecond>::new(2.) * Measure::<Second>::new(3.);
+    assert_eq_64!(velocity.value, 6.);
+    let approx = ApproxMeasure::<AmpereHour>::with_variance(2., 0.01);
+    assert_eq_64!(approx.convert::<Coulomb>().value, 7200.);
+}
+
+#[test]
+fn si_constants() {
+    use si_units::*;
+    let distance = SPEED_OF_LIGHT * Measure::<Second>::new(2.);
+    assert_eq_64!(distance.value, 599_584_916.);
+    assert_eq_64!(
+        PLANCK_CONSTANT.value,
+        REDUCED_PLANCK_CONSTANT.value * core::f64::consts::TAU
+    );
+    assert_eq_64!(ELEMENTARY_CHARGE.value, 1.602_176_634e-19);
+    let per_mole = BOLTZMANN_CONSTANT / Measure::<Mole>::new(1.);
+    assert_eq_64!(
+        (AVOGADRO_CONSTANT * Measure::<Mole>::new(1.)).value,
+        6.022_140_76e23
+    );
+    assert_eq_64!(
+        (MOLAR_GAS_CONSTANT * Measure::<Mole>::new(2.)).value,
+        per_mole.value * 6.022_140_76e23 * 2.
+    );
+    assert_eq_64!(STANDARD_GRAVITY.value, 9.806_65);
+    assert_eq_64!(STANDARD_ATMOSPHERE.convert::<Bar>().value, 1.013_25);
+
+    assert_eq_64!(GRAVITATIONAL_CONSTANT.value, 6.674_3e-11);
+    assert_eq_64!(GRAVITATIONAL_CONSTANT.uncertainty().value, 1.5e-15);
+    assert_eq_64!(ELECTRON_MASS.uncertainty().value, 2.8e-40);
+    assert_eq_64!(
+        PROTON_MASS.value / ELECTRON_MASS.value,
+        1_836.152_673_440_001_3
+    );
+    assert_eq_64!(NEUTRON_MASS.uncertainty().value, 9.5e-37);
+    assert_eq_64!(1. / FINE_STRUCTURE_CONSTANT.value, 137.035_999_083_695_8);
+
+    // The standard gravity at the surface of the Earth, from G, the mass and the radius of the Earth.
+    let earth_mass = ApproxMeasure::<Kilogram>::with_variance(5.972e24, 0.);
+    let mass_parameter = GRAVITATIONAL_CONSTANT * earth_mass;
+    assert_eq_64!(mass_parameter.value, 3.985_891_96e14);
+    let radius = Measure::<Metre>::new(6.371e6);
+    let gravity =
+        Measure::<CubicMetrePerSquareSecond>::new(mass_parameter.value) / (radius * radius);
+    assert_eq_64!(gravity.value, 9.819_973_426_224_687);
+    assert_eq_64!(mass_parameter.uncertainty().value, 1.5e-15 * 5.972e24);
+}
+
+#[cfg(feature = "catalog_electrical")]
+#[test]
+fn electrical_constants() {
+    use electrical_units::*;
+    assert_eq_64!(MAGNETIC_FLUX_QUANTUM.value, 2.067_833_848_461_929e-15);
+    assert_eq_64!(CONDUCTANCE_QUANTUM.value, 7.748_091_729_863_649e-5);
+    assert_eq_64!(VON_KLITZING_CONSTANT.value, 25_812.807_459_304_51);
+    assert_eq_64!(
+        (CONDUCTANCE_QUANTUM.value * VON_KLITZING_CONSTANT.value),
+        2.
+    );
+}
+
+#[cfg(feature = "catalog_astronomy")]
+#[test]
+fn astronomy_constants() {
+    use astronomy_units::*;
+    assert_eq_64!(
+        NOMINAL_SOLAR_MASS_PARAMETER.value
+            / Measure::<SolarMass>::new(1.).convert::<Kilogram>().value,
+        6.674_3e-11
+    );
+    assert_eq_64!(
+        NOMINAL_TERRESTRIAL_MASS_PARAMETER.value
+            / Measure::<EarthMass>::new(1.).convert::<Kilogram>().value,
+        6.674_3e-11
+    );
+    assert_eq_64!(
+        NOMINAL_JOVIAN_MASS_PARAMETER.value
+            / Measure::<JupiterMass>::new(1.).convert::<Kilogram>().value,
+        6.674_3e-11
+    );
+}
+
+#[cfg(all(
+    feature = "catalog_imperial",
+    feature = "catalog_cgs",
+    feature = "catalog_astronomy",
+    feature = "catalog_electrical"
+))]
+#[test]
+fn combined_constants() {
+    use all_units::*;
+    let permittivity = VACUUM_ELECTRIC_PERMITTIVITY.value;
+    let permeability = VACUUM_MAGNETIC_PERMEABILITY.value;
+    measures::assert_eq_tolerance!(
+        1. / (permittivity * permeability).sqrt(),
+        SPEED_OF_LIGHT.value,
+        1e-12
+    );
+    assert_eq_64!(VACUUM_ELECTRIC_PERMITTIVITY.uncertainty().value, 1.3e-21);
+}
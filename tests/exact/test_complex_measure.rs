@@ -0,0 +1,167 @@
+use measures::{assert_eq_32, assert_eq_64, dimensionless::One};
+use units::{
+    Ampere, ComplexMeasure, Degree, Measure, Milliohm, Ohm, SignedDirection, SquareAmpere, Volt,
+};
+
+mod units {
+    measures::define_measure_types! {
+        with_directions exact with_complex,
+        scalar_properties [
+            ElectricPotential [
+                Volt {
+                    suffix: " V",
+                }
+            ]
+            ElectricCurrent [
+                Ampere {
+                    suffix: " A",
+                }
+            ]
+            SquareElectricCurrent [
+                SquareAmpere {
+                    suffix: " A\u{b2}",
+                }
+            ]
+            ElectricalImpedance [
+                Ohm {
+                    suffix: " \u{3a9}",
+                }
+                Milliohm {
+                    suffix: " m\u{3a9}",
+                    ratio: 1e-3,
+                }
+            ]
+        ]
+        angle_measurement_units [
+            Degree {
+                suffix: " deg",
+                cycle_fraction: 360.,
+            }
+        ]
+        relationships [
+            Volt 1 == Ohm 1 * Ampere 1,
+            SquareAmpere 1 == Ampere 1 * __ 1,
+        ]
+    }
+}
+
+#[test]
+fn complex_measure_basics() {
+    let z = ComplexMeasure::<Ohm>::new(3., -4.);
+    assert_eq!(z.re, 3.);
+    assert_eq!(z.im, -4.);
+    assert_eq!(z.real(), Measure::<Ohm>::new(3.));
+    assert_eq!(z.imaginary(), Measure::<Ohm>::new(-4.));
+    assert_eq!(
+        ComplexMeasure::from_parts(Measure::<Ohm>::new(3.), Measure::new(-4.)),
+        z
+    );
+    assert_eq!(
+        ComplexMeasure::from(Measure::<Ohm>::new(3.)),
+        ComplexMeasure::new(3., 0.)
+    );
+    assert_eq!(
+        ComplexMeasure::<Ohm>::default(),
+        ComplexMeasure::new(0., 0.)
+    );
+    assert_eq!(z.conjugate(), ComplexMeasure::new(3., 4.));
+    assert_eq_64!(z.squared_magnitude(), 25.);
+    assert_eq_64!(z.magnitude().value, 5.);
+    let z2 = z.convert::<Milliohm>();
+    assert_eq_64!(z2.re, 3000.);
+    assert_eq_64!(z2.im, -4000.);
+    let z3 = z.lossy_into::<f32>();
+    assert_eq_32!(z3.re, 3.);
+    assert_eq_32!(z3.im, -4.);
+    let z4: ComplexMeasure<Ohm> = z3.into();
+    assert_eq!(z4, z);
+}
+
+#[test]
+fn complex_measure_polar() {
+    let z = ComplexMeasure::<Ohm>::new(1., 1.);
+    assert_eq_64!(z.phase().value, core::f64::consts::FRAC_PI_4);
+    assert_eq_64!(z.phase().convert::<Degree>().value, 45.);
+    let z2 =
+        ComplexMeasure::<Volt>::from_polar(Measure::new(2.), SignedDirection::<Degree>::new(-90.));
+    assert_eq_64!(z2.re, 0.);
+    assert_eq_64!(z2.im, -2.);
+    assert_eq_64!(z2.phase().convert::<Degree>().value, -90.);
+}
+
+#[test]
+fn complex_measure_arithmetic() {
+    let z1 = ComplexMeasure::<Ohm>::new(1., 2.);
+    let z2 = ComplexMeasure::<Ohm>::new(3., -1.);
+    assert_eq!(z1 + z2, ComplexMeasure::new(4., 1.));
+    assert_eq!(z1 - z2, ComplexMeasure::new(-2., 3.));
+    assert_eq!(-z1, ComplexMeasure::new(-1., -2.));
+    assert_eq!(z1 * 2., ComplexMeasure::new(2., 4.));
+    assert_eq!(2. * z1, ComplexMeasure::new(2., 4.));
+    assert_eq!(z1 / 2., ComplexMeasure::new(0.5, 1.));
+    let ratio: ComplexMeasure<One> = z1 / z2;
+    assert_eq_64!(ratio.re, 0.1);
+    assert_eq_64!(ratio.im, 0.7);
+    assert_eq!(
+        z2 * ComplexMeasure::<One>::new(0., 1.),
+        ComplexMeasure::new(1., 3.)
+    );
+    let mut z3 = z1;
+    z3 += z2;
+    z3 -= z1;
+    z3 *= 4.;
+    z3 /= 2.;
+    assert_eq!(z3, z2 * 2.);
+}
+
+#[test]
+fn complex_measure_relationships() {
+    // Current through a resistor of 3 Ω and an inductor having a reactance of 4 Ω, in series.
+    let impedance = ComplexMeasure::<Ohm>::new(3., 4.);
+    let voltage = ComplexMeasure::<Volt>::new(10., 0.);
+    let current: ComplexMeasure<Ampere> = voltage / impedance;
+    assert_eq_64!(current.re, 1.2);
+    assert_eq_64!(current.im, -1.6);
+    assert_eq_64!(current.magnitude().value, 2.);
+    let voltage2: ComplexMeasure<Volt> = impedance * current;
+    assert_eq_64!(voltage2.re, 10.);
+    assert_eq_64!(voltage2.im, 0.);
+    let voltage3: ComplexMeasure<Volt> = current * impedance;
+    assert_eq_64!(voltage3.re, 10.);
+    let impedance2: ComplexMeasure<Ohm> = voltage / current;
+    assert_eq_64!(impedance2.re, 3.);
+    assert_eq_64!(impedance2.im, 4.);
+
+    let squared_current: ComplexMeasure<SquareAmpere> = current * current;
+    assert_eq!(current.squared(), squared_current);
+    let current2: ComplexMeasure<Ampere> = squared_current / current;
+    assert_eq_64!(current2.re, 1.2);
+    assert_eq_64!(current2.im, -1.6);
+}
+
+#[test]
+fn complex_measure_formatting() {
+    let z = ComplexMeasure::<Ohm>::new(3., -4.);
+    assert_eq!(z.to_string(), "(3 - 4i) \u{3a9}");
+    assert_eq!(format!("{:?}", z), "(3 - 4i) \u{3a9}");
+    assert_eq!(
+        format!("{:.1}", ComplexMeasure::<Volt>::new(1.25, 0.5)),
+        "(1.2 + 0.5i) V"
+    );
+    assert_eq!(format!("{:e}", z), "(3e0 - 4e0i) \u{3a9}");
+    assert_eq!(format!("{:E}", z), "(3E0 - 4E0i) \u{3a9}");
+    assert_eq!(
+        format!(
+            "{:.2}",
+            ComplexMeasure::<Volt>::new(0., 5.).polar_formatter::<Degree>()
+        ),
+        "5.00 V \u{2220} 90.00 deg"
+    );
+    assert_eq!(
+        format!(
+            "{:?}",
+            ComplexMeasure::<Volt>::new(-2., 0.).polar_formatter::<Degree>()
+        ),
+        "2 V \u{2220} 180 deg"
+    );
+}
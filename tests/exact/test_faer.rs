@@ -0,0 +1,88 @@
+use faer::{col, mat, Col, ColRef, Mat, MatRef};
+use measures::faer::{
+    inner_product, matrix_product, matrix_vector_product, peel_col_ref, peel_mat_mut, peel_mat_ref,
+    wrap_col_ref, wrap_mat_mut, wrap_mat_ref,
+};
+use units::{Joule, Measure, Metre, Newton, Second};
+
+mod units {
+    measures::define_measure_types! {
+        exact,
+        scalar_properties [
+            Time [
+                Second {
+                    suffix: " s",
+                }
+            ]
+            Length [
+                Metre {
+                    suffix: " m",
+                }
+            ]
+            Force [
+                Newton {
+                    suffix: " N",
+                }
+            ]
+            Energy [
+                Joule {
+                    suffix: " J",
+                }
+            ]
+        ]
+        relationships [
+            Joule 1 == Newton 1 * Metre 1,
+        ]
+    }
+}
+
+#[test]
+fn faer_views() {
+    let mut numbers = mat![[1., 2.], [3., 4.]];
+    {
+        let times: MatRef<Measure<Second>> = wrap_mat_ref(numbers.as_ref());
+        assert_eq!(times[(1, 0)], Measure::new(3.));
+        assert_eq!(times.transpose()[(1, 0)], Measure::new(2.));
+        assert_eq!(peel_mat_ref(times), numbers.as_ref());
+    }
+    let mut times = wrap_mat_mut::<Measure<Second>, _>(numbers.as_mut());
+    times[(0, 1)] += Measure::new(5.);
+    assert_eq!(numbers, mat![[1., 7.], [3., 4.]]);
+
+    let mut lengths = Mat::from_fn(2, 1, |i, _| Measure::<Metre>::new(i as f64 + 0.5));
+    peel_mat_mut(lengths.as_mut())[(1, 0)] = 4.;
+    assert_eq!(lengths[(1, 0)], Measure::new(4.));
+
+    let numbers = col![1., 2.5];
+    let lengths: ColRef<Measure<Metre>> = wrap_col_ref(numbers.as_ref());
+    assert_eq!(lengths[1], Measure::new(2.5));
+    assert_eq!(peel_col_ref(lengths), numbers.as_ref());
+}
+
+#[test]
+fn faer_products() {
+    let forces = Mat::from_fn(3, 2, |i, j| Measure::<Newton>::new((i * 2 + j + 1) as f64));
+    let displacements = Col::from_fn(2, |i| Measure::<Metre>::new([10., -1.][i]));
+
+    let works: Col<Measure<Joule>> = matrix_vector_product(forces.as_ref(), displacements.as_ref());
+    assert_eq!(peel_col_ref(works.as_ref()), col![8., 26., 44.].as_ref());
+
+    let paths = Mat::from_fn(2, 2, |i, j| {
+        Measure::<Metre>::new([[1., 0.], [0., 2.]][i][j])
+    });
+    let works: Mat<Measure<Joule>> = matrix_product(forces.as_ref(), paths.as_ref());
+    assert_eq!(
+        peel_mat_ref(works.as_ref()),
+        mat![[1., 4.], [3., 8.], [5., 12.]].as_ref()
+    );
+
+    // The strides of a transposed view are honored.
+    let works: Mat<Measure<Joule>> = matrix_product(paths.transpose(), forces.transpose());
+    assert_eq!(
+        peel_mat_ref(works.as_ref()),
+        mat![[1., 3., 5.], [4., 8., 12.]].as_ref()
+    );
+
+    let work: Measure<Joule> = inner_product(forces.row(2).transpose(), displacements.as_ref());
+    assert_eq!(work, Measure::new(44.));
+}
@@ -0,0 +1,208 @@
+use measures::{assert_eq_64, assert_eq_tolerance};
+use units::{
+    euler_step, runge_kutta_45_integrate, runge_kutta_45_step, runge_kutta_4_step,
+    velocity_verlet_step, Hertz, Measure, Measure3d, MeasurePoint, MeasurePoint3d, Metre,
+    MetrePerSecond, MetrePerSquareSecond, Second,
+};
+
+mod units {
+    measures::define_measure_types! {
+        with_points with_3d exact,
+        scalar_properties [
+            Time [
+                Second {
+                    suffix: " s",
+                }
+            ]
+            Frequency [
+                Hertz {
+                    suffix: " Hz",
+                }
+            ]
+        ]
+        vector_properties [
+            Length [
+                Metre {
+                    suffix: " m",
+                }
+            ]
+            Velocity [
+                MetrePerSecond {
+                    suffix: " m/s",
+                }
+            ]
+            Acceleration [
+                MetrePerSquareSecond {
+                    suffix: " m/s\u{b2}",
+                }
+            ]
+        ]
+        relationships [
+            Metre 1 == MetrePerSecond 1 * Second 1,
+            Metre 3 == MetrePerSecond 3 * Second 1,
+            MetrePerSecond 1 == MetrePerSquareSecond 1 * Second 1,
+            MetrePerSecond 3 == MetrePerSquareSecond 3 * Second 1,
+            MetrePerSecond 1 == Metre 1 * Hertz 1,
+        ]
+    }
+}
+
+// Exponential decay of a length: dx/dt = -x * 1 Hz.
+fn decay(_t: MeasurePoint<Second>, x: Measure<Metre>) -> Measure<MetrePerSecond> {
+    -x * Measure::<Hertz>::new(1.)
+}
+
+#[test]
+fn euler_step_1d() {
+    let x = euler_step(
+        decay,
+        MeasurePoint::new(0.),
+        Measure::<Metre>::new(8.),
+        Measure::<Second>::new(0.25),
+    );
+    assert_eq_64!(x.value, 6.);
+}
+
+#[test]
+fn euler_step_3d() {
+    let position = euler_step(
+        |_, _| Measure3d::<MetrePerSecond>::new([1., -2., 3.]),
+        MeasurePoint::<Second>::new(0.),
+        MeasurePoint3d::<Metre>::new([10., 20., 30.]),
+        Measure::<Second>::new(2.),
+    );
+    assert_eq_64!(position.values, [12., 16., 36.]);
+}
+
+#[test]
+fn runge_kutta_4_step_1d() {
+    let mut time = MeasurePoint::<Second>::new(0.);
+    let mut x = Measure::<Metre>::new(1.);
+    let step = Measure::<Second>::new(0.1);
+    for _ in 0..10 {
+        x = runge_kutta_4_step(decay, time, x, step);
+        time += step;
+    }
+    assert_eq_tolerance!(x.value, (-1_f64).exp(), 1e-6);
+}
+
+#[test]
+fn runge_kutta_4_step_tuple() {
+    // Free fall, with state (position, velocity) and derivative (velocity, acceleration).
+    let gravity = Measure3d::<MetrePerSquareSecond>::new([0., 0., -10.]);
+    let mut time = MeasurePoint::<Second>::new(0.);
+    let mut state = (
+        MeasurePoint3d::<Metre>::new([0., 0., 100.]),
+        Measure3d::<MetrePerSecond>::new([3., 0., 0.]),
+    );
+    let step = Measure::<Second>::new(0.5);
+    for _ in 0..4 {
+        state = runge_kutta_4_step(|_, (_, velocity)| (velocity, gravity), time, state, step);
+        time += step;
+    }
+    assert_eq_64!(state.0.values, [6., 0., 80.]);
+    assert_eq_64!(state.1.values, [3., 0., -20.]);
+}
+
+#[test]
+fn velocity_verlet_step_1d() {
+    // Harmonic oscillator with angular frequency 1 rad/s: a = -x / s².
+    let acceleration = |x: MeasurePoint<Metre>| -Measure::<MetrePerSquareSecond>::new(x.value);
+    let mut position = MeasurePoint::<Metre>::new(1.);
+    let mut velocity = Measure::<MetrePerSecond>::new(0.);
+    let step = Measure::<Second>::new(0.001);
+    for _ in 0..(core::f64::consts::PI * 1000.).round() as usize {
+        (position, velocity) = velocity_verlet_step(acceleration, position, velocity, step);
+    }
+    // After half a period, the position is reversed.
+    assert_eq_tolerance!(position.value, -1., 1e-6);
+    assert_eq_tolerance!(velocity.value, 0., 1e-3);
+}
+
+#[test]
+fn runge_kutta_45_step_1d() {
+    let (taken_step, x, next_step) = runge_kutta_45_step(
+        decay,
+        MeasurePoint::new(0.),
+        Measure::<Metre>::new(1.),
+        Measure::<Second>::new(1.),
+        Measure::<Metre>::new(1e-9),
+    )
+    .unwrap();
+    assert!(taken_step.value < 1.);
+    assert!(next_step.value > 0.);
+    assert_eq_tolerance!(x.value, (-taken_step.value).exp(), 1e-9);
+}
+
+#[test]
+fn runge_kutta_45_integrate_tuple() {
+    let gravity = Measure3d::<MetrePerSquareSecond>::new([0., 0., -10.]);
+    let state = runge_kutta_45_integrate(
+        |_, (_, velocity)| (velocity, gravity),
+        MeasurePoint::<Second>::new(0.),
+        (
+            MeasurePoint3d::<Metre>::new([0., 0., 100.]),
+            Measure3d::<MetrePerSecond>::new([3., 0., 0.]),
+        ),
+        MeasurePoint::<Second>::new(2.),
+        Measure::<Second>::new(0.1),
+        (
+            Measure::<Metre>::new(1e-9),
+            Measure::<MetrePerSecond>::new(1e-9),
+        ),
+    )
+    .unwrap();
+    assert_eq_64!(state.0.values, [6., 0., 80.]);
+    assert_eq_64!(state.1.values, [3., 0., -20.]);
+}
+
+#[test]
+fn runge_kutta_45_integrate_1d() {
+    let x = runge_kutta_45_integrate(
+        decay,
+        MeasurePoint::new(0.),
+        Measure::<Metre>::new(1.),
+        MeasurePoint::new(5.),
+        Measure::<Second>::new(0.1),
+        Measure::<Metre>::new(1e-10),
+    )
+    .unwrap();
+    assert_eq_tolerance!(x.value, (-5_f64).exp(), 1e-8);
+}
+
+#[test]
+fn runge_kutta_45_failures() {
+    // With a zero tolerance, the step is reduced until it becomes negligible.
+    assert!(runge_kutta_45_integrate(
+        decay,
+        MeasurePoint::new(0.),
+        Measure::<Metre>::new(1.),
+        MeasurePoint::new(5.),
+        Measure::<Second>::new(0.1),
+        Measure::<Metre>::new(0.),
+    )
+    .is_none());
+
+    // A derivative returning NaN makes the error ratio NaN.
+    assert!(runge_kutta_45_step(
+        |_, _| Measure::<MetrePerSecond>::new(f64::NAN),
+        MeasurePoint::<Second>::new(0.),
+        Measure::<Metre>::new(1.),
+        Measure::<Second>::new(1.),
+        Measure::<Metre>::new(1e-9),
+    )
+    .is_none());
+}
+
+#[test]
+#[should_panic(expected = "must be positive")]
+fn runge_kutta_45_integrate_zero_step() {
+    let _ = runge_kutta_45_integrate(
+        decay,
+        MeasurePoint::new(0.),
+        Measure::<Metre>::new(1.),
+        MeasurePoint::new(5.),
+        Measure::<Second>::new(0.),
+        Measure::<Metre>::new(1e-10),
+    );
+}
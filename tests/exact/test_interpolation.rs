@@ -0,0 +1,147 @@
+use measures::assert_eq_64;
+use units::{
+    CubicSplineInterpolator, LinearInterpolator, Measure, Measure3d, MeasurePoint, MeasurePoint3d,
+    Metre, MetrePerSecond, MetrePerSquareSecond, Second,
+};
+
+mod units {
+    measures::define_measure_types! {
+        with_points with_3d exact,
+        scalar_properties [
+            Time [
+                Second {
+                    suffix: " s",
+                }
+            ]
+        ]
+        vector_properties [
+            Length [
+                Metre {
+                    suffix: " m",
+                }
+            ]
+            Velocity [
+                MetrePerSecond {
+                    suffix: " m/s",
+                }
+            ]
+            Acceleration [
+                MetrePerSquareSecond {
+                    suffix: " m/s\u{b2}",
+                }
+            ]
+        ]
+        relationships [
+            Metre 1 == MetrePerSecond 1 * Second 1,
+            Metre 3 == MetrePerSecond 3 * Second 1,
+            MetrePerSecond 1 == MetrePerSquareSecond 1 * Second 1,
+            MetrePerSecond 3 == MetrePerSquareSecond 3 * Second 1,
+        ]
+    }
+}
+
+fn instants(values: &[f64]) -> Vec<MeasurePoint<Second>> {
+    values
+        .iter()
+        .map(|&v| MeasurePoint::<Second>::new(v))
+        .collect()
+}
+
+#[test]
+fn linear_interpolator_1d() {
+    let positions = [0., 10., 40.].map(MeasurePoint::<Metre>::new);
+    let interpolator = LinearInterpolator::new(&instants(&[0., 2., 4.]), &positions);
+    assert_eq!(interpolator.args().len(), 3);
+    assert_eq!(interpolator.values().len(), 3);
+    assert_eq_64!(interpolator.value_at(MeasurePoint::new(0.)).value, 0.);
+    assert_eq_64!(interpolator.value_at(MeasurePoint::new(1.)).value, 5.);
+    assert_eq_64!(interpolator.value_at(MeasurePoint::new(2.)).value, 10.);
+    assert_eq_64!(interpolator.value_at(MeasurePoint::new(3.5)).value, 32.5);
+    assert_eq_64!(interpolator.value_at(MeasurePoint::new(4.)).value, 40.);
+
+    // Extrapolation
+    assert_eq_64!(interpolator.value_at(MeasurePoint::new(-1.)).value, -5.);
+    assert_eq_64!(interpolator.value_at(MeasurePoint::new(5.)).value, 55.);
+
+    let v: Measure<MetrePerSecond> = interpolator.derivative_at(MeasurePoint::new(1.));
+    assert_eq_64!(v.value, 5.);
+    let v: Measure<MetrePerSecond> = interpolator.derivative_at(MeasurePoint::new(2.));
+    assert_eq_64!(v.value, 15.);
+}
+
+#[test]
+fn linear_interpolator_of_measures() {
+    let displacements = [0., 10., 40.].map(Measure::<Metre>::new);
+    let interpolator = LinearInterpolator::new(&instants(&[0., 2., 4.]), &displacements);
+    assert_eq_64!(interpolator.value_at(MeasurePoint::new(3.)).value, 25.);
+    let v: Measure<MetrePerSecond> = interpolator.derivative_at(MeasurePoint::new(3.));
+    assert_eq_64!(v.value, 15.);
+}
+
+#[test]
+fn linear_interpolator_3d() {
+    let positions = [
+        MeasurePoint3d::<Metre>::new([0., 0., 0.]),
+        MeasurePoint3d::<Metre>::new([10., -20., 4.]),
+    ];
+    let interpolator = LinearInterpolator::new(&instants(&[10., 12.]), &positions);
+    let p = interpolator.value_at(MeasurePoint::new(11.5));
+    assert_eq_64!(p.values, [7.5, -15., 3.]);
+    let v: Measure3d<MetrePerSecond> = interpolator.derivative_at(MeasurePoint::new(11.));
+    assert_eq_64!(v.values, [5., -10., 2.]);
+}
+
+#[test]
+#[should_panic(expected = "strictly increasing")]
+fn linear_interpolator_unordered() {
+    let positions = [0., 10.].map(MeasurePoint::<Metre>::new);
+    LinearInterpolator::new(&instants(&[2., 2.]), &positions);
+}
+
+#[test]
+#[should_panic(expected = "at least two knots")]
+fn cubic_spline_interpolator_too_few_knots() {
+    let positions = [0.].map(MeasurePoint::<Metre>::new);
+    CubicSplineInterpolator::new(&instants(&[2.]), &positions);
+}
+
+#[test]
+fn cubic_spline_interpolator_1d() {
+    let positions = [0., 1., 0.].map(MeasurePoint::<Metre>::new);
+    let interpolator = CubicSplineInterpolator::new(&instants(&[0., 1., 2.]), &positions);
+    assert_eq!(interpolator.args().len(), 3);
+    assert_eq!(interpolator.values().len(), 3);
+    assert_eq_64!(interpolator.value_at(MeasurePoint::new(0.)).value, 0.);
+    assert_eq_64!(interpolator.value_at(MeasurePoint::new(0.5)).value, 0.6875);
+    assert_eq_64!(interpolator.value_at(MeasurePoint::new(1.)).value, 1.);
+    assert_eq_64!(interpolator.value_at(MeasurePoint::new(1.5)).value, 0.6875);
+    assert_eq_64!(interpolator.value_at(MeasurePoint::new(2.)).value, 0.);
+
+    let v: Measure<MetrePerSecond> = interpolator.derivative_at(MeasurePoint::new(0.));
+    assert_eq_64!(v.value, 1.5);
+    let v: Measure<MetrePerSecond> = interpolator.derivative_at(MeasurePoint::new(1.));
+    assert_eq_64!(v.value, 0.);
+    let v: Measure<MetrePerSecond> = interpolator.derivative_at(MeasurePoint::new(2.));
+    assert_eq_64!(v.value, -1.5);
+
+    let a: Measure<MetrePerSquareSecond> =
+        interpolator.second_derivative_at::<Measure<MetrePerSecond>, _>(MeasurePoint::new(0.));
+    assert_eq_64!(a.value, 0.);
+    let a: Measure<MetrePerSquareSecond> =
+        interpolator.second_derivative_at::<Measure<MetrePerSecond>, _>(MeasurePoint::new(1.));
+    assert_eq_64!(a.value, -3.);
+}
+
+#[test]
+fn cubic_spline_interpolator_3d() {
+    // Uniform rectilinear motion is reproduced exactly.
+    let positions = [0., 1., 3., 6.].map(|t| MeasurePoint3d::<Metre>::new([2. * t, -t, 5.]));
+    let interpolator = CubicSplineInterpolator::new(&instants(&[0., 1., 3., 6.]), &positions);
+    let p = interpolator.value_at(MeasurePoint::new(2.));
+    assert_eq_64!(p.values, [4., -2., 5.]);
+    let v: Measure3d<MetrePerSecond> = interpolator.derivative_at(MeasurePoint::new(4.5));
+    assert_eq_64!(v.values, [2., -1., 0.]);
+    let a: Measure3d<MetrePerSquareSecond> =
+        interpolator.second_derivative_at::<Measure3d<MetrePerSecond>, _>(MeasurePoint::new(4.5));
+    assert_eq_64!(a.values, [0., 0., 0.]);
+}
@@ -0,0 +1,215 @@
+use measures::{assert_eq_64, assert_eq_tolerance};
+use units::{
+    midpoint_n, runge_kutta_4_step, weighted_midpoint_n, Joule, LinearMap3d, LinearMapN, Measure,
+    Measure2d, Measure3d, MeasureN, MeasurePoint, MeasurePoint2d, MeasurePoint3d, MeasurePointN,
+    Metre, MetrePerSecond, Millimetre, Newton, Second,
+};
+
+mod units {
+    measures::define_measure_types! {
+        with_points with_2d with_3d with_transformations exact with_nd,
+        scalar_properties [
+            Time [
+                Second {
+                    suffix: " s",
+                }
+            ]
+            Energy [
+                Joule {
+                    suffix: " J",
+                }
+            ]
+        ]
+        vector_properties [
+            Length [
+                Metre {
+                    suffix: " m",
+                }
+                Millimetre {
+                    suffix: " mm",
+                    ratio: 1e-3,
+                }
+            ]
+            Velocity [
+                MetrePerSecond {
+                    suffix: " m/s",
+                }
+            ]
+            Force [
+                Newton {
+                    suffix: " N",
+                }
+            ]
+        ]
+        relationships [
+            Metre 1 == MetrePerSecond 1 * Second 1,
+            Joule 1 == Newton 1 * Metre 1,
+        ]
+    }
+}
+
+#[test]
+fn measure_n_basics() {
+    let m = MeasureN::<Metre, 6>::new([1., 2., 3., 4., 5., 6.]);
+    assert_eq!(m.component(4), Measure::<Metre>::new(5.));
+    assert_eq!(MeasureN::<Metre, 6>::default().values, [0.; 6]);
+    assert_eq_64!(m.squared_norm(), 91.);
+    assert_eq_64!(m.norm().value, 91_f64.sqrt());
+    assert_eq_64!(m.normalized().norm().value, 1.);
+    assert_eq!(
+        m.convert::<Millimetre>().values,
+        [1000., 2000., 3000., 4000., 5000., 6000.]
+    );
+    assert_eq!(m.lossy_into::<f32>().values, [1., 2., 3., 4., 5., 6.]);
+    assert_eq!(
+        MeasureN::from([Measure::<Metre>::new(7.), Measure::new(8.)]).values,
+        [7., 8.]
+    );
+    assert_eq!(m.to_string(), "(1, 2, 3, 4, 5, 6) m");
+    assert_eq!(format!("{:?}", m), "(1, 2, 3, 4, 5, 6) m");
+    assert_eq!(
+        format!("{:.1e}", MeasureN::<Metre, 2>::new([1200., -3.])),
+        "(1.2e3, -3.0e0) m"
+    );
+}
+
+#[test]
+fn measure_n_arithmetic() {
+    let m1 = MeasureN::<Metre, 4>::new([1., 2., 3., 4.]);
+    let m2 = MeasureN::<Metre, 4>::new([10., 20., 30., 40.]);
+    assert_eq!((m1 + m2).values, [11., 22., 33., 44.]);
+    assert_eq!((m2 - m1).values, [9., 18., 27., 36.]);
+    assert_eq!((-m1).values, [-1., -2., -3., -4.]);
+    assert_eq!((m1 * 2.).values, [2., 4., 6., 8.]);
+    assert_eq!((2. * m1).values, [2., 4., 6., 8.]);
+    assert_eq!((m2 / 10.).values, m1.values);
+    let mut m3 = m1;
+    m3 += m2;
+    m3 -= m1;
+    m3 *= 3.;
+    m3 /= 30.;
+    assert_eq!(m3, m1);
+}
+
+#[test]
+fn measure_n_products_from_relationships() {
+    let velocity = MeasureN::<MetrePerSecond, 5>::new([1., 2., 3., 4., 5.]);
+    let time = Measure::<Second>::new(2.);
+    let displacement: MeasureN<Metre, 5> = velocity * time;
+    assert_eq!(displacement.values, [2., 4., 6., 8., 10.]);
+    let displacement: MeasureN<Metre, 5> = time * velocity;
+    assert_eq!(displacement.values, [2., 4., 6., 8., 10.]);
+    let velocity2: MeasureN<MetrePerSecond, 5> = displacement / time;
+    assert_eq!(velocity2, velocity);
+
+    // Dot product.
+    let force = MeasureN::<Newton, 4>::new([1., 0., -2., 3.]);
+    let path = MeasureN::<Metre, 4>::new([4., 5., 6., 7.]);
+    let work: Measure<Joule> = force * path;
+    assert_eq_64!(work.value, 13.);
+}
+
+#[test]
+fn measure_n_conversions() {
+    let m: MeasureN<Metre, 1> = Measure::<Metre>::new(3.).into();
+    assert_eq!(m.values, [3.]);
+    assert_eq!(Measure::from(m).value, 3.);
+    let m: MeasureN<Metre, 2> = Measure2d::<Metre>::new([3., 4.]).into();
+    assert_eq!(m.values, [3., 4.]);
+    assert_eq!(Measure2d::from(m).values, [3., 4.]);
+    let m: MeasureN<Metre, 3> = Measure3d::<Metre>::new([3., 4., 5.]).into();
+    assert_eq!(m.values, [3., 4., 5.]);
+    assert_eq!(Measure3d::from(m).values, [3., 4., 5.]);
+    let m: MeasureN<Metre, 3> = MeasureN::<Metre, 3, f32>::new([3., 4., 5.]).into();
+    assert_eq!(m.values, [3., 4., 5.]);
+
+    let p: MeasurePointN<Metre, 1> = MeasurePoint::<Metre>::new(3.).into();
+    assert_eq!(MeasurePoint::from(p).value, 3.);
+    let p: MeasurePointN<Metre, 2> = MeasurePoint2d::<Metre>::new([3., 4.]).into();
+    assert_eq!(MeasurePoint2d::from(p).values, [3., 4.]);
+    let p: MeasurePointN<Metre, 3> = MeasurePoint3d::<Metre>::new([3., 4., 5.]).into();
+    assert_eq!(MeasurePoint3d::from(p).values, [3., 4., 5.]);
+}
+
+#[test]
+fn measure_point_n_arithmetic() {
+    let p1 = MeasurePointN::<Metre, 6>::new([1., 2., 3., 4., 5., 6.]);
+    let m = MeasureN::<Metre, 6>::new([1., 1., 1., -1., -1., -1.]);
+    let p2 = p1 + m;
+    assert_eq!(p2.values, [2., 3., 4., 3., 4., 5.]);
+    assert_eq!(p2 - m, p1);
+    assert_eq!(p2 - p1, m);
+    let mut p3 = p1;
+    p3 += m;
+    assert_eq!(p3, p2);
+    p3 -= m;
+    assert_eq!(p3, p1);
+    assert_eq!(p1.component(5), MeasurePoint::<Metre>::new(6.));
+    assert_eq!(midpoint_n(p1, p2).values, [1.5, 2.5, 3.5, 3.5, 4.5, 5.5]);
+    assert_eq!(
+        weighted_midpoint_n(p1, p2, 0.75).values,
+        [1.25, 2.25, 3.25, 3.75, 4.75, 5.75]
+    );
+    assert_eq!(p1.to_string(), "at (1, 2, 3, 4, 5, 6) m");
+}
+
+#[test]
+fn linear_map_n_operations() {
+    let lm = LinearMapN::<4>::new([
+        [2., 0., 0., 1.],
+        [0., 1., 3., 0.],
+        [0., 0., 1., 0.],
+        [1., 0., 0., 1.],
+    ]);
+    let m = MeasureN::<Metre, 4>::new([1., 2., 3., 4.]);
+    assert_eq!(lm.apply_to(m).values, [6., 11., 3., 5.]);
+    assert_eq!(LinearMapN::<4>::default().apply_to(m), m);
+    assert_eq!(
+        LinearMapN::scaling([1., 2., 3., 4.]).apply_to(m).values,
+        [1., 4., 9., 16.]
+    );
+    assert_eq!(lm.transposed().c[0][3], 1.);
+    assert_eq!(lm.transposed().c[1][2], 0.);
+    assert_eq!(lm.transposed().c[2][1], 3.);
+
+    let inverse = lm.inverted();
+    let identity = lm.combined_with(&inverse);
+    for row in 0..4 {
+        for column in 0..4 {
+            assert_eq_tolerance!(
+                identity.c[row][column],
+                if row == column { 1. } else { 0. },
+                1e-12
+            );
+        }
+    }
+    let m2 = inverse.apply_to(lm.apply_to(m));
+    for (&actual, expected) in m2.values.iter().zip(m.values) {
+        assert_eq_64!(actual, expected);
+    }
+
+    // `combined_with` applies first the argument.
+    let scaling = LinearMapN::scaling([1., 1., 1., 10.]);
+    assert_eq!(
+        lm.combined_with(&scaling).apply_to(m),
+        scaling.apply_to(lm.apply_to(m))
+    );
+
+    let lm3: LinearMapN<3> = LinearMap3d::scaling([1., 2., 3.]).into();
+    assert_eq!(lm3.c, [[1., 0., 0.], [0., 2., 0.], [0., 0., 3.]]);
+}
+
+#[test]
+fn measure_n_integration() {
+    // A 6-dimensional state, moving at constant velocity.
+    let velocity = MeasureN::<MetrePerSecond, 6>::new([1., 2., 3., 4., 5., 6.]);
+    let state = runge_kutta_4_step(
+        |_, _| velocity,
+        MeasurePoint::<Second>::new(0.),
+        MeasurePointN::<Metre, 6>::default(),
+        Measure::<Second>::new(0.5),
+    );
+    for (&actual, expected) in state.values.iter().zip([0.5, 1., 1.5, 2., 2.5, 3.]) {
+        assert_eq_64!(actual, expected);
+    }
+}
@@ -1,7 +1,9 @@
-use measures::{assert_eq_32, assert_eq_64, traits::Trigonometry};
+use measures::{
+    assert_eq_32, assert_eq_64, assert_eq_tolerance, traits::AffineUnit, traits::Trigonometry,
+};
 use units::{
-    barycentric_combination, midpoint, weighted_midpoint, ApproxMeasurePoint, Celsius, Degree,
-    Fahrenheit, Measure, MeasurePoint,
+    barycentric_combination, midpoint, weighted_midpoint, ApproxMeasurePoint, Celsius,
+    DecibelMilliwatt, Degree, Fahrenheit, Measure, MeasurePoint, MolePerLitre, Watt, PH,
 };
 
 mod units {
@@ -20,6 +22,26 @@ mod units {
                     offset: 273.15 - 32. * 5. / 9.,
                 }
             ]
+            AmountConcentration [
+                MolePerLitre {
+                    suffix: " mol/L",
+                }
+                PH {
+                    suffix: " pH",
+                    to_base: |value| 10_f64.powf(-value),
+                    from_base: |value| -value.log10(),
+                }
+            ]
+            Power [
+                Watt {
+                    suffix: " W",
+                }
+                DecibelMilliwatt {
+                    suffix: " dBm",
+                    to_base: |value| 10_f64.powf(value / 10.) * 1e-3,
+                    from_base: |value| (value * 1e3).log10() * 10.,
+                }
+            ]
         ]
         angle_measurement_units [
             Degree {
@@ -61,6 +83,65 @@ fn measure_point_1d_convert() {
     assert_eq_64!(mp8.value, 20_f64);
 }
 
+fn is_affine_unit<Unit: AffineUnit>() {}
+
+#[test]
+fn measure_point_1d_affine_units() {
+    // The units declaring an offset are flagged, to be rejected by the vector measures.
+    is_affine_unit::<Celsius>();
+    is_affine_unit::<Fahrenheit>();
+
+    // The offsets are applied to the points, and ignored for the differences.
+    let mp1 = MeasurePoint::<Celsius>::new(20.);
+    assert_eq_64!(mp1.convert::<Fahrenheit>().value, 68.);
+    assert_eq_64!(
+        Measure::<Celsius>::new(10.).convert::<Fahrenheit>().value,
+        18.
+    );
+
+    // The absolute measure is the difference from the zero of the base unit.
+    let m1: Measure<Celsius> = mp1.to_absolute_measure();
+    assert_eq_64!(m1.value, 293.15);
+    let m2: Measure<Fahrenheit> = mp1.to_absolute_measure();
+    assert_eq_64!(m2.value, 527.67);
+    let m3 = MeasurePoint::<Fahrenheit>::new(-459.67).to_absolute_measure::<Celsius>();
+    assert!(m3.value.abs() < 1e-12);
+}
+
+#[test]
+fn measure_point_1d_convert_non_linear() {
+    let mp1 = MeasurePoint::<PH>::new(7.);
+    let mp2: MeasurePoint<MolePerLitre> = mp1.convert::<MolePerLitre>();
+    assert_eq_64!(mp2.value, 1e-7);
+    assert_eq_64!(mp2.convert::<PH>().value, 7.);
+
+    // The variance is scaled by the square of the derivative of 10^-pH.
+    let amp1 = ApproxMeasurePoint::<PH>::with_variance(7., 0.01);
+    let amp2: ApproxMeasurePoint<MolePerLitre> = amp1.convert::<MolePerLitre>();
+    let derivative = core::f64::consts::LN_10 * 1e-7;
+    assert_eq_64!(amp2.value, 1e-7);
+    assert_eq_tolerance!(amp2.variance / (0.01 * derivative * derivative), 1., 1e-8);
+    let amp3 = amp2.convert::<PH>();
+    assert_eq_tolerance!(amp3.value, 7., 1e-12);
+    assert_eq_tolerance!(amp3.variance, 0.01, 1e-8);
+}
+
+#[test]
+fn measure_point_1d_convert_decibels() {
+    let mp1 = MeasurePoint::<Watt>::new(1.);
+    let mp2: MeasurePoint<DecibelMilliwatt> = mp1.convert::<DecibelMilliwatt>();
+    assert_eq_64!(mp2.value, 30.);
+    assert_eq_64!(mp2.convert::<Watt>().value, 1.);
+
+    let mp3 = MeasurePoint::<DecibelMilliwatt, f32>::new(-10.);
+    let mp4: MeasurePoint<Watt, f32> = mp3.convert::<Watt>();
+    assert!((mp4.value - 1e-4).abs() < 1e-10);
+
+    // Integer values are rounded.
+    let mp5 = MeasurePoint::<Watt, i32>::new(2);
+    assert_eq!(mp5.convert::<DecibelMilliwatt>().value, 33);
+}
+
 #[test]
 fn measure_point_1d_lossless_into_32_to_32() {
     let m1 = MeasurePoint::<Celsius, f32>::new(12.);
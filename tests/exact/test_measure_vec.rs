@@ -0,0 +1,141 @@
+use measures::assert_eq_64;
+use units::{
+    Measure, Measure2d, Measure2dVec, Measure3d, Measure3dVec, MeasureVec, Metre, Millimetre,
+};
+
+mod units {
+    measures::define_measure_types! {
+        with_2d with_3d exact,
+        vector_properties [
+            Length [
+                Metre {
+                    suffix: " m",
+                }
+                Millimetre {
+                    suffix: " mm",
+                    ratio: 1e-3,
+                }
+            ]
+        ]
+    }
+}
+
+#[test]
+fn measure_vec_access() {
+    let mut v = MeasureVec::<Metre>::new();
+    assert!(v.is_empty());
+    v.push(Measure::new(1.5));
+    v.push(Measure::new(2.));
+    v.extend([Measure::new(3.), Measure::new(4.)]);
+    assert_eq!(v.len(), 4);
+    assert_eq!(v.get(1), Some(Measure::new(2.)));
+    assert_eq!(v.get(4), None);
+    v.set(0, Measure::new(1.));
+    assert_eq!(v.values(), &[1., 2., 3., 4.]);
+    v.values_mut()[3] = 5.;
+    assert_eq!(
+        v.iter().collect::<Vec<_>>(),
+        vec![
+            Measure::new(1.),
+            Measure::new(2.),
+            Measure::new(3.),
+            Measure::new(5.)
+        ]
+    );
+    let v2: MeasureVec<Metre> = v.iter().collect();
+    assert_eq!(v2, v);
+    assert_eq!(format!("{:?}", v), "[1 m, 2 m, 3 m, 5 m]");
+    assert_eq!(
+        MeasureVec::<Metre>::from_values(vec![1., 2.]).into_values(),
+        vec![1., 2.]
+    );
+}
+
+#[test]
+fn measure_vec_operations() {
+    let v = MeasureVec::<Metre, f32>::from_values(vec![1., 2., 3.]);
+    let mm: MeasureVec<Millimetre, f32> = v.clone().convert();
+    assert_eq!(mm.values(), &[1000., 2000., 3000.]);
+    let sum = v.clone() + &mm.convert::<Metre>();
+    assert_eq!(sum.values(), &[2., 4., 6.]);
+    let difference = sum - &v;
+    assert_eq!(difference, v);
+    let mut shifted = v.clone();
+    shifted += Measure::new(10.);
+    assert_eq!(shifted.values(), &[11., 12., 13.]);
+    shifted -= Measure::new(1.);
+    assert_eq!(shifted.values(), &[10., 11., 12.]);
+    assert_eq!((v.clone() * 2.).values(), &[2., 4., 6.]);
+    assert_eq!((v.clone() / 2.).values(), &[0.5, 1., 1.5]);
+    let mut scaled = v;
+    scaled *= 3.;
+    scaled /= 1.5;
+    assert_eq!(scaled.values(), &[2., 4., 6.]);
+}
+
+#[test]
+#[should_panic(expected = "different lengths")]
+fn measure_vec_different_lengths() {
+    let _ = MeasureVec::<Metre>::from_values(vec![1., 2.])
+        + &MeasureVec::<Metre>::from_values(vec![1.]);
+}
+
+#[test]
+fn measure_2d_vec() {
+    let mut v: Measure2dVec<Metre> = vec![Measure2d::new([3., 4.]), Measure2d::new([1., 0.])]
+        .into_iter()
+        .collect();
+    assert_eq!(v.len(), 2);
+    assert_eq!(v.x_values(), &[3., 1.]);
+    assert_eq!(v.y_values(), &[4., 0.]);
+    assert_eq!(v.get(0), Some(Measure2d::new([3., 4.])));
+    assert_eq!(v.norms().values(), &[5., 1.]);
+    v += Measure2d::new([1., 1.]);
+    assert_eq!(v.get(1), Some(Measure2d::new([2., 1.])));
+    let mm = v.convert::<Millimetre>();
+    assert_eq!(mm.x_values(), &[4000., 2000.]);
+    assert_eq!(mm.y_values(), &[5000., 1000.]);
+    assert_eq!(format!("{:?}", mm), "[(4000, 5000) mm, (2000, 1000) mm]");
+}
+
+#[test]
+fn measure_3d_vec() {
+    let mut v = Measure3dVec::<Metre>::with_capacity(3);
+    v.push(Measure3d::new([1., 2., 2.]));
+    v.push(Measure3d::new([0., 3., 4.]));
+    v.push(Measure3d::new([6., 0., 8.]));
+    assert_eq!(v.x_values(), &[1., 0., 6.]);
+    assert_eq!(v.y_values(), &[2., 3., 0.]);
+    assert_eq!(v.z_values(), &[2., 4., 8.]);
+    assert_eq!(v.norms().values(), &[3., 5., 10.]);
+    v.z_values_mut()[0] = -2.;
+    v.set(1, Measure3d::new([0., -3., 4.]));
+    assert_eq!(
+        v.iter().collect::<Vec<_>>(),
+        vec![
+            Measure3d::new([1., 2., -2.]),
+            Measure3d::new([0., -3., 4.]),
+            Measure3d::new([6., 0., 8.])
+        ]
+    );
+    assert_eq!(v.get(3), None);
+
+    let mm = v.clone().convert::<Millimetre>();
+    assert_eq_64!(mm.x_values()[2], 6000.);
+    let doubled = v.clone() + &mm.convert::<Metre>();
+    assert_eq!(doubled, v.clone() * 2.);
+    let zero = doubled / 2. - &v;
+    assert!(zero.iter().all(|m| m == Measure3d::default()));
+    let mut shifted = v.clone();
+    shifted -= Measure3d::new([1., 1., 1.]);
+    assert_eq!(shifted.get(0), Some(Measure3d::new([0., 1., -3.])));
+
+    let (x, y, z) = v.into_components();
+    assert_eq!((x.len(), y.len(), z.len()), (3, 3, 3));
+}
+
+#[test]
+#[should_panic(expected = "different lengths")]
+fn measure_3d_vec_different_lengths() {
+    let _ = Measure3dVec::<Metre>::from_components(vec![1.], vec![1.], vec![]);
+}
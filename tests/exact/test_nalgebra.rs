@@ -0,0 +1,111 @@
+use measures::assert_eq_64;
+use nalgebra::{
+    Affine2, Affine3, Isometry3, Matrix2, Matrix3, Matrix4, Point2, Point3, Translation3,
+    UnitQuaternion, Vector2, Vector3,
+};
+use units::{
+    AffineMap3d, ApproxMeasure3d, LinearMap2d, LinearMap3d, Measure2d, Measure3d, MeasurePoint2d,
+    MeasurePoint3d, Metre, Millimetre,
+};
+
+mod units {
+    measures::define_measure_types! {
+        with_points with_2d with_3d with_transformations exact with_approx with_nalgebra,
+        vector_properties [
+            Length [
+                Metre {
+                    suffix: " m",
+                }
+                Millimetre {
+                    suffix: " mm",
+                    ratio: 1e-3,
+                }
+            ]
+        ]
+    }
+}
+
+#[test]
+fn nalgebra_vectors_and_points() {
+    let m: Measure3d<Metre> = Vector3::new(1., 2., 3.).into();
+    assert_eq!(m, Measure3d::new([1., 2., 3.]));
+    // The destination unit is explicit, as the types of `nalgebra` have no unit.
+    let v: Vector3<f64> = m.to_nalgebra_in::<Millimetre>();
+    assert_eq!(v, Vector3::new(1000., 2000., 3000.));
+    assert_eq!(m.to_nalgebra_in::<Metre>(), Vector3::new(1., 2., 3.));
+    let m2 = Measure2d::<Metre, f32>::from(Vector2::new(4., 5.));
+    assert_eq!(m2.to_nalgebra_in::<Metre>(), Vector2::new(4., 5.));
+    assert_eq!(
+        m2.to_nalgebra_in::<Millimetre>(),
+        Vector2::new(4000., 5000.)
+    );
+
+    let p = MeasurePoint3d::<Metre>::from(Point3::new(1., 2., 3.));
+    assert_eq!(p, MeasurePoint3d::new([1., 2., 3.]));
+    assert_eq!(p.to_nalgebra_in::<Metre>(), Point3::new(1., 2., 3.));
+    assert_eq!(
+        p.to_nalgebra_in::<Millimetre>(),
+        Point3::new(1000., 2000., 3000.)
+    );
+    let p2: Point2<f64> = MeasurePoint2d::<Metre>::new([6., 7.]).to_nalgebra_in::<Metre>();
+    assert_eq!(p2, Point2::new(6., 7.));
+    assert_eq!(MeasurePoint2d::<Metre>::from(p2).values, [6., 7.]);
+}
+
+#[test]
+fn nalgebra_linear_maps() {
+    let matrix = Matrix3::new(1., 2., 3., 4., 5., 6., 7., 8., 9.);
+    let map = LinearMap3d::from(matrix);
+    assert_eq!(map.c, [[1., 2., 3.], [4., 5., 6.], [7., 8., 9.]]);
+    let v = Vector3::new(1., -1., 2.);
+    let mapped = map
+        .apply_to(Measure3d::<Metre>::from(v))
+        .to_nalgebra_in::<Metre>();
+    assert_eq!(mapped, matrix * v);
+    assert_eq!(Matrix3::from(map), matrix);
+
+    let matrix2 = Matrix2::new(0., -1., 1., 0.);
+    assert_eq!(LinearMap2d::from(matrix2).c, [[0., -1.], [1., 0.]]);
+    assert_eq!(Matrix2::from(LinearMap2d::from(matrix2)), matrix2);
+}
+
+#[test]
+fn nalgebra_affine_maps() {
+    let isometry = Isometry3::from_parts(
+        Translation3::new(1., 2., 3.),
+        UnitQuaternion::from_axis_angle(&Vector3::z_axis(), core::f64::consts::FRAC_PI_2),
+    );
+    let map = AffineMap3d::<Metre>::from(isometry);
+    let p = Point3::new(1., 0., 0.);
+    let mapped = map.apply_to(MeasurePoint3d::from(p));
+    let expected = isometry * p;
+    for i in 0..3 {
+        assert_eq_64!(mapped.values[i], expected[i]);
+    }
+
+    let affine: Affine3<f64> = map.to_nalgebra_in::<Metre>();
+    assert_eq!(affine.matrix(), &isometry.to_homogeneous());
+    let back = AffineMap3d::<Metre>::from(affine);
+    assert_eq!(back.c, map.c);
+    let affine_in_millimetres = map.to_nalgebra_in::<Millimetre>();
+    assert_eq!(affine_in_millimetres[(0, 3)], 1000.);
+    assert_eq!(affine_in_millimetres[(2, 3)], 3000.);
+
+    let scaling = Affine3::from_matrix_unchecked(Matrix4::new_scaling(2.));
+    let scaled = AffineMap3d::<Metre>::from(scaling).apply_to(MeasurePoint3d::new([1., 2., 3.]));
+    assert_eq!(scaled, MeasurePoint3d::new([2., 4., 6.]));
+
+    let affine2 = Affine2::<f64>::identity();
+    let map2 = units::AffineMap2d::<Metre>::from(affine2);
+    assert_eq!(map2.c, [[1., 0., 0.], [0., 1., 0.]]);
+    assert_eq!(map2.to_nalgebra_in::<Millimetre>(), affine2);
+}
+
+#[test]
+fn nalgebra_covariances() {
+    let covariances = Matrix3::new(4., 1., 0., 1., 9., 0., 0., 0., 1.);
+    let m = ApproxMeasure3d::<Metre>::from_nalgebra(Vector3::new(1., 2., 3.), covariances);
+    assert_eq!(m.values, [1., 2., 3.]);
+    assert_eq!(m.covariances, [[4., 1., 0.], [1., 9., 0.], [0., 0., 1.]]);
+    assert_eq!(m.covariance_matrix(), covariances);
+}
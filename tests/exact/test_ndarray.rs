@@ -0,0 +1,91 @@
+use measures::ndarray::{
+    inner_product, matrix_product, matrix_vector_product, peel_view, peel_view_mut, wrap_view,
+    wrap_view_mut,
+};
+use ndarray::{array, Array1, Array2, ArrayView1};
+use units::{Joule, Measure, Metre, Newton, Second, SquareMetre};
+
+mod units {
+    measures::define_measure_types! {
+        exact,
+        scalar_properties [
+            Time [
+                Second {
+                    suffix: " s",
+                }
+            ]
+            Length [
+                Metre {
+                    suffix: " m",
+                }
+            ]
+            Area [
+                SquareMetre {
+                    suffix: " m\u{b2}",
+                }
+            ]
+            Force [
+                Newton {
+                    suffix: " N",
+                }
+            ]
+            Energy [
+                Joule {
+                    suffix: " J",
+                }
+            ]
+        ]
+        relationships [
+            Joule 1 == Newton 1 * Metre 1,
+            SquareMetre 1 == Metre 1 * __ 1,
+        ]
+    }
+}
+
+#[test]
+fn ndarray_views() {
+    let lengths: Array1<Measure<Metre>> = array![Measure::new(1.), Measure::new(2.5)];
+    let numbers: ArrayView1<f64> = peel_view(&lengths);
+    assert_eq!(numbers, array![1., 2.5]);
+
+    let mut numbers = array![[1., 2.], [3., 4.]];
+    {
+        let times = wrap_view::<Measure<Second>, _, _, _>(&numbers);
+        assert_eq!(times[[1, 0]], Measure::new(3.));
+        assert_eq!(times.t()[[1, 0]], Measure::new(2.));
+    }
+    wrap_view_mut::<Measure<Second>, _, _, _>(&mut numbers)[[0, 1]] += Measure::new(5.);
+    assert_eq!(numbers, array![[1., 7.], [3., 4.]]);
+
+    let mut lengths = lengths;
+    peel_view_mut(&mut lengths)[1] = 4.;
+    assert_eq!(lengths[1], Measure::new(4.));
+}
+
+#[test]
+fn ndarray_products() {
+    let forces: Array2<Measure<Newton>> = array![[1., 2.], [3., 4.], [5., 6.]].mapv(Measure::new);
+    let displacements: Array1<Measure<Metre>> = array![10., -1.].mapv(Measure::new);
+
+    let works: Array1<Measure<Joule>> = matrix_vector_product(&forces, &displacements);
+    assert_eq!(works, array![8., 26., 44.].mapv(Measure::<Joule>::new));
+
+    let paths: Array2<Measure<Metre>> = array![[1., 0.], [0., 2.]].mapv(Measure::new);
+    let works: Array2<Measure<Joule>> = matrix_product(&forces, &paths);
+    assert_eq!(
+        works,
+        array![[1., 4.], [3., 8.], [5., 12.]].mapv(Measure::<Joule>::new)
+    );
+
+    // The strides of a transposed view are honored.
+    let works: Array2<Measure<Joule>> = matrix_product(&paths.t(), &forces.t());
+    assert_eq!(
+        works,
+        array![[1., 3., 5.], [4., 8., 12.]].mapv(Measure::<Joule>::new)
+    );
+
+    let work: Measure<Joule> = inner_product(&forces.row(2), &displacements);
+    assert_eq!(work, Measure::new(44.));
+    let area: Measure<SquareMetre> = inner_product(&displacements, &displacements);
+    assert_eq!(area, Measure::new(101.));
+}
@@ -0,0 +1,186 @@
+use measures::angle::Radian;
+use measures::{assert_eq_32, assert_eq_64, assert_eq_tolerance};
+use serde::{Deserialize, Serialize};
+use units::{
+    serde_tagged, serde_text, ApproxMeasure, ApproxMeasurePoint, Kelvin, Measure, MeasurePoint,
+    Metre, Watt,
+};
+
+mod units {
+    measures::define_measure_types! {
+        with_points exact with_approx with_serde,
+        scalar_properties [
+            Temperature [
+                Kelvin {
+                    suffix: " K",
+                }
+                Celsius {
+                    suffix: " °C",
+                    ratio: 1.,
+                    offset: 273.15,
+                }
+            ]
+            Power [
+                Watt {
+                    suffix: " W",
+                }
+                DecibelMilliwatt {
+                    suffix: " dBm",
+                    to_base: |value| 10_f64.powf(value / 10.) * 1e-3,
+                    from_base: |value| (value * 1e3).log10() * 10.,
+                }
+            ]
+        ]
+        vector_properties [
+            Length [
+                Metre {
+                    suffix: " m",
+                }
+                Millimetre {
+                    suffix: " mm",
+                    ratio: 1e-3,
+                }
+            ]
+        ]
+        angle_measurement_units [
+            Degree {
+                suffix: " deg",
+                cycle_fraction: 360.,
+            }
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Tagged {
+    #[serde(with = "serde_tagged")]
+    length: Measure<Metre>,
+    #[serde(with = "serde_tagged")]
+    temperature: ApproxMeasurePoint<Kelvin>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Text {
+    #[serde(with = "serde_text")]
+    length: Measure<Metre, f32>,
+    #[serde(with = "serde_text")]
+    temperature: MeasurePoint<Kelvin>,
+    #[serde(with = "serde_text")]
+    angle: Measure<Radian>,
+}
+
+#[test]
+fn serde_tagged_format() {
+    let tagged = Tagged {
+        length: Measure::new(10.),
+        temperature: ApproxMeasurePoint::with_variance(300., 0.25),
+    };
+    let json = serde_json::to_string(&tagged).unwrap();
+    assert_eq!(
+        json,
+        r#"{"length":{"value":10.0,"unit":"m"},"temperature":{"value":300.0,"variance":0.25,"unit":"K"}}"#
+    );
+    assert_eq!(serde_json::from_str::<Tagged>(&json).unwrap(), tagged);
+
+    let converted = serde_json::from_str::<Tagged>(
+        r#"{"length":{"unit":"mm","value":1500},"temperature":{"value":25,"unit":"°C","variance":0.25}}"#,
+    )
+    .unwrap();
+    assert_eq_64!(converted.length.value, 1.5);
+    assert_eq_64!(converted.temperature.value, 298.15);
+    assert_eq!(converted.temperature.variance, 0.25);
+
+    let approx: ApproxMeasure<Metre> = serde_tagged::deserialize(
+        &mut serde_json::Deserializer::from_str(r#"{"value":2000,"variance":4,"unit":"mm"}"#),
+    )
+    .unwrap();
+    assert_eq_64!(approx.value, 2.);
+    assert_eq_64!(approx.variance, 4e-6);
+}
+
+#[test]
+fn serde_tagged_errors() {
+    let error = serde_json::from_str::<Tagged>(
+        r#"{"length":{"value":3,"unit":"ft"},"temperature":{"value":0,"variance":0,"unit":"K"}}"#,
+    )
+    .unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("unknown unit `ft`, expected one of the units `m`, `mm`"));
+
+    let error = serde_json::from_str::<Tagged>(
+        r#"{"length":{"value":3},"temperature":{"value":0,"variance":0,"unit":"K"}}"#,
+    )
+    .unwrap_err();
+    assert!(error.to_string().starts_with("missing field `unit`"));
+
+    let error = serde_json::from_str::<Tagged>(
+        r#"{"length":{"value":3,"variance":1,"unit":"m"},"temperature":{"value":0,"variance":0,"unit":"K"}}"#,
+    )
+    .unwrap_err();
+    assert!(error.to_string().starts_with("unknown field `variance`"));
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Power {
+    #[serde(with = "serde_text")]
+    exact: MeasurePoint<Watt>,
+    #[serde(with = "serde_tagged")]
+    approx: ApproxMeasurePoint<Watt>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct PowerDifference {
+    #[serde(with = "serde_text")]
+    exact: Measure<Watt>,
+}
+
+#[test]
+fn serde_non_linear_units() {
+    let power = serde_json::from_str::<Power>(
+        r#"{"exact":"20 dBm","approx":{"value":30.0,"variance":0.01,"unit":"dBm"}}"#,
+    )
+    .unwrap();
+    assert_eq_64!(power.exact.value, 0.1);
+    assert_eq_64!(power.approx.value, 1.);
+    let derivative = core::f64::consts::LN_10 / 10.;
+    assert_eq_tolerance!(power.approx.variance, 0.01 * derivative * derivative, 1e-10);
+
+    // A difference cannot be converted by the functions of a non-linear unit.
+    let error = serde_json::from_str::<PowerDifference>(r#"{"exact":"20 dBm"}"#).unwrap_err();
+    assert!(error.to_string().starts_with(
+        "the unit `dBm` is not linear, and so it can be used only for measure points"
+    ));
+    let power = serde_json::from_str::<PowerDifference>(r#"{"exact":"20 W"}"#).unwrap();
+    assert_eq_64!(power.exact.value, 20.);
+}
+
+#[test]
+fn serde_text_format() {
+    let text = Text {
+        length: Measure::new(10.5),
+        temperature: MeasurePoint::new(300.),
+        angle: Measure::new(1.),
+    };
+    let json = serde_json::to_string(&text).unwrap();
+    assert_eq!(
+        json,
+        r#"{"length":"10.5 m","temperature":"300 K","angle":"1 rad"}"#
+    );
+    assert_eq!(serde_json::from_str::<Text>(&json).unwrap(), text);
+
+    let converted = serde_json::from_str::<Text>(
+        r#"{"length":"1500mm","temperature":" 25 °C ","angle":"180 deg"}"#,
+    )
+    .unwrap();
+    assert_eq_32!(converted.length.value, 1.5);
+    assert_eq_64!(converted.temperature.value, 298.15);
+    assert_eq_64!(converted.angle.value, core::f64::consts::PI);
+
+    let error =
+        serde_json::from_str::<Text>(r#"{"length":"3 ft","temperature":"0 K","angle":"0 rad"}"#)
+            .unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("cannot parse `3 ft` as a number followed by one of the units `m`, `mm`"));
+}
@@ -0,0 +1,278 @@
+use measures::angle::Radian;
+use measures::assert_eq_64;
+use measures::dimensionless::One;
+use serde::{Deserialize, Serialize};
+use units::{
+    serde_axis_angle, AffineMap2d, AffineMap3d, ApproxMeasure, LinearMap2d, LinearMap3d, Measure,
+    Measure3d, MeasurePoint2d, MeasurePoint3d, Metre, Volt, Watt,
+};
+
+mod units {
+    measures::define_measure_types! {
+        with_points with_approx with_2d with_3d with_transformations exact with_serde,
+        scalar_properties [
+            Power [
+                Watt {
+                    suffix: " W",
+                }
+            ]
+            ElectricPotential [
+                Volt {
+                    suffix: " V",
+                }
+            ]
+        ]
+        vector_properties [
+            Length [
+                Metre {
+                    suffix: " m",
+                }
+            ]
+        ]
+        angle_measurement_units [
+        ]
+    }
+
+    impl measures::traits::PowerQuantity for Power {}
+    impl measures::traits::RootPowerQuantity for ElectricPotential {}
+}
+
+#[derive(Serialize, Deserialize)]
+struct Mounts {
+    linear_2d: LinearMap2d,
+    linear_3d: LinearMap3d<f32>,
+    affine_2d: AffineMap2d<Metre>,
+    affine_3d: AffineMap3d<Metre>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct AxisAngleMounts {
+    #[serde(with = "serde_axis_angle")]
+    linear_2d: LinearMap2d,
+    #[serde(with = "serde_axis_angle")]
+    linear_3d: LinearMap3d,
+    #[serde(with = "serde_axis_angle")]
+    affine_2d: AffineMap2d<Metre>,
+    #[serde(with = "serde_axis_angle")]
+    affine_3d: AffineMap3d<Metre>,
+}
+
+fn axis_angle_mounts() -> AxisAngleMounts {
+    AxisAngleMounts {
+        linear_2d: LinearMap2d::rotation(Measure::<Radian>::new(0.5)),
+        linear_3d: LinearMap3d::rotation(
+            Measure::<Radian>::new(1.),
+            Measure3d::<One>::new([0., 0.6, 0.8]),
+        ),
+        affine_2d: AffineMap2d::rotation(
+            MeasurePoint2d::new([1., 2.]),
+            Measure::<Radian>::new(0.5),
+        ),
+        affine_3d: AffineMap3d::rotation(
+            MeasurePoint3d::new([1., 2., 3.]),
+            Measure3d::<One>::new([0.6, 0., -0.8]),
+            Measure::<Radian>::new(-2.),
+        ),
+    }
+}
+
+fn assert_same_maps(a: &AxisAngleMounts, b: &AxisAngleMounts) {
+    for (x, y) in a
+        .linear_2d
+        .c
+        .iter()
+        .flatten()
+        .zip(b.linear_2d.c.iter().flatten())
+    {
+        assert_eq_64!(*x, *y);
+    }
+    for (x, y) in a
+        .linear_3d
+        .c
+        .iter()
+        .flatten()
+        .zip(b.linear_3d.c.iter().flatten())
+    {
+        assert_eq_64!(*x, *y);
+    }
+    for (x, y) in a
+        .affine_2d
+        .c
+        .iter()
+        .flatten()
+        .zip(b.affine_2d.c.iter().flatten())
+    {
+        assert_eq_64!(*x, *y);
+    }
+    for (x, y) in a
+        .affine_3d
+        .c
+        .iter()
+        .flatten()
+        .zip(b.affine_3d.c.iter().flatten())
+    {
+        assert_eq_64!(*x, *y);
+    }
+}
+
+#[test]
+fn serde_maps_as_coefficients() {
+    let mounts = Mounts {
+        linear_2d: LinearMap2d::new([[1., 2.], [3., 4.]]),
+        linear_3d: LinearMap3d::new([[1., 2., 3.], [4., 5., 6.], [7., 8., 9.]]),
+        affine_2d: AffineMap2d::new([[1., 2., 3.], [4., 5., 6.]]),
+        affine_3d: AffineMap3d::new([[1., 2., 3., 4.], [5., 6., 7., 8.], [9., 10., 11., 12.]]),
+    };
+    let json = serde_json::to_string(&mounts).unwrap();
+    assert_eq!(
+        json,
+        r#"{"linear_2d":[[1.0,2.0],[3.0,4.0]],"linear_3d":[[1.0,2.0,3.0],[4.0,5.0,6.0],[7.0,8.0,9.0]],"affine_2d":[[1.0,2.0,3.0],[4.0,5.0,6.0]],"affine_3d":[[1.0,2.0,3.0,4.0],[5.0,6.0,7.0,8.0],[9.0,10.0,11.0,12.0]]}"#
+    );
+
+    let from_json = serde_json::from_str::<Mounts>(&json).unwrap();
+    let from_toml = toml::from_str::<Mounts>(&toml::to_string(&mounts).unwrap()).unwrap();
+    let from_bincode =
+        bincode::deserialize::<Mounts>(&bincode::serialize(&mounts).unwrap()).unwrap();
+    for decoded in [from_json, from_toml, from_bincode].iter() {
+        assert!(decoded.linear_2d == mounts.linear_2d);
+        assert!(decoded.linear_3d == mounts.linear_3d);
+        assert!(decoded.affine_2d == mounts.affine_2d);
+        assert!(decoded.affine_3d == mounts.affine_3d);
+    }
+
+    assert!(serde_json::from_str::<LinearMap2d>("[[1.0,2.0],[3.0,4.0,5.0]]").is_err());
+}
+
+#[test]
+fn serde_maps_as_axis_angle() {
+    let mounts = axis_angle_mounts();
+    let json = serde_json::to_value(&mounts).unwrap();
+    assert_eq_64!(json["linear_2d"]["angle"].as_f64().unwrap(), 0.5);
+    assert!(json["linear_2d"].get("axis").is_none());
+    assert!(json["linear_2d"].get("translation").is_none());
+    assert_eq_64!(json["linear_3d"]["angle"].as_f64().unwrap(), 1.);
+    assert_eq_64!(json["linear_3d"]["axis"][1].as_f64().unwrap(), 0.6);
+    assert_eq_64!(json["linear_3d"]["axis"][2].as_f64().unwrap(), 0.8);
+    assert_eq!(
+        json["affine_2d"]["translation"].as_array().unwrap().len(),
+        2
+    );
+    // A negative angle is serialized as a positive angle around the opposite axis.
+    assert_eq_64!(json["affine_3d"]["angle"].as_f64().unwrap(), 2.);
+    assert_eq_64!(json["affine_3d"]["axis"][0].as_f64().unwrap(), -0.6);
+    assert_eq_64!(json["affine_3d"]["axis"][2].as_f64().unwrap(), 0.8);
+
+    let from_json = serde_json::from_value::<AxisAngleMounts>(json).unwrap();
+    assert_same_maps(&from_json, &mounts);
+    let from_toml = toml::from_str::<AxisAngleMounts>(&toml::to_string(&mounts).unwrap()).unwrap();
+    assert_same_maps(&from_toml, &mounts);
+    let from_bincode =
+        bincode::deserialize::<AxisAngleMounts>(&bincode::serialize(&mounts).unwrap()).unwrap();
+    assert_same_maps(&from_bincode, &mounts);
+}
+
+#[test]
+fn serde_axis_angle_special_rotations() {
+    let mut json = Vec::new();
+    serde_axis_angle::serialize(
+        &LinearMap3d::<f64>::new([[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]]),
+        &mut serde_json::Serializer::new(&mut json),
+    )
+    .unwrap();
+    assert_eq!(
+        String::from_utf8(json).unwrap(),
+        r#"{"axis":[0.0,0.0,1.0],"angle":0.0}"#
+    );
+
+    // A half turn around the axis (0, 0.6, 0.8).
+    let half_turn = LinearMap3d::<f64>::rotation(
+        Measure::<Radian>::new(core::f64::consts::PI),
+        Measure3d::<One>::new([0., 0.6, 0.8]),
+    );
+    let json = serde_json::to_value(MapJson(half_turn)).unwrap();
+    assert_eq_64!(json["angle"].as_f64().unwrap(), core::f64::consts::PI);
+    assert_eq_64!(json["axis"][0].as_f64().unwrap().abs(), 0.);
+    assert_eq_64!(json["axis"][1].as_f64().unwrap().abs(), 0.6);
+    assert_eq_64!(json["axis"][2].as_f64().unwrap().abs(), 0.8);
+
+    // The axis is normalized.
+    let scaled: MapJson = serde_json::from_str(r#"{"axis":[0,3,4],"angle":1}"#).unwrap();
+    let expected = LinearMap3d::<f64>::rotation(
+        Measure::<Radian>::new(1.),
+        Measure3d::<One>::new([0., 0.6, 0.8]),
+    );
+    for (x, y) in scaled.0.c.iter().flatten().zip(expected.c.iter().flatten()) {
+        assert_eq_64!(*x, *y);
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+struct MapJson(#[serde(with = "serde_axis_angle")] LinearMap3d);
+
+fn axis_angle_error(json: &str) -> String {
+    match serde_json::from_str::<MapJson>(json) {
+        Ok(_) => panic!("`{}` should not be deserialized", json),
+        Err(error) => error.to_string(),
+    }
+}
+
+#[test]
+fn serde_axis_angle_errors() {
+    let error = serde_json::to_string(&MapJson(LinearMap3d::new([
+        [2., 0., 0.],
+        [0., 1., 0.],
+        [0., 0., 1.],
+    ])))
+    .unwrap_err();
+    assert!(error.to_string().contains("not a rotation"));
+
+    // A reflection is not a rotation.
+    let error = serde_json::to_string(&MapJson(LinearMap3d::new([
+        [-1., 0., 0.],
+        [0., 1., 0.],
+        [0., 0., 1.],
+    ])))
+    .unwrap_err();
+    assert!(error.to_string().contains("not a rotation"));
+
+    // A matrix which is only nearly a rotation would be altered by the round trip.
+    let error = serde_json::to_string(&MapJson(LinearMap3d::new([
+        [1., 1e-9, 0.],
+        [0., 1., 0.],
+        [0., 0., 1.],
+    ])))
+    .unwrap_err();
+    assert!(error.to_string().contains("not a rotation"));
+
+    let error = axis_angle_error(r#"{"axis":[0,0,0],"angle":1}"#);
+    assert!(error.to_string().contains("zero vector"));
+
+    let error = axis_angle_error(r#"{"axis":[0,0,1]}"#);
+    assert!(error.to_string().starts_with("missing field `angle`"));
+
+    let error = axis_angle_error(r#"{"axis":[0,0,1],"angle":1,"angle":2}"#);
+    assert!(error.to_string().starts_with("duplicate field `angle`"));
+
+    let error = axis_angle_error(r#"{"axis":[0,0,1],"angle":1,"translation":[0,0,0]}"#);
+    assert!(error.to_string().starts_with("unknown field `translation`"));
+}
+
+#[test]
+fn serde_decibels_formatters() {
+    let power = Measure::<Watt>::new(1000.);
+    assert_eq!(
+        serde_json::to_string(&power.power_decibels_formatter()).unwrap(),
+        r#""30 dB W""#
+    );
+    assert_eq!(
+        serde_json::to_string(&Measure::<Volt>::new(1000.).root_power_decibels_formatter())
+            .unwrap(),
+        r#""60 dB V""#
+    );
+    let approx = ApproxMeasure::<Watt>::with_variance(1000., 1.);
+    assert_eq!(
+        serde_json::to_string(&approx.power_decibels_formatter()).unwrap(),
+        serde_json::to_string(&approx.power_decibels_formatter().to_string()).unwrap()
+    );
+}
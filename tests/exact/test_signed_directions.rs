@@ -1,3 +1,4 @@
+use measures::assert_eq_64;
 use units::{Cycle, Degree, Measure, MeasurePoint, SignedDirection, UnsignedDirection};
 
 mod units {
@@ -419,6 +420,40 @@ fn signed_direction_formatting_for_debug_in_degrees_one_fractional_digit() {
     assert_eq!(format!("{:.1?}", sd), "at 12.2 deg (in -180°..180°)");
 }
 
+#[test]
+fn signed_direction_circular_statistics() {
+    // The mean of 170 degrees and -170 degrees is 180 degrees, not 0 degrees.
+    let directions = [170., -170.].map(SignedDirection::<Degree>::new);
+    let mean = SignedDirection::circular_mean(directions).unwrap();
+    assert_eq_64!(mean.value, -180.);
+
+    let directions = [-10., 10., 20.].map(SignedDirection::<Degree>::new);
+    let mean = SignedDirection::circular_mean(directions).unwrap();
+    assert_eq_64!(mean.value, 6.70495327058324);
+    let variance = SignedDirection::circular_variance(directions).unwrap();
+    assert_eq_64!(variance, 0.02355226827232426);
+    let spread: Measure<Degree> = SignedDirection::angular_spread(directions).unwrap();
+    assert_eq_64!(spread.value, 12.509403755030824);
+
+    assert!(SignedDirection::<Degree>::circular_mean([]).is_none());
+    assert!(SignedDirection::<Degree>::circular_variance([]).is_none());
+    assert!(SignedDirection::<Degree>::angular_spread([]).is_none());
+
+    // Directions cancelling out have neither a mean nor a bounded spread.
+    let directions = [90., -90.].map(SignedDirection::<Degree>::new);
+    assert!(SignedDirection::circular_mean(directions).is_none());
+    assert!(SignedDirection::angular_spread(directions).is_none());
+}
+
+#[test]
+fn signed_direction_lerp_shortest() {
+    let sd1 = SignedDirection::<Degree>::new(170.);
+    let sd2 = SignedDirection::<Degree>::new(-170.);
+    assert_eq_64!(sd1.lerp_shortest(sd2, 0.25).value, 175.);
+    assert_eq_64!(sd1.lerp_shortest(sd2, 0.5).value, -180.);
+    assert_eq_64!(sd1.lerp_shortest(sd2, 0.75).value, -175.);
+}
+
 #[test]
 fn signed_direction_traits() {
     fn impl_common_traits<
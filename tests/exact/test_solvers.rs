@@ -0,0 +1,226 @@
+use measures::{assert_eq_64, assert_eq_tolerance};
+use units::{
+    bisection_root, brent_root, central_difference, golden_section_minimum, newton_root, Joule,
+    Measure, Measure3d, MeasurePoint, MeasurePoint3d, Metre, MetrePerSecond, Newton,
+    NewtonPerMetre, Second,
+};
+
+mod units {
+    measures::define_measure_types! {
+        with_points with_3d exact,
+        scalar_properties [
+            Time [
+                Second {
+                    suffix: " s",
+                }
+            ]
+            Energy [
+                Joule {
+                    suffix: " J",
+                }
+            ]
+            Stiffness [
+                NewtonPerMetre {
+                    suffix: " N/m",
+                }
+            ]
+        ]
+        vector_properties [
+            Length [
+                Metre {
+                    suffix: " m",
+                }
+            ]
+            Velocity [
+                MetrePerSecond {
+                    suffix: " m/s",
+                }
+            ]
+            Force [
+                Newton {
+                    suffix: " N",
+                }
+            ]
+        ]
+        relationships [
+            Newton 1 == NewtonPerMetre 1 * Metre 1,
+            Metre 3 == MetrePerSecond 3 * Second 1,
+        ]
+    }
+}
+
+// x³ - 2 x - 5, having a root at about 2.0945514815423265 m.
+fn force(x: Measure<Metre>) -> Measure<Newton> {
+    Measure::new(x.value * x.value * x.value - 2. * x.value - 5.)
+}
+
+// 3 x² - 2
+fn force_derivative(x: Measure<Metre>) -> Measure<NewtonPerMetre> {
+    Measure::new(3. * x.value * x.value - 2.)
+}
+
+const ROOT: f64 = 2.0945514815423265;
+
+#[test]
+fn central_difference_1d() {
+    let d: Measure<NewtonPerMetre> =
+        central_difference(force, Measure::<Metre>::new(2.), Measure::new(1e-5));
+    assert_eq_tolerance!(d.value, 10., 1e-9);
+}
+
+#[test]
+fn central_difference_3d() {
+    // Trajectory of a point in uniform rectilinear motion.
+    let position =
+        |t: MeasurePoint<Second>| MeasurePoint3d::<Metre>::new([3. * t.value, -t.value, 7.]);
+    let v: Measure3d<MetrePerSecond> =
+        central_difference(position, MeasurePoint::new(10.), Measure::new(0.5));
+    assert_eq_64!(v.values, [3., -1., 0.]);
+}
+
+#[test]
+fn bisection_root_measure() {
+    let tolerance = Measure::<Metre>::new(1e-9);
+    let root = bisection_root(force, Measure::new(2.), Measure::new(3.), tolerance, 100).unwrap();
+    assert_eq_tolerance!(root.value, ROOT, 1e-9);
+    let root = bisection_root(force, Measure::new(3.), Measure::new(2.), tolerance, 100).unwrap();
+    assert_eq_tolerance!(root.value, ROOT, 1e-9);
+    assert!(bisection_root(force, Measure::new(3.), Measure::new(4.), tolerance, 100).is_none());
+}
+
+#[test]
+fn bisection_root_measure_point() {
+    let root = bisection_root(
+        |x: MeasurePoint<Metre>| Measure::<Newton>::new(x.value - 1.5),
+        MeasurePoint::new(0.),
+        MeasurePoint::new(10.),
+        Measure::new(1e-9),
+        100,
+    )
+    .unwrap();
+    assert_eq_tolerance!(root.value, 1.5, 1e-9);
+}
+
+#[test]
+fn brent_root_measure() {
+    let tolerance = Measure::<Metre>::new(1e-12);
+    let mut evaluations = 0;
+    let root = brent_root(
+        |x| {
+            evaluations += 1;
+            force(x)
+        },
+        Measure::new(2.),
+        Measure::new(3.),
+        tolerance,
+        100,
+    )
+    .unwrap();
+    assert_eq_tolerance!(root.value, ROOT, 1e-12);
+    // Much faster than bisection.
+    assert!(evaluations < 15);
+    let root = brent_root(force, Measure::new(3.), Measure::new(-1.), tolerance, 100).unwrap();
+    assert_eq_tolerance!(root.value, ROOT, 1e-12);
+    assert!(brent_root(force, Measure::new(3.), Measure::new(4.), tolerance, 100).is_none());
+}
+
+#[test]
+fn brent_root_measure_point() {
+    let root = brent_root(
+        |x: MeasurePoint<Metre>| Measure::<Newton>::new(x.value * x.value - 2.),
+        MeasurePoint::new(0.),
+        MeasurePoint::new(10.),
+        Measure::new(1e-12),
+        100,
+    )
+    .unwrap();
+    assert_eq_tolerance!(root.value, 2_f64.sqrt(), 1e-12);
+}
+
+#[test]
+fn newton_root_measure() {
+    let tolerance = Measure::<Metre>::new(1e-12);
+    let root = newton_root(force, force_derivative, Measure::new(2.), tolerance, 20).unwrap();
+    assert_eq_tolerance!(root.value, ROOT, 1e-12);
+
+    // With a numerical derivative.
+    let root = newton_root(
+        force,
+        |x| central_difference(force, x, Measure::new(1e-6)),
+        Measure::new(2.),
+        tolerance,
+        20,
+    )
+    .unwrap();
+    assert_eq_tolerance!(root.value, ROOT, 1e-12);
+
+    // Zero derivative.
+    assert!(newton_root(
+        force,
+        |_| Measure::<NewtonPerMetre>::new(0.),
+        Measure::new(2.),
+        tolerance,
+        20
+    )
+    .is_none());
+
+    // Too few iterations.
+    assert!(newton_root(force, force_derivative, Measure::new(100.), tolerance, 3).is_none());
+}
+
+#[test]
+fn golden_section_minimum_measure() {
+    // Potential energy of a spring having rest length 1.5 m.
+    let energy = |x: Measure<Metre>| Measure::<Joule>::new((x.value - 1.5) * (x.value - 1.5));
+    let minimum = golden_section_minimum(
+        energy,
+        Measure::new(-4.),
+        Measure::new(10.),
+        Measure::new(1e-8),
+        100,
+    )
+    .unwrap();
+    assert_eq_tolerance!(minimum.value, 1.5, 1e-8);
+}
+
+#[test]
+fn golden_section_minimum_measure_point() {
+    let minimum = golden_section_minimum(
+        |x: MeasurePoint<Metre>| force(Measure::new(x.value)),
+        MeasurePoint::new(0.),
+        MeasurePoint::new(2.),
+        Measure::new(1e-8),
+        100,
+    )
+    .unwrap();
+    assert_eq_tolerance!(minimum.value, (2_f64 / 3.).sqrt(), 1e-8);
+}
+
+#[test]
+fn solvers_zero_tolerance() {
+    // The bounds are narrowed until they cannot be brought closer.
+    let zero = Measure::<Metre>::new(0.);
+    let root = bisection_root(force, Measure::new(2.), Measure::new(3.), zero, 1000).unwrap();
+    assert_eq_tolerance!(root.value, ROOT, 1e-14);
+    let root = brent_root(force, Measure::new(2.), Measure::new(3.), zero, 100).unwrap();
+    assert_eq_tolerance!(root.value, ROOT, 1e-14);
+    let minimum = golden_section_minimum(
+        |x: Measure<Metre>| Measure::<Joule>::new((x.value - 1.5) * (x.value - 1.5)),
+        Measure::new(-4.),
+        Measure::new(10.),
+        zero,
+        1000,
+    )
+    .unwrap();
+    assert_eq_tolerance!(minimum.value, 1.5, 1e-7);
+}
+
+#[test]
+fn solvers_too_few_iterations() {
+    let tolerance = Measure::<Metre>::new(1e-12);
+    assert!(bisection_root(force, Measure::new(2.), Measure::new(3.), tolerance, 3).is_none());
+    assert!(brent_root(force, Measure::new(2.), Measure::new(3.), tolerance, 2).is_none());
+    assert!(
+        golden_section_minimum(force, Measure::new(0.), Measure::new(2.), tolerance, 3).is_none()
+    );
+}
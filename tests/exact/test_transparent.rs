@@ -0,0 +1,67 @@
+use measures::traits::Transparent;
+use units::{Measure, Measure3d, MeasureN, MeasurePoint, MeasurePoint2d, Metre};
+
+mod units {
+    measures::define_measure_types! {
+        with_points with_2d with_3d exact with_nd,
+        vector_properties [
+            Length [
+                Metre {
+                    suffix: " m",
+                }
+            ]
+        ]
+    }
+}
+
+#[test]
+fn transparent_slices() {
+    let values = [1.5, 2., 3.];
+    let measures = Measure::<Metre>::wrap_slice(&values);
+    assert_eq!(measures.len(), 3);
+    assert_eq!(measures[1], Measure::new(2.));
+    assert_eq!(Measure::<Metre>::peel_slice(measures), &values);
+
+    let points = MeasurePoint::<Metre, f32>::wrap_slice(&[4., 5.]);
+    assert_eq!(points[1], MeasurePoint::new(5.));
+
+    let mut components = [[1., 2., 3.], [4., 5., 6.]];
+    let vectors = Measure3d::<Metre>::wrap_slice_mut(&mut components);
+    vectors[1] *= 2.;
+    assert_eq!(components[1], [8., 10., 12.]);
+
+    let mut vectors = [
+        MeasurePoint2d::<Metre>::new([1., 2.]),
+        MeasurePoint2d::new([3., 4.]),
+    ];
+    MeasurePoint2d::peel_slice_mut(&mut vectors)[0][1] = 7.;
+    assert_eq!(vectors[0], MeasurePoint2d::new([1., 7.]));
+    let flat: &[f64] = MeasurePoint2d::peel_slice(&vectors).as_flattened();
+    assert_eq!(flat, &[1., 7., 3., 4.]);
+
+    let states = MeasureN::<Metre, 4>::wrap_slice(&[[1., 2., 3., 4.]]);
+    assert_eq!(states[0].values[3], 4.);
+
+    assert_eq!(Measure::<Metre>::wrap(2.5), Measure::new(2.5));
+    assert_eq!(
+        Measure3d::peel(Measure3d::<Metre>::new([1., 2., 3.])),
+        [1., 2., 3.]
+    );
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn bytemuck_casts() {
+    use measures::bytemuck;
+    let bytes: [u8; 16] = bytemuck::cast([1.5_f64, -2.]);
+    let measures: &[Measure<Metre>] = bytemuck::cast_slice(&bytes);
+    assert_eq!(measures, &[Measure::new(1.5), Measure::new(-2.)]);
+    let vector: Measure3d<Metre, f32> = bytemuck::cast([1_f32, 2., 3.]);
+    assert_eq!(vector, Measure3d::new([1., 2., 3.]));
+    let zero: MeasureN<Metre, 5, f32> = bytemuck::Zeroable::zeroed();
+    assert_eq!(zero.values, [0.; 5]);
+    assert_eq!(
+        bytemuck::bytes_of(&Measure::<Metre, f32>::new(1.)),
+        1_f32.to_ne_bytes()
+    );
+}
@@ -0,0 +1,169 @@
+use measures::{assert_eq_64, assert_eq_tolerance, dimensionless::One};
+use units::{state, Measure, MetrePerSecond, Second, State, StateCovariance, StateJacobian};
+
+mod units {
+    measures::define_measure_types! {
+        exact,
+        scalar_properties [
+            Time [
+                Second {
+                    suffix: " s",
+                }
+            ]
+            AngularSpeed [
+                RadianPerSecond {
+                    suffix: " rad/s",
+                }
+            ]
+        ]
+        vector_properties [
+            Length [
+                Metre {
+                    suffix: " m",
+                }
+            ]
+            Velocity [
+                MetrePerSecond {
+                    suffix: " m/s",
+                }
+            ]
+        ]
+        relationships [
+            Metre 1 == MetrePerSecond 1 * Second 1,
+            Radian 1 == RadianPerSecond 1 * Second 1,
+        ]
+    }
+
+    measures::define_typed_vector! {
+        vector: State,
+        covariance: StateCovariance,
+        jacobian: StateJacobian,
+        components: state,
+        fields [
+            position: Metre,
+            velocity: MetrePerSecond,
+            heading: Radian,
+            turn_rate: RadianPerSecond,
+        ]
+    }
+}
+
+fn sample_state() -> State {
+    State::new(
+        Measure::new(100.),
+        Measure::new(2.),
+        Measure::new(0.5),
+        Measure::new(0.1),
+    )
+}
+
+// Transition of the state in a time step, with constant velocity and turn rate.
+fn transition(step: Measure<Second>) -> StateJacobian {
+    let mut jacobian = StateJacobian::default();
+    jacobian.set::<state::position, state::velocity, _>(step);
+    jacobian.set::<state::heading, state::turn_rate, _>(step);
+    jacobian
+}
+
+#[test]
+fn typed_vector_basics() {
+    assert_eq!(state::DIMENSIONS, 4);
+    let s = sample_state();
+    assert_eq!(s.values(), [100., 2., 0.5, 0.1]);
+    assert_eq!(State::from_values([100., 2., 0.5, 0.1]), s);
+    assert_eq!(
+        s.get::<state::velocity>(),
+        Measure::<MetrePerSecond>::new(2.)
+    );
+    let mut s2 = s;
+    s2.set::<state::heading>(Measure::new(1.5));
+    assert_eq!(s2.heading.value, 1.5);
+    assert_eq!(State::<f32>::default().values(), [0.; 4]);
+    assert_eq!(
+        s.to_string(),
+        "{ position: 100 m, velocity: 2 m/s, heading: 0.5 rad, turn_rate: 0.1 rad/s }"
+    );
+}
+
+#[test]
+fn typed_vector_arithmetic() {
+    let s = sample_state();
+    assert_eq!((s + s).values(), [200., 4., 1., 0.2]);
+    assert_eq!((s - s).values(), [0.; 4]);
+    assert_eq!((-s).values(), [-100., -2., -0.5, -0.1]);
+    assert_eq!((s * 2.).values(), [200., 4., 1., 0.2]);
+    assert_eq!((s / 2.).values(), [50., 1., 0.25, 0.05]);
+    let mut s2 = s;
+    s2 += s;
+    s2 -= s;
+    assert_eq!(s2, s);
+}
+
+#[test]
+fn jacobian_apply_to() {
+    let step = Measure::<Second>::new(10.);
+    let jacobian = transition(step);
+    assert_eq!(
+        jacobian.get::<state::position, state::velocity, Second>(),
+        step
+    );
+    assert_eq!(
+        jacobian.get::<state::position, state::position, One>(),
+        Measure::<One>::new(1.)
+    );
+    let s = jacobian.apply_to(sample_state());
+    assert_eq_64!(s.position.value, 120.);
+    assert_eq_64!(s.velocity.value, 2.);
+    assert_eq_64!(s.heading.value, 1.5);
+    assert_eq_64!(s.turn_rate.value, 0.1);
+
+    // Two steps are equivalent to a double step.
+    let double = jacobian.combined_with(&jacobian);
+    assert_eq!(double, transition(step * 2.));
+    assert_eq!(
+        StateJacobian::default().apply_to(sample_state()),
+        sample_state()
+    );
+}
+
+#[test]
+fn covariance_propagation() {
+    let mut covariance = StateCovariance::from_standard_deviations(State::new(
+        Measure::new(3.),
+        Measure::new(0.5),
+        Measure::new(0.1),
+        Measure::new(0.01),
+    ));
+    assert_eq_64!(covariance.variance::<state::position>(), 9.);
+    assert_eq_64!(
+        covariance.standard_deviation::<state::velocity>().value,
+        0.5
+    );
+    assert_eq_64!(
+        covariance.covariance::<state::position, state::velocity>(),
+        0.
+    );
+
+    covariance = covariance.transformed(&transition(Measure::new(2.)));
+    // Var(x + 2 v) = Var(x) + 4 Var(v).
+    assert_eq_64!(covariance.variance::<state::position>(), 10.);
+    // Cov(x + 2 v, v) = 2 Var(v).
+    assert_eq_64!(
+        covariance.covariance::<state::position, state::velocity>(),
+        0.5
+    );
+    assert_eq_64!(
+        covariance.covariance::<state::velocity, state::position>(),
+        0.5
+    );
+    assert_eq_tolerance!(
+        covariance.correlation::<state::position, state::velocity>(),
+        0.5 / (10. * 0.25_f64).sqrt(),
+        1e-12
+    );
+
+    let mut process_noise = StateCovariance::default();
+    process_noise.set_covariance::<state::velocity, state::velocity>(0.75);
+    let covariance = covariance + process_noise;
+    assert_eq_64!(covariance.variance::<state::velocity>(), 1.);
+}
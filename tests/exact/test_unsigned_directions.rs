@@ -1,3 +1,4 @@
+use measures::assert_eq_64;
 use units::{Cycle, Degree, Measure, MeasurePoint, SignedDirection, UnsignedDirection};
 
 mod units {
@@ -422,6 +423,63 @@ fn unsigned_direction_formatting_for_debug_in_degrees_one_fractional_digit() {
     assert_eq!(format!("{:.1?}", ud), "at 12.2 deg (in 0°..360°)");
 }
 
+#[test]
+fn unsigned_direction_circular_mean() {
+    let directions = [350., 10., 20.].map(UnsignedDirection::<Degree>::new);
+    let mean = UnsignedDirection::circular_mean(directions).unwrap();
+    assert_eq_64!(mean.value, 6.70495327058324);
+
+    // The mean of 350 degrees and 10 degrees is 0 degrees, not 180 degrees.
+    let directions = [350., 10.].map(UnsignedDirection::<Degree>::new);
+    let mean = UnsignedDirection::circular_mean(directions).unwrap();
+    assert!(mean.value < 1e-12 || mean.value > 360. - 1e-12);
+
+    let directions = [0., 180.].map(UnsignedDirection::<Degree>::new);
+    assert!(UnsignedDirection::circular_mean(directions).is_none());
+    assert!(UnsignedDirection::<Degree>::circular_mean([]).is_none());
+}
+
+#[test]
+fn unsigned_direction_circular_variance_and_spread() {
+    let directions = [350., 10., 20.].map(UnsignedDirection::<Degree>::new);
+    let variance = UnsignedDirection::circular_variance(directions).unwrap();
+    assert_eq_64!(variance, 0.02355226827232426);
+    let spread: Measure<Degree> = UnsignedDirection::angular_spread(directions).unwrap();
+    assert_eq_64!(spread.value, 12.509403755030824);
+
+    let directions = [0., 0.25].map(UnsignedDirection::<Cycle>::new);
+    let variance = UnsignedDirection::circular_variance(directions).unwrap();
+    assert_eq_64!(variance, 1. - core::f64::consts::FRAC_1_SQRT_2);
+    let spread = UnsignedDirection::angular_spread(directions).unwrap();
+    assert_eq_64!(spread.convert::<Degree>().value, 47.701865433491434);
+
+    let directions = [30., 30.].map(UnsignedDirection::<Degree>::new);
+    assert_eq!(UnsignedDirection::circular_variance(directions), Some(0.));
+    assert_eq!(
+        UnsignedDirection::angular_spread(directions).unwrap().value,
+        0.
+    );
+    assert!(UnsignedDirection::<Degree>::angular_spread([]).is_none());
+
+    // Directions cancelling out have no bounded spread.
+    let directions = [0., 120., 240.].map(UnsignedDirection::<Degree>::new);
+    assert!(UnsignedDirection::angular_spread(directions).is_none());
+    assert_eq_64!(
+        UnsignedDirection::circular_variance(directions).unwrap(),
+        1.
+    );
+}
+
+#[test]
+fn unsigned_direction_lerp_shortest() {
+    let ud1 = UnsignedDirection::<Degree>::new(350.);
+    let ud2 = UnsignedDirection::<Degree>::new(10.);
+    assert_eq_64!(ud1.lerp_shortest(ud2, 0.25).value, 355.);
+    assert_eq_64!(ud1.lerp_shortest(ud2, 0.75).value, 5.);
+    assert_eq_64!(ud2.lerp_shortest(ud1, 0.75).value, 355.);
+    assert_eq_64!(ud1.lerp_shortest(ud2, 1.).value, 10.);
+}
+
 #[test]
 fn unsigned_direction_traits() {
     fn impl_common_traits<
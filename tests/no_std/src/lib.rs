@@ -0,0 +1,112 @@
+//! Check that the code generated by the macros of the crate `measures`,
+//! with every option, compiles in a `no_std` crate, which does not use the crate `alloc`.
+//! It is only built, by `cargo build --workspace`.
+#![no_std]
+
+pub mod units {
+    measures::define_measure_types! {
+        with_points with_directions with_2d with_3d with_transformations exact with_approx with_serde with_nd with_complex,
+        scalar_properties [
+            Time [
+                Second {
+                    suffix: " s",
+                }
+            ]
+        ]
+        vector_properties [
+            Length [
+                Metre {
+                    suffix: " m",
+                }
+                Millimetre {
+                    suffix: " mm",
+                    ratio: 1e-3,
+                }
+            ]
+            Velocity [
+                MetrePerSecond {
+                    suffix: " m/s",
+                }
+            ]
+            AngularVelocity [
+                RadianPerSecond {
+                    suffix: " rad/s",
+                }
+            ]
+        ]
+        angle_measurement_units [
+            Degree {
+                suffix: " deg",
+                cycle_fraction: 360.,
+            }
+        ]
+        relationships [
+            Metre 1 == MetrePerSecond 1 * Second 1,
+            Metre 2 == MetrePerSecond 2 * Second 1,
+            Metre 3 == MetrePerSecond 3 * Second 1,
+        ]
+    }
+
+    measures::define_typed_vector! {
+        vector: State,
+        covariance: StateCovariance,
+        jacobian: StateJacobian,
+        components: state,
+        fields [
+            position: Metre,
+            velocity: MetrePerSecond,
+            heading: Radian,
+        ]
+    }
+}
+
+use core::fmt::{self, Write};
+use units::{
+    ApproxMeasurePoint3d, KalmanFilter3d, LinearMap3d, Measure, Measure3d, MeasurePoint, Metre,
+    Millimetre, Second,
+};
+
+// Writer to a fixed-size buffer, used to check the formatting without allocating memory.
+pub struct Buffer {
+    bytes: [u8; 512],
+    len: usize,
+}
+
+impl Write for Buffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > self.bytes.len() {
+            return Err(fmt::Error);
+        }
+        self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// It computes and formats some measures, using only the core library.
+pub fn format_measures(buffer: &mut Buffer) -> fmt::Result {
+    let position = MeasurePoint::<Metre>::new(1.5) + Measure::<Millimetre>::new(250.).convert();
+    let rotation = LinearMap3d::rotation(
+        Measure::<units::Degree>::new(90.),
+        Measure3d::<Metre>::new([0., 0., 1.]),
+    );
+    let mut filter = KalmanFilter3d::new(ApproxMeasurePoint3d::<Metre>::with_covariances(
+        [0., 0., 0.],
+        [[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]],
+    ));
+    filter.update(ApproxMeasurePoint3d::with_covariances(
+        [1., 2., 3.],
+        [[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]],
+    ));
+    let step = Measure::<Second, i32>::new(3);
+    writeln!(
+        buffer,
+        "{} {} {}",
+        position,
+        step,
+        rotation.apply_to(Measure3d::<Metre>::new([1., 0., 0.]))
+    )?;
+    writeln!(buffer, "{}", rotation)?;
+    writeln!(buffer, "{}", filter.state)
+}
@@ -0,0 +1,158 @@
+// Helpers to run generic tests against every supported number type,
+// including the ones enabled by the features `f128`, `double_double`, `decimal`, `fixed`, `dual` and `interval`.
+// The types processing batches of values, enabled by the feature `simd`, are tested separately,
+// as their comparisons are performed lane by lane.
+
+use measures::{
+    assert_eq_tolerance,
+    traits::{ArithmeticOps, BasicArithmeticOps, LossyFrom},
+};
+
+/// Number types which can be compared with the expected `f64` values.
+pub trait TestNumber: ArithmeticOps {
+    fn to_f64(self) -> f64;
+}
+
+impl<Number: ArithmeticOps> TestNumber for Number
+where
+    f64: LossyFrom<Number>,
+{
+    fn to_f64(self) -> f64 {
+        f64::lossy_from(self)
+    }
+}
+
+/// Number types which can be compared by `assert_eq!`,
+/// including the integer and fixed-point ones.
+pub trait BasicTestNumber: BasicArithmeticOps + core::fmt::Debug {}
+
+impl<Number: BasicArithmeticOps + core::fmt::Debug> BasicTestNumber for Number {}
+
+pub fn number<Number: ArithmeticOps>(n: f64) -> Number {
+    Number::from_f64(n)
+}
+
+pub fn check_close<Number: TestNumber>(actual: Number, expected: f64, tolerance: f64) {
+    assert_eq_tolerance!(actual.to_f64(), expected, tolerance);
+}
+
+pub fn check_close_values<Number: TestNumber, const N: usize>(
+    actual: [Number; N],
+    expected: [f64; N],
+    tolerance: f64,
+) {
+    for (actual, expected) in actual.iter().zip(expected.iter()) {
+        check_close(*actual, *expected, tolerance);
+    }
+}
+
+/// test_number_types!(generic test function)
+/// It defines a module named like the given function, having a test for every number type,
+/// which calls the function with the relative tolerance suited for that type.
+/// The function must have the signature `fn<Number: TestNumber>(tolerance: f64)`.
+/// test_number_types!(generic test function, ordered)
+/// The same, but without intervals, as overlapping intervals are not comparable,
+/// and so the adaptive algorithms, which decide by comparing numbers, cannot be tested on them.
+macro_rules! test_number_types {
+    { $check:ident } => {
+        test_number_types! { @module $check
+            #[cfg(feature = "interval")]
+            #[test]
+            fn interval_number_type() {
+                super::$check::<measures::interval::Interval>(1e-13);
+            }
+        }
+    };
+    { $check:ident, ordered } => {
+        test_number_types! { @module $check }
+    };
+    { @module $check:ident $($interval_test:item)* } => {
+        mod $check {
+            #[test]
+            fn f32_number_type() {
+                super::$check::<f32>(1e-4);
+            }
+
+            #[test]
+            fn f64_number_type() {
+                super::$check::<f64>(1e-13);
+            }
+
+            #[cfg(feature = "f128")]
+            #[test]
+            fn f128_number_type() {
+                super::$check::<measures::float128::Float128>(1e-13);
+            }
+
+            #[cfg(feature = "double_double")]
+            #[test]
+            fn double_double_number_type() {
+                super::$check::<measures::double_double::DoubleDouble>(1e-13);
+            }
+
+            #[cfg(feature = "decimal")]
+            #[test]
+            fn decimal_number_type() {
+                super::$check::<measures::decimal::Decimal>(1e-13);
+            }
+
+            #[cfg(feature = "dual")]
+            #[test]
+            fn dual_number_type() {
+                super::$check::<measures::dual::Dual>(1e-13);
+            }
+
+            $($interval_test)*
+        }
+    };
+}
+
+/// test_basic_number_types!(generic test function)
+/// It defines a module named like the given function, having a test for every number type,
+/// including the integer and fixed-point ones,
+/// which calls the function with a function converting small integers to that type.
+/// The function must have the signature `fn<Number: BasicTestNumber>(n: fn(i16) -> Number)`.
+macro_rules! test_basic_number_types {
+    { $check:ident } => {
+        mod $check {
+            #[test]
+            fn f32_number_type() {
+                super::$check::<f32>(f32::from);
+            }
+
+            #[test]
+            fn f64_number_type() {
+                super::$check::<f64>(f64::from);
+            }
+
+            #[test]
+            fn i32_number_type() {
+                super::$check::<i32>(i32::from);
+            }
+
+            #[test]
+            fn i64_number_type() {
+                super::$check::<i64>(i64::from);
+            }
+
+            #[cfg(feature = "double_double")]
+            #[test]
+            fn double_double_number_type() {
+                super::$check::<measures::double_double::DoubleDouble>(|n| f64::from(n).into());
+            }
+
+            #[cfg(feature = "decimal")]
+            #[test]
+            fn decimal_number_type() {
+                super::$check::<measures::decimal::Decimal>(measures::decimal::Decimal::from);
+            }
+
+            #[cfg(feature = "fixed")]
+            #[test]
+            fn fixed_point_number_type() {
+                super::$check::<measures::fixed::types::I16F16>(measures::fixed::types::I16F16::from_num);
+                super::$check::<measures::fixed::types::I32F32>(measures::fixed::types::I32F32::from_num);
+            }
+        }
+    };
+}
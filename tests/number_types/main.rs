@@ -0,0 +1,573 @@
+// Tests run against every supported value type,
+// including the ones enabled by the features `f128`, `double_double`, `decimal`, `fixed`, `dual`, `interval` and `simd`.
+#![cfg_attr(feature = "f128", feature(f128))]
+
+#[macro_use]
+mod backends;
+mod test_approx_measure_1d;
+mod test_integration;
+mod test_linear_map_3d;
+mod test_measure_1d;
+mod test_measure_3d;
+mod test_measure_point_1d;
+mod test_solvers;
+
+use backends::{check_close, number, TestNumber};
+
+mod units {
+    measures::define_measure_types! {
+        with_points with_3d with_transformations exact,
+        scalar_properties [
+            Time [
+                Second {
+                    suffix: " s",
+                }
+            ]
+        ]
+        vector_properties [
+            Length [
+                Metre {
+                    suffix: " m",
+                }
+                Kilometre {
+                    suffix: " km",
+                    ratio: 1e3,
+                }
+                Picometre {
+                    suffix: " pm",
+                    ratio: 1e-12,
+                }
+                Millimetre {
+                    suffix: " mm",
+                    ratio: 1e-3,
+                }
+                Inch {
+                    suffix: " in",
+                    ratio: 0.0254,
+                }
+            ]
+            Velocity [
+                MetrePerSecond {
+                    suffix: " m/s",
+                }
+            ]
+        ]
+        angle_measurement_units [
+            Degree {
+                suffix: " deg",
+                cycle_fraction: 360.,
+            }
+        ]
+        relationships [
+            Metre 1 == MetrePerSecond 1 * Second 1,
+        ]
+    }
+}
+
+// Functions which must work with every value type, with the specified relative tolerance.
+// The operations on measures are tested for every value type by the other suites.
+fn number_functions<Number: TestNumber>(tolerance: f64) {
+    let x: Number = number(0.6);
+    check_close(x.sqrt() * x.sqrt(), 0.6, tolerance);
+    check_close(number::<Number>(-27.).cubic_root(), -3., tolerance);
+    check_close(number::<Number>(2.).powf(number(10.)), 1024., tolerance);
+    check_close(number::<Number>(7.) % number(0.4), 0.2, tolerance);
+    check_close(number::<Number>(20.).ln(), 20_f64.ln(), tolerance);
+    let (sin, cos) = x.sin_cos();
+    check_close(sin * sin + cos * cos, 1., tolerance);
+    check_close(x.sin() / x.cos() - x.tan(), 0., tolerance);
+    check_close(sin.asin(), 0.6, tolerance);
+    check_close(cos.acos(), 0.6, tolerance);
+    check_close(sin.atan2(cos), 0.6, tolerance);
+    check_close(
+        number::<Number>(250.)
+            .to_power_decibels()
+            .power_decibels_to_value(),
+        250.,
+        tolerance,
+    );
+    check_close(
+        number::<Number>(100.).to_root_power_decibels(),
+        40.,
+        tolerance,
+    );
+}
+
+test_number_types!(number_functions);
+
+#[cfg(feature = "f128")]
+#[test]
+fn f128_precision() {
+    use measures::float128::Float128;
+    // The precision of f128 is beyond the one of f64.
+    let third = Float128(1.) / Float128(3.);
+    assert!((third * Float128(3.) - Float128(1.)).0.abs() < 1e-32);
+    assert_eq!(third.to_string(), "0.33333333333333333333333333333333");
+}
+
+#[cfg(feature = "double_double")]
+mod double_double {
+    use super::units::Metre;
+    use measures::double_double::DoubleDouble;
+    use measures::traits::{HasOne, InverseTrigonometry, Sqrt, Trigonometry};
+
+    type Measure<Unit> = super::units::Measure<Unit, DoubleDouble>;
+
+    fn dd(s: &str) -> DoubleDouble {
+        s.parse().unwrap()
+    }
+
+    fn assert_close(actual: DoubleDouble, expected: DoubleDouble) {
+        let difference = (actual - expected).hi().abs();
+        assert!(
+            difference <= 1e-30 * expected.hi().abs().max(1.),
+            "Actual: {}, Expected: {}, Difference: {:e}",
+            actual,
+            expected,
+            difference
+        );
+    }
+
+    #[test]
+    fn double_double_precision() {
+        // A tiny increment, lost by f64, is kept.
+        let one = DoubleDouble::from(1.);
+        let tiny = DoubleDouble::from(1e-20);
+        assert_eq!(((one + tiny) - one).hi(), 1e-20);
+
+        // Repeated sums of a decimal fraction.
+        let step = Measure::<Metre>::new(dd("0.1"));
+        let mut total = Measure::<Metre>::default();
+        for _ in 0..100_000 {
+            total += step;
+        }
+        assert_close(total.value, DoubleDouble::from(10_000.));
+
+        assert_close(
+            DoubleDouble::from(2.).sqrt(),
+            dd("1.4142135623730950488016887242097"),
+        );
+        assert_close(
+            DoubleDouble::ONE.exp(),
+            dd("2.7182818284590452353602874713527"),
+        );
+        assert_close(DoubleDouble::from(10.).ln(), DoubleDouble::LN_10);
+        assert_close(DoubleDouble::PI.sin(), DoubleDouble::from(0.));
+        assert_close(
+            (DoubleDouble::PI / DoubleDouble::from(6.)).sin(),
+            DoubleDouble::from(0.5),
+        );
+        assert_close(
+            DoubleDouble::ONE.atan2(DoubleDouble::ONE) * DoubleDouble::from(4.),
+            DoubleDouble::PI,
+        );
+        assert_close(
+            DoubleDouble::from(0.5).asin() * DoubleDouble::from(6.),
+            DoubleDouble::PI,
+        );
+    }
+
+    #[test]
+    fn double_double_formatting() {
+        let third = DoubleDouble::ONE / DoubleDouble::from(3.);
+        assert_eq!(third.to_string(), "0.33333333333333333333333333333333");
+        assert_eq!(format!("{:.5}", third), "0.33333");
+        assert_eq!(
+            format!("{:.3e}", third * DoubleDouble::from(-1e5)),
+            "-3.333e4"
+        );
+        assert_eq!(
+            format!("{:e}", dd("1234.5678901234567890123")),
+            "1.2345678901234567890123e3"
+        );
+        assert_eq!(format!("{:.2}", dd("0.0012")), "0.00");
+        assert_eq!(format!("{:.2}", dd("0.0051")), "0.01");
+        assert_eq!(format!("{:.1}", dd("9.96")), "10.0");
+        assert_eq!(
+            Measure::<Metre>::new(dd("0.1") + dd("1e-25")).to_string(),
+            "0.1000000000000000000000001 m"
+        );
+    }
+}
+
+#[cfg(feature = "decimal")]
+mod decimal {
+    use super::units::{Metre, Millimetre};
+    use measures::decimal::Decimal;
+
+    type Measure<Unit> = super::units::Measure<Unit, Decimal>;
+
+    #[test]
+    fn decimal_exactness() {
+        let a = Measure::<Metre>::new(Decimal::new(1, 1));
+        let b = Measure::<Metre>::new(Decimal::new(2, 1));
+        assert_eq!((a + b).value, Decimal::new(3, 1));
+        assert_eq!(a.convert::<Millimetre>().value, Decimal::new(100, 0));
+        assert_eq!((a + b).to_string(), "0.3 m");
+    }
+}
+
+mod integer {
+    use super::units::{
+        Inch, Kilometre, Measure, MeasurePoint, MeasureVec, Metre, Millimetre, Picometre,
+    };
+
+    #[test]
+    fn integer_conversions() {
+        // The result is rounded to the nearest integer.
+        assert_eq!(
+            Measure::<Millimetre, i32>::new(1499)
+                .convert::<Metre>()
+                .value,
+            1
+        );
+        assert_eq!(
+            Measure::<Millimetre, i32>::new(1500)
+                .convert::<Metre>()
+                .value,
+            2
+        );
+        assert_eq!(
+            Measure::<Millimetre, i32>::new(-1500)
+                .convert::<Metre>()
+                .value,
+            -2
+        );
+        assert_eq!(
+            Measure::<Millimetre, i16>::new(-1499)
+                .convert::<Metre>()
+                .value,
+            -1
+        );
+        assert_eq!(
+            Measure::<Metre, i64>::new(1 << 40)
+                .convert::<Millimetre>()
+                .value,
+            1000 << 40
+        );
+
+        // The ratio between inches and millimetres is represented exactly as 127 / 5.
+        assert_eq!(
+            Measure::<Inch, i32>::new(10).convert::<Millimetre>().value,
+            254
+        );
+        assert_eq!(
+            Measure::<Millimetre, i32>::new(-254)
+                .convert::<Inch>()
+                .value,
+            -10
+        );
+        assert_eq!(
+            Measure::<Inch, i32>::new(1).convert::<Millimetre>().value,
+            25
+        );
+
+        // A ratio of 10^-15 is approximated by a fraction having a large denominator.
+        assert_eq!(
+            Measure::<Picometre, i64>::new(3_499_999_999_999_999)
+                .convert::<Kilometre>()
+                .value,
+            3
+        );
+        assert_eq!(
+            Measure::<Kilometre, i64>::new(-2)
+                .convert::<Picometre>()
+                .value,
+            -2_000_000_000_000_000
+        );
+        let lengths = MeasureVec::<Picometre, i64>::from_values(vec![
+            500_000_000_000_000,
+            -1_500_000_000_000_000,
+            7_000_000_000_000_000,
+        ]);
+        assert_eq!(lengths.convert::<Kilometre>().values(), [1, -2, 7]);
+
+        // Conversion of the value type.
+        let ticks = Measure::<Millimetre, i32>::new(-1234);
+        assert_eq!(ticks.lossless_into::<f64>().value, -1234.);
+        assert_eq!(ticks.lossy_into::<f32>().value, -1234.);
+        assert_eq!(
+            Measure::<Millimetre, f64>::new(2.6)
+                .lossy_into::<i32>()
+                .value,
+            3
+        );
+        assert_eq!(
+            MeasurePoint::<Millimetre, i8>::new(-7)
+                .lossless_into::<i32>()
+                .value,
+            -7
+        );
+    }
+}
+
+#[cfg(feature = "fixed")]
+mod fixed_point {
+    use super::units::{Measure, Metre, Millimetre};
+    use measures::fixed::types::I16F16;
+
+    #[test]
+    fn fixed_point_conversions() {
+        let length = Measure::<Metre, I16F16>::new(I16F16::from_num(1.5));
+        assert_eq!(length.convert::<Millimetre>().value, 1500);
+        // 0.001 is rounded to the nearest multiple of 2^-16.
+        assert_eq!(
+            Measure::<Millimetre, I16F16>::new(I16F16::ONE)
+                .convert::<Metre>()
+                .value
+                .to_bits(),
+            66
+        );
+        assert_eq!(length.lossy_into::<f64>().value, 1.5);
+        assert_eq!(length.to_string(), "1.5 m");
+    }
+}
+
+#[cfg(feature = "dual")]
+mod dual {
+    use super::units::{
+        dual_derivative, partial_derivative, Measure, Metre, MetrePerSecond, Second,
+    };
+    use measures::assert_eq_64;
+    use measures::dual::{Dual, DualN};
+    use measures::traits::{
+        HasHalf, HasOne, InverseTrigonometry, Logarithm, Pow, PowerDecibel, RootPowerDecibel, Sqrt,
+        Trigonometry,
+    };
+
+    #[test]
+    fn dual_function_derivatives() {
+        let x = Dual::variable(0.5, 0);
+        assert_eq_64!(x.sin().derivative(), 0.5_f64.cos());
+        assert_eq_64!(x.cos().derivative(), -0.5_f64.sin());
+        assert_eq_64!(x.tan().derivative(), 1. / (0.5_f64.cos() * 0.5_f64.cos()));
+        assert_eq_64!(x.asin().derivative(), 1. / 0.75_f64.sqrt());
+        assert_eq_64!(x.acos().derivative(), -1. / 0.75_f64.sqrt());
+        assert_eq_64!(x.sqrt().derivative(), 0.5 / 0.5_f64.sqrt());
+        assert_eq_64!((x * x * x).derivative(), 0.75);
+        assert_eq_64!((Dual::ONE / x).derivative(), -4.);
+        assert_eq_64!(x.powf(Dual::from(3.)).derivative(), 0.75);
+        assert_eq_64!(x.ln().derivative(), 2.);
+        // d(x^x)/dx = x^x * (ln(x) + 1)
+        assert_eq_64!(
+            x.powf(x).derivative(),
+            0.5_f64.powf(0.5) * (0.5_f64.ln() + 1.)
+        );
+        // d(atan2(x, 2 - x))/dx = 2 / (x^2 + (2 - x)^2)
+        assert_eq_64!(x.atan2(Dual::from(2.) - x).derivative(), 2. / 2.5);
+        assert_eq_64!(
+            x.to_power_decibels().derivative(),
+            10. / (0.5 * core::f64::consts::LN_10)
+        );
+        assert_eq_64!(
+            x.root_power_decibels_to_value().derivative(),
+            10_f64.powf(0.5 / 20.) * core::f64::consts::LN_10 / 20.
+        );
+        assert_eq_64!((Dual::from(7.) % x).derivative(), -14.);
+    }
+
+    #[test]
+    fn dual_measure_derivatives() {
+        // The position of a falling body, as a function of time.
+        let acceleration = 9.8;
+        let position = |t: Measure<Second, Dual>| -> Measure<Metre, Dual> {
+            let velocity = Measure::<MetrePerSecond, Dual>::new(t.value * Dual::from(acceleration));
+            velocity * t * Dual::HALF
+        };
+        let speed: Measure<MetrePerSecond> = dual_derivative(position, Measure::new(3.));
+        assert_eq_64!(speed.value, 29.4);
+
+        // Partial derivatives of a distance travelled at constant speed.
+        let v = Measure::<MetrePerSecond, DualN<2>>::new(DualN::variable(2., 0));
+        let t = Measure::<Second, DualN<2>>::new(DualN::variable(5., 1));
+        let distance: Measure<Metre, DualN<2>> = v * t;
+        assert_eq_64!(distance.value.value, 10.);
+        let distance_by_time: Measure<MetrePerSecond> = partial_derivative(distance, t);
+        assert_eq_64!(distance_by_time.value, 2.);
+        let distance_by_velocity: Measure<Second> = partial_derivative(distance, v);
+        assert_eq_64!(distance_by_velocity.value, 5.);
+    }
+
+    #[test]
+    fn dual_formatting() {
+        let x = Dual::new(1.5, [2.]);
+        assert_eq!(x.to_string(), "1.5");
+        assert_eq!(format!("{:?}", x), "1.5 + 2.0\u{3b5}");
+        assert_eq!(
+            format!("{:?}", DualN::<2>::variable(3., 1)),
+            "3.0 + [0.0, 1.0]\u{3b5}"
+        );
+        assert_eq!(Measure::<Metre, Dual>::new(x).to_string(), "1.5 m");
+    }
+}
+
+#[cfg(feature = "interval")]
+mod interval {
+    use super::units::{Measure, Metre, MetrePerSecond, Second};
+    use measures::interval::Interval;
+    use measures::traits::{HasSign, InverseTrigonometry, Pow, Sqrt, Trigonometry};
+
+    #[test]
+    fn interval_bounds_are_guaranteed() {
+        let tenth = Interval::from(0.1);
+        let mut sum = Interval::from(0.);
+        for _ in 0..10 {
+            sum += tenth;
+        }
+        // The sum of ten f64 numbers 0.1 is not 1, but its bounds contain 1.
+        assert!(sum.contains(1.));
+        assert!(sum.lo() < sum.hi());
+        assert!(sum.width() < 1e-14);
+
+        let x = Interval::new(-2., 3.);
+        assert_eq!(x * x, Interval::new((-6_f64).next_down(), 9_f64.next_up()));
+        assert_eq!((x * x).lo(), (-6_f64).next_down());
+        assert_eq!(x.powf(Interval::from(2.)).lo(), 0.);
+        assert_eq!(x.abs(), Interval::new(0., 3.));
+        assert_eq!(Interval::from(1.) / x, Interval::entire());
+
+        // Zero times an unbounded interval is zero, and not NaN.
+        let zero_product = Interval::entire() * Interval::from(0.);
+        assert!(zero_product.contains(0.));
+        assert!(zero_product.width() < 1e-300);
+        let half_line = Interval::new(0., 2.) * Interval::new(3., f64::INFINITY);
+        assert_eq!(half_line.lo(), (0_f64).next_down());
+        assert_eq!(half_line.hi(), f64::INFINITY);
+        assert!((Interval::new(4., 9.).sqrt()).contains(2.));
+        assert!((Interval::new(4., 9.).sqrt()).contains(3.));
+
+        // Functions which are not monotonic.
+        let cos = Interval::new(-1., 4.).cos();
+        assert_eq!(cos.hi(), 1.);
+        assert_eq!(cos.lo(), -1.);
+        let sin = Interval::new(0.1, 0.2).sin();
+        assert!(sin.contains(0.15_f64.sin()));
+        assert!(sin.hi() < 0.2);
+        assert_eq!(Interval::new(1.5, 1.6).tan(), Interval::entire());
+        let angle = Interval::from(1.).atan2(Interval::new(-1., 1.));
+        assert!(angle.contains(core::f64::consts::FRAC_PI_4));
+        assert!(angle.contains(core::f64::consts::FRAC_PI_2));
+        assert!(angle.contains(3. * core::f64::consts::FRAC_PI_4));
+        assert!(Interval::from(0.).atan2(Interval::from(-1.)).contains(-3.));
+
+        // Remainders.
+        assert!((Interval::new(7.25, 7.5) % Interval::from(2.)).contains(1.25));
+        assert_eq!(
+            Interval::new(7.5, 8.5) % Interval::from(2.),
+            Interval::new(0., 2.)
+        );
+    }
+
+    #[test]
+    fn interval_comparisons() {
+        let a = Interval::new(1., 2.);
+        let b = Interval::new(1.5, 3.);
+        let c = Interval::new(2.5, 3.);
+        assert!(a < c);
+        assert!(c > a);
+        assert_eq!(a.partial_cmp(&b), None);
+        // Overlapping intervals are neither less, nor greater, nor equal.
+        assert!(![a < b, a > b, a == b, a <= b, a >= b].contains(&true));
+        assert_eq!(a.partial_cmp(&a), Some(core::cmp::Ordering::Equal));
+        assert_eq!(a.hull(c), Interval::new(1., 3.));
+    }
+
+    #[test]
+    fn interval_measures() {
+        let distance = Measure::<Metre, Interval>::new(Interval::new(99.5, 100.5));
+        let time = Measure::<Second, Interval>::new(Interval::new(9.9, 10.1));
+        let speed: Measure<MetrePerSecond, Interval> = distance / time;
+        assert!(speed.value.contains(99.5 / 10.1));
+        assert!(speed.value.contains(100.5 / 9.9));
+        assert!(speed.value.lo() < 99.5 / 10.1);
+        assert_eq!(distance.to_string(), "[99.5, 100.5] m");
+        assert_eq!(format!("{:.1}", distance), "[99.5, 100.5] m");
+        assert_eq!(
+            format!(
+                "{:e}",
+                Measure::<Metre, Interval>::new(Interval::new(1., 2.))
+            ),
+            "[1e0, 2e0] m"
+        );
+        assert_eq!(
+            format!("{:?}", Measure::<Metre, Interval>::new(Interval::from(1.5))),
+            "[1.5, 1.5] m"
+        );
+    }
+}
+
+#[cfg(feature = "simd")]
+mod simd {
+    use super::units::{
+        Degree, LinearMap3d, Measure, Measure3d, Metre, MetrePerSecond, Millimetre, Second,
+    };
+    use measures::simd::{f32x4, f32x8, f64x2, f64x4, Lanes};
+    use measures::traits::{HasOne, HasZero, LossyFrom, Sqrt, Trigonometry};
+
+    #[test]
+    fn simd_arithmetic() {
+        let lengths =
+            Measure::<Metre, f32x8>::new(f32x8::from_array([1., 2., 3., 4., 5., 6., 7., 8.]));
+        let millimetres = lengths.convert::<Millimetre>();
+        assert_eq!(
+            millimetres.value,
+            f32x8::from_array([1000., 2000., 3000., 4000., 5000., 6000., 7000., 8000.])
+        );
+        let times = Measure::<Second, f32x8>::new(f32x8::splat(2.));
+        let speeds: Measure<MetrePerSecond, f32x8> = lengths / times;
+        assert_eq!(
+            speeds.value.to_array(),
+            [0.5, 1., 1.5, 2., 2.5, 3., 3.5, 4.]
+        );
+        assert_eq!((lengths * f32x8::splat(2.)).value.lane(7), 16.);
+        assert_eq!((-lengths).value.lane(0), -1.);
+        assert_eq!(lengths.value.sum_lanes(), 36.);
+
+        let x = f64x4::from_array([0., 0.25, 0.5, 1.]);
+        let (sin, cos) = x.sin_cos();
+        let one = sin * sin + cos * cos;
+        for i in 0..4 {
+            assert!((one.lane(i) - 1.).abs() < 1e-15);
+            assert_eq!(x.sqrt().lane(i), x.lane(i).sqrt());
+        }
+
+        // A rotation applied to two vectors at once.
+        let rotation = LinearMap3d::<f64x2>::rotation(
+            Measure::<Degree, f64x2>::new(f64x2::from_array([90., 180.])),
+            Measure3d::<Metre, f64x2>::new([f64x2::ZERO, f64x2::ZERO, f64x2::ONE]),
+        );
+        let v = Measure3d::<Metre, f64x2>::new([f64x2::ONE, f64x2::ZERO, f64x2::ZERO]);
+        let rotated = rotation.apply_to(v);
+        assert!((rotated.values[0].lane(0)).abs() < 1e-15);
+        assert!((rotated.values[1].lane(0) - 1.).abs() < 1e-15);
+        assert!((rotated.values[0].lane(1) + 1.).abs() < 1e-15);
+        assert!((rotated.values[1].lane(1)).abs() < 1e-15);
+
+        let wide = Lanes::<f64, 4>::lossy_from(f32x4::from_array([1., 2., 3., 4.]));
+        assert_eq!(wide, f64x4::from_array([1., 2., 3., 4.]));
+    }
+
+    #[test]
+    fn simd_comparisons() {
+        let a = f32x4::from_array([1., 2., 3., 4.]);
+        let b = f32x4::from_array([2., 2., 2., 2.]);
+        assert_eq!(a.lanes_lt(b), [true, false, false, false]);
+        assert_eq!(a.lanes_le(b), [true, true, false, false]);
+        assert_eq!(a.lanes_eq(b), [false, true, false, false]);
+        assert_eq!(a.lanes_gt(b), [false, false, true, true]);
+        assert_eq!(a.lanes_ge(b), [false, true, true, true]);
+        assert_eq!(a.partial_cmp(&b), None);
+        assert!(a < a + f32x4::ONE);
+        assert!(a + f32x4::ONE > a);
+        assert_eq!(a.partial_cmp(&a), Some(core::cmp::Ordering::Equal));
+    }
+
+    #[test]
+    fn simd_formatting() {
+        let lengths = Measure::<Metre, f32x4>::new(f32x4::from_array([1.5, 2., 0.25, 4.]));
+        assert_eq!(lengths.to_string(), "[1.5, 2, 0.25, 4] m");
+        assert_eq!(format!("{:.1}", lengths), "[1.5, 2.0, 0.2, 4.0] m");
+        assert_eq!(format!("{:e}", f64x2::from_array([1., 20.])), "[1e0, 2e1]");
+        assert_eq!(format!("{:?}", f64x2::from_array([1., 2.])), "[1.0, 2.0]");
+    }
+}
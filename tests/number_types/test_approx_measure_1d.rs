@@ -0,0 +1,194 @@
+use crate::backends::{check_close, number, TestNumber};
+use measures::dimensionless::One;
+use units::{ApproxMeasure, Metre, Millimetre};
+
+mod units {
+    measures::define_measure_types! {
+        exact with_approx,
+        vector_properties [
+            Length [
+                Metre {
+                    suffix: " m",
+                }
+                Millimetre {
+                    suffix: " mm",
+                    ratio: 1e-3,
+                }
+            ]
+        ]
+    }
+}
+
+fn approx_measure_convert<Number: TestNumber>(tolerance: f64) {
+    let am1 = ApproxMeasure::<Metre, Number>::with_variance(number(12.), number(9.));
+    let am2: ApproxMeasure<Millimetre, Number> = am1.convert::<Millimetre>();
+    check_close(am1.value, 12., tolerance);
+    check_close(am1.variance, 9., tolerance);
+    check_close(am2.value, 12_000., tolerance);
+    check_close(am2.variance, 9_000_000., tolerance);
+}
+
+test_number_types!(approx_measure_convert);
+
+fn approx_measure_norm_positive<Number: TestNumber>(tolerance: f64) {
+    let am1 = ApproxMeasure::<Metre, Number>::with_variance(number(12.), number(9.));
+    let am2: ApproxMeasure<Metre, Number> = am1.norm();
+    check_close(am2.value, 12., tolerance);
+    check_close(am2.variance, 9., tolerance);
+}
+
+test_number_types!(approx_measure_norm_positive);
+
+fn approx_measure_norm_negative<Number: TestNumber>(tolerance: f64) {
+    let am1 = ApproxMeasure::<Metre, Number>::with_variance(number(-12.), number(9.));
+    let am2: ApproxMeasure<Metre, Number> = am1.norm();
+    check_close(am2.value, 12., tolerance);
+    check_close(am2.variance, 9., tolerance);
+}
+
+test_number_types!(approx_measure_norm_negative);
+
+fn approx_measure_norm_zero<Number: TestNumber>(tolerance: f64) {
+    let am1 = ApproxMeasure::<Metre, Number>::with_variance(number(0.), number(9.));
+    let am2: ApproxMeasure<Metre, Number> = am1.norm();
+    check_close(am2.value, 0., tolerance);
+    check_close(am2.variance, 9., tolerance);
+}
+
+test_number_types!(approx_measure_norm_zero);
+
+fn approx_measure_squared_norm_positive<Number: TestNumber>(tolerance: f64) {
+    let am1 = ApproxMeasure::<Metre, Number>::with_variance(number(12.), number(9.));
+    let am2: ApproxMeasure<One, Number> = am1.squared_norm();
+    check_close(am2.value, 12. * 12., tolerance);
+    check_close(am2.variance, 4. * 12. * 12. * 9., tolerance);
+}
+
+test_number_types!(approx_measure_squared_norm_positive);
+
+fn approx_measure_squared_norm_negative<Number: TestNumber>(tolerance: f64) {
+    let am1 = ApproxMeasure::<Metre, Number>::with_variance(number(-12.), number(9.));
+    let am2: ApproxMeasure<One, Number> = am1.squared_norm();
+    check_close(am2.value, 12. * 12., tolerance);
+    check_close(am2.variance, 4. * 12. * 12. * 9., tolerance);
+}
+
+test_number_types!(approx_measure_squared_norm_negative);
+
+fn approx_measure_squared_norm_zero<Number: TestNumber>(tolerance: f64) {
+    let am1 = ApproxMeasure::<Metre, Number>::with_variance(number(0.), number(9.));
+    let am2: ApproxMeasure<One, Number> = am1.squared_norm();
+    check_close(am2.value, 0., tolerance);
+    check_close(am2.variance, 0., tolerance);
+}
+
+test_number_types!(approx_measure_squared_norm_zero);
+
+fn approx_measure_normalized_positive<Number: TestNumber>(tolerance: f64) {
+    let am1 = ApproxMeasure::<Metre, Number>::with_variance(number(12.), number(9.));
+    let am2: ApproxMeasure<Metre, Number> = am1.normalized();
+    check_close(am2.value, 1., tolerance);
+    check_close(am2.variance, 9. / (12. * 12.), tolerance);
+}
+
+test_number_types!(approx_measure_normalized_positive);
+
+fn approx_measure_normalized_negative<Number: TestNumber>(tolerance: f64) {
+    let am1 = ApproxMeasure::<Metre, Number>::with_variance(number(-12.), number(9.));
+    let am2: ApproxMeasure<Metre, Number> = am1.normalized();
+    check_close(am2.value, -1., tolerance);
+    check_close(am2.variance, 9. / (12. * 12.), tolerance);
+}
+
+test_number_types!(approx_measure_normalized_negative);
+
+fn approx_measure_unary_minus<Number: TestNumber>(tolerance: f64) {
+    let am: ApproxMeasure<Metre, Number> =
+        -ApproxMeasure::<Metre, Number>::with_variance(number(12.), number(9.));
+    check_close(am.value, -12., tolerance);
+    check_close(am.variance, 9., tolerance);
+}
+
+test_number_types!(approx_measure_unary_minus);
+
+fn approx_measure_addition<Number: TestNumber>(tolerance: f64) {
+    let m1 = ApproxMeasure::<Metre, Number>::with_variance(number(12.), number(9.));
+    let m2 = ApproxMeasure::<Metre, Number>::with_variance(number(7.), number(4.));
+    let m3: ApproxMeasure<Metre, Number> = m1 + m2;
+    check_close(m3.value, 19., tolerance);
+    check_close(m3.variance, 9. + 4., tolerance);
+}
+
+test_number_types!(approx_measure_addition);
+
+fn approx_measure_addition_assignment<Number: TestNumber>(tolerance: f64) {
+    let mut am = ApproxMeasure::<Metre, Number>::with_variance(number(12.), number(9.));
+    am += ApproxMeasure::<Metre, Number>::with_variance(number(7.), number(4.));
+    check_close(am.value, 19., tolerance);
+    check_close(am.variance, 9. + 4., tolerance);
+}
+
+test_number_types!(approx_measure_addition_assignment);
+
+fn approx_measure_subtraction<Number: TestNumber>(tolerance: f64) {
+    let m1 = ApproxMeasure::<Metre, Number>::with_variance(number(12.), number(9.));
+    let m2 = ApproxMeasure::<Metre, Number>::with_variance(number(7.), number(4.));
+    let m3: ApproxMeasure<Metre, Number> = m1 - m2;
+    check_close(m3.value, 5., tolerance);
+    check_close(m3.variance, 9. + 4., tolerance);
+}
+
+test_number_types!(approx_measure_subtraction);
+
+fn approx_measure_subtraction_assignment<Number: TestNumber>(tolerance: f64) {
+    let mut am = ApproxMeasure::<Metre, Number>::with_variance(number(12.), number(9.));
+    am -= ApproxMeasure::<Metre, Number>::with_variance(number(7.), number(4.));
+    check_close(am.value, 5., tolerance);
+    check_close(am.variance, 9. + 4., tolerance);
+}
+
+test_number_types!(approx_measure_subtraction_assignment);
+
+fn approx_measure_scalar_multiplication_assignment<Number: TestNumber>(tolerance: f64) {
+    let mut am = ApproxMeasure::<Metre, Number>::with_variance(number(12.), number(9.));
+    am *= number::<Number>(5.);
+    check_close(am.value, 12. * 5., tolerance);
+    check_close(am.variance, 9. * (5. * 5.), tolerance);
+}
+
+test_number_types!(approx_measure_scalar_multiplication_assignment);
+
+fn approx_measure_scalar_division<Number: TestNumber>(tolerance: f64) {
+    let am: ApproxMeasure<Metre, Number> =
+        ApproxMeasure::<Metre, Number>::with_variance(number(12.), number(9.))
+            / number::<Number>(5.);
+    check_close(am.value, 12. / 5., tolerance);
+    check_close(am.variance, 9. / (5. * 5.), tolerance);
+}
+
+test_number_types!(approx_measure_scalar_division);
+
+fn approx_measure_scalar_division_assignment<Number: TestNumber>(tolerance: f64) {
+    let mut am = ApproxMeasure::<Metre, Number>::with_variance(number(12.), number(9.));
+    am /= number::<Number>(5.);
+    check_close(am.value, 12. / 5., tolerance);
+    check_close(am.variance, 9. / (5. * 5.), tolerance);
+}
+
+test_number_types!(approx_measure_scalar_division_assignment);
+
+fn approx_measure_measure_division<Number: TestNumber>(tolerance: f64) {
+    let am1: ApproxMeasure<Metre, Number> =
+        ApproxMeasure::<Metre, Number>::with_variance(number(12.), number(9.));
+    let am2: ApproxMeasure<Metre, Number> =
+        ApproxMeasure::<Metre, Number>::with_variance(number(5.), number(4.));
+    let am3: ApproxMeasure<One, Number> = am1 / am2;
+    check_close(am3.value, 2.4, tolerance);
+    let self_ratio = 9. / (12. * 12.);
+    let other_ratio = 4. / (5. * 5.);
+    let value_ratio = 12. / 5.;
+    let quotient_variance = value_ratio * value_ratio * (self_ratio + other_ratio);
+    check_close(am3.variance, quotient_variance, tolerance);
+}
+
+test_number_types!(approx_measure_measure_division);
@@ -0,0 +1,170 @@
+use crate::backends::{check_close, check_close_values, number, TestNumber};
+use measures::traits::ArithmeticOps;
+use units::{
+    euler_step, runge_kutta_45_integrate, runge_kutta_45_step, runge_kutta_4_step, Hertz, Measure,
+    Measure3d, MeasurePoint, MeasurePoint3d, Metre, MetrePerSecond, MetrePerSquareSecond, Second,
+};
+
+mod units {
+    measures::define_measure_types! {
+        with_points with_3d exact,
+        scalar_properties [
+            Time [
+                Second {
+                    suffix: " s",
+                }
+            ]
+            Frequency [
+                Hertz {
+                    suffix: " Hz",
+                }
+            ]
+        ]
+        vector_properties [
+            Length [
+                Metre {
+                    suffix: " m",
+                }
+            ]
+            Velocity [
+                MetrePerSecond {
+                    suffix: " m/s",
+                }
+            ]
+            Acceleration [
+                MetrePerSquareSecond {
+                    suffix: " m/s\u{b2}",
+                }
+            ]
+        ]
+        relationships [
+            Metre 1 == MetrePerSecond 1 * Second 1,
+            Metre 3 == MetrePerSecond 3 * Second 1,
+            MetrePerSecond 1 == MetrePerSquareSecond 1 * Second 1,
+            MetrePerSecond 3 == MetrePerSquareSecond 3 * Second 1,
+            MetrePerSecond 1 == Metre 1 * Hertz 1,
+        ]
+    }
+}
+
+// Exponential decay of a length: dx/dt = -x * 1 Hz.
+fn decay<Number: ArithmeticOps>(
+    _t: MeasurePoint<Second, Number>,
+    x: Measure<Metre, Number>,
+) -> Measure<MetrePerSecond, Number> {
+    -x * Measure::<Hertz, Number>::new(Number::ONE)
+}
+
+fn euler_step_1d<Number: TestNumber>(tolerance: f64) {
+    let x = euler_step(
+        decay,
+        MeasurePoint::new(Number::ZERO),
+        Measure::<Metre, Number>::new(number(8.)),
+        Measure::<Second, Number>::new(number(0.25)),
+    );
+    check_close(x.value, 6., tolerance);
+}
+
+test_number_types!(euler_step_1d);
+
+fn euler_step_3d<Number: TestNumber>(tolerance: f64) {
+    let position = euler_step(
+        |_, _| Measure3d::<MetrePerSecond, Number>::new([1., -2., 3.].map(number)),
+        MeasurePoint::<Second, Number>::new(Number::ZERO),
+        MeasurePoint3d::<Metre, Number>::new([10., 20., 30.].map(number)),
+        Measure::<Second, Number>::new(number(2.)),
+    );
+    check_close_values(position.values, [12., 16., 36.], tolerance);
+}
+
+test_number_types!(euler_step_3d);
+
+fn runge_kutta_4_step_1d<Number: TestNumber>(tolerance: f64) {
+    let mut time = MeasurePoint::<Second, Number>::new(Number::ZERO);
+    let mut x = Measure::<Metre, Number>::new(Number::ONE);
+    let step = Measure::<Second, Number>::new(number(0.1));
+    for _ in 0..10 {
+        x = runge_kutta_4_step(decay, time, x, step);
+        time += step;
+    }
+    check_close(x.value, (-1_f64).exp(), tolerance.max(1e-6));
+}
+
+test_number_types!(runge_kutta_4_step_1d);
+
+fn runge_kutta_4_step_tuple<Number: TestNumber>(tolerance: f64) {
+    // Free fall, with state (position, velocity) and derivative (velocity, acceleration).
+    let gravity = Measure3d::<MetrePerSquareSecond, Number>::new([0., 0., -10.].map(number));
+    let mut time = MeasurePoint::<Second, Number>::new(Number::ZERO);
+    let mut state = (
+        MeasurePoint3d::<Metre, Number>::new([0., 0., 100.].map(number)),
+        Measure3d::<MetrePerSecond, Number>::new([3., 0., 0.].map(number)),
+    );
+    let step = Measure::<Second, Number>::new(number(0.5));
+    for _ in 0..4 {
+        state = runge_kutta_4_step(|_, (_, velocity)| (velocity, gravity), time, state, step);
+        time += step;
+    }
+    check_close_values(state.0.values, [6., 0., 80.], tolerance);
+    check_close_values(state.1.values, [3., 0., -20.], tolerance);
+}
+
+test_number_types!(runge_kutta_4_step_tuple);
+
+fn runge_kutta_45_step_1d<Number: TestNumber>(tolerance: f64) {
+    // The required accuracy is limited by the precision of the number type.
+    let accuracy = tolerance.max(1e-9);
+    let (taken_step, x, next_step) = runge_kutta_45_step(
+        decay,
+        MeasurePoint::new(Number::ZERO),
+        Measure::<Metre, Number>::new(Number::ONE),
+        Measure::<Second, Number>::new(Number::ONE),
+        Measure::<Metre, Number>::new(number(accuracy)),
+    )
+    .unwrap();
+    assert!(taken_step.value < Number::ONE);
+    assert!(next_step.value > Number::ZERO);
+    check_close(x.value, (-taken_step.value.to_f64()).exp(), accuracy);
+}
+
+test_number_types!(runge_kutta_45_step_1d, ordered);
+
+fn runge_kutta_45_integrate_tuple<Number: TestNumber>(tolerance: f64) {
+    let accuracy = tolerance.max(1e-9);
+    let gravity = Measure3d::<MetrePerSquareSecond, Number>::new([0., 0., -10.].map(number));
+    let state = runge_kutta_45_integrate(
+        |_, (_, velocity)| (velocity, gravity),
+        MeasurePoint::<Second, Number>::new(Number::ZERO),
+        (
+            MeasurePoint3d::<Metre, Number>::new([0., 0., 100.].map(number)),
+            Measure3d::<MetrePerSecond, Number>::new([3., 0., 0.].map(number)),
+        ),
+        MeasurePoint::<Second, Number>::new(number(2.)),
+        Measure::<Second, Number>::new(number(0.1)),
+        (
+            Measure::<Metre, Number>::new(number(accuracy)),
+            Measure::<MetrePerSecond, Number>::new(number(accuracy)),
+        ),
+    )
+    .unwrap();
+    check_close_values(state.0.values, [6., 0., 80.], tolerance);
+    check_close_values(state.1.values, [3., 0., -20.], tolerance);
+}
+
+test_number_types!(runge_kutta_45_integrate_tuple);
+
+fn runge_kutta_45_integrate_1d<Number: TestNumber>(tolerance: f64) {
+    let accuracy = tolerance.max(1e-10);
+    let x = runge_kutta_45_integrate(
+        decay,
+        MeasurePoint::new(Number::ZERO),
+        Measure::<Metre, Number>::new(Number::ONE),
+        MeasurePoint::new(number(5.)),
+        Measure::<Second, Number>::new(number(0.1)),
+        Measure::<Metre, Number>::new(number(accuracy)),
+    )
+    .unwrap();
+    check_close(x.value, (-5_f64).exp(), accuracy * 100.);
+}
+
+test_number_types!(runge_kutta_45_integrate_1d, ordered);
@@ -0,0 +1,344 @@
+use crate::backends::{check_close_values, number, TestNumber};
+use measures::dimensionless::One;
+use units::{Degree, LinearMap3d, Measure, Measure3d, Metre};
+
+mod units {
+    measures::define_measure_types! {
+        with_3d with_transformations exact,
+        vector_properties [
+            Length [
+                Metre {
+                    suffix: " m",
+                }
+            ]
+        ]
+        angle_measurement_units [
+            Degree {
+                suffix: " deg",
+                cycle_fraction: 360.,
+            }
+        ]
+    }
+}
+
+// N.B.: Linear maps have no translations.
+
+// Rotations
+
+fn linear_map_3d_rotation_around_x_axis<Number: TestNumber>(tolerance: f64) {
+    let m1 = Measure3d::<Metre, Number>::new([8., 5., -2.].map(number));
+    let lm1 = LinearMap3d::rotation(
+        Measure::<Degree, Number>::new(number(90.)),
+        Measure3d::<One, Number>::new([1., 0., 0.].map(number)),
+    );
+    let m2 = lm1.apply_to(m1);
+    check_close_values(m2.values, [8., 2., 5.], tolerance);
+}
+
+test_number_types!(linear_map_3d_rotation_around_x_axis);
+
+fn linear_map_3d_rotation_around_y_axis<Number: TestNumber>(tolerance: f64) {
+    let m1 = Measure3d::<Metre, Number>::new([8., 5., -2.].map(number));
+    let lm1 = LinearMap3d::rotation(
+        Measure::<Degree, Number>::new(number(90.)),
+        Measure3d::<One, Number>::new([0., 1., 0.].map(number)),
+    );
+    let m2 = lm1.apply_to(m1);
+    check_close_values(m2.values, [-2., 5., -8.], tolerance);
+}
+
+test_number_types!(linear_map_3d_rotation_around_y_axis);
+
+fn linear_map_3d_rotation_around_z_axis<Number: TestNumber>(tolerance: f64) {
+    let m1 = Measure3d::<Metre, Number>::new([8., 5., -2.].map(number));
+    let lm1 = LinearMap3d::rotation(
+        Measure::<Degree, Number>::new(number(90.)),
+        Measure3d::<One, Number>::new([0., 0., 1.].map(number)),
+    );
+    let m2 = lm1.apply_to(m1);
+    check_close_values(m2.values, [-5., 8., -2.], tolerance);
+}
+
+test_number_types!(linear_map_3d_rotation_around_z_axis);
+
+fn linear_map_3d_rotation_by_angle<Number: TestNumber>(tolerance: f64) {
+    let m1 = Measure3d::<Metre, Number>::new([8., 5., -2.].map(number));
+    let lm1 = LinearMap3d::rotation(
+        Measure::<Degree, Number>::new(number(30.)),
+        Measure3d::<One, Number>::new([2., 3., 4.].map(number)).normalized(),
+    );
+    let m2 = lm1.apply_to(m1);
+    check_close_values(
+        m2.values,
+        [4.726675262453692, 7.9914102488289105, -2.6068953178485286],
+        tolerance,
+    );
+}
+
+test_number_types!(linear_map_3d_rotation_by_angle);
+
+fn linear_map_3d_rotation_as_reflection<Number: TestNumber>(tolerance: f64) {
+    let m1 = Measure3d::<Metre, Number>::new([8.1, 5.2, -2.3].map(number));
+    let direction = Measure3d::<One, Number>::new([-2.3, 1.7, 0.4].map(number)).normalized();
+    let rotation = LinearMap3d::rotation(Measure::<Degree, Number>::new(number(180.)), direction);
+    let rotated = rotation.apply_to(m1);
+    let reflection = LinearMap3d::reflection_over_line(direction);
+    let reflected = reflection.apply_to(m1);
+    check_close_values(
+        rotated.values,
+        reflected.values.map(TestNumber::to_f64),
+        tolerance,
+    );
+}
+
+test_number_types!(linear_map_3d_rotation_as_reflection);
+
+// Projections
+
+fn linear_map_3d_projection_onto_x_axis<Number: TestNumber>(tolerance: f64) {
+    let m1 = Measure3d::<Metre, Number>::new([8., 5., -2.].map(number));
+    let lm1 =
+        LinearMap3d::projection_onto_line(Measure3d::<One, Number>::new([1., 0., 0.].map(number)));
+    let m2 = lm1.apply_to(m1);
+    check_close_values(m2.values, [8., 0., 0.], tolerance);
+}
+
+test_number_types!(linear_map_3d_projection_onto_x_axis);
+
+fn linear_map_3d_projection_onto_y_axis<Number: TestNumber>(tolerance: f64) {
+    let m1 = Measure3d::<Metre, Number>::new([8., 5., -2.].map(number));
+    let lm1 =
+        LinearMap3d::projection_onto_line(Measure3d::<One, Number>::new([0., 1., 0.].map(number)));
+    let m2 = lm1.apply_to(m1);
+    check_close_values(m2.values, [0., 5., 0.], tolerance);
+}
+
+test_number_types!(linear_map_3d_projection_onto_y_axis);
+
+fn linear_map_3d_projection_onto_z_axis<Number: TestNumber>(tolerance: f64) {
+    let m1 = Measure3d::<Metre, Number>::new([8., 5., -2.].map(number));
+    let lm1 =
+        LinearMap3d::projection_onto_line(Measure3d::<One, Number>::new([0., 0., 1.].map(number)));
+    let m2 = lm1.apply_to(m1);
+    check_close_values(m2.values, [0., 0., -2.], tolerance);
+}
+
+test_number_types!(linear_map_3d_projection_onto_z_axis);
+
+fn linear_map_3d_projection_onto_line<Number: TestNumber>(tolerance: f64) {
+    let m1 = Measure3d::<Metre, Number>::new([8., 5., -2.].map(number));
+    let lm1 = LinearMap3d::projection_onto_line(
+        Measure3d::<One, Number>::new([2., 3., 4.].map(number)).normalized(),
+    );
+    let m2 = lm1.apply_to(m1);
+    check_close_values(
+        m2.values,
+        [1.5862068965517244, 2.3793103448275863, 3.172413793103449],
+        tolerance,
+    );
+}
+
+test_number_types!(linear_map_3d_projection_onto_line);
+
+fn linear_map_3d_projection_onto_yz_plane<Number: TestNumber>(tolerance: f64) {
+    let m1 = Measure3d::<Metre, Number>::new([8., 5., -2.].map(number));
+    let lm1 =
+        LinearMap3d::projection_onto_plane(Measure3d::<One, Number>::new([1., 0., 0.].map(number)));
+    let m2 = lm1.apply_to(m1);
+    check_close_values(m2.values, [0., 5., -2.], tolerance);
+}
+
+test_number_types!(linear_map_3d_projection_onto_yz_plane);
+
+fn linear_map_3d_projection_onto_xz_plane<Number: TestNumber>(tolerance: f64) {
+    let m1 = Measure3d::<Metre, Number>::new([8., 5., -2.].map(number));
+    let lm1 =
+        LinearMap3d::projection_onto_plane(Measure3d::<One, Number>::new([0., 1., 0.].map(number)));
+    let m2 = lm1.apply_to(m1);
+    check_close_values(m2.values, [8., 0., -2.], tolerance);
+}
+
+test_number_types!(linear_map_3d_projection_onto_xz_plane);
+
+fn linear_map_3d_projection_onto_xy_plane<Number: TestNumber>(tolerance: f64) {
+    let m1 = Measure3d::<Metre, Number>::new([8., 5., -2.].map(number));
+    let lm1 =
+        LinearMap3d::projection_onto_plane(Measure3d::<One, Number>::new([0., 0., 1.].map(number)));
+    let m2 = lm1.apply_to(m1);
+    check_close_values(m2.values, [8., 5., 0.], tolerance);
+}
+
+test_number_types!(linear_map_3d_projection_onto_xy_plane);
+
+fn linear_map_3d_projection_onto_plane<Number: TestNumber>(tolerance: f64) {
+    let m1 = Measure3d::<Metre, Number>::new([8., 5., -2.].map(number));
+    let lm1 = LinearMap3d::projection_onto_plane(
+        Measure3d::<One, Number>::new([2., 3., 4.].map(number)).normalized(),
+    );
+    let m2 = lm1.apply_to(m1);
+    check_close_values(
+        m2.values,
+        [6.413793103448276, 2.620689655172414, -5.172413793103448],
+        tolerance,
+    );
+}
+
+test_number_types!(linear_map_3d_projection_onto_plane);
+
+fn linear_map_3d_projection_onto_plane_plus_onto_line<Number: TestNumber>(tolerance: f64) {
+    let m1 = Measure3d::<Metre, Number>::new([8., 5., -2.].map(number));
+    let unit_vector = Measure3d::<One, Number>::new([2., 3., 4.].map(number)).normalized();
+    let projector_onto_line = LinearMap3d::projection_onto_line(unit_vector);
+    let projector_onto_plane = LinearMap3d::projection_onto_plane(unit_vector);
+    let projected_onto_line = projector_onto_line.apply_to(m1);
+    let projected_onto_plane = projector_onto_plane.apply_to(m1);
+    let estimate = projected_onto_line + projected_onto_plane;
+    check_close_values(
+        m1.values,
+        estimate.values.map(TestNumber::to_f64),
+        tolerance,
+    );
+}
+
+test_number_types!(linear_map_3d_projection_onto_plane_plus_onto_line);
+
+// Reflections
+
+fn linear_map_3d_reflection_over_x_axis<Number: TestNumber>(tolerance: f64) {
+    let m1 = Measure3d::<Metre, Number>::new([8., 5., -2.].map(number));
+    let lm1 =
+        LinearMap3d::reflection_over_line(Measure3d::<One, Number>::new([1., 0., 0.].map(number)));
+    let m2 = lm1.apply_to(m1);
+    check_close_values(m2.values, [8., -5., 2.], tolerance);
+}
+
+test_number_types!(linear_map_3d_reflection_over_x_axis);
+
+fn linear_map_3d_reflection_over_y_axis<Number: TestNumber>(tolerance: f64) {
+    let m1 = Measure3d::<Metre, Number>::new([8., 5., -2.].map(number));
+    let lm1 =
+        LinearMap3d::reflection_over_line(Measure3d::<One, Number>::new([0., 1., 0.].map(number)));
+    let m2 = lm1.apply_to(m1);
+    check_close_values(m2.values, [-8., 5., 2.], tolerance);
+}
+
+test_number_types!(linear_map_3d_reflection_over_y_axis);
+
+fn linear_map_3d_reflection_over_z_axis<Number: TestNumber>(tolerance: f64) {
+    let m1 = Measure3d::<Metre, Number>::new([8., 5., -2.].map(number));
+    let lm1 =
+        LinearMap3d::reflection_over_line(Measure3d::<One, Number>::new([0., 0., 1.].map(number)));
+    let m2 = lm1.apply_to(m1);
+    check_close_values(m2.values, [-8., -5., -2.], tolerance);
+}
+
+test_number_types!(linear_map_3d_reflection_over_z_axis);
+
+fn linear_map_3d_reflection_over_line<Number: TestNumber>(tolerance: f64) {
+    let m1 = Measure3d::<Metre, Number>::new([8., 5., -2.].map(number));
+    let lm1 = LinearMap3d::reflection_over_line(
+        Measure3d::<One, Number>::new([2., 3., 4.].map(number)).normalized(),
+    );
+    let m2 = lm1.apply_to(m1);
+    check_close_values(
+        m2.values,
+        [-4.827586206896552, -0.24137931034482762, 8.344827586206897],
+        tolerance,
+    );
+}
+
+test_number_types!(linear_map_3d_reflection_over_line);
+
+fn linear_map_3d_reflection_over_yz_plane<Number: TestNumber>(tolerance: f64) {
+    let m1 = Measure3d::<Metre, Number>::new([8., 5., -2.].map(number));
+    let lm1 =
+        LinearMap3d::reflection_over_plane(Measure3d::<One, Number>::new([1., 0., 0.].map(number)));
+    let m2 = lm1.apply_to(m1);
+    check_close_values(m2.values, [-8., 5., -2.], tolerance);
+}
+
+test_number_types!(linear_map_3d_reflection_over_yz_plane);
+
+fn linear_map_3d_reflection_over_xz_plane<Number: TestNumber>(tolerance: f64) {
+    let m1 = Measure3d::<Metre, Number>::new([8., 5., -2.].map(number));
+    let lm1 =
+        LinearMap3d::reflection_over_plane(Measure3d::<One, Number>::new([0., 1., 0.].map(number)));
+    let m2 = lm1.apply_to(m1);
+    check_close_values(m2.values, [8., -5., -2.], tolerance);
+}
+
+test_number_types!(linear_map_3d_reflection_over_xz_plane);
+
+fn linear_map_3d_reflection_over_xy_plane<Number: TestNumber>(tolerance: f64) {
+    let m1 = Measure3d::<Metre, Number>::new([8., 5., -2.].map(number));
+    let lm1 =
+        LinearMap3d::reflection_over_plane(Measure3d::<One, Number>::new([0., 0., 1.].map(number)));
+    let m2 = lm1.apply_to(m1);
+    check_close_values(m2.values, [8., 5., 2.], tolerance);
+}
+
+test_number_types!(linear_map_3d_reflection_over_xy_plane);
+
+fn linear_map_3d_reflection_over_plane<Number: TestNumber>(tolerance: f64) {
+    let m1 = Measure3d::<Metre, Number>::new([8., 5., -2.].map(number));
+    let lm1 = LinearMap3d::reflection_over_plane(
+        Measure3d::<One, Number>::new([2., 3., 4.].map(number)).normalized(),
+    );
+    let m2 = lm1.apply_to(m1);
+    check_close_values(
+        m2.values,
+        [4.827586206896552, 0.24137931034482762, -8.344827586206897],
+        tolerance,
+    );
+}
+
+test_number_types!(linear_map_3d_reflection_over_plane);
+
+// Inversion
+
+fn linear_map_3d_inverted<Number: TestNumber>(tolerance: f64) {
+    let m = Measure3d::<Metre, Number>::new([8., 5., -2.].map(number));
+    let lm = LinearMap3d::<Number>::new(
+        [[1.2, 0.8, 4.7], [3.4, -1.3, 0.6], [9.1, -5., 0.2]].map(|row| row.map(number)),
+    );
+    let inverse_of_lm = lm.inverted();
+    let transformed = lm.apply_to(m);
+    assert!(m != transformed);
+    let transformed_back = inverse_of_lm.apply_to(transformed);
+    check_close_values(transformed_back.values, [8., 5., -2.], tolerance);
+}
+
+test_number_types!(linear_map_3d_inverted);
+
+fn linear_map_3d_combined_with<Number: TestNumber>(tolerance: f64) {
+    let m1 = Measure3d::<Metre, Number>::new([8., 5., -2.].map(number));
+    let lm1 = LinearMap3d::<Number>::new(
+        [[1.2, 0.8, 4.7], [3.4, -1.3, 0.6], [1.1, -5., 0.2]].map(|row| row.map(number)),
+    );
+    let lm2 = LinearMap3d::<Number>::new(
+        [[8.3, 1.2, 7.4], [-6.1, 0.4, -7.7], [4., 5.2, -3.1]].map(|row| row.map(number)),
+    );
+
+    // To the original vector, first a transformation is applied,
+    // which represents the application of lm2 and then of lm1.
+    let lm2_and_then_lm1 = lm1.combined_with(&lm2);
+    let m2 = lm2_and_then_lm1.apply_to(m1);
+
+    // To the resulting vector, another transformation is applied,
+    // which represents the application of lm1 inverted and then of lm2 inverted.
+    let lm1_inverted_and_then_lm2_inverted = lm2.inverted().combined_with(&lm1.inverted());
+    let m3 = lm1_inverted_and_then_lm2_inverted.apply_to(m2);
+
+    // The original vector should be obtained.
+    check_close_values(m3.values, [8., 5., -2.], tolerance);
+
+    // If lm1 and lm2 are swapped, and also their inverses are swapped,
+    // the same result should be obtained.
+    let lm1_and_then_lm2 = lm2.combined_with(&lm1);
+    let m3 = lm1_and_then_lm2.apply_to(m1);
+    let lm2_inverted_and_then_lm1_inverted = lm1.inverted().combined_with(&lm2.inverted());
+    let m4 = lm2_inverted_and_then_lm1_inverted.apply_to(m3);
+    check_close_values(m4.values, [8., 5., -2.], tolerance);
+}
+
+test_number_types!(linear_map_3d_combined_with);
@@ -0,0 +1,308 @@
+use crate::backends::{check_close, number, BasicTestNumber, TestNumber};
+use measures::{dimensionless::One, traits::Trigonometry};
+use units::{Degree, Measure, Metre, Millimetre};
+
+mod units {
+    measures::define_measure_types! {
+        exact,
+        vector_properties [
+            Length [
+                Metre {
+                    suffix: " m",
+                }
+                Millimetre {
+                    suffix: " mm",
+                    ratio: 1e-3,
+                }
+            ]
+        ]
+        angle_measurement_units [
+            Degree {
+                suffix: " deg",
+                cycle_fraction: 360.,
+            }
+        ]
+    }
+}
+
+fn measure_1d_convert<Number: TestNumber>(tolerance: f64) {
+    let m1 = Measure::<Metre, Number>::new(number(12.));
+    let m2: Measure<Millimetre, Number> = m1.convert::<Millimetre>();
+    check_close(m1.value, 12., tolerance);
+    check_close(m2.value, 12000., tolerance);
+    check_close(m2.convert::<Metre>().value, 12., tolerance);
+}
+
+test_number_types!(measure_1d_convert);
+
+fn measure_1d_norm_positive<Number: BasicTestNumber>(n: fn(i16) -> Number) {
+    let m1 = Measure::<Metre, Number>::new(n(12));
+    let m2: Measure<Metre, Number> = m1.norm();
+    assert_eq!(m2.value, n(12));
+}
+
+test_basic_number_types!(measure_1d_norm_positive);
+
+fn measure_1d_norm_negative<Number: BasicTestNumber>(n: fn(i16) -> Number) {
+    let m1 = Measure::<Metre, Number>::new(n(-12));
+    let m2: Measure<Metre, Number> = m1.norm();
+    assert_eq!(m2.value, n(12));
+}
+
+test_basic_number_types!(measure_1d_norm_negative);
+
+fn measure_1d_norm_zero<Number: BasicTestNumber>(n: fn(i16) -> Number) {
+    let m1 = Measure::<Metre, Number>::new(n(0));
+    let m2: Measure<Metre, Number> = m1.norm();
+    assert_eq!(m2.value, n(0));
+}
+
+test_basic_number_types!(measure_1d_norm_zero);
+
+fn measure_1d_squared_norm_positive<Number: BasicTestNumber>(n: fn(i16) -> Number) {
+    let m1 = Measure::<Metre, Number>::new(n(12));
+    let m2: Number = m1.squared_norm();
+    assert_eq!(m2, n(12 * 12));
+}
+
+test_basic_number_types!(measure_1d_squared_norm_positive);
+
+fn measure_1d_squared_norm_negative<Number: BasicTestNumber>(n: fn(i16) -> Number) {
+    let m1 = Measure::<Metre, Number>::new(n(-12));
+    let m2: Number = m1.squared_norm();
+    assert_eq!(m2, n(12 * 12));
+}
+
+test_basic_number_types!(measure_1d_squared_norm_negative);
+
+fn measure_1d_squared_norm_zero<Number: BasicTestNumber>(n: fn(i16) -> Number) {
+    let m1 = Measure::<Metre, Number>::new(n(0));
+    let m2: Number = m1.squared_norm();
+    assert_eq!(m2, n(0));
+}
+
+test_basic_number_types!(measure_1d_squared_norm_zero);
+
+fn measure_1d_min<Number: BasicTestNumber>(n: fn(i16) -> Number) {
+    let m1 = Measure::<Metre, Number>::new(n(12));
+    let m2 = Measure::<Metre, Number>::new(n(13));
+    let m3: Measure<Metre, Number> = m1.min(m2);
+    let m4: Measure<Metre, Number> = m2.min(m1);
+    let m5: Measure<Metre, Number> = m1.min(m1);
+    assert_eq!(m3.value, n(12));
+    assert_eq!(m4.value, n(12));
+    assert_eq!(m5.value, n(12));
+}
+
+test_basic_number_types!(measure_1d_min);
+
+fn measure_1d_max<Number: BasicTestNumber>(n: fn(i16) -> Number) {
+    let m1 = Measure::<Metre, Number>::new(n(12));
+    let m2 = Measure::<Metre, Number>::new(n(13));
+    let m3: Measure<Metre, Number> = m1.max(m2);
+    let m4: Measure<Metre, Number> = m2.max(m1);
+    let m5: Measure<Metre, Number> = m1.max(m1);
+    assert_eq!(m3.value, n(13));
+    assert_eq!(m4.value, n(13));
+    assert_eq!(m5.value, n(12));
+}
+
+test_basic_number_types!(measure_1d_max);
+
+fn measure_1d_clamp<Number: BasicTestNumber>(n: fn(i16) -> Number) {
+    let m1 = Measure::<Metre, Number>::new(n(12));
+    let m2 = Measure::<Metre, Number>::new(n(13));
+    let m3 = Measure::<Metre, Number>::new(n(14));
+    assert_eq!(m1.clamp(m2, m3), m2);
+    assert_eq!(m1.clamp(m3, m2), m2);
+    assert_eq!(m2.clamp(m1, m3), m2);
+    assert_eq!(m2.clamp(m3, m1), m2);
+    assert_eq!(m3.clamp(m1, m2), m2);
+    assert_eq!(m3.clamp(m2, m1), m2);
+}
+
+test_basic_number_types!(measure_1d_clamp);
+
+fn measure_1d_default<Number: BasicTestNumber>(n: fn(i16) -> Number) {
+    let m: Measure<Metre, Number> = Measure::default();
+    assert_eq!(m.value, n(0));
+}
+
+test_basic_number_types!(measure_1d_default);
+
+fn measure_1d_unary_minus<Number: BasicTestNumber>(n: fn(i16) -> Number) {
+    let m1 = -Measure::<Metre, Number>::new(n(12));
+    assert_eq!(m1.value, n(-12));
+    let m2 = -Measure::<Metre, Number>::new(n(-13));
+    assert_eq!(m2.value, n(13));
+    let m3 = -Measure::<Metre, Number>::new(n(0));
+    assert_eq!(m3.value, n(0));
+}
+
+test_basic_number_types!(measure_1d_unary_minus);
+
+fn measure_1d_addition<Number: BasicTestNumber>(n: fn(i16) -> Number) {
+    let m1 = Measure::<Metre, Number>::new(n(12));
+    let m2 = Measure::<Metre, Number>::new(n(7));
+    let m3: Measure<Metre, Number> = m1 + m2;
+    assert_eq!(m3.value, n(19));
+    let m4 = Measure::<Metre, Number>::new(n(-7));
+    let m5: Measure<Metre, Number> = m1 + m4;
+    assert_eq!(m5.value, n(5));
+}
+
+test_basic_number_types!(measure_1d_addition);
+
+fn measure_1d_addition_assignment<Number: BasicTestNumber>(n: fn(i16) -> Number) {
+    let mut m = Measure::<Metre, Number>::new(n(12));
+    m += Measure::<Metre, Number>::new(n(7));
+    assert_eq!(m.value, n(19));
+    m += Measure::<Metre, Number>::new(n(-3));
+    assert_eq!(m.value, n(16));
+}
+
+test_basic_number_types!(measure_1d_addition_assignment);
+
+fn measure_1d_subtraction<Number: BasicTestNumber>(n: fn(i16) -> Number) {
+    let m1 = Measure::<Metre, Number>::new(n(12));
+    let m2 = Measure::<Metre, Number>::new(n(7));
+    let m3: Measure<Metre, Number> = m1 - m2;
+    assert_eq!(m3.value, n(5));
+    let m4 = Measure::<Metre, Number>::new(n(-7));
+    let m5: Measure<Metre, Number> = m1 - m4;
+    assert_eq!(m5.value, n(19));
+}
+
+test_basic_number_types!(measure_1d_subtraction);
+
+fn measure_1d_subtraction_assignment<Number: BasicTestNumber>(n: fn(i16) -> Number) {
+    let mut m = Measure::<Metre, Number>::new(n(12));
+    m -= Measure::<Metre, Number>::new(n(7));
+    assert_eq!(m.value, n(5));
+    m -= Measure::<Metre, Number>::new(n(-3));
+    assert_eq!(m.value, n(8));
+}
+
+test_basic_number_types!(measure_1d_subtraction_assignment);
+
+fn measure_1d_scalar_post_multiplication<Number: BasicTestNumber>(n: fn(i16) -> Number) {
+    let m = Measure::<Metre, Number>::new(n(12)) * n(3);
+    assert_eq!(m.value, n(36));
+}
+
+test_basic_number_types!(measure_1d_scalar_post_multiplication);
+
+fn measure_1d_one_post_multiplication<Number: BasicTestNumber>(n: fn(i16) -> Number) {
+    let m = Measure::<Metre, Number>::new(n(12)) * Measure::<One, Number>::new(n(3));
+    assert_eq!(m.value, n(36));
+}
+
+test_basic_number_types!(measure_1d_one_post_multiplication);
+
+fn measure_1d_one_pre_multiplication<Number: TestNumber>(tolerance: f64) {
+    let m = Measure::<One, Number>::new(number(12.)) * Measure::<Metre, Number>::new(number(3.));
+    check_close(m.value, 36., tolerance);
+}
+
+test_number_types!(measure_1d_one_pre_multiplication);
+
+fn measure_1d_one_one_multiplication<Number: BasicTestNumber>(n: fn(i16) -> Number) {
+    let m = Measure::<One, Number>::new(n(12)) * Measure::<One, Number>::new(n(3));
+    assert_eq!(m.value, n(36));
+}
+
+test_basic_number_types!(measure_1d_one_one_multiplication);
+
+fn measure_1d_scalar_multiplication_assignment<Number: BasicTestNumber>(n: fn(i16) -> Number) {
+    let mut m = Measure::<Metre, Number>::new(n(12));
+    m *= n(3);
+    assert_eq!(m.value, n(36));
+}
+
+test_basic_number_types!(measure_1d_scalar_multiplication_assignment);
+
+fn measure_1d_one_multiplication_assignment<Number: BasicTestNumber>(n: fn(i16) -> Number) {
+    let mut m = Measure::<Metre, Number>::new(n(12));
+    m *= Measure::<One, Number>::new(n(3));
+    assert_eq!(m.value, n(36));
+}
+
+test_basic_number_types!(measure_1d_one_multiplication_assignment);
+
+fn measure_1d_scalar_division<Number: BasicTestNumber>(n: fn(i16) -> Number) {
+    let m = Measure::<Metre, Number>::new(n(12)) / n(3);
+    assert_eq!(m.value, n(4));
+}
+
+test_basic_number_types!(measure_1d_scalar_division);
+
+fn measure_1d_measure_division<Number: BasicTestNumber>(n: fn(i16) -> Number) {
+    let m1 = Measure::<Metre, Number>::new(n(12));
+    let m2 = Measure::<Metre, Number>::new(n(3));
+    let m3: Measure<One, Number> = m1 / m2;
+    assert_eq!(m3.value, n(4));
+}
+
+test_basic_number_types!(measure_1d_measure_division);
+
+fn measure_1d_one_division<Number: TestNumber>(tolerance: f64) {
+    let m1 = Measure::<Metre, Number>::new(number(12.));
+    let m2 = Measure::<One, Number>::new(number(3.));
+    let m3: Measure<Metre, Number> = m1 / m2;
+    check_close(m3.value, 4., tolerance);
+}
+
+test_number_types!(measure_1d_one_division);
+
+fn measure_1d_one_one_division<Number: BasicTestNumber>(n: fn(i16) -> Number) {
+    let m1 = Measure::<One, Number>::new(n(12));
+    let m2 = Measure::<One, Number>::new(n(3));
+    let m3: Measure<One, Number> = m1 / m2;
+    assert_eq!(m3.value, n(4));
+}
+
+test_basic_number_types!(measure_1d_one_one_division);
+
+fn measure_1d_scalar_division_assignment<Number: BasicTestNumber>(n: fn(i16) -> Number) {
+    let mut m = Measure::<Metre, Number>::new(n(12));
+    m /= n(3);
+    assert_eq!(m.value, n(4));
+}
+
+test_basic_number_types!(measure_1d_scalar_division_assignment);
+
+fn measure_1d_one_division_assignment<Number: BasicTestNumber>(n: fn(i16) -> Number) {
+    let mut m = Measure::<Metre, Number>::new(n(12));
+    m /= Measure::<One, Number>::new(n(3));
+    assert_eq!(m.value, n(4));
+}
+
+test_basic_number_types!(measure_1d_one_division_assignment);
+
+fn measure_1d_trigonometry<Number: TestNumber>(tolerance: f64) {
+    let half_sqrt = 1. / 2_f64.sqrt();
+    let three_sqrt = 3_f64.sqrt();
+    let check_angle = |degrees: f64, sin: f64, cos: f64| {
+        let m = Measure::<Degree, Number>::new(number(degrees));
+        check_close(m.cos(), cos, tolerance);
+        check_close(m.sin(), sin, tolerance);
+        check_close(m.tan(), sin / cos, tolerance);
+        check_close(m.sin_cos().0, sin, tolerance);
+        check_close(m.sin_cos().1, cos, tolerance);
+    };
+
+    check_angle(0., 0., 1.);
+    check_angle(30., 0.5, three_sqrt * 0.5);
+    check_angle(45., half_sqrt, half_sqrt);
+    check_angle(60., three_sqrt * 0.5, 0.5);
+    check_angle(-45., -half_sqrt, half_sqrt);
+    check_angle(-135., -half_sqrt, -half_sqrt);
+
+    let m = Measure::<Degree, Number>::new(number(90.));
+    check_close(m.cos(), 0., tolerance);
+    check_close(m.sin(), 1., tolerance);
+    check_close(m.sin_cos().0, 1., tolerance);
+    check_close(m.sin_cos().1, 0., tolerance);
+}
+
+test_number_types!(measure_1d_trigonometry);
@@ -0,0 +1,319 @@
+use crate::backends::{check_close, check_close_values, number, TestNumber};
+use measures::dimensionless::One;
+use units::{Measure, Measure3d, Metre, Millimetre};
+
+mod units {
+    measures::define_measure_types! {
+        with_3d exact,
+        vector_properties [
+            Length [
+                Metre {
+                    suffix: " m",
+                }
+                Millimetre {
+                    suffix: " mm",
+                    ratio: 1e-3,
+                }
+            ]
+        ]
+    }
+}
+
+fn measure_3d_convert<Number: TestNumber>(tolerance: f64) {
+    let m1 = Measure3d::<Metre, Number>::new([number(12.), number(23.), number(34.)]);
+    let m2: Measure3d<Millimetre, Number> = m1.convert::<Millimetre>();
+    check_close_values(m1.values, [12., 23., 34.], tolerance);
+    check_close_values(m2.values, [12000., 23000., 34000.], tolerance);
+}
+
+test_number_types!(measure_3d_convert);
+
+fn measure_3d_norm_positive<Number: TestNumber>(tolerance: f64) {
+    let m1 = Measure3d::<Metre, Number>::new([number(12.), number(23.), number(34.)]);
+    let m2: Measure<Metre, Number> = m1.norm();
+    check_close(
+        m2.value,
+        (12_f64 * 12. + 23. * 23. + 34. * 34.).sqrt(),
+        tolerance,
+    );
+}
+
+test_number_types!(measure_3d_norm_positive);
+
+fn measure_3d_norm_negative<Number: TestNumber>(tolerance: f64) {
+    let m1 = Measure3d::<Metre, Number>::new([number(-12.), number(-23.), number(-34.)]);
+    let m2: Measure<Metre, Number> = m1.norm();
+    check_close(
+        m2.value,
+        (12_f64 * 12. + 23. * 23. + 34. * 34.).sqrt(),
+        tolerance,
+    );
+}
+
+test_number_types!(measure_3d_norm_negative);
+
+fn measure_3d_norm_zero<Number: TestNumber>(tolerance: f64) {
+    let m1 = Measure3d::<Metre, Number>::new([number(0.), number(0.), number(0.)]);
+    let m2: Measure<Metre, Number> = m1.norm();
+    check_close(m2.value, 0., tolerance);
+}
+
+test_number_types!(measure_3d_norm_zero);
+
+fn measure_3d_squared_norm_positive<Number: TestNumber>(tolerance: f64) {
+    let m = Measure3d::<Metre, Number>::new([number(12.), number(23.), number(34.)]);
+    let n: Number = m.squared_norm();
+    check_close(n, 12. * 12. + 23. * 23. + 34. * 34., tolerance);
+}
+
+test_number_types!(measure_3d_squared_norm_positive);
+
+fn measure_3d_squared_norm_negative<Number: TestNumber>(tolerance: f64) {
+    let m = Measure3d::<Metre, Number>::new([number(-12.), number(-23.), number(-34.)]);
+    let n: Number = m.squared_norm();
+    check_close(n, 12. * 12. + 23. * 23. + 34. * 34., tolerance);
+}
+
+test_number_types!(measure_3d_squared_norm_negative);
+
+fn measure_3d_squared_norm_zero<Number: TestNumber>(tolerance: f64) {
+    let m = Measure3d::<Metre, Number>::new([number(0.), number(0.), number(0.)]);
+    let n: Number = m.squared_norm();
+    check_close(n, 0., tolerance);
+}
+
+test_number_types!(measure_3d_squared_norm_zero);
+
+fn measure_3d_normalized_positive<Number: TestNumber>(tolerance: f64) {
+    let values = [12., 23., 34.];
+    let m1 = Measure3d::<Metre, Number>::new(values.map(number));
+    let m2: Measure3d<Metre, Number> = m1.normalized();
+    check_close(m2.squared_norm(), 1., tolerance);
+    let norm = (values[0] * values[0] + values[1] * values[1] + values[2] * values[2]).sqrt();
+    check_close_values(m2.values, values.map(|value| value / norm), tolerance);
+}
+
+test_number_types!(measure_3d_normalized_positive);
+
+fn measure_3d_normalized_x_negative<Number: TestNumber>(tolerance: f64) {
+    let values = [-12., 23., 34.];
+    let m1 = Measure3d::<Metre, Number>::new(values.map(number));
+    let m2: Measure3d<Metre, Number> = m1.normalized();
+    check_close(m2.squared_norm(), 1., tolerance);
+    let norm = (values[0] * values[0] + values[1] * values[1] + values[2] * values[2]).sqrt();
+    check_close_values(m2.values, values.map(|value| value / norm), tolerance);
+}
+
+test_number_types!(measure_3d_normalized_x_negative);
+
+fn measure_3d_normalized_y_negative<Number: TestNumber>(tolerance: f64) {
+    let values = [12., -23., 34.];
+    let m1 = Measure3d::<Metre, Number>::new(values.map(number));
+    let m2: Measure3d<Metre, Number> = m1.normalized();
+    check_close(m2.squared_norm(), 1., tolerance);
+    let norm = (values[0] * values[0] + values[1] * values[1] + values[2] * values[2]).sqrt();
+    check_close_values(m2.values, values.map(|value| value / norm), tolerance);
+}
+
+test_number_types!(measure_3d_normalized_y_negative);
+
+fn measure_3d_normalized_z_negative<Number: TestNumber>(tolerance: f64) {
+    let values = [12., 23., -34.];
+    let m1 = Measure3d::<Metre, Number>::new(values.map(number));
+    let m2: Measure3d<Metre, Number> = m1.normalized();
+    check_close(m2.squared_norm(), 1., tolerance);
+    let norm = (values[0] * values[0] + values[1] * values[1] + values[2] * values[2]).sqrt();
+    check_close_values(m2.values, values.map(|value| value / norm), tolerance);
+}
+
+test_number_types!(measure_3d_normalized_z_negative);
+
+fn measure_3d_normalized_xyz_negative<Number: TestNumber>(tolerance: f64) {
+    let values = [-12., -23., -34.];
+    let m1 = Measure3d::<Metre, Number>::new(values.map(number));
+    let m2: Measure3d<Metre, Number> = m1.normalized();
+    check_close(m2.squared_norm(), 1., tolerance);
+    let norm = (values[0] * values[0] + values[1] * values[1] + values[2] * values[2]).sqrt();
+    check_close_values(m2.values, values.map(|value| value / norm), tolerance);
+}
+
+test_number_types!(measure_3d_normalized_xyz_negative);
+
+fn measure_3d_unary_minus<Number: TestNumber>(tolerance: f64) {
+    let m = -Measure3d::<Metre, Number>::new([number(12.), number(23.), number(34.)]);
+    check_close_values(m.values, [-12., -23., -34.], tolerance);
+
+    let m = -Measure3d::<Metre, Number>::new([number(-12.), number(-23.), number(-34.)]);
+    check_close_values(m.values, [12., 23., 34.], tolerance);
+
+    let m = -Measure3d::<Metre, Number>::new([number(0.), number(0.), number(0.)]);
+    check_close_values(m.values, [0., 0., 0.], tolerance);
+}
+
+test_number_types!(measure_3d_unary_minus);
+
+fn measure_3d_addition<Number: TestNumber>(tolerance: f64) {
+    let m1 = Measure3d::<Metre, Number>::new([number(12.), number(23.), number(34.)]);
+    let m2 = Measure3d::<Metre, Number>::new([number(45.), number(-56.), number(67.)]);
+    let m3: Measure3d<Metre, Number> = m1 + m2;
+    check_close_values(m1.values, [12., 23., 34.], tolerance);
+    check_close_values(m2.values, [45., -56., 67.], tolerance);
+    check_close_values(m3.values, [12. + 45., 23. + -56., 34. + 67.], tolerance);
+}
+
+test_number_types!(measure_3d_addition);
+
+fn measure_3d_addition_assignment<Number: TestNumber>(tolerance: f64) {
+    let mut m1 = Measure3d::<Metre, Number>::new([number(12.), number(23.), number(34.)]);
+    let m2 = Measure3d::<Metre, Number>::new([number(34.), number(-45.), number(67.)]);
+    m1 += m2;
+    check_close_values(m1.values, [12. + 34., 23. + -45., 34. + 67.], tolerance);
+    check_close_values(m2.values, [34., -45., 67.], tolerance);
+}
+
+test_number_types!(measure_3d_addition_assignment);
+
+fn measure_3d_subtraction<Number: TestNumber>(tolerance: f64) {
+    let m1 = Measure3d::<Metre, Number>::new([number(12.), number(23.), number(34.)]);
+    let m2 = Measure3d::<Metre, Number>::new([number(45.), number(-56.), number(67.)]);
+    let m3 = m1 - m2;
+    check_close_values(m1.values, [12., 23., 34.], tolerance);
+    check_close_values(m2.values, [45., -56., 67.], tolerance);
+    check_close_values(m3.values, [12. - 45., 23. - -56., 34. - 67.], tolerance);
+}
+
+test_number_types!(measure_3d_subtraction);
+
+fn measure_3d_subtraction_assignment<Number: TestNumber>(tolerance: f64) {
+    let mut m1 = Measure3d::<Metre, Number>::new([number(12.), number(23.), number(34.)]);
+    let m2 = Measure3d::<Metre, Number>::new([number(45.), number(-56.), number(67.)]);
+    m1 -= m2;
+    check_close_values(m1.values, [12. - 45., 23. - -56., 34. - 67.], tolerance);
+    check_close_values(m2.values, [45., -56., 67.], tolerance);
+}
+
+test_number_types!(measure_3d_subtraction_assignment);
+
+fn measure_3d_scalar_post_multiplication<Number: TestNumber>(tolerance: f64) {
+    let m: Measure3d<Metre, Number> =
+        Measure3d::<Metre, Number>::new([number(12.), number(23.), number(34.)])
+            * number::<Number>(3.);
+    check_close_values(m.values, [12. * 3., 23. * 3., 34. * 3.], tolerance);
+}
+
+test_number_types!(measure_3d_scalar_post_multiplication);
+
+fn measure_3d_one_post_multiplication<Number: TestNumber>(tolerance: f64) {
+    let m = Measure3d::<Metre, Number>::new([number(12.), number(23.), number(34.)])
+        * Measure::<One, Number>::new(number(3.));
+    check_close_values(m.values, [12. * 3., 23. * 3., 34. * 3.], tolerance);
+}
+
+test_number_types!(measure_3d_one_post_multiplication);
+
+fn measure_3d_one_3d_post_multiplication<Number: TestNumber>(tolerance: f64) {
+    let m = Measure::<Metre, Number>::new(number(12.))
+        * Measure3d::<One, Number>::new([number(3.), number(4.), number(-7.)]);
+    check_close_values(m.values, [12. * 3., 12. * 4., 12. * -7.], tolerance);
+}
+
+test_number_types!(measure_3d_one_3d_post_multiplication);
+
+fn measure_3d_one_pre_multiplication<Number: TestNumber>(tolerance: f64) {
+    let m = Measure::<One, Number>::new(number(12.))
+        * Measure3d::<Metre, Number>::new([number(3.), number(4.), number(-7.)]);
+    check_close_values(m.values, [12. * 3., 12. * 4., 12. * -7.], tolerance);
+}
+
+test_number_types!(measure_3d_one_pre_multiplication);
+
+fn measure_3d_one_3d_pre_multiplication<Number: TestNumber>(tolerance: f64) {
+    let m = Measure3d::<One, Number>::new([number(12.), number(23.), number(34.)])
+        * Measure::<Metre, Number>::new(number(3.));
+    check_close_values(m.values, [12. * 3., 23. * 3., 34. * 3.], tolerance);
+}
+
+test_number_types!(measure_3d_one_3d_pre_multiplication);
+
+fn measure_3d_one_one_multiplication<Number: TestNumber>(tolerance: f64) {
+    let m = Measure::<One, Number>::new(number(12.))
+        * Measure3d::<One, Number>::new([number(3.), number(4.), number(-7.)]);
+    check_close_values(m.values, [12. * 3., 12. * 4., 12. * -7.], tolerance);
+}
+
+test_number_types!(measure_3d_one_one_multiplication);
+
+fn measure_3d_one_3d_one_multiplication<Number: TestNumber>(tolerance: f64) {
+    let m = Measure3d::<One, Number>::new([number(12.), number(23.), number(34.)])
+        * Measure::<One, Number>::new(number(3.));
+    check_close_values(m.values, [12. * 3., 23. * 3., 34. * 3.], tolerance);
+}
+
+test_number_types!(measure_3d_one_3d_one_multiplication);
+
+fn measure_3d_scalar_multiplication_assignment<Number: TestNumber>(tolerance: f64) {
+    let mut m = Measure3d::<Metre, Number>::new([number(12.), number(23.), number(34.)]);
+    m *= number::<Number>(3.);
+    check_close_values(m.values, [12. * 3., 23. * 3., 34. * 3.], tolerance);
+}
+
+test_number_types!(measure_3d_scalar_multiplication_assignment);
+
+fn measure_3d_one_multiplication_assignment<Number: TestNumber>(tolerance: f64) {
+    let mut m = Measure3d::<Metre, Number>::new([number(12.), number(23.), number(34.)]);
+    m *= Measure::<One, Number>::new(number(3.));
+    check_close_values(m.values, [12. * 3., 23. * 3., 34. * 3.], tolerance);
+}
+
+test_number_types!(measure_3d_one_multiplication_assignment);
+
+fn measure_3d_scalar_division<Number: TestNumber>(tolerance: f64) {
+    let m = Measure3d::<Metre, Number>::new([number(12.), number(48.), number(-21.)])
+        / number::<Number>(3.);
+    check_close_values(m.values, [4., 16., -7.], tolerance);
+}
+
+test_number_types!(measure_3d_scalar_division);
+
+fn measure_3d_measure_division<Number: TestNumber>(tolerance: f64) {
+    let m1 = Measure3d::<Metre, Number>::new([number(12.), number(48.), number(-21.)]);
+    let m2 = Measure::<Metre, Number>::new(number(3.));
+    let m3: Measure3d<One, Number> = m1 / m2;
+    check_close_values(m3.values, [4., 16., -7.], tolerance);
+}
+
+test_number_types!(measure_3d_measure_division);
+
+fn measure_3d_one_division<Number: TestNumber>(tolerance: f64) {
+    let m1 = Measure3d::<Metre, Number>::new([number(12.), number(48.), number(-21.)]);
+    let m2 = Measure::<One, Number>::new(number(3.));
+    let m3: Measure3d<Metre, Number> = m1 / m2;
+    check_close_values(m3.values, [4., 16., -7.], tolerance);
+}
+
+test_number_types!(measure_3d_one_division);
+
+fn measure_3d_one_one_division<Number: TestNumber>(tolerance: f64) {
+    let m1 = Measure3d::<One, Number>::new([number(12.), number(48.), number(-21.)]);
+    let m2 = Measure::<One, Number>::new(number(3.));
+    let m3: Measure3d<One, Number> = m1 / m2;
+    check_close_values(m3.values, [4., 16., -7.], tolerance);
+}
+
+test_number_types!(measure_3d_one_one_division);
+
+fn measure_3d_scalar_division_assignment<Number: TestNumber>(tolerance: f64) {
+    let mut m = Measure3d::<Metre, Number>::new([number(12.), number(48.), number(-21.)]);
+    m /= number::<Number>(3.);
+    check_close_values(m.values, [4., 16., -7.], tolerance);
+}
+
+test_number_types!(measure_3d_scalar_division_assignment);
+
+fn measure_3d_one_division_assignment<Number: TestNumber>(tolerance: f64) {
+    let mut m = Measure3d::<Metre, Number>::new([number(12.), number(48.), number(-21.)]);
+    m /= Measure::<One, Number>::new(number(3.));
+    check_close_values(m.values, [4., 16., -7.], tolerance);
+}
+
+test_number_types!(measure_3d_one_division_assignment);
@@ -0,0 +1,195 @@
+use crate::backends::{check_close, number, BasicTestNumber, TestNumber};
+use measures::traits::Trigonometry;
+use units::{
+    barycentric_combination, midpoint, weighted_midpoint, Celsius, Degree, Fahrenheit, Measure,
+    MeasurePoint,
+};
+
+mod units {
+    measures::define_measure_types! {
+        with_points exact,
+        scalar_properties [
+            Temperature [
+                Celsius {
+                    suffix: " \u{B0}C",
+                    ratio: 1.,
+                    offset: 273.15,
+                }
+                Fahrenheit {
+                    suffix: " \u{B0}F",
+                    ratio: 5. / 9.,
+                    offset: 273.15 - 32. * 5. / 9.,
+                }
+            ]
+        ]
+        angle_measurement_units [
+            Degree {
+                suffix: " deg",
+                cycle_fraction: 360.,
+            }
+        ]
+    }
+}
+
+fn measure_point_1d_convert<Number: TestNumber>(tolerance: f64) {
+    // 0 °C is 32 °F
+    let mp1 = MeasurePoint::<Celsius, Number>::new(number(0.));
+    let mp2: MeasurePoint<Fahrenheit, Number> = mp1.convert::<Fahrenheit>();
+    check_close(mp2.value, 32., tolerance);
+
+    // 68 °F is 20 °C
+    let mp3 = MeasurePoint::<Fahrenheit, Number>::new(number(68.));
+    let mp4: MeasurePoint<Celsius, Number> = mp3.convert::<Celsius>();
+    check_close(mp4.value, 20., tolerance);
+}
+
+test_number_types!(measure_point_1d_convert);
+
+fn measure_min<Number: BasicTestNumber>(n: fn(i16) -> Number) {
+    let m1 = MeasurePoint::<Celsius, Number>::new(n(12));
+    let m2 = MeasurePoint::<Celsius, Number>::new(n(13));
+    let m3: MeasurePoint<Celsius, Number> = m1.min(m2);
+    let m4: MeasurePoint<Celsius, Number> = m2.min(m1);
+    let m5: MeasurePoint<Celsius, Number> = m1.min(m1);
+    assert_eq!(m3.value, n(12));
+    assert_eq!(m4.value, n(12));
+    assert_eq!(m5.value, n(12));
+}
+
+test_basic_number_types!(measure_min);
+
+fn measure_max<Number: BasicTestNumber>(n: fn(i16) -> Number) {
+    let m1 = MeasurePoint::<Celsius, Number>::new(n(12));
+    let m2 = MeasurePoint::<Celsius, Number>::new(n(13));
+    let m3: MeasurePoint<Celsius, Number> = m1.max(m2);
+    let m4: MeasurePoint<Celsius, Number> = m2.max(m1);
+    let m5: MeasurePoint<Celsius, Number> = m1.max(m1);
+    assert_eq!(m3.value, n(13));
+    assert_eq!(m4.value, n(13));
+    assert_eq!(m5.value, n(12));
+}
+
+test_basic_number_types!(measure_max);
+
+fn measure_clamp<Number: BasicTestNumber>(n: fn(i16) -> Number) {
+    let m1 = MeasurePoint::<Celsius, Number>::new(n(12));
+    let m2 = MeasurePoint::<Celsius, Number>::new(n(13));
+    let m3 = MeasurePoint::<Celsius, Number>::new(n(14));
+    assert_eq!(m1.clamp(m2, m3), m2);
+    assert_eq!(m1.clamp(m3, m2), m2);
+    assert_eq!(m2.clamp(m1, m3), m2);
+    assert_eq!(m2.clamp(m3, m1), m2);
+    assert_eq!(m3.clamp(m1, m2), m2);
+    assert_eq!(m3.clamp(m2, m1), m2);
+}
+
+test_basic_number_types!(measure_clamp);
+
+fn measure_point_1d_default<Number: BasicTestNumber>(n: fn(i16) -> Number) {
+    let mp: MeasurePoint<Celsius, Number> = MeasurePoint::default();
+    assert_eq!(mp.value, n(0));
+}
+
+test_basic_number_types!(measure_point_1d_default);
+
+fn measure_point_1d_addition_of_measure<Number: BasicTestNumber>(n: fn(i16) -> Number) {
+    let mp1 = MeasurePoint::<Celsius, Number>::new(n(12));
+    let m = Measure::<Celsius, Number>::new(n(7));
+    let mp2: MeasurePoint<Celsius, Number> = mp1 + m;
+    assert_eq!(mp2.value, n(19));
+}
+
+test_basic_number_types!(measure_point_1d_addition_of_measure);
+
+fn measure_point_1d_addition_of_measure_assignment<Number: BasicTestNumber>(n: fn(i16) -> Number) {
+    let mut mp = MeasurePoint::<Celsius, Number>::new(n(12));
+    mp += Measure::<Celsius, Number>::new(n(7));
+    assert_eq!(mp.value, n(19));
+}
+
+test_basic_number_types!(measure_point_1d_addition_of_measure_assignment);
+
+fn measure_point_1d_subtraction_of_measure<Number: BasicTestNumber>(n: fn(i16) -> Number) {
+    let mp1 = MeasurePoint::<Celsius, Number>::new(n(12));
+    let m = Measure::<Celsius, Number>::new(n(7));
+    let mp2: MeasurePoint<Celsius, Number> = mp1 - m;
+    assert_eq!(mp2.value, n(5));
+}
+
+test_basic_number_types!(measure_point_1d_subtraction_of_measure);
+
+fn measure_point_1d_subtraction_of_measure_assignment<Number: BasicTestNumber>(
+    n: fn(i16) -> Number,
+) {
+    let mut mp = MeasurePoint::<Celsius, Number>::new(n(12));
+    mp -= Measure::<Celsius, Number>::new(n(7));
+    assert_eq!(mp.value, n(5));
+}
+
+test_basic_number_types!(measure_point_1d_subtraction_of_measure_assignment);
+
+fn measures_point_subtraction<Number: BasicTestNumber>(n: fn(i16) -> Number) {
+    let mp1 = MeasurePoint::<Celsius, Number>::new(n(12));
+    let mp2 = MeasurePoint::<Celsius, Number>::new(n(7));
+    let m: Measure<Celsius, Number> = mp1 - mp2;
+    assert_eq!(m.value, n(5));
+}
+
+test_basic_number_types!(measures_point_subtraction);
+
+fn measures_point_weighted_midpoint<Number: TestNumber>(tolerance: f64) {
+    let mp1 = MeasurePoint::<Celsius, Number>::new(number(20.));
+    let mp2 = MeasurePoint::<Celsius, Number>::new(number(30.));
+    let mp3: MeasurePoint<Celsius, Number> = weighted_midpoint(mp1, mp2, number(0.4));
+    check_close(mp3.value, 26., tolerance);
+}
+
+test_number_types!(measures_point_weighted_midpoint);
+
+fn measures_point_midpoint<Number: TestNumber>(tolerance: f64) {
+    let mp1 = MeasurePoint::<Celsius, Number>::new(number(20.));
+    let mp2 = MeasurePoint::<Celsius, Number>::new(number(30.));
+    let mp3: MeasurePoint<Celsius, Number> = midpoint(mp1, mp2);
+    check_close(mp3.value, 25., tolerance);
+}
+
+test_number_types!(measures_point_midpoint);
+
+fn measures_point_barycentric_combination<Number: TestNumber>(tolerance: f64) {
+    let mp1 = MeasurePoint::<Celsius, Number>::new(number(20.));
+    let mp2 = MeasurePoint::<Celsius, Number>::new(number(30.));
+    let mp3 = MeasurePoint::<Celsius, Number>::new(number(80.));
+    let mp4: MeasurePoint<Celsius, Number> =
+        barycentric_combination(&[mp1, mp2, mp3], &[number(0.1), number(0.3), number(0.7)]);
+    check_close(mp4.value, 67., tolerance);
+}
+
+test_number_types!(measures_point_barycentric_combination);
+
+fn measure_trigonometry<Number: TestNumber>(tolerance: f64) {
+    let half_sqrt = 1. / 2_f64.sqrt();
+    let three_sqrt = 3_f64.sqrt();
+    let check_angle = |degrees: f64, sin: f64, cos: f64| {
+        let m = MeasurePoint::<Degree, Number>::new(number(degrees));
+        check_close(m.cos(), cos, tolerance);
+        check_close(m.sin(), sin, tolerance);
+        check_close(m.tan(), sin / cos, tolerance);
+        check_close(m.sin_cos().0, sin, tolerance);
+        check_close(m.sin_cos().1, cos, tolerance);
+    };
+
+    check_angle(0., 0., 1.);
+    check_angle(30., 0.5, three_sqrt * 0.5);
+    check_angle(45., half_sqrt, half_sqrt);
+    check_angle(60., three_sqrt * 0.5, 0.5);
+    check_angle(-45., -half_sqrt, half_sqrt);
+    check_angle(-135., -half_sqrt, -half_sqrt);
+
+    let m = MeasurePoint::<Degree, Number>::new(number(90.));
+    check_close(m.cos(), 0., tolerance);
+    check_close(m.sin(), 1., tolerance);
+    check_close(m.sin_cos().0, 1., tolerance);
+    check_close(m.sin_cos().1, 0., tolerance);
+}
+
+test_number_types!(measure_trigonometry);
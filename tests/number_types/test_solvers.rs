@@ -0,0 +1,150 @@
+use crate::backends::{check_close, number, TestNumber};
+use measures::traits::ArithmeticOps;
+use units::{
+    bisection_root, brent_root, central_difference, golden_section_minimum, newton_root, Joule,
+    Measure, Metre, Newton, NewtonPerMetre,
+};
+
+mod units {
+    measures::define_measure_types! {
+        exact,
+        scalar_properties [
+            Energy [
+                Joule {
+                    suffix: " J",
+                }
+            ]
+            Stiffness [
+                NewtonPerMetre {
+                    suffix: " N/m",
+                }
+            ]
+        ]
+        vector_properties [
+            Length [
+                Metre {
+                    suffix: " m",
+                }
+            ]
+            Force [
+                Newton {
+                    suffix: " N",
+                }
+            ]
+        ]
+        relationships [
+            Newton 1 == NewtonPerMetre 1 * Metre 1,
+        ]
+    }
+}
+
+// x³ - 2 x - 5, having a root at about 2.0945514815423265 m.
+fn force<Number: ArithmeticOps>(x: Measure<Metre, Number>) -> Measure<Newton, Number> {
+    Measure::new(x.value * x.value * x.value - number::<Number>(2.) * x.value - number(5.))
+}
+
+// 3 x² - 2
+fn force_derivative<Number: ArithmeticOps>(
+    x: Measure<Metre, Number>,
+) -> Measure<NewtonPerMetre, Number> {
+    Measure::new(number::<Number>(3.) * x.value * x.value - number(2.))
+}
+
+const ROOT: f64 = 2.0945514815423265;
+
+fn bisection_root_measure<Number: TestNumber>(tolerance: f64) {
+    let accuracy = tolerance.max(1e-9);
+    let tolerance = Measure::<Metre, Number>::new(number(accuracy));
+    let bound = |value| Measure::new(number(value));
+    let root = bisection_root(force, bound(2.), bound(3.), tolerance, 100).unwrap();
+    check_close(root.value, ROOT, accuracy);
+    let root = bisection_root(force, bound(3.), bound(2.), tolerance, 100).unwrap();
+    check_close(root.value, ROOT, accuracy);
+    assert!(bisection_root(force, bound(3.), bound(4.), tolerance, 100).is_none());
+}
+
+test_number_types!(bisection_root_measure);
+
+fn brent_root_measure<Number: TestNumber>(tolerance: f64) {
+    let accuracy = tolerance.max(1e-12);
+    let tolerance = Measure::<Metre, Number>::new(number(accuracy));
+    let bound = |value| Measure::new(number(value));
+    let mut evaluations = 0;
+    let root = brent_root(
+        |x| {
+            evaluations += 1;
+            force(x)
+        },
+        bound(2.),
+        bound(3.),
+        tolerance,
+        100,
+    )
+    .unwrap();
+    check_close(root.value, ROOT, accuracy);
+    // Much faster than bisection.
+    assert!(evaluations < 15);
+    let root = brent_root(force, bound(3.), bound(-1.), tolerance, 100).unwrap();
+    check_close(root.value, ROOT, accuracy);
+    assert!(brent_root(force, bound(3.), bound(4.), tolerance, 100).is_none());
+}
+
+test_number_types!(brent_root_measure, ordered);
+
+fn newton_root_measure<Number: TestNumber>(tolerance: f64) {
+    let accuracy = tolerance.max(1e-12);
+    let tolerance = Measure::<Metre, Number>::new(number(accuracy));
+    let start = Measure::new(number(2.));
+    let root = newton_root(force, force_derivative, start, tolerance, 20).unwrap();
+    check_close(root.value, ROOT, accuracy);
+
+    // With a numerical derivative, whose step is suited to the precision of the number type.
+    let step = Measure::new(number(accuracy.sqrt()));
+    let root = newton_root(
+        force,
+        |x| central_difference(force, x, step),
+        start,
+        tolerance,
+        20,
+    )
+    .unwrap();
+    check_close(root.value, ROOT, accuracy);
+
+    // Zero derivative.
+    assert!(newton_root(
+        force,
+        |_| Measure::<NewtonPerMetre, Number>::new(Number::ZERO),
+        start,
+        tolerance,
+        20
+    )
+    .is_none());
+
+    // Too few iterations.
+    let far_start = Measure::new(number(100.));
+    assert!(newton_root(force, force_derivative, far_start, tolerance, 3).is_none());
+}
+
+test_number_types!(newton_root_measure, ordered);
+
+fn golden_section_minimum_measure<Number: TestNumber>(tolerance: f64) {
+    // The position of a minimum cannot be found more accurately
+    // than the square root of the precision of the number type.
+    let accuracy = tolerance.sqrt().max(1e-8);
+    // Potential energy of a spring having rest length 1.5 m.
+    let energy = |x: Measure<Metre, Number>| {
+        let elongation = x.value - number(1.5);
+        Measure::<Joule, Number>::new(elongation * elongation)
+    };
+    let minimum = golden_section_minimum(
+        energy,
+        Measure::new(number(-4.)),
+        Measure::new(number(10.)),
+        Measure::new(number(accuracy)),
+        100,
+    )
+    .unwrap();
+    check_close(minimum.value, 1.5, accuracy);
+}
+
+test_number_types!(golden_section_minimum_measure);
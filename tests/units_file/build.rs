@@ -0,0 +1,5 @@
+fn main() {
+    if let Err(error) = measures_build::generate("../../examples/units/units.toml", "units.rs") {
+        panic!("{}", error);
+    }
+}
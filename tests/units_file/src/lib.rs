@@ -0,0 +1,8 @@
+//! Check that the units file `examples/units/units.toml`, read by the crate `measures-build`
+//! in the build script, generates an invocation of the macro `define_measure_types!` which compiles.
+//! It is only built, by `cargo build --workspace`.
+
+#[allow(dead_code)]
+pub mod units {
+    include!(concat!(env!("OUT_DIR"), "/units.rs"));
+}